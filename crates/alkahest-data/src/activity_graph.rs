@@ -0,0 +1,308 @@
+use std::fmt::Write;
+
+use alkahest_pm::package_manager;
+use destiny_pkg::TagHash;
+use rustc_hash::FxHashMap;
+use serde::Serialize;
+use tiger_parse::{PackageManagerExt, TigerReadable};
+use tracing::error;
+
+use crate::{
+    activity::{SActivity, SDestination},
+    map::SBubbleParentShallow,
+    text::StringContainer,
+};
+
+#[derive(Serialize, Copy, Clone, PartialEq, Eq, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum GraphNodeKind {
+    Destination,
+    Activity,
+    Map,
+    Patrols,
+    Tagbag,
+}
+
+#[derive(Serialize, Copy, Clone, PartialEq, Eq, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum GraphEdgeKind {
+    /// Destination -> activity
+    Activity,
+    /// Activity -> the ambient activity it is based on
+    AmbientActivity,
+    /// Activity -> map, labeled with the bubble name
+    Bubble,
+    /// Destination -> patrol table
+    Patrols,
+    /// Destination -> tagbag
+    Tagbag,
+}
+
+#[derive(Serialize, Debug)]
+pub struct GraphNode {
+    /// Tag hash of the node, unique within the graph
+    pub id: String,
+    pub kind: GraphNodeKind,
+    /// Localized name, if one could be resolved
+    pub name: Option<String>,
+    /// Internal name, such as the destination or activity code
+    pub code: Option<String>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct GraphEdge {
+    pub from: String,
+    pub to: String,
+    pub kind: GraphEdgeKind,
+    pub label: Option<String>,
+}
+
+/// Graph of all destinations, their activities and the maps those activities load
+#[derive(Serialize, Default)]
+pub struct ActivityGraph {
+    pub nodes: Vec<GraphNode>,
+    pub edges: Vec<GraphEdge>,
+
+    #[serde(skip)]
+    node_indices: FxHashMap<TagHash, usize>,
+}
+
+impl ActivityGraph {
+    pub fn build(global_strings: &StringContainer) -> Self {
+        let mut graph = Self::default();
+
+        for (destination_hash, _) in
+            package_manager().get_all_by_reference(SDestination::ID.unwrap())
+        {
+            let destination: SDestination =
+                match package_manager().read_tag_struct(destination_hash) {
+                    Ok(d) => d,
+                    Err(e) => {
+                        error!("Failed to read SDestination {destination_hash}: {e}");
+                        continue;
+                    }
+                };
+
+            let strings = StringContainer::load(destination.string_container).unwrap_or_default();
+            let resolve = |hash: u32| {
+                strings
+                    .try_get(hash)
+                    .or_else(|| global_strings.try_get(hash))
+            };
+
+            let destination_code = destination.destination_name.to_string();
+            graph.add_node(
+                destination_hash,
+                GraphNodeKind::Destination,
+                resolve(destination.location_name.0),
+                Some(destination_code.clone()),
+            );
+
+            if destination.patrols.is_some() {
+                graph.add_node(destination.patrols, GraphNodeKind::Patrols, None, None);
+                graph.add_edge(
+                    destination_hash,
+                    destination.patrols,
+                    GraphEdgeKind::Patrols,
+                    None,
+                );
+            }
+
+            for tagbag in destination.tagbags.iter().filter(|t| t.is_some()) {
+                graph.add_node(*tagbag, GraphNodeKind::Tagbag, None, None);
+                graph.add_edge(destination_hash, *tagbag, GraphEdgeKind::Tagbag, None);
+            }
+
+            for activity in &destination.activities {
+                let activity_code = activity.activity_code.to_string();
+                let Some(activity_hash) =
+                    package_manager().get_named_tag(&activity_code, SActivity::ID.unwrap())
+                else {
+                    error!(
+                        "Failed to find activity {activity_code} in destination {destination_code}"
+                    );
+                    continue;
+                };
+
+                graph.add_node(
+                    activity_hash,
+                    GraphNodeKind::Activity,
+                    resolve(activity.activity_name.0),
+                    Some(activity_code),
+                );
+                graph.add_edge(
+                    destination_hash,
+                    activity_hash,
+                    GraphEdgeKind::Activity,
+                    None,
+                );
+
+                if let Err(e) = graph.add_activity_maps(activity_hash, &resolve) {
+                    error!("Failed to read activity {activity_hash}: {e}");
+                }
+            }
+        }
+
+        graph
+    }
+
+    fn add_activity_maps(
+        &mut self,
+        activity_hash: TagHash,
+        resolve: &impl Fn(u32) -> Option<String>,
+    ) -> anyhow::Result<()> {
+        let activity: SActivity = package_manager().read_tag_struct(activity_hash)?;
+
+        let ambient_activity = activity.ambient_activity.hash32();
+        if ambient_activity.is_some() {
+            self.add_node(ambient_activity, GraphNodeKind::Activity, None, None);
+            self.add_edge(
+                activity_hash,
+                ambient_activity,
+                GraphEdgeKind::AmbientActivity,
+                None,
+            );
+        }
+
+        for bubble in &activity.unk50 {
+            for map in &bubble.map_references {
+                let map_hash = map.hash32();
+                if map_hash.is_none() {
+                    continue;
+                }
+
+                let map_name = package_manager()
+                    .read_tag_struct::<SBubbleParentShallow>(map_hash)
+                    .ok()
+                    .and_then(|m| resolve(m.map_name.0));
+
+                self.add_node(map_hash, GraphNodeKind::Map, map_name, None);
+                self.add_edge(
+                    activity_hash,
+                    map_hash,
+                    GraphEdgeKind::Bubble,
+                    resolve(bubble.bubble_name.0),
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Adds a node, or fills in the missing names of an existing one
+    fn add_node(
+        &mut self,
+        hash: TagHash,
+        kind: GraphNodeKind,
+        name: Option<String>,
+        code: Option<String>,
+    ) {
+        if let Some(&i) = self.node_indices.get(&hash) {
+            let node = &mut self.nodes[i];
+            node.name = node.name.take().or(name);
+            node.code = node.code.take().or(code);
+            return;
+        }
+
+        self.node_indices.insert(hash, self.nodes.len());
+        self.nodes.push(GraphNode {
+            id: hash.to_string(),
+            kind,
+            name,
+            code,
+        });
+    }
+
+    fn add_edge(&mut self, from: TagHash, to: TagHash, kind: GraphEdgeKind, label: Option<String>) {
+        let (from, to) = (from.to_string(), to.to_string());
+        if self
+            .edges
+            .iter()
+            .any(|e| e.from == from && e.to == to && e.kind == kind)
+        {
+            return;
+        }
+
+        self.edges.push(GraphEdge {
+            from,
+            to,
+            kind,
+            label,
+        });
+    }
+
+    pub fn to_json(&self) -> anyhow::Result<String> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    /// Writes the graph in Graphviz DOT format
+    pub fn to_dot(&self) -> String {
+        let mut out = String::new();
+        writeln!(out, "digraph activities {{").unwrap();
+        writeln!(out, "    rankdir=LR;").unwrap();
+        writeln!(out, "    node [fontname=\"Helvetica\"];").unwrap();
+
+        for node in &self.nodes {
+            let shape = match node.kind {
+                GraphNodeKind::Destination => "folder",
+                GraphNodeKind::Activity => "box",
+                GraphNodeKind::Map => "ellipse",
+                GraphNodeKind::Patrols => "note",
+                GraphNodeKind::Tagbag => "component",
+            };
+
+            let mut label = node
+                .name
+                .clone()
+                .or_else(|| node.code.clone())
+                .unwrap_or_else(|| node.id.clone());
+            if node.name.is_some() {
+                if let Some(code) = &node.code {
+                    label = format!("{label}\\n{code}");
+                }
+            }
+
+            writeln!(
+                out,
+                "    \"{}\" [shape={shape}, label=\"{}\\n{}\"];",
+                node.id,
+                dot_escape(&label),
+                node.id
+            )
+            .unwrap();
+        }
+
+        for edge in &self.edges {
+            let style = match edge.kind {
+                GraphEdgeKind::AmbientActivity => ", style=dashed",
+                GraphEdgeKind::Patrols | GraphEdgeKind::Tagbag => ", style=dotted",
+                _ => "",
+            };
+
+            match &edge.label {
+                Some(label) => writeln!(
+                    out,
+                    "    \"{}\" -> \"{}\" [label=\"{}\"{style}];",
+                    edge.from,
+                    edge.to,
+                    dot_escape(label)
+                ),
+                None => writeln!(
+                    out,
+                    "    \"{}\" -> \"{}\" [{}];",
+                    edge.from,
+                    edge.to,
+                    style.trim_start_matches(", ")
+                ),
+            }
+            .unwrap();
+        }
+
+        writeln!(out, "}}").unwrap();
+        out
+    }
+}
+
+fn dot_escape(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('"', "\\\"")
+}
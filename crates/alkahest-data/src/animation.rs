@@ -0,0 +1,337 @@
+//! Animation clips referenced by entity resources
+//!
+//! Clips are stored as per-node tracks with translation, rotation and scale keys spread evenly
+//! over the duration of the clip. A track with a single key holds that value for the whole clip.
+//! Node indices match the nodes of the entity skeleton ([`crate::entity::SSkeleton`]), node 0 being
+//! the root of the entity. Only uncompressed clips are parsed.
+
+use alkahest_pm::package_manager;
+use destiny_pkg::TagHash;
+use glam::{Quat, Vec3, Vec4};
+use tiger_parse::{tiger_tag, PackageManagerExt, TigerReadable};
+
+use crate::entity::{find_resource_references, SEntity};
+
+#[derive(Debug, Clone)]
+#[tiger_tag(id = 0x80808C0D)]
+pub struct SAnimationClip {
+    pub file_size: u64,
+    /// Length in seconds
+    pub duration: f32,
+    pub frame_rate: f32,
+    pub frame_count: u32,
+    pub node_count: u32,
+    pub tracks: Vec<SAnimationTrack>,
+}
+
+#[derive(Debug, Clone)]
+#[tiger_tag(id = 0x80808C10)]
+pub struct SAnimationTrack {
+    pub node_index: u16,
+    pub flags: u16,
+    pub unk4: u32,
+    pub translations: Vec<Vec4>,
+    pub rotations: Vec<Quat>,
+    pub scales: Vec<Vec4>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Interpolation {
+    Step,
+    Linear,
+}
+
+pub trait Interpolate: Copy {
+    fn interpolate(a: Self, b: Self, t: f32) -> Self;
+}
+
+impl Interpolate for Vec3 {
+    fn interpolate(a: Self, b: Self, t: f32) -> Self {
+        a.lerp(b, t)
+    }
+}
+
+impl Interpolate for Quat {
+    fn interpolate(a: Self, b: Self, t: f32) -> Self {
+        a.slerp(b, t).normalize()
+    }
+}
+
+/// Keyframe times in seconds, with a value per time. Times are sorted in ascending order
+#[derive(Debug, Clone)]
+pub struct Keyframes<T> {
+    pub times: Vec<f32>,
+    pub values: Vec<T>,
+    pub interpolation: Interpolation,
+}
+
+impl<T: Interpolate> Keyframes<T> {
+    /// Spreads `values` evenly over `0..=duration`
+    pub fn evenly_spaced(values: Vec<T>, duration: f32) -> Self {
+        let step = if values.len() > 1 {
+            duration / (values.len() - 1) as f32
+        } else {
+            0.0
+        };
+
+        Self {
+            times: (0..values.len()).map(|i| i as f32 * step).collect(),
+            values,
+            interpolation: Interpolation::Linear,
+        }
+    }
+
+    /// Samples the keyframes at `time`, holding the first and last values outside of the keyed range
+    pub fn sample(&self, time: f32) -> Option<T> {
+        let last = self.values.len().min(self.times.len()).checked_sub(1)?;
+        if time <= self.times[0] || last == 0 {
+            return Some(self.values[0]);
+        }
+        if time >= self.times[last] {
+            return Some(self.values[last]);
+        }
+
+        // Index of the first key after `time`
+        let next = self.times[..=last].partition_point(|&t| t <= time);
+        let prev = next - 1;
+        match self.interpolation {
+            Interpolation::Step => Some(self.values[prev]),
+            Interpolation::Linear => {
+                let span = self.times[next] - self.times[prev];
+                let t = if span > f32::EPSILON {
+                    (time - self.times[prev]) / span
+                } else {
+                    0.0
+                };
+                Some(T::interpolate(self.values[prev], self.values[next], t))
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NodeTransform {
+    pub translation: Vec3,
+    pub rotation: Quat,
+    pub scale: Vec3,
+}
+
+impl Default for NodeTransform {
+    fn default() -> Self {
+        Self {
+            translation: Vec3::ZERO,
+            rotation: Quat::IDENTITY,
+            scale: Vec3::ONE,
+        }
+    }
+}
+
+impl NodeTransform {
+    pub fn to_mat4(&self) -> glam::Mat4 {
+        glam::Mat4::from_scale_rotation_translation(self.scale, self.rotation, self.translation)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct NodeTrack {
+    pub node: usize,
+    pub translation: Option<Keyframes<Vec3>>,
+    pub rotation: Option<Keyframes<Quat>>,
+    pub scale: Option<Keyframes<Vec3>>,
+}
+
+impl NodeTrack {
+    /// Samples the track at `time`, taking unanimated channels from `rest`
+    pub fn sample(&self, time: f32, rest: NodeTransform) -> NodeTransform {
+        NodeTransform {
+            translation: self
+                .translation
+                .as_ref()
+                .and_then(|k| k.sample(time))
+                .unwrap_or(rest.translation),
+            rotation: self
+                .rotation
+                .as_ref()
+                .and_then(|k| k.sample(time))
+                .unwrap_or(rest.rotation),
+            scale: self
+                .scale
+                .as_ref()
+                .and_then(|k| k.sample(time))
+                .unwrap_or(rest.scale),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct AnimationClip {
+    pub hash: TagHash,
+    pub duration: f32,
+    pub tracks: Vec<NodeTrack>,
+}
+
+impl AnimationClip {
+    pub fn load(hash: TagHash) -> anyhow::Result<Self> {
+        let clip: SAnimationClip = package_manager().read_tag_struct(hash)?;
+        Ok(Self::from_data(hash, &clip))
+    }
+
+    pub fn from_data(hash: TagHash, clip: &SAnimationClip) -> Self {
+        let duration = clip.duration.max(0.0);
+        let keys = |values: Vec<Vec3>| {
+            (!values.is_empty()).then(|| Keyframes::evenly_spaced(values, duration))
+        };
+
+        let tracks = clip
+            .tracks
+            .iter()
+            .map(|t| NodeTrack {
+                node: t.node_index as usize,
+                translation: keys(t.translations.iter().map(|v| v.truncate()).collect()),
+                rotation: (!t.rotations.is_empty()).then(|| {
+                    Keyframes::evenly_spaced(
+                        t.rotations.iter().map(|q| q.normalize()).collect(),
+                        duration,
+                    )
+                }),
+                scale: keys(t.scales.iter().map(|v| v.truncate()).collect()),
+            })
+            .collect();
+
+        Self {
+            hash,
+            duration,
+            tracks,
+        }
+    }
+
+    /// Wraps `time` into the clip when looping, or clamps it otherwise
+    pub fn clip_time(&self, time: f32, looping: bool) -> f32 {
+        if self.duration <= f32::EPSILON {
+            0.0
+        } else if looping {
+            time.rem_euclid(self.duration)
+        } else {
+            time.clamp(0.0, self.duration)
+        }
+    }
+
+    pub fn track(&self, node: usize) -> Option<&NodeTrack> {
+        self.tracks.iter().find(|t| t.node == node)
+    }
+
+    /// Samples every animated node at `time`, returning `(node index, transform)` pairs
+    pub fn sample(&self, time: f32, looping: bool) -> Vec<(usize, NodeTransform)> {
+        let time = self.clip_time(time, looping);
+        self.tracks
+            .iter()
+            .map(|t| (t.node, t.sample(time, NodeTransform::default())))
+            .collect()
+    }
+}
+
+/// Finds the animation clips referenced by the resources of an entity
+///
+/// The resource field holding the clips isn't mapped yet, so this falls back to
+/// [`find_resource_references`]
+pub fn find_entity_clips(entity: &SEntity) -> Vec<TagHash> {
+    find_resource_references(entity, SAnimationClip::ID.unwrap())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn clip() -> AnimationClip {
+        AnimationClip {
+            hash: TagHash::NONE,
+            duration: 2.0,
+            tracks: vec![NodeTrack {
+                node: 0,
+                translation: Some(Keyframes::evenly_spaced(
+                    vec![
+                        Vec3::ZERO,
+                        Vec3::new(2.0, 0.0, 0.0),
+                        Vec3::new(2.0, 4.0, 0.0),
+                    ],
+                    2.0,
+                )),
+                rotation: Some(Keyframes::evenly_spaced(
+                    vec![Quat::IDENTITY, Quat::from_rotation_z(std::f32::consts::PI)],
+                    2.0,
+                )),
+                scale: None,
+            }],
+        }
+    }
+
+    #[test]
+    fn sample_keyframes() {
+        let clip = clip();
+        let track = clip.track(0).unwrap();
+
+        let at = |time| track.sample(time, NodeTransform::default());
+        assert_eq!(at(0.0).translation, Vec3::ZERO);
+        assert_eq!(at(1.0).translation, Vec3::new(2.0, 0.0, 0.0));
+        assert!(at(0.5)
+            .translation
+            .abs_diff_eq(Vec3::new(1.0, 0.0, 0.0), 1e-6));
+        assert!(at(1.5)
+            .translation
+            .abs_diff_eq(Vec3::new(2.0, 2.0, 0.0), 1e-6));
+        assert_eq!(at(2.0).translation, Vec3::new(2.0, 4.0, 0.0));
+
+        // Halfway through a half turn around Z
+        assert!(at(1.0)
+            .rotation
+            .abs_diff_eq(Quat::from_rotation_z(std::f32::consts::FRAC_PI_2), 1e-5));
+        // Unanimated channels come from the rest transform
+        assert_eq!(at(1.0).scale, Vec3::ONE);
+    }
+
+    #[test]
+    fn sample_outside_of_clip() {
+        let clip = clip();
+        let track = clip.track(0).unwrap();
+
+        assert_eq!(
+            track.sample(-1.0, NodeTransform::default()).translation,
+            Vec3::ZERO
+        );
+        assert_eq!(
+            track.sample(5.0, NodeTransform::default()).translation,
+            Vec3::new(2.0, 4.0, 0.0)
+        );
+
+        assert_eq!(clip.clip_time(2.5, true), 0.5);
+        assert_eq!(clip.clip_time(-0.5, true), 1.5);
+        assert_eq!(clip.clip_time(2.5, false), 2.0);
+
+        let looped = clip.sample(2.5, true);
+        assert_eq!(looped.len(), 1);
+        assert!(looped[0]
+            .1
+            .translation
+            .abs_diff_eq(Vec3::new(1.0, 0.0, 0.0), 1e-6));
+    }
+
+    #[test]
+    fn step_interpolation() {
+        let keys = Keyframes {
+            times: vec![0.0, 1.0, 3.0],
+            values: vec![Vec3::X, Vec3::Y, Vec3::Z],
+            interpolation: Interpolation::Step,
+        };
+
+        assert_eq!(keys.sample(0.5), Some(Vec3::X));
+        assert_eq!(keys.sample(1.0), Some(Vec3::Y));
+        assert_eq!(keys.sample(2.9), Some(Vec3::Y));
+        assert_eq!(keys.sample(3.0), Some(Vec3::Z));
+
+        let single = Keyframes::evenly_spaced(vec![Vec3::ONE], 4.0);
+        assert_eq!(single.sample(2.0), Some(Vec3::ONE));
+
+        let empty: Keyframes<Vec3> = Keyframes::evenly_spaced(vec![], 1.0);
+        assert_eq!(empty.sample(0.0), None);
+    }
+}
@@ -0,0 +1,151 @@
+//! Named view of the `SMapAtmosphere` parameter block
+//!
+//! The 32 words are assumed to follow the layout of the `atmosphere` TFX extern starting at
+//! [`AtmosphereParameters::EXTERN_OFFSET`], with word N at extern offset 0x140 + N * 4. The names
+//! come from that assumption: the words whose extern field has a known use are named after it,
+//! the others keep their extern offset as their name. The mapping hasn't been checked against a
+//! captured constant buffer, so the words are not forwarded to the extern.
+
+use serde::Serialize;
+
+use crate::map::SMapAtmosphere;
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "bevy", derive(bevy_ecs::component::Component))]
+pub struct AtmosphereParameters {
+    pub fog_color: [f32; 4],
+    pub unk150: f32,
+    pub unk154: f32,
+    pub unk158: f32,
+    pub unk15c: f32,
+    pub fog_intensity: f32,
+    pub unk164: f32,
+    pub unk168: f32,
+    pub unk16c: f32,
+    pub unk170: f32,
+    pub unk174: f32,
+    pub unk178: f32,
+    pub unk17c: f32,
+
+    pub unk180: [f32; 4],
+    pub unk190: f32,
+    pub unk194: f32,
+    pub unk198: f32,
+    pub unk19c: f32,
+
+    pub unk1a0: [f32; 4],
+    pub unk1b0: f32,
+    /// Atmosphere rotation
+    pub rotation: f32,
+    pub intensity: f32,
+    /// Some kind of cutoff
+    pub unk1bc: f32,
+
+    /// The undecoded words
+    pub raw: [u32; 32],
+}
+
+impl AtmosphereParameters {
+    /// Offset of the first word in the `atmosphere` extern
+    pub const EXTERN_OFFSET: usize = 0x140;
+
+    pub fn from_words(raw: [u32; 32]) -> Self {
+        let f = |i: usize| f32::from_bits(raw[i]);
+        let vec4 = |i: usize| [f(i), f(i + 1), f(i + 2), f(i + 3)];
+
+        Self {
+            fog_color: vec4(0),
+            unk150: f(4),
+            unk154: f(5),
+            unk158: f(6),
+            unk15c: f(7),
+            fog_intensity: f(8),
+            unk164: f(9),
+            unk168: f(10),
+            unk16c: f(11),
+            unk170: f(12),
+            unk174: f(13),
+            unk178: f(14),
+            unk17c: f(15),
+            unk180: vec4(16),
+            unk190: f(20),
+            unk194: f(21),
+            unk198: f(22),
+            unk19c: f(23),
+            unk1a0: vec4(24),
+            unk1b0: f(28),
+            rotation: f(29),
+            intensity: f(30),
+            unk1bc: f(31),
+            raw,
+        }
+    }
+
+    /// Name and value of every decoded parameter, in word order
+    pub fn fields(&self) -> Vec<(&'static str, String)> {
+        let v = |values: &[f32]| {
+            values
+                .iter()
+                .map(|v| format!("{v:.4}"))
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+
+        vec![
+            ("Fog color", v(&self.fog_color)),
+            ("unk150", v(&[self.unk150])),
+            ("unk154", v(&[self.unk154])),
+            ("unk158", v(&[self.unk158])),
+            ("unk15c", v(&[self.unk15c])),
+            ("Fog intensity", v(&[self.fog_intensity])),
+            ("unk164", v(&[self.unk164])),
+            ("unk168", v(&[self.unk168])),
+            ("unk16c", v(&[self.unk16c])),
+            ("unk170", v(&[self.unk170])),
+            ("unk174", v(&[self.unk174])),
+            ("unk178", v(&[self.unk178])),
+            ("unk17c", v(&[self.unk17c])),
+            ("unk180", v(&self.unk180)),
+            ("unk190", v(&[self.unk190])),
+            ("unk194", v(&[self.unk194])),
+            ("unk198", v(&[self.unk198])),
+            ("unk19c", v(&[self.unk19c])),
+            ("unk1a0", v(&self.unk1a0)),
+            ("unk1b0", v(&[self.unk1b0])),
+            ("Rotation", v(&[self.rotation])),
+            ("Intensity", v(&[self.intensity])),
+            ("unk1bc", v(&[self.unk1bc])),
+        ]
+    }
+}
+
+impl SMapAtmosphere {
+    pub fn parameters(&self) -> AtmosphereParameters {
+        AtmosphereParameters::from_words(self.unk0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn words_follow_extern_offsets() {
+        // Every word holds its own extern offset
+        let raw: [u32; 32] = std::array::from_fn(|i| {
+            ((AtmosphereParameters::EXTERN_OFFSET + i * 4) as f32).to_bits()
+        });
+        let params = AtmosphereParameters::from_words(raw);
+
+        assert_eq!(params.fog_color, [320.0, 324.0, 328.0, 332.0]);
+        assert_eq!(params.unk150, 0x150 as f32);
+        assert_eq!(params.fog_intensity, 0x160 as f32);
+        assert_eq!(params.unk170, 0x170 as f32);
+        assert_eq!(params.unk180[0], 0x180 as f32);
+        assert_eq!(params.unk198, 0x198 as f32);
+        assert_eq!(params.rotation, 0x1b4 as f32);
+        assert_eq!(params.intensity, 0x1b8 as f32);
+        assert_eq!(params.unk1bc, 0x1bc as f32);
+        assert_eq!(params.raw, raw);
+    }
+}
@@ -0,0 +1,200 @@
+//! Map decals (graffiti, road markings, grime), projected onto the surrounding geometry
+//!
+//! A decal collection groups its decals by material. Each group covers a range of the points
+//! buffer, where every point holds the decal position with its scale in W, and the same range of
+//! the occlusion bounds, which hold the world space bounds of every decal.
+//!
+//! We haven't found an orientation for decals yet, so projectors are axis aligned boxes fit to the
+//! bounds, projecting along their thinnest axis.
+
+use destiny_pkg::TagHash;
+use glam::{Mat4, Quat, Vec3, Vec4};
+use tiger_parse::tiger_tag;
+
+use crate::{
+    occlusion::{Aabb, SObjectOcclusionBounds, SOcclusionBounds},
+    Tag,
+};
+
+/// Decal collection, referenced by the `0x80806E62` map resource
+#[derive(Clone, Debug)]
+#[tiger_tag(id = 0x80806E68)]
+pub struct SMapDecals {
+    pub file_size: u64,
+    pub groups: Vec<SMapDecalGroup>,
+    /// Vertex buffer with a float4 per decal
+    pub instance_points: TagHash,
+    pub unk_vertex_colors: TagHash,
+
+    pub unk30: [u32; 2],
+    pub occlusion_bounds: Tag<SOcclusionBounds>,
+    _pad3c: u32,
+    pub bounds: Aabb,
+}
+
+/// Decals drawn with the same material
+#[derive(Clone, Debug)]
+#[tiger_tag(id = 0x80806963)]
+pub struct SMapDecalGroup {
+    /// Technique the decals are drawn with
+    pub material: TagHash,
+    pub start: u16,
+    pub count: u16,
+}
+
+/// A single decal, decoded to world space
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DecalInstance {
+    pub material: TagHash,
+    /// Index into the points buffer and occlusion bounds
+    pub index: usize,
+    pub position: Vec3,
+    pub scale: f32,
+    /// World space bounds, if the collection has any for this decal
+    pub bounds: Option<Aabb>,
+}
+
+/// Axis aligned box a decal is projected through, in world space
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DecalProjector {
+    pub center: Vec3,
+    pub half_extents: Vec3,
+}
+
+impl SMapDecals {
+    /// Decodes every decal of the collection. `points` holds the contents of
+    /// [`SMapDecals::instance_points`]
+    pub fn instances(&self, points: &[Vec4]) -> Vec<DecalInstance> {
+        decode_decals(&self.groups, points, &self.occlusion_bounds.bounds)
+    }
+}
+
+fn decode_decals(
+    groups: &[SMapDecalGroup],
+    points: &[Vec4],
+    bounds: &[SObjectOcclusionBounds],
+) -> Vec<DecalInstance> {
+    let mut instances = vec![];
+    for group in groups {
+        let start = group.start as usize;
+        let end = (start + group.count as usize).min(points.len());
+        for (index, point) in points.iter().enumerate().take(end).skip(start) {
+            instances.push(DecalInstance {
+                material: group.material,
+                index,
+                position: point.truncate(),
+                scale: point.w,
+                bounds: bounds.get(index).map(|b| b.bb),
+            });
+        }
+    }
+
+    instances
+}
+
+impl DecalInstance {
+    pub fn projector(&self) -> DecalProjector {
+        match self.bounds {
+            Some(bounds) if bounds.min.cmple(bounds.max).all() => DecalProjector {
+                center: bounds.center(),
+                half_extents: bounds.extents(),
+            },
+            // Without bounds, the best we can do is a cube the size of the decal
+            _ => DecalProjector {
+                center: self.position,
+                half_extents: Vec3::splat(self.scale.abs()),
+            },
+        }
+    }
+}
+
+impl DecalProjector {
+    /// Maps the -1..1 unit cube onto the projector
+    pub fn local_to_world(&self) -> Mat4 {
+        Mat4::from_scale_rotation_translation(self.half_extents, Quat::IDENTITY, self.center)
+    }
+
+    /// Direction the decal is projected along, which is the thinnest axis of the box, pointing
+    /// down for decals projected vertically
+    pub fn projection_axis(&self) -> Vec3 {
+        let e = self.half_extents;
+        if e.z <= e.x && e.z <= e.y {
+            Vec3::NEG_Z
+        } else if e.x <= e.y {
+            Vec3::X
+        } else {
+            Vec3::Y
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn instances_follow_groups() {
+        let points: Vec<Vec4> = (0..4).map(|i| Vec4::new(i as f32, 0.0, 0.0, 2.0)).collect();
+        let bounds = vec![
+            SObjectOcclusionBounds {
+                bb: Aabb::from_center_extents(Vec3::ZERO, Vec3::ONE),
+                unk20: [0; 4],
+            };
+            3
+        ];
+        // The second group runs past the end of the points buffer
+        let groups = [(1, 0, 2), (2, 3, 4)].map(|(material, start, count)| SMapDecalGroup {
+            material: TagHash(material),
+            start,
+            count,
+        });
+
+        let instances = decode_decals(&groups, &points, &bounds);
+        assert_eq!(instances.len(), 3);
+        assert_eq!(instances[1].material, TagHash(1));
+        assert_eq!(instances[1].position, Vec3::X);
+        assert_eq!(instances[1].scale, 2.0);
+        assert_eq!(instances[2].material, TagHash(2));
+        assert_eq!(instances[2].index, 3);
+        // There are fewer bounds than points
+        assert_eq!(instances[2].bounds, None);
+    }
+
+    #[test]
+    fn projector_from_bounds() {
+        let flat = DecalInstance {
+            material: TagHash::NONE,
+            index: 0,
+            position: Vec3::ZERO,
+            scale: 1.5,
+            bounds: Some(Aabb {
+                min: Vec3::new(0.0, 0.0, 0.0),
+                max: Vec3::new(4.0, 2.0, 0.5),
+            }),
+        };
+
+        let projector = flat.projector();
+        assert_eq!(projector.center, Vec3::new(2.0, 1.0, 0.25));
+        assert_eq!(projector.half_extents, Vec3::new(2.0, 1.0, 0.25));
+        assert_eq!(projector.projection_axis(), Vec3::NEG_Z);
+        assert_eq!(
+            projector.local_to_world().transform_point3(Vec3::ONE),
+            Vec3::new(4.0, 2.0, 0.5)
+        );
+
+        let wall = DecalInstance {
+            bounds: Some(Aabb {
+                min: Vec3::ZERO,
+                max: Vec3::new(3.0, 0.2, 3.0),
+            }),
+            ..flat
+        };
+        assert_eq!(wall.projector().projection_axis(), Vec3::Y);
+
+        let unbounded = DecalInstance {
+            bounds: None,
+            ..flat
+        };
+        assert_eq!(unbounded.projector().half_extents, Vec3::splat(1.5));
+    }
+}
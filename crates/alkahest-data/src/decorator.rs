@@ -1,5 +1,7 @@
+use std::ops::Range;
+
 use destiny_pkg::TagHash;
-use glam::Vec4;
+use glam::{Quat, Vec3, Vec4};
 use tiger_parse::tiger_tag;
 
 use crate::{
@@ -22,6 +24,85 @@ pub struct SDecorator {
     pub bounds: Aabb,
 }
 
+/// A run of instances that are drawn with the same model
+#[derive(Clone, Debug, PartialEq)]
+pub struct DecoratorInstanceGroup {
+    /// Index into [`SDecorator::unk8`]
+    pub model_index: usize,
+    /// Mesh part identifier the group is drawn with
+    pub identifier: u16,
+    pub instances: Range<u32>,
+}
+
+/// A decorator instance decoded to world space
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DecoratorInstance {
+    /// Index into [`SDecorator::unk8`]
+    pub model_index: usize,
+    pub entity_model: TagHash,
+    pub identifier: u16,
+    pub translation: Vec3,
+    pub rotation: Quat,
+    /// RGBA color
+    pub color: [u8; 4],
+}
+
+impl SDecorator {
+    /// Instance groups as laid out by `unk18`
+    ///
+    /// Decorators with a single model use the group index as the mesh part identifier. Decorators with
+    /// multiple models (usually trees) have one group per model instead, which seems to act as a LOD level
+    pub fn instance_groups(&self) -> Vec<DecoratorInstanceGroup> {
+        self.unk18
+            .windows(2)
+            .enumerate()
+            .map(|(id, range)| {
+                let (model_index, identifier) = if self.unk8.len() == 1 {
+                    (0, id as u16)
+                } else {
+                    (id, 0)
+                };
+
+                DecoratorInstanceGroup {
+                    model_index,
+                    identifier,
+                    instances: range[0]..range[1],
+                }
+            })
+            .collect()
+    }
+
+    /// Decodes every instance to a world space transform and color
+    pub fn instances(&self) -> Vec<DecoratorInstance> {
+        let consts = &self.unk48.unk14;
+        let data = &self.unk48.instance_data.data;
+
+        let mut instances = vec![];
+        for group in self.instance_groups() {
+            let entity_model = self
+                .unk8
+                .get(group.model_index)
+                .map(|m| m.entity_model)
+                .unwrap_or(TagHash::NONE);
+
+            let range =
+                group.instances.start as usize..(group.instances.end as usize).min(data.len());
+            for element in data.get(range).unwrap_or_default() {
+                instances.push(DecoratorInstance {
+                    model_index: group.model_index,
+                    entity_model,
+                    identifier: group.identifier,
+                    translation: consts.decode_position(element.position),
+                    rotation: element.rotation(),
+                    color: element.color,
+                });
+            }
+        }
+
+        instances
+    }
+}
+
 #[derive(Clone, Debug)]
 #[tiger_tag(id = 0x80806CA4)]
 pub struct SUnk80806CA4 {
@@ -51,6 +132,20 @@ pub struct SDecoratorInstanceElement {
     /// RGBA color
     pub color: [u8; 4],
 }
+
+impl SDecoratorInstanceElement {
+    /// Unpacks the 8-bit unorm quaternion
+    pub fn rotation(&self) -> Quat {
+        let [x, y, z, w] = self.rotation.map(|v| v as f32 / 255.0 * 2.0 - 1.0);
+        let q = Quat::from_xyzw(x, y, z, w);
+        if q.length_squared() > f32::EPSILON {
+            q.normalize()
+        } else {
+            Quat::IDENTITY
+        }
+    }
+}
+
 #[derive(Clone, Debug)]
 #[tiger_tag(id = 0x80806C9F)]
 pub struct SUnk80806C9F {
@@ -62,6 +157,14 @@ pub struct SUnk80806C9F {
     pub unk50: Vec4,
 }
 
+impl SUnk80806C9F {
+    /// Maps a normalized instance position into world space
+    pub fn decode_position(&self, position: [u16; 3]) -> Vec3 {
+        let normalized = Vec3::from_array(position.map(|v| v as f32 / u16::MAX as f32));
+        self.instances_offset.truncate() + normalized * self.instances_scale.truncate()
+    }
+}
+
 #[derive(Clone, Debug)]
 #[tiger_tag(id = 0x80806CB2)]
 pub struct SUnk80806CB2 {
@@ -0,0 +1,464 @@
+use std::{
+    fmt::Write,
+    io::{Cursor, Seek, SeekFrom},
+};
+
+use alkahest_pm::package_manager;
+use binrw::BinReaderExt;
+use destiny_pkg::TagHash;
+use rustc_hash::FxHashSet;
+use serde::Serialize;
+use tiger_parse::{Endian, PackageManagerExt, TigerReadable};
+use tracing::warn;
+
+use crate::{
+    activity::{SActivity, SDestination, SEntityResource},
+    decorator::SDecorator,
+    entity::{SDynamicModel, SEntity},
+    map::{
+        SBubbleDefinition, SBubbleParent, SLightCollection, SMapContainer, SMapDataTable,
+        SShadowingLight, STerrain, SUnk80806ef4,
+    },
+    statics::SStaticMesh,
+    technique::STechnique,
+    texture::STextureHeader,
+    WideHash,
+};
+
+/// A tag and everything it references, as far as the walker could resolve
+#[derive(Serialize, Debug)]
+pub struct DependencyNode {
+    #[serde(serialize_with = "crate::tag::serialize_taghash")]
+    pub hash: TagHash,
+    pub kind: String,
+    /// How the parent refers to this tag (eg. `technique`, `ps t2`)
+    pub relation: String,
+    pub children: Vec<DependencyNode>,
+
+    /// This tag is one of its own ancestors, so its references weren't walked again
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub cycle: bool,
+    /// This tag was already walked elsewhere in the tree
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub repeated: bool,
+    /// The depth limit was reached before this tag's references could be walked
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub truncated: bool,
+}
+
+impl DependencyNode {
+    /// Walks the references of `root`, up to `max_depth` levels deep
+    pub fn walk(root: TagHash, max_depth: usize) -> DependencyNode {
+        let mut path = vec![];
+        let mut visited = FxHashSet::default();
+        walk_recursive(
+            root,
+            "root".to_string(),
+            0,
+            max_depth,
+            &mut path,
+            &mut visited,
+        )
+    }
+
+    pub fn count(&self) -> usize {
+        1 + self.children.iter().map(|c| c.count()).sum::<usize>()
+    }
+
+    /// All unique tags in the tree, including the root
+    pub fn unique_tags(&self) -> Vec<TagHash> {
+        let mut tags = vec![];
+        let mut seen = FxHashSet::default();
+        self.visit(0, None, &mut |node, _, _| {
+            if seen.insert(node.hash) {
+                tags.push(node.hash);
+            }
+        });
+
+        tags
+    }
+
+    fn visit(
+        &self,
+        depth: usize,
+        parent: Option<TagHash>,
+        f: &mut impl FnMut(&DependencyNode, usize, Option<TagHash>),
+    ) {
+        f(self, depth, parent);
+        for c in &self.children {
+            c.visit(depth + 1, Some(self.hash), f);
+        }
+    }
+
+    pub fn to_json(&self) -> anyhow::Result<String> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    /// Flattens the tree into one row per edge
+    pub fn to_csv(&self) -> String {
+        let mut out = String::from("depth,parent,hash,kind,relation,cycle,repeated,truncated\n");
+        self.visit(0, None, &mut |node, depth, parent| {
+            writeln!(
+                out,
+                "{depth},{},{},{},{},{},{},{}",
+                parent.map(|p| p.to_string()).unwrap_or_default(),
+                node.hash,
+                node.kind,
+                node.relation,
+                node.cycle,
+                node.repeated,
+                node.truncated
+            )
+            .unwrap();
+        });
+
+        out
+    }
+}
+
+fn walk_recursive(
+    hash: TagHash,
+    relation: String,
+    depth: usize,
+    max_depth: usize,
+    path: &mut Vec<TagHash>,
+    visited: &mut FxHashSet<TagHash>,
+) -> DependencyNode {
+    let mut node = DependencyNode {
+        hash,
+        kind: tag_kind(hash),
+        relation,
+        children: vec![],
+        cycle: path.contains(&hash),
+        repeated: false,
+        truncated: false,
+    };
+
+    if node.cycle {
+        return node;
+    }
+
+    if visited.contains(&hash) {
+        node.repeated = true;
+        return node;
+    }
+
+    let references = match references(hash) {
+        Ok(r) => r,
+        Err(e) => {
+            warn!("Failed to read references for {hash}: {e}");
+            return node;
+        }
+    };
+
+    // Tags cut off by the depth limit can still be walked if they show up higher in the tree
+    if depth >= max_depth {
+        node.truncated = !references.is_empty();
+        return node;
+    }
+
+    visited.insert(hash);
+    path.push(hash);
+    for (relation, child) in references {
+        node.children.push(walk_recursive(
+            child,
+            relation,
+            depth + 1,
+            max_depth,
+            path,
+            visited,
+        ));
+    }
+    path.pop();
+
+    node
+}
+
+/// Describes the type of a tag, either by its header type or its class
+pub fn tag_kind(hash: TagHash) -> String {
+    let Some(entry) = package_manager().get_entry(hash) else {
+        return "Missing".to_string();
+    };
+
+    match (entry.file_type, entry.reference) {
+        (32, _) => "Texture".to_string(),
+        (33, _) => "Shader".to_string(),
+        (34, _) => "Sampler".to_string(),
+        (_, r) => class_name(r)
+            .map(str::to_string)
+            .unwrap_or_else(|| format!("{r:08X}")),
+    }
+}
+
+fn class_name(class: u32) -> Option<&'static str> {
+    let c = Some(class);
+    Some(if c == SDestination::ID {
+        "Destination"
+    } else if c == SActivity::ID {
+        "Activity"
+    } else if c == SBubbleParent::ID {
+        "Map"
+    } else if c == SBubbleDefinition::ID {
+        "Bubble Definition"
+    } else if c == SMapContainer::ID {
+        "Map Container"
+    } else if c == SMapDataTable::ID {
+        "Map Data Table"
+    } else if c == SUnk80806ef4::ID {
+        "Static Placements"
+    } else if c == SStaticMesh::ID {
+        "Static Mesh"
+    } else if c == STechnique::ID {
+        "Technique"
+    } else if c == SEntity::ID {
+        "Entity"
+    } else if c == SEntityResource::ID {
+        "Entity Resource"
+    } else if c == SDynamicModel::ID {
+        "Dynamic Model"
+    } else if c == STerrain::ID {
+        "Terrain"
+    } else if c == SDecorator::ID {
+        "Decorator"
+    } else if c == SLightCollection::ID {
+        "Light Collection"
+    } else if c == SShadowingLight::ID {
+        "Shadowing Light"
+    } else {
+        return None;
+    })
+}
+
+/// Returns the tags directly referenced by `hash`, along with how they're referenced.
+/// Tags of unknown classes don't reference anything
+pub fn references(hash: TagHash) -> anyhow::Result<Vec<(String, TagHash)>> {
+    let Some(entry) = package_manager().get_entry(hash) else {
+        anyhow::bail!("Tag {hash} not found");
+    };
+
+    let mut refs = References::default();
+    match (entry.file_type, Some(entry.reference)) {
+        (32, _) => {
+            let texture: STextureHeader = package_manager().read_tag_struct(hash)?;
+            refs.add("data", TagHash(entry.reference));
+            refs.add("large buffer", texture.large_buffer);
+        }
+        (33 | 34, _) => refs.add("data", TagHash(entry.reference)),
+        (_, c) if c == SDestination::ID => {
+            let d: SDestination = package_manager().read_tag_struct(hash)?;
+            refs.add_wide("strings", d.string_container);
+            refs.add("patrols", d.patrols);
+            for t in &d.tagbags {
+                refs.add("tagbag", *t);
+            }
+            for a in &d.activities {
+                if let Some(activity) = package_manager()
+                    .get_named_tag(&a.activity_code.to_string(), SActivity::ID.unwrap())
+                {
+                    refs.add("activity", activity);
+                }
+            }
+        }
+        (_, c) if c == SActivity::ID => {
+            let a: SActivity = package_manager().read_tag_struct(hash)?;
+            refs.add_wide("ambient activity", a.ambient_activity);
+            for bubble in &a.unk50 {
+                for map in &bubble.map_references {
+                    refs.add_wide("map", *map);
+                }
+                for e in &bubble.unk18 {
+                    for r in &e.unk_entity_reference.unk18.entity_resources {
+                        refs.add("entity resource", r.entity_resource);
+                    }
+                }
+            }
+        }
+        (_, c) if c == SBubbleParent::ID => {
+            let m: SBubbleParent = package_manager().read_tag_struct(hash)?;
+            refs.add("bubble definition", m.child_map);
+        }
+        (_, c) if c == SBubbleDefinition::ID => {
+            let d: SBubbleDefinition = package_manager().read_tag_struct(hash)?;
+            for c in &d.map_resources {
+                refs.add("map container", c.1);
+            }
+        }
+        (_, c) if c == SMapContainer::ID => {
+            let c: SMapContainer = package_manager().read_tag_struct(hash)?;
+            for t in &c.data_tables {
+                refs.add("data table", *t);
+            }
+        }
+        (_, c) if c == SMapDataTable::ID => {
+            let data = package_manager().read_tag(hash)?;
+            let mut cur = Cursor::new(&data);
+            let table: SMapDataTable = TigerReadable::read_ds(&mut cur)?;
+            for entry in &table.data_entries {
+                refs.add_wide("entity", entry.entity);
+
+                let resource_type = entry.data_resource.resource_type;
+                cur.seek(SeekFrom::Start(entry.data_resource.offset + 16))?;
+                match resource_type {
+                    // Ambient sound source
+                    0x8080666f => {
+                        let tag: WideHash = TigerReadable::read_ds(&mut cur)?;
+                        refs.add_wide("sound", tag);
+                    }
+                    // Resources with a tag reference at +0x10
+                    0x80806cc9 | 0x80806aa3 | 0x80806a63 | 0x80806c5e | 0x808067b5 | 0x80808cb5
+                    | 0x80806cc3 => {
+                        let tag: TagHash = cur.read_le()?;
+                        refs.add(&format!("resource {resource_type:08X}"), tag);
+                    }
+                    // Terrain
+                    0x80806c7d => {
+                        cur.seek(SeekFrom::Start(entry.data_resource.offset + 0x18))?;
+                        let tag: TagHash = cur.read_le()?;
+                        refs.add("terrain", tag);
+                    }
+                    _ => {}
+                }
+            }
+        }
+        (_, c) if c == SUnk80806ef4::ID => {
+            let p: SUnk80806ef4 = package_manager().read_tag_struct(hash)?;
+            refs.add("instances", p.instances.taghash());
+            for s in &p.instances.statics {
+                refs.add("static", *s);
+            }
+        }
+        (_, c) if c == SStaticMesh::ID => {
+            let m: SStaticMesh = package_manager().read_tag_struct(hash)?;
+            refs.add("mesh data", m.opaque_meshes.taghash());
+            for (index, vertex0, vertex1, color) in &m.opaque_meshes.buffers {
+                refs.add("index buffer", *index);
+                refs.add("vertex buffer 0", *vertex0);
+                refs.add("vertex buffer 1", *vertex1);
+                refs.add("color buffer", *color);
+            }
+            for t in &m.techniques {
+                refs.add("technique", *t);
+            }
+            for s in &m.special_meshes {
+                refs.add("index buffer", s.index_buffer);
+                refs.add("vertex buffer 0", s.vertex0_buffer);
+                refs.add("vertex buffer 1", s.vertex1_buffer);
+                refs.add("color buffer", s.color_buffer);
+                refs.add("special technique", s.technique);
+            }
+        }
+        (_, c) if c == STechnique::ID => {
+            let t: STechnique = package_manager().read_tag_struct(hash)?;
+            for (stage, shader) in t.all_valid_shaders() {
+                let stage = format!("{stage:?}").to_lowercase();
+                refs.add(&format!("{stage} shader"), shader.shader);
+                for tex in &shader.textures {
+                    refs.add_wide(&format!("{stage} t{}", tex.slot), tex.texture);
+                }
+                for (i, sampler) in shader.constants.samplers.iter().enumerate() {
+                    refs.add_wide(&format!("{stage} s{i}"), *sampler);
+                }
+                refs.add(
+                    &format!("{stage} constant buffer"),
+                    shader.constants.constant_buffer,
+                );
+            }
+        }
+        (_, c) if c == SEntity::ID => {
+            let e: SEntity = package_manager().read_tag_struct(hash)?;
+            for r in &e.entity_resources {
+                refs.add("entity resource", r.unk0.taghash());
+            }
+        }
+        (_, c) if c == SEntityResource::ID => {
+            let data = package_manager().read_tag(hash)?;
+            let mut cur = Cursor::new(&data);
+            let res: SEntityResource = TigerReadable::read_ds_endian(&mut cur, Endian::Little)?;
+
+            // Dynamic model resource
+            if res.unk10.resource_type == 0x80806d8a {
+                cur.seek(SeekFrom::Start(res.unk18.offset + 0x224))?;
+                let model: TagHash = cur.read_le()?;
+                refs.add("model", model);
+
+                cur.seek(SeekFrom::Start(res.unk18.offset + 0x400))?;
+                let materials: Vec<TagHash> =
+                    TigerReadable::read_ds_endian(&mut cur, Endian::Little)?;
+                for m in materials {
+                    refs.add("material", m);
+                }
+            }
+
+            for r in &res.resource_table2 {
+                if r.unk14 != 0xFFFFFFFF {
+                    refs.add_wide("entity", r.unk0);
+                }
+            }
+        }
+        (_, c) if c == SDynamicModel::ID => {
+            let m: SDynamicModel = package_manager().read_tag_struct(hash)?;
+            for mesh in &m.meshes {
+                refs.add("index buffer", mesh.index_buffer);
+                refs.add("vertex buffer 0", mesh.vertex0_buffer);
+                refs.add("vertex buffer 1", mesh.vertex1_buffer);
+                refs.add("color buffer", mesh.color_buffer);
+                refs.add("skinning buffer", mesh.skinning_buffer);
+                for part in &mesh.parts {
+                    refs.add("technique", part.technique);
+                }
+            }
+        }
+        (_, c) if c == STerrain::ID => {
+            let t: STerrain = package_manager().read_tag_struct(hash)?;
+            refs.add("index buffer", t.index_buffer);
+            refs.add("vertex buffer 0", t.vertex0_buffer);
+            refs.add("vertex buffer 1", t.vertex1_buffer);
+            refs.add("technique", t.unk_technique1);
+            refs.add("technique", t.unk_technique2);
+            for part in &t.mesh_parts {
+                refs.add("technique", part.technique);
+            }
+            for group in &t.mesh_groups {
+                refs.add("dyemap", group.dyemap);
+            }
+        }
+        (_, c) if c == SDecorator::ID => {
+            let d: SDecorator = package_manager().read_tag_struct(hash)?;
+            for m in &d.unk8 {
+                refs.add("model", m.entity_model);
+            }
+            refs.add("instance buffer", d.unk48.instance_buffer);
+        }
+        (_, c) if c == SLightCollection::ID => {
+            let l: SLightCollection = package_manager().read_tag_struct(hash)?;
+            for light in &l.unk30 {
+                refs.add("shading technique", light.technique_shading);
+            }
+        }
+        (_, c) if c == SShadowingLight::ID => {
+            let l: SShadowingLight = package_manager().read_tag_struct(hash)?;
+            refs.add("shading technique", l.technique_shading);
+            refs.add("shadowing shading technique", l.technique_shading_shadowing);
+            refs.add("volumetrics technique", l.technique_volumetrics);
+        }
+        _ => {}
+    }
+
+    Ok(refs.0)
+}
+
+/// Ordered set of references, skipping null and duplicate tags
+#[derive(Default)]
+struct References(Vec<(String, TagHash)>);
+
+impl References {
+    fn add(&mut self, relation: &str, hash: TagHash) {
+        if hash.is_some() && !self.0.iter().any(|(_, h)| *h == hash) {
+            self.0.push((relation.to_string(), hash));
+        }
+    }
+
+    fn add_wide(&mut self, relation: &str, hash: WideHash) {
+        self.add(relation, hash.hash32());
+    }
+}
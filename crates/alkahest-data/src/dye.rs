@@ -0,0 +1,200 @@
+//! Gear dyes, which tint the parts of gear and dyeable props
+//!
+//! Every dynamic mesh part has a `gear_dye_change_color_index` selecting one of 6 dye slots. An
+//! entity references up to 3 dyes (armor, cloth and suit), each of which colors a primary and a
+//! secondary slot, and is bound to the matching `gear_dye_{0,1,2}` scope when drawing.
+//!
+//! Dyes carry a pixel stage with detail textures and a constant buffer, evaluated the same way as
+//! technique constants. The constant buffer is laid out as 3 shared detail transforms, followed by
+//! the parameters of the primary and the secondary slot ([`DyeChannel`]).
+
+use destiny_pkg::TagHash;
+use glam::Vec4;
+use serde::Serialize;
+use tiger_parse::{tiger_tag, TigerReadable};
+
+use crate::{
+    entity::{find_resource_references, SEntity},
+    technique::STechniqueShader,
+};
+
+#[derive(Debug, Clone)]
+#[tiger_tag(id = 0x80806CE3)]
+pub struct SGearDye {
+    pub file_size: u64,
+    pub unk8: u32,
+    pub unkc: u32,
+    /// Detail textures and dye constants, bound to the `gear_dye_*` scopes
+    pub shader: STechniqueShader,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GearDyeSlot {
+    ArmorPrimary = 0,
+    ArmorSecondary = 1,
+    ClothPrimary = 2,
+    ClothSecondary = 3,
+    SuitPrimary = 4,
+    SuitSecondary = 5,
+}
+
+impl GearDyeSlot {
+    pub const ALL: [GearDyeSlot; 6] = [
+        Self::ArmorPrimary,
+        Self::ArmorSecondary,
+        Self::ClothPrimary,
+        Self::ClothSecondary,
+        Self::SuitPrimary,
+        Self::SuitSecondary,
+    ];
+
+    /// Slot for a part's `gear_dye_change_color_index`. Parts with any other index aren't dyed
+    pub fn from_change_color_index(index: u8) -> Option<Self> {
+        Self::ALL.get(index as usize).copied()
+    }
+
+    /// Index of the dye (and the `gear_dye_*` scope) coloring this slot
+    pub fn dye_index(self) -> usize {
+        self as usize / 2
+    }
+
+    pub fn is_primary(self) -> bool {
+        self as usize & 1 == 0
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::ArmorPrimary => "Armor (primary)",
+            Self::ArmorSecondary => "Armor (secondary)",
+            Self::ClothPrimary => "Cloth (primary)",
+            Self::ClothSecondary => "Cloth (secondary)",
+            Self::SuitPrimary => "Suit (primary)",
+            Self::SuitSecondary => "Suit (secondary)",
+        }
+    }
+}
+
+/// Dye parameters of a single slot
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize)]
+pub struct DyeChannel {
+    pub albedo_tint: [f32; 4],
+    /// RGB emissive tint, with the intensity bias in W
+    pub emissive_tint: [f32; 4],
+    pub material_params: [f32; 4],
+    pub material_advanced_params: [f32; 4],
+    pub roughness_remap: [f32; 4],
+    pub worn_albedo_tint: [f32; 4],
+    pub wear_remap: [f32; 4],
+    pub worn_roughness_remap: [f32; 4],
+    pub worn_material_params: [f32; 4],
+}
+
+impl DyeChannel {
+    pub const SIZE: usize = 9;
+
+    fn from_cbuffer(data: &[Vec4]) -> Self {
+        let at = |i: usize| data.get(i).copied().unwrap_or(Vec4::ZERO).to_array();
+        Self {
+            albedo_tint: at(0),
+            emissive_tint: at(1),
+            material_params: at(2),
+            material_advanced_params: at(3),
+            roughness_remap: at(4),
+            worn_albedo_tint: at(5),
+            wear_remap: at(6),
+            worn_roughness_remap: at(7),
+            worn_material_params: at(8),
+        }
+    }
+}
+
+/// Evaluated constants of a dye
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct GearDyeParameters {
+    pub detail_diffuse_transform: [f32; 4],
+    pub detail_normal_transform: [f32; 4],
+    pub spec_aa_transform: [f32; 4],
+    pub primary: DyeChannel,
+    pub secondary: DyeChannel,
+}
+
+impl GearDyeParameters {
+    /// Number of constant buffer elements used by the dye parameters
+    pub const CBUFFER_SIZE: usize = 3 + 2 * DyeChannel::SIZE;
+
+    /// Reads the parameters from an evaluated dye constant buffer. Missing elements are zeroed
+    pub fn from_cbuffer(data: &[Vec4]) -> Self {
+        let at = |i: usize| data.get(i).copied().unwrap_or(Vec4::ZERO).to_array();
+        let channel = |start: usize| DyeChannel::from_cbuffer(data.get(start..).unwrap_or(&[]));
+        Self {
+            detail_diffuse_transform: at(0),
+            detail_normal_transform: at(1),
+            spec_aa_transform: at(2),
+            primary: channel(3),
+            secondary: channel(3 + DyeChannel::SIZE),
+        }
+    }
+
+    pub fn channel(&self, slot: GearDyeSlot) -> &DyeChannel {
+        if slot.is_primary() {
+            &self.primary
+        } else {
+            &self.secondary
+        }
+    }
+}
+
+/// Finds the dyes referenced by the resources of an entity
+///
+/// The resource field holding the dyes isn't mapped yet, so this falls back to
+/// [`find_resource_references`]. The dye index is assumed to follow the order of the references
+/// (the first dye colors the armor slots), which isn't read from the data
+pub fn find_entity_dyes(entity: &SEntity) -> Vec<TagHash> {
+    find_resource_references(entity, SGearDye::ID.unwrap())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dye_slots() {
+        assert_eq!(
+            GearDyeSlot::from_change_color_index(3),
+            Some(GearDyeSlot::ClothSecondary)
+        );
+        assert_eq!(GearDyeSlot::from_change_color_index(6), None);
+        assert_eq!(GearDyeSlot::from_change_color_index(0xff), None);
+
+        assert_eq!(GearDyeSlot::ArmorSecondary.dye_index(), 0);
+        assert_eq!(GearDyeSlot::SuitPrimary.dye_index(), 2);
+        assert!(GearDyeSlot::ClothPrimary.is_primary());
+        assert!(!GearDyeSlot::ClothSecondary.is_primary());
+    }
+
+    #[test]
+    fn parameters_from_cbuffer() {
+        let cbuffer: Vec<Vec4> = (0..GearDyeParameters::CBUFFER_SIZE)
+            .map(|i| Vec4::splat(i as f32))
+            .collect();
+
+        let params = GearDyeParameters::from_cbuffer(&cbuffer);
+        assert_eq!(params.spec_aa_transform, [2.0; 4]);
+        assert_eq!(params.primary.albedo_tint, [3.0; 4]);
+        assert_eq!(params.primary.worn_material_params, [11.0; 4]);
+        assert_eq!(params.secondary.albedo_tint, [12.0; 4]);
+        assert_eq!(
+            params
+                .channel(GearDyeSlot::SuitSecondary)
+                .worn_material_params,
+            [20.0; 4]
+        );
+
+        // Short buffers leave the remaining parameters zeroed
+        let params = GearDyeParameters::from_cbuffer(&cbuffer[..5]);
+        assert_eq!(params.primary.emissive_tint, [4.0; 4]);
+        assert_eq!(params.primary.material_params, [0.0; 4]);
+        assert_eq!(params.secondary, DyeChannel::default());
+    }
+}
@@ -1,7 +1,10 @@
-use std::ops::Range;
+use std::{io::Cursor, ops::Range};
 
+use alkahest_pm::package_manager;
 use destiny_pkg::TagHash;
-use tiger_parse::{tiger_tag, FnvHash, NullString, Pointer, PointerOptional};
+use tiger_parse::{
+    tiger_tag, Endian, FnvHash, NullString, Pointer, PointerOptional, TigerReadable,
+};
 
 use super::geometry::{ELodCategory, EPrimitiveType};
 use crate::{activity::SEntityResource, tfx::TfxRenderStage, Tag};
@@ -21,6 +24,41 @@ pub struct Unk80809c04 {
     pub unk8: u32,
 }
 
+/// Finds tags of the given type referenced by the resources of an entity, by scanning the resource
+/// data for tag hashes whose entry reference matches `reference`. Tags are returned in the order
+/// they are first referenced
+///
+/// This reads every resource of the entity, so it's meant for lookups done on demand (selection,
+/// export) where the field holding the reference isn't mapped, not for use while loading a map
+pub fn find_resource_references(entity: &SEntity, reference: u32) -> Vec<TagHash> {
+    let mut found = vec![];
+    for resource in &entity.entity_resources {
+        let Ok(data) = package_manager().read_tag(resource.unk0.taghash()) else {
+            continue;
+        };
+
+        let mut cur = Cursor::new(&data);
+        while (cur.position() as usize) + 4 <= data.len() {
+            let Ok(hash) = TagHash::read_ds_endian(&mut cur, Endian::Little) else {
+                break;
+            };
+
+            if !(0x80800000..0x82000000).contains(&hash.0) || found.contains(&hash) {
+                continue;
+            }
+
+            if package_manager()
+                .get_entry(hash)
+                .is_some_and(|e| e.reference == reference)
+            {
+                found.push(hash);
+            }
+        }
+    }
+
+    found
+}
+
 #[derive(Debug, Clone)]
 #[tiger_tag(id = 0x80806F07, size = 0x70)]
 pub struct SDynamicModel {
@@ -44,6 +82,8 @@ pub struct SDynamicMesh {
     pub buffer3: TagHash,
     pub index_buffer: TagHash,
     pub color_buffer: TagHash,
+    /// Blend joints and weights for vertices influenced by more than one node, indexed through
+    /// the W component of the vertex position
     pub skinning_buffer: TagHash,
     pub unk1c: u32,
     pub parts: Vec<SDynamicMeshPart>, // 0x20
@@ -66,6 +106,113 @@ impl SDynamicMesh {
     pub fn get_input_layout_for_stage(&self, stage: TfxRenderStage) -> u8 {
         self.input_layout_per_render_stage[stage as usize]
     }
+
+    /// Sorted list of the unique part identifiers of this mesh
+    pub fn identifiers(&self) -> Vec<u16> {
+        let mut identifiers: Vec<u16> = self.parts.iter().map(|p| p.external_identifier).collect();
+        identifiers.sort_unstable();
+        identifiers.dedup();
+        identifiers
+    }
+}
+
+/// Selects which parts of a dynamic mesh are drawn, by their `external_identifier`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum IdentifierMask {
+    #[default]
+    All,
+    Single(u16),
+    /// Every identifier with its bit set. Identifiers of 64 and above are never included
+    Bits(u64),
+}
+
+impl IdentifierMask {
+    /// Mask for a single identifier, where `u16::MAX` selects every part
+    pub fn single(identifier: u16) -> Self {
+        if identifier == u16::MAX {
+            Self::All
+        } else {
+            Self::Single(identifier)
+        }
+    }
+
+    pub fn contains(&self, identifier: u16) -> bool {
+        match *self {
+            Self::All => true,
+            Self::Single(i) => i == identifier,
+            Self::Bits(bits) => identifier < 64 && bits & (1 << identifier) != 0,
+        }
+    }
+
+    /// Adds or removes an identifier, out of the identifiers in `available`
+    pub fn set(&mut self, identifier: u16, included: bool, available: &[u16]) {
+        let mut bits = available
+            .iter()
+            .filter(|&&i| i < 64 && self.contains(i))
+            .fold(0u64, |acc, &i| acc | (1 << i));
+
+        if identifier < 64 {
+            if included {
+                bits |= 1 << identifier;
+            } else {
+                bits &= !(1 << identifier);
+            }
+        }
+
+        let all_bits = available
+            .iter()
+            .filter(|&&i| i < 64)
+            .fold(0u64, |acc, &i| acc | (1 << i));
+        *self = if bits == all_bits && available.iter().all(|&i| i < 64) {
+            Self::All
+        } else {
+            Self::Bits(bits)
+        };
+    }
+}
+
+impl std::fmt::Display for IdentifierMask {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Self::All => f.write_str("All"),
+            Self::Single(i) => write!(f, "ID {i}"),
+            Self::Bits(bits) => {
+                let ids: Vec<String> = (0..64)
+                    .filter(|i| bits & (1 << i) != 0)
+                    .map(|i| i.to_string())
+                    .collect();
+                if ids.is_empty() {
+                    f.write_str("None")
+                } else {
+                    write!(f, "IDs {}", ids.join(", "))
+                }
+            }
+        }
+    }
+}
+
+impl std::str::FromStr for IdentifierMask {
+    type Err = std::num::ParseIntError;
+
+    /// Parses `all`, or a comma separated list of identifiers
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.eq_ignore_ascii_case("all") {
+            return Ok(Self::All);
+        }
+
+        let ids = s
+            .split(',')
+            .map(|i| i.trim().parse::<u16>())
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(match ids.as_slice() {
+            [i] => Self::single(*i),
+            _ => Self::Bits(
+                ids.iter()
+                    .filter(|&&i| i < 64)
+                    .fold(0, |acc, &i| acc | (1 << i)),
+            ),
+        })
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -88,6 +235,47 @@ pub struct SDynamicMeshPart {
     pub unk20: u32,
 }
 
+/// Skeleton entity resource, found in `unk18` of entity resources with resource type 0x808081DD
+///
+/// Transforms are indexed the same as `nodes`, and are in object space (relative to the model
+/// origin, not the parent node)
+#[derive(Debug, Clone)]
+#[tiger_tag(id = 0x808081DE)]
+pub struct SSkeleton {
+    pub nodes: Vec<SSkeletonNode>,
+    pub default_object_space_transforms: Vec<SSkeletonTransform>,
+    pub default_inverse_object_space_transforms: Vec<SSkeletonTransform>,
+}
+
+#[derive(Debug, Clone)]
+#[tiger_tag(id = 0x80808642)]
+pub struct SSkeletonNode {
+    pub name_hash: FnvHash,
+    /// -1 for root nodes
+    pub parent_node_index: i32,
+    pub first_child_node_index: i32,
+    pub next_sibling_node_index: i32,
+}
+
+#[derive(Debug, Clone, Copy)]
+#[tiger_tag(id = 0x808070F1)]
+pub struct SSkeletonTransform {
+    pub rotation: glam::Quat,
+    pub translation: glam::Vec3,
+    pub scale: f32,
+}
+
+impl SSkeletonTransform {
+    pub fn to_mat4(&self) -> glam::Mat4 {
+        glam::Mat4::from_scale_rotation_translation(
+            glam::Vec3::splat(self.scale),
+            self.rotation,
+            self.translation,
+        )
+    }
+}
+
+/// Range of an entity's techniques used by a part, one technique per material variant
 #[derive(Debug, Clone)]
 #[tiger_tag(id = 0x80806D97)]
 pub struct Unk808072c5 {
@@ -96,6 +284,35 @@ pub struct Unk808072c5 {
     pub unk8: u32,
 }
 
+/// Number of material variants selectable through an entity's technique map
+pub fn variant_count(technique_map: &[Unk808072c5]) -> usize {
+    technique_map
+        .iter()
+        .filter(|m| m.unk8 == 0)
+        .map(|m| m.technique_count as usize)
+        .next()
+        .unwrap_or(0)
+}
+
+/// Index into the entity's technique list for a part's `variant_shader_index`. Variants wrap around
+/// for parts with fewer techniques than the entity has variants
+pub fn variant_technique_index(
+    technique_map: &[Unk808072c5],
+    variant_shader_index: u16,
+    variant: usize,
+) -> Option<usize> {
+    if variant_shader_index == u16::MAX {
+        return None;
+    }
+
+    let range = technique_map.get(variant_shader_index as usize)?;
+    if range.technique_count == 0 {
+        return None;
+    }
+
+    Some(range.technique_start as usize + (variant % range.technique_count as usize))
+}
+
 #[derive(Debug, Clone)]
 #[tiger_tag(id = 0xffffffff)]
 pub struct Unk80809905 {
@@ -123,3 +340,53 @@ pub struct Unk80809d02 {
 pub struct Unk8080894d {
     pub name: Pointer<NullString>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn identifier_mask() {
+        let available = [0, 1, 3];
+        let mut mask = IdentifierMask::All;
+        assert!(available.iter().all(|&i| mask.contains(i)));
+
+        mask.set(1, false, &available);
+        assert_eq!(mask, IdentifierMask::Bits(0b1001));
+        assert!(!mask.contains(1));
+        assert_eq!(mask.to_string(), "IDs 0, 3");
+
+        mask.set(1, true, &available);
+        assert_eq!(mask, IdentifierMask::All);
+
+        let mut single = IdentifierMask::single(3);
+        assert_eq!(single, IdentifierMask::Single(3));
+        single.set(0, true, &available);
+        assert_eq!(single, IdentifierMask::Bits(0b1001));
+        assert_eq!(IdentifierMask::single(u16::MAX), IdentifierMask::All);
+
+        assert_eq!("all".parse(), Ok(IdentifierMask::All));
+        assert_eq!("2".parse(), Ok(IdentifierMask::Single(2)));
+        assert_eq!("0, 3".parse(), Ok(IdentifierMask::Bits(0b1001)));
+        assert!("x".parse::<IdentifierMask>().is_err());
+    }
+
+    #[test]
+    fn variant_techniques() {
+        let map = |technique_count, technique_start| Unk808072c5 {
+            technique_count,
+            technique_start,
+            unk8: 0,
+        };
+        let technique_map = [map(3, 0), map(1, 3), map(0, 4)];
+
+        assert_eq!(variant_count(&technique_map), 3);
+        assert_eq!(variant_technique_index(&technique_map, 0, 2), Some(2));
+        // Parts with fewer techniques wrap around
+        assert_eq!(variant_technique_index(&technique_map, 0, 4), Some(1));
+        assert_eq!(variant_technique_index(&technique_map, 1, 2), Some(3));
+        assert_eq!(variant_technique_index(&technique_map, 2, 0), None);
+        assert_eq!(variant_technique_index(&technique_map, 7, 0), None);
+        assert_eq!(variant_technique_index(&technique_map, u16::MAX, 0), None);
+    }
+}
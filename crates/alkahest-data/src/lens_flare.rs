@@ -0,0 +1,101 @@
+use alkahest_pm::package_manager;
+use destiny_pkg::TagHash;
+use serde::{Deserialize, Serialize};
+use tiger_parse::PackageManagerExt;
+use tracing::warn;
+
+use crate::{
+    map::SLensFlare,
+    tag::{deserialize_taghash, serialize_taghash},
+    technique::STechnique,
+};
+
+/// Exportable description of a lens flare, with the textures of every element resolved
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LensFlareParameters {
+    #[serde(
+        serialize_with = "serialize_taghash",
+        deserialize_with = "deserialize_taghash"
+    )]
+    pub hash: TagHash,
+    pub elements: Vec<LensFlareElementParameters>,
+    pub occlusion: LensFlareOcclusionParameters,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LensFlareElementParameters {
+    #[serde(
+        serialize_with = "serialize_taghash",
+        deserialize_with = "deserialize_taghash"
+    )]
+    pub technique: TagHash,
+    pub flags: u32,
+    pub unk8: f32,
+    pub unkc: f32,
+    pub scale: [f32; 2],
+    pub color: [f32; 4],
+    /// Pixel shader textures of the element technique
+    pub textures: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LensFlareOcclusionParameters {
+    #[serde(
+        serialize_with = "serialize_taghash",
+        deserialize_with = "deserialize_taghash"
+    )]
+    pub technique: TagHash,
+    pub unk4: f32,
+    pub unk8: f32,
+    pub unkc: f32,
+}
+
+impl SLensFlare {
+    pub fn parameters(&self, hash: TagHash) -> LensFlareParameters {
+        LensFlareParameters {
+            hash,
+            elements: self
+                .elements
+                .iter()
+                .map(|e| LensFlareElementParameters {
+                    technique: e.technique,
+                    flags: e.flags,
+                    unk8: e.unk8,
+                    unkc: e.unkc,
+                    scale: e.scale.to_array(),
+                    color: e.color.to_array(),
+                    textures: technique_textures(e.technique)
+                        .iter()
+                        .map(|t| t.to_string())
+                        .collect(),
+                })
+                .collect(),
+            occlusion: LensFlareOcclusionParameters {
+                technique: self.occlusion.technique,
+                unk4: self.occlusion.unk4,
+                unk8: self.occlusion.unk8,
+                unkc: self.occlusion.unkc,
+            },
+        }
+    }
+}
+
+/// Textures bound to the pixel stage of a technique
+pub fn technique_textures(technique: TagHash) -> Vec<TagHash> {
+    if technique.is_none() {
+        return vec![];
+    }
+
+    match package_manager().read_tag_struct::<STechnique>(technique) {
+        Ok(technique) => technique
+            .shader_pixel
+            .textures
+            .iter()
+            .filter_map(|t| t.texture.hash32_checked())
+            .collect(),
+        Err(e) => {
+            warn!("Failed to read lens flare technique {technique}: {e}");
+            vec![]
+        }
+    }
+}
@@ -1,15 +1,24 @@
 #![allow(clippy::missing_transmute_annotations)]
 
 pub mod activity;
+pub mod activity_graph;
+pub mod animation;
+pub mod atmosphere;
 pub mod buffers;
 pub mod common;
+pub mod decal;
 pub mod decorator;
+pub mod dependencies;
 pub mod dxgi;
+pub mod dye;
 pub mod entity;
 pub mod geometry;
+pub mod lens_flare;
+pub mod lightprobe;
 pub mod map;
 pub mod occlusion;
 pub mod render_globals;
+pub mod reverse_index;
 pub mod sound;
 pub mod statics;
 pub mod tag;
@@ -18,5 +27,6 @@ pub mod text;
 pub mod texture;
 pub mod tfx;
 pub mod unknown;
+pub mod wwise;
 
 pub use tag::{Tag, WideHash, WideTag};
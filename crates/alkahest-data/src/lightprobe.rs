@@ -0,0 +1,306 @@
+//! Baked indirect lighting, stored in the voxel IBL texture of cubemap volumes
+//!
+//! The voxel IBL texture is a 3D texture spanning the influence box of its cubemap volume, with U
+//! along +X, V along +Y and the depth slices along +Z, starting from the negative corner. Its depth
+//! holds several groups of slices, one per basis function ([`VoxelIblEncoding`]).
+//!
+//! Decoding is experimental. The encoding isn't stored anywhere we know of and hasn't been checked
+//! against the shaders sampling the texture, so unless it's given explicitly it is guessed from the
+//! number of slices ([`VoxelIblEncoding::detect`]).
+//!
+//! Probes are converted to L1 spherical harmonics, which are linear, so trilinearly blending the
+//! coefficients of neighbouring probes gives the same result as blending their lighting.
+
+use std::f32::consts::PI;
+
+use anyhow::ensure;
+use glam::{Vec3, Vec4};
+
+/// Normalization constant of the L0 basis function
+pub const SH_Y00: f32 = 0.282_095;
+/// Normalization constant of the L1 basis functions
+pub const SH_Y1: f32 = 0.488_603;
+
+/// Cosine lobe convolution factors for the L0 and L1 bands
+const SH_A0: f32 = PI;
+const SH_A1: f32 = 2.0 * PI / 3.0;
+
+/// RGB radiance projected onto L1 spherical harmonics
+///
+/// Coefficients are in the usual order (`Y00, Y1-1, Y10, Y11`), so the 3 linear coefficients line
+/// up with the Y, Z and X axes respectively.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct ShL1 {
+    pub coefficients: [Vec3; 4],
+}
+
+impl ShL1 {
+    /// Ambient lighting with uniform `irradiance` from every direction
+    pub fn from_irradiance(irradiance: Vec3) -> Self {
+        Self {
+            coefficients: [
+                irradiance / (SH_A0 * SH_Y00),
+                Vec3::ZERO,
+                Vec3::ZERO,
+                Vec3::ZERO,
+            ],
+        }
+    }
+
+    /// Projects an ambient cube (radiance along `+X, -X, +Y, -Y, +Z, -Z`) onto L1 spherical
+    /// harmonics
+    pub fn from_ambient_cube(cube: &[Vec3; 6]) -> Self {
+        let sum: Vec3 = cube.iter().sum();
+        let axis = |positive: Vec3, negative: Vec3| SH_Y1 * PI / 2.0 * (positive - negative);
+
+        Self {
+            coefficients: [
+                SH_Y00 * 2.0 * PI / 3.0 * sum,
+                axis(cube[2], cube[3]),
+                axis(cube[4], cube[5]),
+                axis(cube[0], cube[1]),
+            ],
+        }
+    }
+
+    /// Irradiance received by a surface facing `normal`
+    pub fn irradiance(&self, normal: Vec3) -> Vec3 {
+        let [l0, y, z, x] = self.coefficients;
+        SH_A0 * SH_Y00 * l0 + SH_A1 * SH_Y1 * (y * normal.y + z * normal.z + x * normal.x)
+    }
+
+    /// Direction the most light arrives from, if the lighting is directional at all
+    pub fn dominant_direction(&self) -> Option<Vec3> {
+        let [_, y, z, x] = self.coefficients;
+        // Weigh the color channels by luminance
+        let luma = Vec3::new(0.2126, 0.7152, 0.0722);
+        Vec3::new(x.dot(luma), y.dot(luma), z.dot(luma)).try_normalize()
+    }
+
+    pub fn lerp(&self, other: &Self, t: f32) -> Self {
+        Self {
+            coefficients: std::array::from_fn(|i| {
+                self.coefficients[i].lerp(other.coefficients[i], t)
+            }),
+        }
+    }
+
+    /// Coefficients as 12 floats, RGB per coefficient
+    pub fn to_array(&self) -> [f32; 12] {
+        let mut out = [0.0; 12];
+        for (i, c) in self.coefficients.iter().enumerate() {
+            out[i * 3..i * 3 + 3].copy_from_slice(&c.to_array());
+        }
+        out
+    }
+}
+
+/// How the slice groups of a voxel IBL texture are interpreted
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VoxelIblEncoding {
+    /// A single group holding RGB irradiance, without any directionality
+    Irradiance,
+    /// 4 groups holding the RGB L1 spherical harmonics coefficients
+    ShL1,
+    /// 6 groups holding RGB radiance along `+X, -X, +Y, -Y, +Z, -Z`
+    AmbientCube,
+}
+
+impl VoxelIblEncoding {
+    /// Guesses the encoding from the number of depth slices. Ambient cubes are preferred when
+    /// the depth allows for both
+    ///
+    /// Experimental: this is a heuristic, not the encoding the game uses
+    pub fn detect(depth: usize) -> Self {
+        if depth >= 12 && depth % 6 == 0 {
+            Self::AmbientCube
+        } else if depth >= 8 && depth % 4 == 0 {
+            Self::ShL1
+        } else {
+            Self::Irradiance
+        }
+    }
+
+    /// Number of slice groups in the texture
+    pub fn group_count(self) -> usize {
+        match self {
+            Self::Irradiance => 1,
+            Self::ShL1 => 4,
+            Self::AmbientCube => 6,
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Irradiance => "irradiance",
+            Self::ShL1 => "sh_l1",
+            Self::AmbientCube => "ambient_cube",
+        }
+    }
+}
+
+/// Probe grid decoded from a voxel IBL texture
+#[derive(Debug, Clone)]
+pub struct VoxelProbeGrid {
+    /// Number of probes along each axis
+    pub size: [usize; 3],
+    pub encoding: VoxelIblEncoding,
+    probes: Vec<ShL1>,
+}
+
+impl VoxelProbeGrid {
+    /// Decodes a grid from texels stored row by row, slice by slice, with the slice groups of the
+    /// encoding following each other
+    pub fn from_texels(
+        width: usize,
+        height: usize,
+        depth: usize,
+        encoding: VoxelIblEncoding,
+        texels: &[Vec4],
+    ) -> anyhow::Result<Self> {
+        let groups = encoding.group_count();
+        ensure!(
+            width > 0 && height > 0 && depth > 0,
+            "Voxel IBL texture is empty"
+        );
+        ensure!(
+            depth % groups == 0,
+            "Depth {depth} can't be split into {groups} slice groups"
+        );
+        ensure!(
+            texels.len() == width * height * depth,
+            "Expected {} texels, got {}",
+            width * height * depth,
+            texels.len()
+        );
+
+        let size = [width, height, depth / groups];
+        let group_stride = width * height * size[2];
+        let probes = (0..group_stride)
+            .map(|i| {
+                let group = |g: usize| texels[g * group_stride + i].truncate();
+                match encoding {
+                    VoxelIblEncoding::Irradiance => ShL1::from_irradiance(group(0)),
+                    VoxelIblEncoding::ShL1 => ShL1 {
+                        coefficients: std::array::from_fn(group),
+                    },
+                    VoxelIblEncoding::AmbientCube => {
+                        ShL1::from_ambient_cube(&std::array::from_fn(group))
+                    }
+                }
+            })
+            .collect();
+
+        Ok(Self {
+            size,
+            encoding,
+            probes,
+        })
+    }
+
+    pub fn probe(&self, x: usize, y: usize, z: usize) -> &ShL1 {
+        let [w, h, _] = self.size;
+        &self.probes[(z * h + y) * w + x]
+    }
+
+    /// Trilinearly samples the grid at `uvw` (0-1 across the grid, clamped), with the probes at
+    /// the texel centers
+    pub fn sample(&self, uvw: Vec3) -> ShL1 {
+        let coord = |v: f32, size: usize| {
+            let p = (v.clamp(0.0, 1.0) * size as f32 - 0.5).clamp(0.0, (size - 1) as f32);
+            let i0 = p as usize;
+            (i0, (i0 + 1).min(size - 1), p - i0 as f32)
+        };
+
+        let (x0, x1, fx) = coord(uvw.x, self.size[0]);
+        let (y0, y1, fy) = coord(uvw.y, self.size[1]);
+        let (z0, z1, fz) = coord(uvw.z, self.size[2]);
+
+        let plane = |z: usize| {
+            let near = self.probe(x0, y0, z).lerp(self.probe(x1, y0, z), fx);
+            let far = self.probe(x0, y1, z).lerp(self.probe(x1, y1, z), fx);
+            near.lerp(&far, fy)
+        };
+
+        plane(z0).lerp(&plane(z1), fz)
+    }
+
+    /// Position of a probe in the 0-1 range of the grid
+    pub fn probe_uvw(&self, x: usize, y: usize, z: usize) -> Vec3 {
+        (Vec3::new(x as f32, y as f32, z as f32) + 0.5)
+            / Vec3::new(
+                self.size[0] as f32,
+                self.size[1] as f32,
+                self.size[2] as f32,
+            )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(a: Vec3, b: Vec3) {
+        assert!(a.abs_diff_eq(b, 1e-4), "{a} != {b}");
+    }
+
+    #[test]
+    fn uniform_lighting() {
+        // Constant radiance L gives an irradiance of pi * L in every direction
+        let cube = [Vec3::splat(0.5); 6];
+        let sh = ShL1::from_ambient_cube(&cube);
+        for n in [Vec3::X, Vec3::NEG_Y, Vec3::Z] {
+            assert_close(sh.irradiance(n), Vec3::splat(0.5 * PI));
+        }
+        assert_eq!(sh.dominant_direction(), None);
+
+        let sh = ShL1::from_irradiance(Vec3::new(1.0, 2.0, 3.0));
+        assert_close(sh.irradiance(Vec3::Y), Vec3::new(1.0, 2.0, 3.0));
+    }
+
+    #[test]
+    fn directional_ambient_cube() {
+        let mut cube = [Vec3::ZERO; 6];
+        // Light from above
+        cube[4] = Vec3::ONE;
+        let sh = ShL1::from_ambient_cube(&cube);
+
+        assert_close(sh.dominant_direction().unwrap(), Vec3::Z);
+        assert!(sh.irradiance(Vec3::Z).x > sh.irradiance(Vec3::X).x);
+        assert!(sh.irradiance(Vec3::X).x > sh.irradiance(Vec3::NEG_Z).x);
+    }
+
+    #[test]
+    fn detect_encoding() {
+        assert_eq!(VoxelIblEncoding::detect(24), VoxelIblEncoding::AmbientCube);
+        assert_eq!(VoxelIblEncoding::detect(16), VoxelIblEncoding::ShL1);
+        assert_eq!(VoxelIblEncoding::detect(6), VoxelIblEncoding::Irradiance);
+        assert_eq!(VoxelIblEncoding::detect(1), VoxelIblEncoding::Irradiance);
+    }
+
+    #[test]
+    fn grid_sampling() {
+        // 2x1x1 probes stored as 4 SH groups, with only the L0 coefficient set
+        let texels: Vec<Vec4> = [0.0, 1.0]
+            .into_iter()
+            .chain([0.0; 6])
+            .map(Vec4::splat)
+            .collect();
+        let grid = VoxelProbeGrid::from_texels(2, 1, 4, VoxelIblEncoding::ShL1, &texels).unwrap();
+        assert_eq!(grid.size, [2, 1, 1]);
+
+        assert_eq!(grid.probe(1, 0, 0).coefficients[0], Vec3::ONE);
+        assert_eq!(grid.probe(1, 0, 0).coefficients[1], Vec3::ZERO);
+        assert_close(grid.probe_uvw(1, 0, 0), Vec3::new(0.75, 0.5, 0.5));
+
+        // Halfway between the probes, and clamped outside of them
+        assert_close(
+            grid.sample(Vec3::new(0.5, 0.5, 0.5)).coefficients[0],
+            Vec3::splat(0.5),
+        );
+        assert_close(grid.sample(Vec3::ZERO).coefficients[0], Vec3::ZERO);
+        assert_close(grid.sample(Vec3::ONE).coefficients[0], Vec3::ONE);
+
+        assert!(VoxelProbeGrid::from_texels(2, 1, 3, VoxelIblEncoding::ShL1, &texels).is_err());
+    }
+}
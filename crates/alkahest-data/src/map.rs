@@ -165,7 +165,7 @@ pub struct SUnk8080714b {
     pub terrain_bounds: TagHash,
 }
 
-/// Cubemap volume resource
+/// Cubemap volume resource, only the extents, cubemap center and textures are known
 #[derive(Clone, Debug)]
 #[tiger_tag(id = 0xffffffff, size = 0x1d0)]
 pub struct SCubemapVolume {
@@ -200,27 +200,6 @@ pub struct SCubemapVolume {
     pub unk1c4: [u32; 3],
 }
 
-/// Decal collection resource
-#[derive(Clone, Debug)]
-#[tiger_tag(id = 0xffffffff)]
-pub struct SUnk80806e68 {
-    pub file_size: u64,
-    pub instances: Vec<SUnk80806e6c>,
-    pub instance_points: TagHash,
-    pub unk_vertex_colors: TagHash,
-
-    pub unk30: [u32; 2],
-    pub occlusion_bounds: Tag<SOcclusionBounds>,
-    _pad3c: u32,
-    pub bounds: Aabb,
-}
-#[derive(Clone, Debug)]
-#[tiger_tag(id = 0x80806963)]
-pub struct SUnk80806e6c {
-    pub material: TagHash,
-    pub start: u16,
-    pub count: u16,
-}
 #[derive(Clone, Debug)]
 #[tiger_tag(id = 0xffffffff)]
 pub struct SUnk80806df3 {
@@ -358,6 +337,7 @@ pub struct SLight {
     pub unk20: Vec4,
     pub unk30: Vec4,
     pub unk40: [u32; 4],
+    /// Passed to the techniques through the `DeferredLight` extern (0x100)
     pub unk50: Vec4,
     pub light_to_world: Mat4,
     pub unka0: u32,
@@ -389,6 +369,7 @@ pub struct SShadowingLight {
     pub unk20: Vec4,
     pub unk30: Vec4,
     pub unk40: [u32; 4],
+    /// Passed to the techniques through the `DeferredLight` extern (0x100)
     pub unk50: Vec4,
     pub light_to_world: Mat4,
     pub unka0: u32,
@@ -532,7 +513,8 @@ pub struct SSlipSurfaceVolume {
     pub shape_index: u32,
 }
 
-#[derive(Clone)]
+/// Water surface. Only the entity model is known, it holds the surface mesh and its techniques
+#[derive(Clone, Debug)]
 #[tiger_tag(id = 0xffffffff)]
 pub struct SUnk808068d4 {
     pub unk0: u32,
@@ -654,9 +636,108 @@ pub struct SMapAtmosphere {
     pub unkd0: WideHash,
 }
 
-#[derive(Debug)]
-#[cfg_attr(feature = "bevy", derive(bevy_ecs::component::Component))]
+#[derive(Clone, Debug)]
 #[tiger_tag(id = 0x80806A78)]
 pub struct SLensFlare {
-    // TODO(cohae): Placeholder struct
+    pub file_size: u64,
+    pub elements: Vec<SLensFlareElement>,
+    pub occlusion: SLensFlareOcclusion,
+}
+
+/// A single sprite of a lens flare
+#[derive(Clone, Debug)]
+#[tiger_tag(size = 0x30)]
+pub struct SLensFlareElement {
+    pub technique: TagHash,
+    pub flags: u32,
+    pub unk8: f32,
+    pub unkc: f32,
+    pub scale: glam::Vec2,
+    pub unk18: [u32; 2],
+    pub color: Vec4,
+}
+
+#[derive(Clone, Debug)]
+#[tiger_tag(size = 0x10)]
+pub struct SLensFlareOcclusion {
+    pub technique: TagHash,
+    pub unk4: f32,
+    pub unk8: f32,
+    pub unkc: f32,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use tiger_parse::TigerReadable;
+
+    use super::*;
+
+    fn put(data: &mut [u8], offset: usize, value: u32) {
+        data[offset..offset + 4].copy_from_slice(&value.to_le_bytes());
+    }
+
+    #[test]
+    fn light_layout() {
+        let mut data = vec![0u8; 240];
+        put(&mut data, 0x50, 2.0f32.to_bits());
+        // light_to_world translation (4th column)
+        put(&mut data, 0x90, 5.0f32.to_bits());
+        put(&mut data, 0xc4, 0x80c0ffee);
+        put(&mut data, 0xcc, 0x80c0ffef);
+
+        let light: SLight = TigerReadable::read_ds(&mut Cursor::new(&data)).unwrap();
+        assert_eq!(light.unk50.x, 2.0);
+        assert_eq!(light.light_to_world.w_axis.x, 5.0);
+        assert_eq!(light.technique_shading, TagHash(0x80c0ffee));
+        assert_eq!(light.technique_compute_lightprobe, TagHash(0x80c0ffef));
+    }
+
+    #[test]
+    fn shadowing_light_layout() {
+        let mut data = vec![0u8; 0x110];
+        put(&mut data, 0xc0, 250.0f32.to_bits());
+        put(&mut data, 0xc4, 0.5f32.to_bits());
+        put(&mut data, 0xd0, 0x80c0ffee);
+        put(&mut data, 0xd4, 0x80c0ffef);
+        put(&mut data, 0xdc, u32::MAX);
+
+        let light: SShadowingLight = TigerReadable::read_ds(&mut Cursor::new(&data)).unwrap();
+        assert_eq!(light.far_plane, 250.0);
+        assert_eq!(light.half_fov, 0.5);
+        assert_eq!(light.technique_shading, TagHash(0x80c0ffee));
+        assert_eq!(light.technique_shading_shadowing, TagHash(0x80c0ffef));
+        assert!(light.technique_volumetrics_shadowing.is_none());
+    }
+
+    #[test]
+    fn cubemap_volume_layout() {
+        let mut data = vec![0u8; 0x1d0];
+        put(&mut data, 0x20, 4.0f32.to_bits());
+        put(&mut data, 0x3c, 1.0f32.to_bits());
+        put(&mut data, 0x70, 7);
+        put(&mut data, 0xc0, 2.0f32.to_bits());
+        put(&mut data, 0x120, 3.0f32.to_bits());
+        put(&mut data, 0x1ac, 0x80c0ffee);
+        put(&mut data, 0x1b4, 0x80c0ffef);
+
+        let volume: SCubemapVolume = TigerReadable::read_ds(&mut Cursor::new(&data)).unwrap();
+        assert_eq!(volume.cubemap_extents.x, 4.0);
+        assert_eq!(volume.cubemap_center.w, 1.0);
+        assert_eq!(volume.unk70[0], 7);
+        assert_eq!(volume.unkc0.x_axis.x, 2.0);
+        assert_eq!(volume.unk120.x_axis.x, 3.0);
+        assert_eq!(volume.cubemap_texture, TagHash(0x80c0ffee));
+        assert_eq!(volume.voxel_ibl_texture, TagHash(0x80c0ffef));
+    }
+
+    #[test]
+    fn water_layout() {
+        let mut data = vec![0u8; 0x14];
+        put(&mut data, 0x10, 0x80c0ffee);
+
+        let water: SUnk808068d4 = TigerReadable::read_ds(&mut Cursor::new(&data)).unwrap();
+        assert_eq!(water.entity_model, TagHash(0x80c0ffee));
+    }
 }
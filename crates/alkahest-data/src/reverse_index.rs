@@ -0,0 +1,221 @@
+use std::{
+    io::{Cursor, Read, Write},
+    path::Path,
+};
+
+use alkahest_pm::package_manager;
+use binrw::{BinReaderExt, BinWriterExt};
+use destiny_pkg::TagHash;
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
+use rustc_hash::{FxHashMap, FxHashSet};
+use tiger_parse::{FnvHash, TigerReadable};
+use tracing::{info, warn};
+
+use crate::{
+    activity::SEntityResource,
+    decorator::SDecorator,
+    dependencies,
+    entity::{SDynamicModel, SEntity},
+    map::{SBubbleDefinition, SBubbleParent, SMapContainer, SMapDataTable, STerrain, SUnk80806ef4},
+    statics::SStaticMesh,
+    technique::STechnique,
+};
+
+const CACHE_MAGIC: &[u8; 8] = b"ALKRIDX\0";
+const CACHE_VERSION: u32 = 1;
+
+/// Maps every tag to the tags that reference it, built from the same structures the dependency walker understands
+#[derive(Default)]
+pub struct ReverseIndex {
+    users: FxHashMap<TagHash, Vec<TagHash>>,
+}
+
+impl ReverseIndex {
+    /// Loads the index from `cache_path` if it was built for the current package set, rebuilding it otherwise
+    pub fn load_or_build(cache_path: &Path) -> Self {
+        match Self::load(cache_path) {
+            Ok(Some(index)) => {
+                info!(
+                    "Loaded reverse reference index from {}",
+                    cache_path.display()
+                );
+                return index;
+            }
+            Ok(None) => info!("Reverse reference index is out of date, rebuilding"),
+            Err(e) => info!("Failed to load reverse reference index, rebuilding: {e}"),
+        }
+
+        let index = Self::build();
+        if let Err(e) = index.save(cache_path) {
+            warn!("Failed to save reverse reference index: {e}");
+        }
+
+        index
+    }
+
+    pub fn build() -> Self {
+        let classes = [
+            SBubbleParent::ID,
+            SBubbleDefinition::ID,
+            SMapContainer::ID,
+            SMapDataTable::ID,
+            SUnk80806ef4::ID,
+            SStaticMesh::ID,
+            SDynamicModel::ID,
+            STerrain::ID,
+            SDecorator::ID,
+            STechnique::ID,
+            SEntity::ID,
+            SEntityResource::ID,
+        ];
+
+        let tags: Vec<TagHash> = classes
+            .into_iter()
+            .flatten()
+            .flat_map(|class| package_manager().get_all_by_reference(class))
+            .map(|(hash, _)| hash)
+            .collect();
+
+        info!("Building reverse reference index from {} tags", tags.len());
+        let edges: Vec<(TagHash, Vec<(String, TagHash)>)> = tags
+            .into_par_iter()
+            .filter_map(|user| Some((user, dependencies::references(user).ok()?)))
+            .collect();
+
+        let mut index = Self::default();
+        for (user, references) in edges {
+            for (_, used) in references {
+                index.users.entry(used).or_default().push(user);
+            }
+        }
+
+        index
+    }
+
+    /// Tags that directly reference `hash`
+    pub fn users(&self, hash: TagHash) -> &[TagHash] {
+        self.users
+            .get(&hash)
+            .map(|v| v.as_slice())
+            .unwrap_or_default()
+    }
+
+    /// Walks up the references of `hash`, returning every tag of class `class` that (indirectly) uses it.
+    /// Eg. finding the maps that place a static, or the statics that use a texture
+    pub fn users_of_class(&self, hash: TagHash, class: u32) -> Vec<TagHash> {
+        let mut result = vec![];
+        let mut visited = FxHashSet::default();
+        let mut queue = vec![hash];
+        while let Some(current) = queue.pop() {
+            for &user in self.users(current) {
+                if !visited.insert(user) {
+                    continue;
+                }
+
+                if package_manager()
+                    .get_entry(user)
+                    .map_or(false, |e| e.reference == class)
+                {
+                    result.push(user);
+                } else {
+                    queue.push(user);
+                }
+            }
+        }
+
+        result.sort_by_key(|t| t.0);
+        result
+    }
+
+    pub fn len(&self) -> usize {
+        self.users.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.users.is_empty()
+    }
+
+    /// Identifies the package set the index was built from. Besides the entry counts, this covers
+    /// the path (which holds the patch ID), size and modification time of every package file, so
+    /// a game update invalidates the index even when it doesn't add or remove entries
+    fn package_set_key() -> FnvHash {
+        let pm = package_manager();
+        let mut data = pm.package_dir.to_string_lossy().as_bytes().to_vec();
+        let mut packages: Vec<_> = pm.package_entry_index.iter().collect();
+        packages.sort_by_key(|(id, _)| **id);
+        for (id, entries) in packages {
+            data.extend_from_slice(&id.to_le_bytes());
+            data.extend_from_slice(&(entries.len() as u32).to_le_bytes());
+
+            let Some(path) = pm.package_paths.get(id) else {
+                continue;
+            };
+            data.extend_from_slice(path.path.as_bytes());
+            if let Ok(metadata) = std::fs::metadata(&path.path) {
+                data.extend_from_slice(&metadata.len().to_le_bytes());
+                let modified = metadata
+                    .modified()
+                    .ok()
+                    .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
+                    .unwrap_or_default();
+                data.extend_from_slice(&modified.as_secs().to_le_bytes());
+            }
+        }
+
+        fnv1(&data)
+    }
+
+    fn load(path: &Path) -> anyhow::Result<Option<Self>> {
+        let mut data = vec![];
+        std::fs::File::open(path)?.read_to_end(&mut data)?;
+        let mut cur = Cursor::new(data);
+
+        let mut magic = [0u8; 8];
+        cur.read_exact(&mut magic)?;
+        let version: u32 = cur.read_le()?;
+        let key: u32 = cur.read_le()?;
+        if &magic != CACHE_MAGIC || version != CACHE_VERSION || key != Self::package_set_key() {
+            return Ok(None);
+        }
+
+        let count: u32 = cur.read_le()?;
+        let mut index = Self::default();
+        for _ in 0..count {
+            let used: TagHash = cur.read_le()?;
+            let user_count: u32 = cur.read_le()?;
+            let mut users = Vec::with_capacity(user_count as usize);
+            for _ in 0..user_count {
+                users.push(cur.read_le()?);
+            }
+            index.users.insert(used, users);
+        }
+
+        Ok(Some(index))
+    }
+
+    fn save(&self, path: &Path) -> anyhow::Result<()> {
+        let mut cur = Cursor::new(vec![]);
+        cur.write_all(CACHE_MAGIC)?;
+        cur.write_le(&CACHE_VERSION)?;
+        cur.write_le(&Self::package_set_key())?;
+        cur.write_le(&(self.users.len() as u32))?;
+        for (used, users) in &self.users {
+            cur.write_le(&used.0)?;
+            cur.write_le(&(users.len() as u32))?;
+            for user in users {
+                cur.write_le(&user.0)?;
+            }
+        }
+
+        std::fs::write(path, cur.into_inner())?;
+        Ok(())
+    }
+}
+
+const FNV1_BASE: u32 = 0x811c9dc5;
+const FNV1_PRIME: u32 = 0x01000193;
+fn fnv1(data: &[u8]) -> FnvHash {
+    data.iter().fold(FNV1_BASE, |acc, b| {
+        acc.wrapping_mul(FNV1_PRIME) ^ (*b as u32)
+    })
+}
@@ -6,6 +6,7 @@ use std::{
 use alkahest_pm::package_manager;
 use binrw::{BinRead, BinReaderExt};
 use destiny_pkg::{TagHash, TagHash64};
+use serde::{Deserialize, Deserializer, Serializer};
 use tiger_parse::{dpkg::PackageManagerExt, TigerReadable};
 
 #[derive(Clone)]
@@ -201,3 +202,18 @@ impl<T: TigerReadable + Debug> Debug for WideTag<T> {
         self.0.fmt(f)
     }
 }
+
+/// Serializes a tag hash as its display string, for use with `#[serde(serialize_with)]`
+pub fn serialize_taghash<S: Serializer>(hash: &TagHash, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(hash)
+}
+
+/// Counterpart of [`serialize_taghash`], for use with `#[serde(deserialize_with)]`
+pub fn deserialize_taghash<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<TagHash, D::Error> {
+    let s = String::deserialize(deserializer)?;
+    u32::from_str_radix(s.trim_start_matches("0x"), 16)
+        .map(|v| TagHash(u32::from_be(v)))
+        .map_err(serde::de::Error::custom)
+}
@@ -0,0 +1,116 @@
+/// Reads bits least-significant first, as packed by Vorbis
+pub struct BitReader<'a> {
+    data: &'a [u8],
+    position: usize,
+}
+
+impl<'a> BitReader<'a> {
+    pub fn new(data: &'a [u8]) -> Self {
+        Self { data, position: 0 }
+    }
+
+    pub fn read(&mut self, bits: u32) -> anyhow::Result<u32> {
+        debug_assert!(bits <= 32);
+        let mut value = 0;
+        for i in 0..bits {
+            let Some(byte) = self.data.get(self.position / 8) else {
+                anyhow::bail!("Out of bits after {} bits", self.position);
+            };
+
+            value |= (((byte >> (self.position % 8)) & 1) as u32) << i;
+            self.position += 1;
+        }
+
+        Ok(value)
+    }
+
+    pub fn bits_read(&self) -> usize {
+        self.position
+    }
+}
+
+#[derive(Default)]
+pub struct BitWriter {
+    data: Vec<u8>,
+    position: usize,
+}
+
+impl BitWriter {
+    pub fn write(&mut self, value: u32, bits: u32) {
+        debug_assert!(bits <= 32);
+        for i in 0..bits {
+            if self.position % 8 == 0 {
+                self.data.push(0);
+            }
+
+            if (value >> i) & 1 != 0 {
+                *self.data.last_mut().unwrap() |= 1 << (self.position % 8);
+            }
+            self.position += 1;
+        }
+    }
+
+    pub fn write_bool(&mut self, value: bool) {
+        self.write(value as u32, 1);
+    }
+
+    pub fn write_bytes(&mut self, bytes: &[u8]) {
+        for &b in bytes {
+            self.write(b as u32, 8);
+        }
+    }
+
+    /// Copies `bits` bits from `reader`, returning the copied value
+    pub fn copy(&mut self, reader: &mut BitReader, bits: u32) -> anyhow::Result<u32> {
+        let value = reader.read(bits)?;
+        self.write(value, bits);
+        Ok(value)
+    }
+
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.data
+    }
+}
+
+/// Number of bits needed to store `v`
+pub fn ilog(mut v: u32) -> u32 {
+    let mut bits = 0;
+    while v != 0 {
+        bits += 1;
+        v >>= 1;
+    }
+    bits
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_roundtrip() {
+        let mut writer = BitWriter::default();
+        writer.write(0b101, 3);
+        writer.write(0x564342, 24);
+        writer.write_bool(true);
+        writer.write(0x1234, 13);
+
+        let data = writer.into_bytes();
+        assert_eq!(data.len(), 6);
+
+        let mut reader = BitReader::new(&data);
+        assert_eq!(reader.read(3).unwrap(), 0b101);
+        assert_eq!(reader.read(24).unwrap(), 0x564342);
+        assert_eq!(reader.read(1).unwrap(), 1);
+        assert_eq!(reader.read(13).unwrap(), 0x1234);
+        assert_eq!(reader.bits_read(), 41);
+        assert!(reader.read(8).is_err());
+    }
+
+    #[test]
+    fn test_ilog() {
+        assert_eq!(ilog(0), 0);
+        assert_eq!(ilog(1), 1);
+        assert_eq!(ilog(7), 3);
+        assert_eq!(ilog(8), 4);
+    }
+}
@@ -0,0 +1,360 @@
+//! Decoding of Wwise audio streams (.wem), as referenced by [`crate::sound::SSoundCollection::streams`]
+
+mod bits;
+pub mod vorbis;
+
+use std::io::Cursor;
+
+use alkahest_pm::package_manager;
+use anyhow::Context;
+use destiny_pkg::TagHash;
+use ogg::writing::{PacketWriteEndInfo, PacketWriter};
+use tiger_parse::{PackageManagerExt, TigerReadable};
+
+use self::vorbis::{CodebookLibrary, VorbisInfo, VorbisPackets};
+use crate::sound::SSoundCollection;
+
+const CODEC_PCM: u16 = 0x0001;
+const CODEC_PCM_EXTENSIBLE: u16 = 0xFFFE;
+const CODEC_VORBIS: u16 = 0xFFFF;
+
+/// A parsed RIFF .wem container
+pub struct Wem<'a> {
+    pub codec: u16,
+    pub channels: u16,
+    pub sample_rate: u32,
+    pub avg_bytes_per_second: u32,
+    pub bits_per_sample: u16,
+    fmt: &'a [u8],
+    vorb: Option<&'a [u8]>,
+    data: &'a [u8],
+}
+
+impl<'a> Wem<'a> {
+    pub fn parse(data: &'a [u8]) -> anyhow::Result<Self> {
+        anyhow::ensure!(data.len() >= 12, "File is too small to be a RIFF file");
+        match &data[0..4] {
+            b"RIFF" => {}
+            b"RIFX" => anyhow::bail!("Big-endian (RIFX) streams are not supported"),
+            _ => anyhow::bail!("Missing RIFF header"),
+        }
+        anyhow::ensure!(&data[8..12] == b"WAVE", "Missing WAVE header");
+
+        let riff_end =
+            (u32::from_le_bytes(data[4..8].try_into().unwrap()) as usize + 8).min(data.len());
+        let mut fmt = None;
+        let mut vorb = None;
+        let mut data_chunk = None;
+        let mut offset = 12;
+        while offset + 8 <= riff_end {
+            let id = &data[offset..offset + 4];
+            let size =
+                u32::from_le_bytes(data[offset + 4..offset + 8].try_into().unwrap()) as usize;
+            let chunk = data.get(offset + 8..offset + 8 + size).with_context(|| {
+                format!("Chunk '{}' is out of bounds", String::from_utf8_lossy(id))
+            })?;
+
+            match id {
+                b"fmt " => fmt = Some(chunk),
+                b"vorb" => vorb = Some(chunk),
+                b"data" => data_chunk = Some(chunk),
+                _ => {}
+            }
+
+            offset += 8 + size;
+        }
+
+        let fmt = fmt.context("Missing fmt chunk")?;
+        anyhow::ensure!(fmt.len() >= 0x10, "fmt chunk is too small");
+        let u16_at = |o: usize| u16::from_le_bytes([fmt[o], fmt[o + 1]]);
+        let u32_at = |o: usize| u32::from_le_bytes(fmt[o..o + 4].try_into().unwrap());
+
+        Ok(Self {
+            codec: u16_at(0),
+            channels: u16_at(2),
+            sample_rate: u32_at(4),
+            avg_bytes_per_second: u32_at(8),
+            bits_per_sample: u16_at(14),
+            fmt,
+            vorb,
+            data: data_chunk.context("Missing data chunk")?,
+        })
+    }
+
+    pub fn codec_name(&self) -> &'static str {
+        match self.codec {
+            CODEC_PCM | CODEC_PCM_EXTENSIBLE => "PCM",
+            0x0002 => "Wwise IMA ADPCM",
+            CODEC_VORBIS => "Wwise Vorbis",
+            0x3040 => "Opus",
+            _ => "Unknown",
+        }
+    }
+
+    fn vorbis_info(&self) -> anyhow::Result<VorbisInfo> {
+        // Newer versions store the vorb data at the end of an extended fmt chunk
+        let vorb = match self.vorb {
+            Some(vorb) => vorb,
+            None if self.fmt.len() == 0x42 => &self.fmt[0x18..],
+            None => anyhow::bail!("Missing vorb chunk"),
+        };
+
+        anyhow::ensure!(
+            (1..=u8::MAX as u16).contains(&self.channels),
+            "Invalid channel count {}",
+            self.channels
+        );
+
+        VorbisInfo::parse(
+            vorb,
+            self.channels as u8,
+            self.sample_rate,
+            self.avg_bytes_per_second,
+        )
+    }
+
+    /// Converts Wwise vorbis streams to standard Vorbis packets
+    pub fn vorbis_packets(&self, codebooks: &CodebookLibrary) -> anyhow::Result<VorbisPackets> {
+        anyhow::ensure!(
+            self.codec == CODEC_VORBIS,
+            "Stream is not Wwise Vorbis ({})",
+            self.codec_name()
+        );
+
+        VorbisPackets::rebuild(&self.vorbis_info()?, self.data, codebooks)
+    }
+
+    pub fn decode(&self, codebooks: &CodebookLibrary) -> anyhow::Result<DecodedAudio> {
+        match self.codec {
+            CODEC_PCM | CODEC_PCM_EXTENSIBLE => {
+                anyhow::ensure!(
+                    self.bits_per_sample == 16,
+                    "Unsupported PCM bit depth {}",
+                    self.bits_per_sample
+                );
+
+                Ok(DecodedAudio {
+                    channels: self.channels,
+                    sample_rate: self.sample_rate,
+                    samples: self
+                        .data
+                        .chunks_exact(2)
+                        .map(|s| i16::from_le_bytes([s[0], s[1]]))
+                        .collect(),
+                })
+            }
+            CODEC_VORBIS => {
+                let info = self.vorbis_info()?;
+                let packets = VorbisPackets::rebuild(&info, self.data, codebooks)?;
+                let mut decoded = decode_vorbis(&packets)?;
+
+                // Trim the padding of the last block
+                let sample_count = info.sample_count as usize * self.channels as usize;
+                if sample_count != 0 && decoded.samples.len() > sample_count {
+                    decoded.samples.truncate(sample_count);
+                }
+
+                Ok(decoded)
+            }
+            _ => anyhow::bail!(
+                "Unsupported codec 0x{:04X} ({})",
+                self.codec,
+                self.codec_name()
+            ),
+        }
+    }
+}
+
+fn decode_vorbis(packets: &VorbisPackets) -> anyhow::Result<DecodedAudio> {
+    let ident = lewton::header::read_header_ident(&packets.ident)
+        .context("Failed to read rebuilt identification header")?;
+    let setup = lewton::header::read_header_setup(
+        &packets.setup,
+        ident.audio_channels,
+        (ident.blocksize_0, ident.blocksize_1),
+    )
+    .context("Failed to read rebuilt setup header")?;
+
+    let mut samples = vec![];
+    let mut previous_window = lewton::audio::PreviousWindowRight::new();
+    for (i, packet) in packets.audio.iter().enumerate() {
+        let channels =
+            lewton::audio::read_audio_packet(&ident, &setup, packet, &mut previous_window)
+                .with_context(|| format!("Failed to decode audio packet {i}"))?;
+
+        // Interleave
+        let frame_count = channels.first().map_or(0, |c| c.len());
+        samples.reserve(frame_count * channels.len());
+        for frame in 0..frame_count {
+            for channel in &channels {
+                samples.push(channel[frame]);
+            }
+        }
+    }
+
+    Ok(DecodedAudio {
+        channels: ident.audio_channels as u16,
+        sample_rate: ident.audio_sample_rate,
+        samples,
+    })
+}
+
+/// Interleaved 16-bit PCM
+#[derive(Clone)]
+pub struct DecodedAudio {
+    pub channels: u16,
+    pub sample_rate: u32,
+    pub samples: Vec<i16>,
+}
+
+impl DecodedAudio {
+    pub fn frame_count(&self) -> usize {
+        self.samples.len() / self.channels.max(1) as usize
+    }
+
+    pub fn duration_seconds(&self) -> f32 {
+        self.frame_count() as f32 / self.sample_rate.max(1) as f32
+    }
+
+    /// FNV-1a over the little-endian samples, used to verify decoder output
+    pub fn checksum(&self) -> u64 {
+        const FNV64_BASE: u64 = 0xcbf29ce484222325;
+        const FNV64_PRIME: u64 = 0x100000001b3;
+        self.samples
+            .iter()
+            .flat_map(|s| s.to_le_bytes())
+            .fold(FNV64_BASE, |acc, b| {
+                (acc ^ b as u64).wrapping_mul(FNV64_PRIME)
+            })
+    }
+
+    pub fn to_wav(&self) -> Vec<u8> {
+        let data_size = self.samples.len() as u32 * 2;
+        let block_align = self.channels * 2;
+
+        let mut out = Vec::with_capacity(44 + data_size as usize);
+        out.extend_from_slice(b"RIFF");
+        out.extend_from_slice(&(36 + data_size).to_le_bytes());
+        out.extend_from_slice(b"WAVEfmt ");
+        out.extend_from_slice(&16u32.to_le_bytes());
+        out.extend_from_slice(&CODEC_PCM.to_le_bytes());
+        out.extend_from_slice(&self.channels.to_le_bytes());
+        out.extend_from_slice(&self.sample_rate.to_le_bytes());
+        out.extend_from_slice(&(self.sample_rate * block_align as u32).to_le_bytes());
+        out.extend_from_slice(&block_align.to_le_bytes());
+        out.extend_from_slice(&16u16.to_le_bytes());
+        out.extend_from_slice(b"data");
+        out.extend_from_slice(&data_size.to_le_bytes());
+        for s in &self.samples {
+            out.extend_from_slice(&s.to_le_bytes());
+        }
+
+        out
+    }
+}
+
+impl VorbisPackets {
+    /// Muxes the packets into an Ogg Vorbis file, without re-encoding
+    pub fn to_ogg(&self, serial: u32) -> anyhow::Result<Vec<u8>> {
+        let ident = lewton::header::read_header_ident(&self.ident)?;
+        let setup = lewton::header::read_header_setup(
+            &self.setup,
+            ident.audio_channels,
+            (ident.blocksize_0, ident.blocksize_1),
+        )?;
+
+        let mut writer = PacketWriter::new(Cursor::new(vec![]));
+        writer.write_packet(
+            self.ident.clone().into_boxed_slice(),
+            serial,
+            PacketWriteEndInfo::EndPage,
+            0,
+        )?;
+        writer.write_packet(
+            self.comment.clone().into_boxed_slice(),
+            serial,
+            PacketWriteEndInfo::NormalPacket,
+            0,
+        )?;
+        writer.write_packet(
+            self.setup.clone().into_boxed_slice(),
+            serial,
+            PacketWriteEndInfo::EndPage,
+            0,
+        )?;
+
+        // Granule positions are the number of decoded frames up to the end of each page
+        let mut previous_window = lewton::audio::PreviousWindowRight::new();
+        let mut granule = 0;
+        for (i, packet) in self.audio.iter().enumerate() {
+            let decoded =
+                lewton::audio::read_audio_packet(&ident, &setup, packet, &mut previous_window)?;
+            granule += decoded.first().map_or(0, |c| c.len()) as u64;
+
+            let end = if i + 1 == self.audio.len() {
+                PacketWriteEndInfo::EndStream
+            } else {
+                PacketWriteEndInfo::NormalPacket
+            };
+            writer.write_packet(packet.clone().into_boxed_slice(), serial, end, granule)?;
+        }
+
+        Ok(writer.into_inner().into_inner())
+    }
+}
+
+/// Resolves a sound collection to its streams, or returns the tag itself if it is a stream
+pub fn resolve_streams(tag: TagHash) -> anyhow::Result<Vec<TagHash>> {
+    let entry = package_manager()
+        .get_entry(tag)
+        .context("Tag does not exist")?;
+
+    if Some(entry.reference) == SSoundCollection::ID {
+        let collection: SSoundCollection = package_manager().read_tag_struct(tag)?;
+        Ok(collection.streams)
+    } else {
+        Ok(vec![tag])
+    }
+}
+
+pub fn decode_stream(tag: TagHash, codebooks: &CodebookLibrary) -> anyhow::Result<DecodedAudio> {
+    let data = package_manager().read_tag(tag)?;
+    Wem::parse(&data)?.decode(codebooks)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pcm_wem(channels: u16, sample_rate: u32, samples: &[i16]) -> Vec<u8> {
+        DecodedAudio {
+            channels,
+            sample_rate,
+            samples: samples.to_vec(),
+        }
+        .to_wav()
+    }
+
+    #[test]
+    fn test_pcm_checksum() {
+        let samples = [0, 1, -1, i16::MAX, i16::MIN, 1234];
+        let data = pcm_wem(2, 48000, &samples);
+        let wem = Wem::parse(&data).unwrap();
+        assert_eq!(wem.codec_name(), "PCM");
+        assert_eq!(wem.channels, 2);
+        assert_eq!(wem.sample_rate, 48000);
+
+        let decoded = wem.decode(&CodebookLibrary::default()).unwrap();
+        assert_eq!(decoded.samples, samples);
+        assert_eq!(decoded.frame_count(), 3);
+        assert_eq!(decoded.checksum(), 0x273d2c893718543e);
+    }
+
+    #[test]
+    fn test_rejects_non_riff() {
+        assert!(Wem::parse(b"OggS\0\0\0\0\0\0\0\0").is_err());
+        let mut data = pcm_wem(1, 48000, &[0; 4]);
+        data[0..4].copy_from_slice(b"RIFX");
+        assert!(Wem::parse(&data).is_err());
+    }
+}
@@ -0,0 +1,523 @@
+//! Rebuilds standard Vorbis packets from Wwise's stripped Vorbis streams.
+//! Wwise drops the header packets and replaces codebooks with ids into a shared codebook library,
+//! so we regenerate all three headers and patch the window flags back into every audio packet.
+
+use anyhow::Context;
+
+use super::bits::{ilog, BitReader, BitWriter};
+
+/// Packed codebook library shipped with ww2ogg (`packed_codebooks_aoTuV_603.bin`)
+#[derive(Default)]
+pub struct CodebookLibrary {
+    data: Vec<u8>,
+    offsets: Vec<usize>,
+}
+
+impl CodebookLibrary {
+    pub fn load(path: impl AsRef<std::path::Path>) -> anyhow::Result<Self> {
+        let path = path.as_ref();
+        Self::from_bytes(
+            std::fs::read(path)
+                .with_context(|| format!("Failed to read codebook library {}", path.display()))?,
+        )
+    }
+
+    pub fn from_bytes(data: Vec<u8>) -> anyhow::Result<Self> {
+        anyhow::ensure!(data.len() >= 4, "Codebook library is too small");
+        let offset_table = u32::from_le_bytes(data[data.len() - 4..].try_into().unwrap()) as usize;
+        anyhow::ensure!(
+            offset_table <= data.len() - 4,
+            "Codebook library offset table is out of bounds"
+        );
+
+        let offsets = data[offset_table..]
+            .chunks_exact(4)
+            .map(|c| u32::from_le_bytes(c.try_into().unwrap()) as usize)
+            .collect();
+
+        Ok(Self { data, offsets })
+    }
+
+    pub fn len(&self) -> usize {
+        self.offsets.len().saturating_sub(1)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn codebook(&self, id: usize) -> anyhow::Result<&[u8]> {
+        anyhow::ensure!(id < self.len(), "Codebook id {id} is out of range");
+        let (start, end) = (self.offsets[id], self.offsets[id + 1]);
+        self.data
+            .get(start..end)
+            .context("Codebook data is out of bounds")
+    }
+
+    /// Expands a packed codebook into its full Vorbis representation
+    fn rebuild(&self, id: usize, out: &mut BitWriter) -> anyhow::Result<()> {
+        let data = self.codebook(id)?;
+        let mut bits = BitReader::new(data);
+
+        let dimensions = bits.read(4)?;
+        let entries = bits.read(14)?;
+
+        // "BCV" sync pattern
+        out.write(0x564342, 24);
+        out.write(dimensions, 16);
+        out.write(entries, 24);
+
+        let ordered = out.copy(&mut bits, 1)? != 0;
+        if ordered {
+            out.copy(&mut bits, 5)?;
+            let mut current_entry = 0;
+            while current_entry < entries {
+                current_entry += out.copy(&mut bits, ilog(entries - current_entry))?;
+            }
+        } else {
+            let codeword_length_length = bits.read(3)?;
+            let sparse = out.copy(&mut bits, 1)? != 0;
+            anyhow::ensure!(
+                (1..=5).contains(&codeword_length_length),
+                "Bad codeword length length {codeword_length_length}"
+            );
+
+            for _ in 0..entries {
+                let present = !sparse || out.copy(&mut bits, 1)? != 0;
+                if present {
+                    out.write(bits.read(codeword_length_length)?, 5);
+                }
+            }
+        }
+
+        let lookup_type = bits.read(1)?;
+        out.write(lookup_type, 4);
+        if lookup_type == 1 {
+            // Minimum and maximum value
+            out.copy(&mut bits, 32)?;
+            out.copy(&mut bits, 32)?;
+            let value_length = out.copy(&mut bits, 4)?;
+            // Sequence flag
+            out.copy(&mut bits, 1)?;
+
+            for _ in 0..maptype1_quantvals(entries, dimensions) {
+                out.copy(&mut bits, value_length + 1)?;
+            }
+        }
+
+        anyhow::ensure!(
+            bits.bits_read() / 8 + 1 == data.len(),
+            "Codebook {id} size mismatch ({} bits read, {} bytes)",
+            bits.bits_read(),
+            data.len()
+        );
+
+        Ok(())
+    }
+}
+
+fn maptype1_quantvals(entries: u32, dimensions: u32) -> u32 {
+    if dimensions == 0 {
+        return 0;
+    }
+
+    let bits = ilog(entries);
+    let mut vals = entries >> ((bits - 1) * (dimensions - 1) / dimensions);
+    loop {
+        let mut acc = 1u64;
+        let mut acc1 = 1u64;
+        for _ in 0..dimensions {
+            acc *= vals as u64;
+            acc1 *= vals as u64 + 1;
+        }
+
+        if acc <= entries as u64 && acc1 > entries as u64 {
+            return vals;
+        } else if acc > entries as u64 {
+            vals -= 1;
+        } else {
+            vals += 1;
+        }
+    }
+}
+
+/// Parameters from the `vorb` chunk (or the extended `fmt` chunk on newer Wwise versions)
+#[derive(Debug, Clone)]
+pub struct VorbisInfo {
+    pub channels: u8,
+    pub sample_rate: u32,
+    pub avg_bytes_per_second: u32,
+    pub sample_count: u32,
+    pub setup_packet_offset: u32,
+    pub first_audio_packet_offset: u32,
+    pub blocksize_0_pow: u8,
+    pub blocksize_1_pow: u8,
+    /// Packets have a 2-byte header without granule position
+    pub no_granule: bool,
+    /// Audio packets are missing the packet type and window flags
+    pub mod_packets: bool,
+}
+
+impl VorbisInfo {
+    /// Parses the vorbis parameters. `vorb` is either the `vorb` chunk, or the `fmt` chunk data past offset 0x18
+    pub fn parse(
+        vorb: &[u8],
+        channels: u8,
+        sample_rate: u32,
+        avg_bytes_per_second: u32,
+    ) -> anyhow::Result<Self> {
+        let u32_at = |offset: usize| -> anyhow::Result<u32> {
+            Ok(u32::from_le_bytes(
+                vorb.get(offset..offset + 4)
+                    .context("vorb chunk is truncated")?
+                    .try_into()
+                    .unwrap(),
+            ))
+        };
+
+        let (offsets, uid_offset, no_granule, mod_packets) = match vorb.len() {
+            0x2A => {
+                // Only set when the first packet carries window flags
+                let mod_signal = u32_at(0x4)?;
+                let mod_packets = !matches!(mod_signal, 0x4A | 0x4B | 0x69 | 0x70);
+                (0x10, 0x24, true, mod_packets)
+            }
+            0x32 | 0x34 => (0x18, 0x2C, false, false),
+            0x28 | 0x2C => {
+                anyhow::bail!("Old Wwise vorbis streams with header triads are not supported")
+            }
+            size => anyhow::bail!("Unsupported vorb chunk size 0x{size:X}"),
+        };
+
+        Ok(Self {
+            channels,
+            sample_rate,
+            avg_bytes_per_second,
+            sample_count: u32_at(0)?,
+            setup_packet_offset: u32_at(offsets)?,
+            first_audio_packet_offset: u32_at(offsets + 4)?,
+            blocksize_0_pow: vorb[uid_offset + 4],
+            blocksize_1_pow: vorb[uid_offset + 5],
+            no_granule,
+            mod_packets,
+        })
+    }
+
+    fn packet_header_size(&self) -> usize {
+        if self.no_granule {
+            2
+        } else {
+            6
+        }
+    }
+
+    /// Splits the data chunk into packets, starting at `offset`
+    fn packet_at<'a>(&self, data: &'a [u8], offset: usize) -> anyhow::Result<&'a [u8]> {
+        let header = data
+            .get(offset..offset + self.packet_header_size())
+            .context("Packet header is out of bounds")?;
+        let size = u16::from_le_bytes([header[0], header[1]]) as usize;
+        let start = offset + self.packet_header_size();
+        data.get(start..start + size)
+            .context("Packet data is out of bounds")
+    }
+}
+
+/// A Wwise vorbis stream converted back to standard Vorbis packets
+pub struct VorbisPackets {
+    pub ident: Vec<u8>,
+    pub comment: Vec<u8>,
+    pub setup: Vec<u8>,
+    pub audio: Vec<Vec<u8>>,
+}
+
+impl VorbisPackets {
+    pub fn rebuild(
+        info: &VorbisInfo,
+        data: &[u8],
+        codebooks: &CodebookLibrary,
+    ) -> anyhow::Result<Self> {
+        let ident = ident_packet(info);
+        let comment = comment_packet();
+
+        let setup_data = info.packet_at(data, info.setup_packet_offset as usize)?;
+        let (setup, mode_blockflags) = setup_packet(info, setup_data, codebooks)?;
+        let mode_bits = ilog(mode_blockflags.len() as u32 - 1);
+
+        let mut audio = vec![];
+        let mut offset = info.first_audio_packet_offset as usize;
+        let mut prev_blockflag = false;
+        while offset + info.packet_header_size() <= data.len() {
+            let packet = info.packet_at(data, offset)?;
+            offset += info.packet_header_size() + packet.len();
+            if packet.is_empty() {
+                continue;
+            }
+
+            if !info.mod_packets {
+                audio.push(packet.to_vec());
+                continue;
+            }
+
+            let mut bits = BitReader::new(packet);
+            let mut out = BitWriter::default();
+
+            // Packet type (audio)
+            out.write(0, 1);
+            let mode_number = out.copy(&mut bits, mode_bits)? as usize;
+            let remainder = bits.read(8 - mode_bits)?;
+
+            let blockflag = *mode_blockflags
+                .get(mode_number)
+                .with_context(|| format!("Audio packet uses invalid mode {mode_number}"))?;
+            if blockflag {
+                // Long window, the window shape depends on the neighbouring packets
+                let next_blockflag = info
+                    .packet_at(data, offset)
+                    .ok()
+                    .filter(|next| !next.is_empty())
+                    .and_then(|next| {
+                        let next_mode = BitReader::new(next).read(mode_bits).ok()?;
+                        mode_blockflags.get(next_mode as usize).copied()
+                    })
+                    .unwrap_or(false);
+
+                out.write_bool(prev_blockflag);
+                out.write_bool(next_blockflag);
+            }
+            prev_blockflag = blockflag;
+
+            out.write(remainder, 8 - mode_bits);
+            out.write_bytes(&packet[1..]);
+            audio.push(out.into_bytes());
+        }
+
+        Ok(Self {
+            ident,
+            comment,
+            setup,
+            audio,
+        })
+    }
+}
+
+fn write_vorbis_header(out: &mut BitWriter, packet_type: u8) {
+    out.write(packet_type as u32, 8);
+    out.write_bytes(b"vorbis");
+}
+
+fn ident_packet(info: &VorbisInfo) -> Vec<u8> {
+    let mut out = BitWriter::default();
+    write_vorbis_header(&mut out, 1);
+    // Version
+    out.write(0, 32);
+    out.write(info.channels as u32, 8);
+    out.write(info.sample_rate, 32);
+    // Maximum, nominal and minimum bitrate
+    out.write(0, 32);
+    out.write(info.avg_bytes_per_second * 8, 32);
+    out.write(0, 32);
+    out.write(info.blocksize_0_pow as u32, 4);
+    out.write(info.blocksize_1_pow as u32, 4);
+    // Framing
+    out.write(1, 1);
+    out.into_bytes()
+}
+
+fn comment_packet() -> Vec<u8> {
+    const VENDOR: &[u8] = b"converted from Wwise by alkahest";
+
+    let mut out = BitWriter::default();
+    write_vorbis_header(&mut out, 3);
+    out.write(VENDOR.len() as u32, 32);
+    out.write_bytes(VENDOR);
+    // No user comments
+    out.write(0, 32);
+    // Framing
+    out.write(1, 1);
+    out.into_bytes()
+}
+
+/// Rebuilds the setup header, returning it along with the block flag of every mode
+fn setup_packet(
+    info: &VorbisInfo,
+    data: &[u8],
+    codebooks: &CodebookLibrary,
+) -> anyhow::Result<(Vec<u8>, Vec<bool>)> {
+    let mut bits = BitReader::new(data);
+    let mut out = BitWriter::default();
+    write_vorbis_header(&mut out, 5);
+
+    let codebook_count = out.copy(&mut bits, 8)? + 1;
+    for _ in 0..codebook_count {
+        let id = bits.read(10)?;
+        codebooks.rebuild(id as usize, &mut out)?;
+    }
+
+    // Time domain transforms, placeholder
+    out.write(0, 6);
+    out.write(0, 16);
+
+    // Floors
+    let floor_count = out.copy(&mut bits, 6)? + 1;
+    for _ in 0..floor_count {
+        // Floor type 1
+        out.write(1, 16);
+
+        let partitions = out.copy(&mut bits, 5)?;
+        let mut partition_classes = Vec::with_capacity(partitions as usize);
+        for _ in 0..partitions {
+            partition_classes.push(out.copy(&mut bits, 4)?);
+        }
+
+        let max_class = partition_classes.iter().copied().max().map_or(0, |c| c + 1);
+        let mut class_dimensions = Vec::with_capacity(max_class as usize);
+        for _ in 0..max_class {
+            class_dimensions.push(out.copy(&mut bits, 3)? + 1);
+            let subclasses = out.copy(&mut bits, 2)?;
+            if subclasses != 0 {
+                let masterbook = out.copy(&mut bits, 8)?;
+                anyhow::ensure!(masterbook < codebook_count, "Invalid floor masterbook");
+            }
+
+            for _ in 0..(1 << subclasses) {
+                let subclass_book = out.copy(&mut bits, 8)?;
+                anyhow::ensure!(
+                    subclass_book == 0 || subclass_book - 1 < codebook_count,
+                    "Invalid floor subclass book"
+                );
+            }
+        }
+
+        // Multiplier
+        out.copy(&mut bits, 2)?;
+        let range_bits = out.copy(&mut bits, 4)?;
+        for class in partition_classes {
+            for _ in 0..class_dimensions[class as usize] {
+                out.copy(&mut bits, range_bits)?;
+            }
+        }
+    }
+
+    // Residues
+    let residue_count = out.copy(&mut bits, 6)? + 1;
+    for _ in 0..residue_count {
+        let residue_type = bits.read(2)?;
+        anyhow::ensure!(residue_type <= 2, "Invalid residue type {residue_type}");
+        out.write(residue_type, 16);
+
+        // Begin, end, partition size
+        out.copy(&mut bits, 24)?;
+        out.copy(&mut bits, 24)?;
+        out.copy(&mut bits, 24)?;
+        let classifications = out.copy(&mut bits, 6)? + 1;
+        let classbook = out.copy(&mut bits, 8)?;
+        anyhow::ensure!(classbook < codebook_count, "Invalid residue classbook");
+
+        let mut cascades = Vec::with_capacity(classifications as usize);
+        for _ in 0..classifications {
+            let low_bits = out.copy(&mut bits, 3)?;
+            let high_bits = if out.copy(&mut bits, 1)? != 0 {
+                out.copy(&mut bits, 5)?
+            } else {
+                0
+            };
+            cascades.push(high_bits * 8 + low_bits);
+        }
+
+        for cascade in cascades {
+            for k in 0..8 {
+                if cascade & (1 << k) != 0 {
+                    let book = out.copy(&mut bits, 8)?;
+                    anyhow::ensure!(book < codebook_count, "Invalid residue book");
+                }
+            }
+        }
+    }
+
+    // Mappings
+    let mapping_count = out.copy(&mut bits, 6)? + 1;
+    for _ in 0..mapping_count {
+        // Mapping type 0
+        out.write(0, 16);
+
+        let submaps = if out.copy(&mut bits, 1)? != 0 {
+            out.copy(&mut bits, 4)? + 1
+        } else {
+            1
+        };
+
+        // Square polar channel coupling
+        if out.copy(&mut bits, 1)? != 0 {
+            let coupling_steps = out.copy(&mut bits, 8)? + 1;
+            let channel_bits = ilog(info.channels as u32 - 1);
+            for _ in 0..coupling_steps {
+                let magnitude = out.copy(&mut bits, channel_bits)?;
+                let angle = out.copy(&mut bits, channel_bits)?;
+                anyhow::ensure!(
+                    magnitude != angle
+                        && magnitude < info.channels as u32
+                        && angle < info.channels as u32,
+                    "Invalid channel coupling"
+                );
+            }
+        }
+
+        let reserved = out.copy(&mut bits, 2)?;
+        anyhow::ensure!(reserved == 0, "Mapping reserved field is nonzero");
+
+        if submaps > 1 {
+            for _ in 0..info.channels {
+                let mux = out.copy(&mut bits, 4)?;
+                anyhow::ensure!(mux < submaps, "Invalid mapping mux");
+            }
+        }
+
+        for _ in 0..submaps {
+            // Time config
+            out.copy(&mut bits, 8)?;
+            let floor = out.copy(&mut bits, 8)?;
+            anyhow::ensure!(floor < floor_count, "Invalid mapping floor");
+            let residue = out.copy(&mut bits, 8)?;
+            anyhow::ensure!(residue < residue_count, "Invalid mapping residue");
+        }
+    }
+
+    // Modes
+    let mode_count = out.copy(&mut bits, 6)? + 1;
+    let mut mode_blockflags = Vec::with_capacity(mode_count as usize);
+    for _ in 0..mode_count {
+        mode_blockflags.push(out.copy(&mut bits, 1)? != 0);
+        // Window and transform type
+        out.write(0, 16);
+        out.write(0, 16);
+        let mapping = out.copy(&mut bits, 8)?;
+        anyhow::ensure!(mapping < mapping_count, "Invalid mode mapping");
+    }
+
+    // Framing
+    out.write(1, 1);
+
+    anyhow::ensure!(
+        bits.bits_read().div_ceil(8) == data.len(),
+        "Setup packet size mismatch ({} bits read, {} bytes)",
+        bits.bits_read(),
+        data.len()
+    );
+
+    Ok((out.into_bytes(), mode_blockflags))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_quantvals() {
+        // Values from common aoTuV codebooks
+        assert_eq!(maptype1_quantvals(81, 4), 3);
+        assert_eq!(maptype1_quantvals(625, 4), 5);
+        assert_eq!(maptype1_quantvals(169, 2), 13);
+        assert_eq!(maptype1_quantvals(170, 2), 13);
+    }
+}
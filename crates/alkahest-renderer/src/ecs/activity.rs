@@ -0,0 +1,137 @@
+use alkahest_data::common::ResourceHash;
+use bevy_ecs::{entity::Entity, system::Resource};
+use destiny_pkg::TagHash;
+use rustc_hash::FxHashSet;
+
+use super::{
+    common::ResourceOrigin, hierarchy::Children, map::NodeMetadata, visibility::Visibility, Scene,
+};
+
+/// An activity phase, and the scene entity all of its spawns are parented to
+pub struct ActivityPhase {
+    pub name_hash: ResourceHash,
+    pub name: String,
+    pub origin: ResourceOrigin,
+    /// Group entity containing all of the phase's spawns
+    pub entity: Entity,
+}
+
+/// Identifies a single placement within a phase, used for diffing phases against each other
+#[derive(Copy, Clone, Hash, PartialEq, Eq, Debug)]
+pub struct PhaseMember {
+    pub entity_tag: TagHash,
+    pub world_id: u64,
+}
+
+#[derive(Default)]
+pub struct PhaseDiff {
+    /// Members present in this phase, but not in the previous one
+    pub added: Vec<PhaseMember>,
+    /// Members present in the previous phase, but not in this one
+    pub removed: Vec<PhaseMember>,
+}
+
+/// Ordered list of the activity phases loaded into a scene
+#[derive(Resource, Default)]
+pub struct ActivityPhases {
+    pub phases: Vec<ActivityPhase>,
+}
+
+impl ActivityPhases {
+    pub fn len(&self) -> usize {
+        self.phases.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.phases.is_empty()
+    }
+
+    pub fn is_visible(&self, scene: &Scene, index: usize) -> bool {
+        self.phases.get(index).map_or(false, |p| {
+            scene
+                .get::<Visibility>(p.entity)
+                .map_or(true, |v| v.is_visible())
+        })
+    }
+
+    pub fn set_visible(&self, scene: &mut Scene, index: usize, visible: bool) {
+        let Some(phase) = self.phases.get(index) else {
+            return;
+        };
+
+        if let Some(mut e) = scene.get_entity_mut(phase.entity) {
+            e.insert((if visible {
+                Visibility::Visible
+            } else {
+                Visibility::Hidden
+            },));
+        }
+    }
+
+    /// Hides every phase except the one at `index`. Passing `None` shows all phases
+    pub fn solo(&self, scene: &mut Scene, index: Option<usize>) {
+        for i in 0..self.phases.len() {
+            self.set_visible(scene, i, index.map_or(true, |solo| solo == i));
+        }
+    }
+
+    /// Moves a phase to a new position in the ordering, affecting which phase it is diffed against
+    pub fn move_phase(&mut self, from: usize, to: usize) {
+        if from >= self.phases.len() || to >= self.phases.len() {
+            return;
+        }
+
+        let phase = self.phases.remove(from);
+        self.phases.insert(to, phase);
+    }
+
+    /// Collects the placements spawned by the phase at `index`
+    pub fn members(&self, scene: &Scene, index: usize) -> FxHashSet<PhaseMember> {
+        let mut members = FxHashSet::default();
+        if let Some(phase) = self.phases.get(index) {
+            collect_members_recursive(scene, phase.entity, &mut members);
+        }
+
+        members
+    }
+
+    /// Diffs the phase at `index` against the phase ordered before it.
+    /// The first phase is diffed against an empty set
+    pub fn diff(&self, scene: &Scene, index: usize) -> PhaseDiff {
+        let current = self.members(scene, index);
+        let previous = if index > 0 {
+            self.members(scene, index - 1)
+        } else {
+            FxHashSet::default()
+        };
+
+        let mut diff = PhaseDiff {
+            added: current.difference(&previous).copied().collect(),
+            removed: previous.difference(&current).copied().collect(),
+        };
+
+        diff.added.sort_by_key(|m| (m.entity_tag.0, m.world_id));
+        diff.removed.sort_by_key(|m| (m.entity_tag.0, m.world_id));
+
+        diff
+    }
+}
+
+fn collect_members_recursive(scene: &Scene, entity: Entity, members: &mut FxHashSet<PhaseMember>) {
+    let Some(e) = scene.get_entity(entity) else {
+        return;
+    };
+
+    if let Some(meta) = e.get::<NodeMetadata>() {
+        members.insert(PhaseMember {
+            entity_tag: meta.entity_tag,
+            world_id: meta.world_id,
+        });
+    }
+
+    if let Some(children) = e.get::<Children>() {
+        for child in children.iter() {
+            collect_members_recursive(scene, *child, members);
+        }
+    }
+}
@@ -0,0 +1,141 @@
+use std::time::Instant;
+
+use alkahest_data::{
+    animation::{find_entity_clips, AnimationClip},
+    entity::SEntity,
+};
+use alkahest_pm::package_manager;
+use bevy_ecs::{prelude::Component, system::Query};
+use destiny_pkg::TagHash;
+use tiger_parse::PackageManagerExt;
+
+use crate::ecs::transform::Transform;
+
+/// Entity tag whose animation clips are looked up once the entity is selected, see
+/// [`EntityAnimation::from_source`]
+#[derive(Component, Clone, Copy)]
+pub struct EntityAnimationSource(pub TagHash);
+
+/// Plays back the animation clips referenced by an entity
+///
+/// Dynamic models aren't skinned by the renderer yet, so only the root track (track 0) of the clip
+/// is applied, on top of the transform the entity was placed with. That covers rigid movers like
+/// platforms, doors and elevators.
+#[derive(Component)]
+pub struct EntityAnimation {
+    pub clips: Vec<TagHash>,
+    pub selected_clip: usize,
+    /// Playback position in seconds
+    pub time: f32,
+    pub playing: bool,
+    pub looping: bool,
+    pub speed: f32,
+    /// Transform the entity was placed with
+    pub base_transform: Transform,
+
+    loaded: Option<(TagHash, Option<AnimationClip>)>,
+    last_update: Option<Instant>,
+}
+
+impl EntityAnimation {
+    pub fn new(clips: Vec<TagHash>, base_transform: Transform) -> Self {
+        Self {
+            clips,
+            selected_clip: 0,
+            time: 0.0,
+            playing: false,
+            looping: true,
+            speed: 1.0,
+            base_transform,
+            loaded: None,
+            last_update: None,
+        }
+    }
+
+    /// Looks up the clips referenced by the entity. Finding them means scanning the entity
+    /// resources, so this is only done for entities that get selected. Returns `None` if the entity
+    /// doesn't reference any clips
+    pub fn from_source(
+        source: EntityAnimationSource,
+        base_transform: Transform,
+    ) -> anyhow::Result<Option<Self>> {
+        let header: SEntity = package_manager().read_tag_struct(source.0)?;
+        let clips = find_entity_clips(&header);
+        Ok((!clips.is_empty()).then(|| Self::new(clips, base_transform)))
+    }
+
+    /// The selected clip, loaded on first use. Returns `None` if the clip failed to load
+    pub fn clip(&mut self) -> Option<&AnimationClip> {
+        let hash = *self.clips.get(self.selected_clip)?;
+        if self.loaded.as_ref().map(|(h, _)| *h) != Some(hash) {
+            let clip = AnimationClip::load(hash)
+                .map_err(|e| error!("Failed to load animation clip {hash}: {e:?}"))
+                .ok();
+            self.loaded = Some((hash, clip));
+        }
+
+        self.loaded.as_ref().and_then(|(_, clip)| clip.as_ref())
+    }
+
+    pub fn select(&mut self, index: usize) {
+        self.selected_clip = index;
+        self.time = 0.0;
+    }
+
+    /// The base transform with the root node of the selected clip applied at the current time
+    pub fn posed_transform(&mut self) -> Transform {
+        let (time, looping, base) = (self.time, self.looping, self.base_transform);
+        let Some(root) = self.clip().and_then(|clip| {
+            Some(
+                clip.track(0)?
+                    .sample(clip.clip_time(time, looping), Default::default()),
+            )
+        }) else {
+            return base;
+        };
+
+        Transform {
+            flags: base.flags,
+            ..Transform::from_mat4(base.local_to_world() * root.to_mat4())
+        }
+    }
+}
+
+pub fn update_entity_animation_system(
+    mut q_animation: Query<(&mut Transform, &mut EntityAnimation)>,
+) {
+    profiling::scope!("update_entity_animation_system");
+    let now = Instant::now();
+    for (mut transform, mut animation) in q_animation.iter_mut() {
+        let delta = animation
+            .last_update
+            .map_or(0.0, |t| (now - t).as_secs_f32());
+        animation.bypass_change_detection().last_update = Some(now);
+
+        if animation.playing {
+            let (speed, looping) = (animation.speed, animation.looping);
+            let time = animation.time + delta * speed;
+            let Some(duration) = animation.clip().map(|c| c.duration) else {
+                animation.playing = false;
+                continue;
+            };
+
+            animation.time = if looping {
+                time.rem_euclid(duration.max(f32::EPSILON))
+            } else {
+                if !(0.0..duration).contains(&time) {
+                    animation.playing = false;
+                }
+                time.clamp(0.0, duration)
+            };
+        }
+
+        // Skip freshly spawned entities, so loading a map doesn't load every clip up front
+        if animation.is_changed() && !animation.is_added() {
+            let posed = animation.posed_transform();
+            if *transform != posed {
+                *transform = posed;
+            }
+        }
+    }
+}
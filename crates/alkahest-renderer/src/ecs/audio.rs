@@ -1,13 +1,19 @@
 use alkahest_data::map::SAudioClipCollection;
 use bevy_ecs::prelude::Component;
+use destiny_pkg::TagHash;
 
 #[derive(Component)]
 pub struct AmbientAudio {
-    _data: SAudioClipCollection,
+    pub data: SAudioClipCollection,
 }
 
 impl AmbientAudio {
     pub fn new(data: SAudioClipCollection) -> Self {
-        Self { _data: data }
+        Self { data }
+    }
+
+    /// Wwise streams played by this source
+    pub fn streams(&self) -> &[TagHash] {
+        &self.data.streams
     }
 }
@@ -20,6 +20,8 @@ pub enum ResourceOrigin {
 
 // pub struct HavokShape(pub TagHash, pub Option<CustomDebugShape>);
 
+/// Marks the group entity of an activity phase
+#[derive(Component, Copy, Clone, PartialEq, Eq, Hash)]
 pub struct ActivityGroup(pub u32);
 
 #[derive(Component, Clone)]
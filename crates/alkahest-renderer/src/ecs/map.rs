@@ -1,4 +1,7 @@
-use alkahest_data::map::SMapAtmosphere;
+use alkahest_data::{
+    decal::{DecalInstance, DecalProjector},
+    map::SMapAtmosphere,
+};
 use bevy_ecs::{prelude::Component, system::Resource};
 use destiny_pkg::TagHash;
 use glam::Vec3;
@@ -7,7 +10,10 @@ use crate::{
     gpu::{texture::Texture, GpuContext},
     handle::Handle,
     loaders::texture::load_texture,
-    tfx::externs::{self, TextureView},
+    tfx::{
+        externs::{self, TextureView},
+        technique::Technique,
+    },
 };
 
 // TODO(cohae): This should probably be a resource, since there can only be one per map
@@ -111,6 +117,16 @@ impl CubemapVolume {
     }
 }
 
+#[derive(Component)]
+pub struct Decal {
+    pub collection: TagHash,
+    pub instance: DecalInstance,
+    pub projector: DecalProjector,
+    pub technique: Handle<Technique>,
+    /// Pixel stage textures of the technique, by slot
+    pub textures: Vec<(u32, TagHash)>,
+}
+
 #[derive(Component, Clone)]
 pub struct NodeMetadata {
     pub entity_tag: TagHash,
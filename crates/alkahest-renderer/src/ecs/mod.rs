@@ -2,6 +2,8 @@ use bevy_ecs::system::Resource;
 use destiny_pkg::TagHash;
 use resources::SelectedEntity;
 
+pub mod activity;
+pub mod animation;
 pub mod audio;
 pub mod common;
 pub mod culling;
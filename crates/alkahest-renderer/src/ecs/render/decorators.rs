@@ -1,5 +1,6 @@
 use alkahest_data::{
     decorator::{SDecorator, SUnk80806CB8},
+    entity::IdentifierMask,
     tfx::{TfxFeatureRenderer, TfxRenderStage, TfxShaderStage},
 };
 use alkahest_pm::package_manager;
@@ -116,15 +117,12 @@ impl DecoratorRenderer {
             });
         }
 
-        for id in 0..(self.data.unk18.len() - 1) {
-            let instance_start = self.data.unk18[id];
-            let instance_end = self.data.unk18[id + 1];
-            let instance_count = instance_end - instance_start;
+        for group in self.data.instance_groups() {
+            let instance_start = group.instances.start;
+            let instance_count = group.instances.end - group.instances.start;
 
-            let (model, ext, cb) = if self.models.len() == 1 {
-                &self.models[0]
-            } else {
-                &self.models[id]
+            let Some((model, ext, cb)) = self.models.get(group.model_index) else {
+                continue;
             };
 
             if let Some(cb) = cb {
@@ -143,17 +141,10 @@ impl DecoratorRenderer {
 
             renderer.data.lock().externs.rigid_model = Some(ext.clone());
 
-            let dyn_id = if self.models.len() == 1 {
-                id as u16
-            } else {
-                // cohae: Multi-models (usually trees) seem to use the ID as a LOD level?
-                0
-            };
-
             model.draw_wrapped(
                 renderer,
                 stage,
-                dyn_id,
+                IdentifierMask::single(group.identifier),
                 move |_model, renderer, mesh, part| unsafe {
                     let layout = mesh.get_input_layout_for_stage(stage);
                     if !RenderStates::is_input_layout_instanced(layout as usize) {
@@ -1,5 +1,9 @@
 use alkahest_data::{
-    entity::{SDynamicMesh, SDynamicMeshPart, SDynamicModel, Unk808072c5},
+    dye::GearDyeSlot,
+    entity::{
+        variant_count, variant_technique_index, IdentifierMask, SDynamicMesh, SDynamicMeshPart,
+        SDynamicModel, Unk808072c5,
+    },
     occlusion::Aabb,
     technique::TfxScopeBits,
     tfx::{TfxFeatureRenderer, TfxRenderStage, TfxShaderStage},
@@ -25,7 +29,7 @@ use crate::{
     gpu::buffer::ConstantBuffer,
     gpu_event,
     handle::Handle,
-    loaders::AssetManager,
+    loaders::{gear_dye::EntityDyes, AssetManager},
     renderer::Renderer,
     tfx::{externs, scope::ScopeSkinning, technique::Technique, view::RenderStageSubscriptions},
     util::packages::TagHashExt,
@@ -36,6 +40,7 @@ pub struct DynamicModel {
 
     technique_map: Vec<Unk808072c5>,
     techniques: Vec<Handle<Technique>>,
+    technique_hashes: Vec<TagHash>,
 
     pub model: SDynamicModel,
     pub mesh_stages: Vec<RenderStageSubscriptions>,
@@ -61,7 +66,8 @@ impl DynamicModel {
         feature_type: TfxFeatureRenderer,
     ) -> anyhow::Result<Self> {
         let model = package_manager().read_tag_struct::<SDynamicModel>(hash)?;
-        let techniques = techniques
+        let technique_hashes = techniques;
+        let techniques = technique_hashes
             .iter()
             .map(|&tag| am.get_or_load_technique(tag))
             .collect_vec();
@@ -94,12 +100,7 @@ impl DynamicModel {
             })
             .collect_vec();
 
-        let variant_count = technique_map
-            .iter()
-            .filter(|m| m.unk8 == 0)
-            .map(|m| m.technique_count as usize)
-            .next()
-            .unwrap_or(0);
+        let variant_count = variant_count(&technique_map);
 
         let identifier_count = model
             .meshes
@@ -123,6 +124,7 @@ impl DynamicModel {
             mesh_buffers,
             technique_map,
             techniques,
+            technique_hashes,
             model,
             subscribed_stages: mesh_stages
                 .iter()
@@ -146,19 +148,68 @@ impl DynamicModel {
         self.identifier_count
     }
 
-    fn get_variant_technique(&self, index: u16, variant: usize) -> Option<Handle<Technique>> {
-        if index == u16::MAX {
-            None
-        } else {
-            self.technique_map
-                .get(index as usize)
-                .as_ref()
-                .map(|variant_range| {
-                    self.techniques[variant_range.technique_start as usize
-                        + (variant % variant_range.technique_count as usize)]
-                        .clone()
+    /// Unique part identifiers of the selected mesh
+    pub fn identifiers(&self) -> Vec<u16> {
+        self.model
+            .meshes
+            .get(self.selected_mesh)
+            .map(|m| m.identifiers())
+            .unwrap_or_default()
+    }
+
+    /// Number of highest detail parts of the selected mesh drawn with the given identifier
+    pub fn identifier_part_count(&self, identifier: u16) -> usize {
+        self.model.meshes.get(self.selected_mesh).map_or(0, |m| {
+            m.parts
+                .iter()
+                .filter(|p| {
+                    p.external_identifier == identifier && p.lod_category.is_highest_detail()
                 })
-        }
+                .count()
+        })
+    }
+
+    /// Technique applied by a material variant to each part of the selected mesh, as
+    /// `(part index, technique)` pairs. Parts that don't change between variants are left out
+    pub fn variant_techniques(&self, variant: usize) -> Vec<(usize, TagHash)> {
+        let Some(mesh) = self.model.meshes.get(self.selected_mesh) else {
+            return vec![];
+        };
+
+        mesh.parts
+            .iter()
+            .enumerate()
+            .filter(|(_, p)| p.lod_category.is_highest_detail())
+            .filter_map(|(i, p)| {
+                let index =
+                    variant_technique_index(&self.technique_map, p.variant_shader_index, variant)?;
+                Some((i, *self.technique_hashes.get(index)?))
+            })
+            .collect()
+    }
+
+    /// Dye slot of every dyed part of the selected mesh, as `(part index, slot)` pairs
+    pub fn part_dye_slots(&self) -> Vec<(usize, GearDyeSlot)> {
+        let Some(mesh) = self.model.meshes.get(self.selected_mesh) else {
+            return vec![];
+        };
+
+        mesh.parts
+            .iter()
+            .enumerate()
+            .filter(|(_, p)| p.lod_category.is_highest_detail())
+            .filter_map(|(i, p)| {
+                Some((
+                    i,
+                    GearDyeSlot::from_change_color_index(p.gear_dye_change_color_index)?,
+                ))
+            })
+            .collect()
+    }
+
+    fn get_variant_technique(&self, index: u16, variant: usize) -> Option<Handle<Technique>> {
+        variant_technique_index(&self.technique_map, index, variant)
+            .and_then(|i| self.techniques.get(i).cloned())
     }
 
     /// ⚠ Expects the `rigid_model` scope to be bound
@@ -166,12 +217,12 @@ impl DynamicModel {
         &self,
         renderer: &Renderer,
         render_stage: TfxRenderStage,
-        identifier: u16,
+        identifiers: IdentifierMask,
     ) -> anyhow::Result<()> {
         self.draw_wrapped(
             renderer,
             render_stage,
-            identifier,
+            identifiers,
             |_, renderer, _mesh, part| unsafe {
                 renderer
                     .gpu
@@ -185,7 +236,7 @@ impl DynamicModel {
         &self,
         renderer: &Renderer,
         render_stage: TfxRenderStage,
-        identifier: u16,
+        identifiers: IdentifierMask,
         f: F,
     ) -> anyhow::Result<()>
     where
@@ -232,7 +283,7 @@ impl DynamicModel {
         self.mesh_buffers[self.selected_mesh].bind(renderer);
         for part_index in mesh.get_range_for_stage(render_stage) {
             let part = &mesh.parts[part_index];
-            if identifier != u16::MAX && part.external_identifier != identifier {
+            if !identifiers.contains(part.external_identifier) {
                 continue;
             }
 
@@ -293,7 +344,16 @@ pub struct DynamicModelComponent {
     pub ext: externs::RigidModel,
     pub cbuffer: ConstantBuffer<externs::RigidModel>,
     pub cbuffer_skinning: Option<ConstantBuffer<ScopeSkinning>>,
-    pub identifier: u16,
+    /// Parts of the model to draw, by identifier
+    pub identifiers: IdentifierMask,
+    pub dyes: EntityDyes,
+    /// Entity the dyes are looked up in by [`Self::load_pending_dyes`]. Cleared once they've been
+    /// looked up
+    pub dye_source: Option<TagHash>,
+    /// Binds the dye constants to the `gear_dye_*` scopes when drawing dyed parts
+    pub apply_dyes: bool,
+    /// Constant buffer for every dye in `dyes`
+    dye_cbuffers: Vec<Option<ConstantBuffer<Vec4>>>,
     cbuffer_dirty: bool,
 }
 
@@ -315,7 +375,11 @@ impl DynamicModelComponent {
         )?;
 
         let mut d = Self {
-            identifier: u16::MAX,
+            identifiers: IdentifierMask::All,
+            dyes: EntityDyes::default(),
+            dye_source: None,
+            apply_dyes: true,
+            dye_cbuffers: vec![],
             cbuffer_skinning: model
                 .subscribed_stages
                 .contains(RenderStageSubscriptions::COMPUTE_SKINNING)
@@ -331,6 +395,34 @@ impl DynamicModelComponent {
         Ok(d)
     }
 
+    pub fn set_dyes(&mut self, renderer: &Renderer, dyes: EntityDyes) -> anyhow::Result<()> {
+        self.dye_cbuffers = dyes
+            .dyes
+            .iter()
+            .map(|dye| {
+                dye.as_ref()
+                    .map(|dye| {
+                        ConstantBuffer::create_array_init(renderer.gpu.clone(), &dye.cbuffer)
+                    })
+                    .transpose()
+            })
+            .collect::<anyhow::Result<_>>()?;
+        self.dyes = dyes;
+
+        Ok(())
+    }
+
+    /// Loads the dyes of [`Self::dye_source`]. Finding them means scanning the entity resources, so
+    /// this is only done once the model is inspected rather than while loading the map
+    pub fn load_pending_dyes(&mut self, renderer: &Renderer) -> anyhow::Result<()> {
+        let Some(entity) = self.dye_source.take() else {
+            return Ok(());
+        };
+
+        let header: SEntity = package_manager().read_tag_struct(entity)?;
+        self.set_dyes(renderer, EntityDyes::load(&header))
+    }
+
     pub fn mark_dirty(&mut self) {
         self.cbuffer_dirty = true;
     }
@@ -381,8 +473,44 @@ impl DynamicModelComponent {
         );
         // }
 
-        // TODO(cohae): Error reporting
-        self.model.draw(renderer, render_stage, self.identifier)
+        if !self.apply_dyes || self.dyes.is_empty() {
+            // TODO(cohae): Error reporting
+            return self.model.draw(renderer, render_stage, self.identifiers);
+        }
+
+        let scopes = &renderer.render_globals.scopes;
+        let dye_slots = [&scopes.gear_dye_0, &scopes.gear_dye_1, &scopes.gear_dye_2].map(|s| {
+            s.stage_pixel
+                .as_ref()
+                .map(|s| s.stage.constants.constant_buffer_slot)
+                .filter(|&slot| slot >= 0)
+        });
+
+        self.model.draw_wrapped(
+            renderer,
+            render_stage,
+            self.identifiers,
+            |_, renderer, _mesh, part| unsafe {
+                if let Some(slot) =
+                    GearDyeSlot::from_change_color_index(part.gear_dye_change_color_index)
+                {
+                    let cbuffer = self
+                        .dye_cbuffers
+                        .get(slot.dye_index())
+                        .and_then(Option::as_ref);
+                    if let (Some(cbuffer), Some(scope_slot)) =
+                        (cbuffer, dye_slots[slot.dye_index()])
+                    {
+                        cbuffer.bind(scope_slot as u32, TfxShaderStage::Pixel);
+                    }
+                }
+
+                renderer
+                    .gpu
+                    .context()
+                    .DrawIndexed(part.index_count, part.index_start, 0);
+            },
+        )
     }
 }
 
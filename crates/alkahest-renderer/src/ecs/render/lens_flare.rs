@@ -0,0 +1,33 @@
+use alkahest_data::{lens_flare::LensFlareParameters, map::SLensFlare};
+use bevy_ecs::component::Component;
+use destiny_pkg::TagHash;
+
+use crate::{handle::Handle, loaders::AssetManager, tfx::technique::Technique};
+
+#[derive(Component)]
+pub struct LensFlare {
+    pub data: SLensFlare,
+    pub hash: TagHash,
+
+    pub element_techniques: Vec<Handle<Technique>>,
+    pub occlusion_technique: Handle<Technique>,
+}
+
+impl LensFlare {
+    pub fn load(asset_manager: &mut AssetManager, hash: TagHash, data: SLensFlare) -> Self {
+        Self {
+            element_techniques: data
+                .elements
+                .iter()
+                .map(|e| asset_manager.get_or_load_technique(e.technique))
+                .collect(),
+            occlusion_technique: asset_manager.get_or_load_technique(data.occlusion.technique),
+            data,
+            hash,
+        }
+    }
+
+    pub fn parameters(&self) -> LensFlareParameters {
+        self.data.parameters(self.hash)
+    }
+}
@@ -33,7 +33,7 @@ use crate::{
     gpu_event,
     handle::Handle,
     icons::{ICON_LIGHTBULB_FLUORESCENT_TUBE, ICON_LIGHTBULB_ON, ICON_SPOTLIGHT_BEAM},
-    loaders::AssetManager,
+    loaders::{light_params::LightParameters, AssetManager},
     renderer::{gbuffer::ShadowDepthMap, Renderer},
     tfx::{
         externs::{self, TextureView},
@@ -61,6 +61,8 @@ pub struct LightRenderer {
     _technique_compute_lightprobe: Handle<Technique>,
     _technique_compute_lightprobe_shadowing: Option<Handle<Technique>>,
 
+    pub parameters: Option<LightParameters>,
+
     pub debug_label: String,
     pub debug_info: String,
 }
@@ -160,6 +162,7 @@ impl LightRenderer {
             _technique_volumetrics_shadowing: None,
             _technique_compute_lightprobe: Handle::none(),
             _technique_compute_lightprobe_shadowing: None,
+            parameters: None,
             debug_label: "Unknown DeferredLight".to_string(),
             debug_info: "Unknown DeferredLight".to_string(),
         })
@@ -178,6 +181,9 @@ impl LightRenderer {
                 .get_or_load_technique(light.technique_volumetrics),
             _technique_compute_lightprobe: asset_manager
                 .get_or_load_technique(light.technique_compute_lightprobe),
+            parameters: LightParameters::from_light(light)
+                .map_err(|e| warn!("Failed to decode light parameters: {e:?}"))
+                .ok(),
             debug_label,
             debug_info: format!("{light:X?}"),
             ..Self::new_empty(gctx.clone())?
@@ -206,6 +212,9 @@ impl LightRenderer {
             _technique_compute_lightprobe_shadowing: Some(
                 asset_manager.get_or_load_technique(light.technique_compute_lightprobe_shadowing),
             ),
+            parameters: LightParameters::from_shadowing_light(light)
+                .map_err(|e| warn!("Failed to decode light parameters: {e:?}"))
+                .ok(),
             debug_label,
             debug_info: format!("{light:X?}"),
             ..Self::new_empty(gctx.clone())?
@@ -501,6 +510,7 @@ impl View for ShadowMapRenderer {
     }
 }
 
+#[derive(strum::EnumIter, Debug, Copy, Clone, Hash, PartialEq, Eq, serde::Serialize)]
 pub enum LightShape {
     Omni,
     Spot,
@@ -23,9 +23,11 @@ use crate::{
 pub mod decorators;
 pub mod dynamic_geometry;
 pub mod havok;
+pub mod lens_flare;
 pub mod light;
 pub mod static_geometry;
 pub mod terrain;
+pub mod water;
 
 /// Draw a specific entity. Only works for entities with geometry, but not screen-space decals, lights, etc
 /// Ignores the renderer's feature visibility settings
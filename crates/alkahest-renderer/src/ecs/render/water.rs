@@ -0,0 +1,31 @@
+use alkahest_data::occlusion::Aabb;
+use bevy_ecs::component::Component;
+
+use crate::{
+    gpu::texture::Texture,
+    handle::Handle,
+    loaders::{water::WaterParameters, AssetManager},
+};
+
+#[derive(Component)]
+pub struct WaterSurface {
+    pub parameters: WaterParameters,
+    pub reflection_probes: Vec<Handle<Texture>>,
+}
+
+impl WaterSurface {
+    pub fn load(asset_manager: &mut AssetManager, parameters: WaterParameters) -> Self {
+        Self {
+            reflection_probes: parameters
+                .reflection_probes
+                .iter()
+                .map(|p| asset_manager.get_or_load_texture(p.texture))
+                .collect(),
+            parameters,
+        }
+    }
+
+    pub fn bounds(&self) -> Aabb {
+        self.parameters.bounds()
+    }
+}
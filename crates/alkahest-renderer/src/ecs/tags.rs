@@ -7,9 +7,9 @@ use tiger_parse::FnvHash;
 use super::Scene;
 use crate::{
     icons::{
-        ICON_ACCOUNT_CONVERT, ICON_CHESS_PAWN, ICON_CUBE, ICON_DROPBOX, ICON_HELP,
-        ICON_LIGHTBULB_ON, ICON_PINE_TREE, ICON_REPLY, ICON_SKULL, ICON_SPHERE, ICON_TAG,
-        ICON_TOOLBOX, ICON_VOLUME_HIGH, ICON_WEATHER_PARTLY_CLOUDY,
+        ICON_ACCOUNT_CONVERT, ICON_CHESS_PAWN, ICON_CUBE, ICON_DROPBOX, ICON_FORMAT_PAINT,
+        ICON_HELP, ICON_LIGHTBULB_ON, ICON_PINE_TREE, ICON_REPLY, ICON_SKULL, ICON_SPHERE,
+        ICON_TAG, ICON_TOOLBOX, ICON_VOLUME_HIGH, ICON_WEATHER_PARTLY_CLOUDY,
     },
     util::{color::Color, scene::EntityWorldMutExt},
 };
@@ -25,6 +25,7 @@ pub enum NodeFilter {
     Decorator,
     SkyObject,
     Cubemap,
+    Decal,
     Static,
 
     InstakillBarrier,
@@ -48,6 +49,7 @@ impl NodeFilter {
             NodeFilter::Decorator => ICON_PINE_TREE,
             NodeFilter::SkyObject => ICON_WEATHER_PARTLY_CLOUDY,
             NodeFilter::Cubemap => ICON_SPHERE,
+            NodeFilter::Decal => ICON_FORMAT_PAINT,
             NodeFilter::Static => ICON_CUBE,
             NodeFilter::InstakillBarrier => ICON_SKULL,
             NodeFilter::TurnbackBarrier => ICON_REPLY,
@@ -68,6 +70,7 @@ impl NodeFilter {
             NodeFilter::Decorator => Color::from_srgba_unmultiplied(80, 210, 80, 255),
             NodeFilter::SkyObject => Color::from_srgba_unmultiplied(0xAD, 0xD8, 0xE6, 255),
             NodeFilter::Cubemap => Color::from_srgba_unmultiplied(50, 255, 50, 255),
+            NodeFilter::Decal => Color::from_srgba_unmultiplied(255, 140, 60, 255),
             NodeFilter::Static => Color::WHITE,
             NodeFilter::InstakillBarrier => Color::from_srgba_unmultiplied(220, 60, 60, 255),
             NodeFilter::TurnbackBarrier => Color::from_srgba_unmultiplied(220, 120, 60, 255),
@@ -1,8 +1,11 @@
-use std::{ffi::CStr, fmt::Write};
+use std::fmt::Write;
 
-use alkahest_data::dxgi::DxgiFormat;
 use anyhow::Context;
 use itertools::Itertools;
+use render_states::{
+    InputLayout, RenderTargetBlendDesc, StencilFaceDesc, BLEND_STATES, DEPTH_BIASES, DEPTH_STATES,
+    DEPTH_STENCIL_COMBOS, INPUT_LAYOUTS, RASTERIZER_STATES, STENCIL_STATES,
+};
 use windows::{
     core::{s, PCSTR},
     Win32::{
@@ -11,23 +14,12 @@ use windows::{
             Direct3D::Fxc::D3DCompile,
             Direct3D11::{
                 ID3D11BlendState, ID3D11DepthStencilState, ID3D11Device, ID3D11InputLayout,
-                ID3D11RasterizerState, D3D11_APPEND_ALIGNED_ELEMENT, D3D11_BLEND_BLEND_FACTOR,
-                D3D11_BLEND_DESC, D3D11_BLEND_DEST_ALPHA, D3D11_BLEND_DEST_COLOR,
-                D3D11_BLEND_INV_BLEND_FACTOR, D3D11_BLEND_INV_DEST_ALPHA,
-                D3D11_BLEND_INV_SRC1_ALPHA, D3D11_BLEND_INV_SRC1_COLOR, D3D11_BLEND_INV_SRC_ALPHA,
-                D3D11_BLEND_ONE, D3D11_BLEND_OP_ADD, D3D11_BLEND_OP_MAX, D3D11_BLEND_OP_MIN,
-                D3D11_BLEND_OP_REV_SUBTRACT, D3D11_BLEND_SRC1_ALPHA, D3D11_BLEND_SRC1_COLOR,
-                D3D11_BLEND_SRC_ALPHA, D3D11_BLEND_SRC_COLOR, D3D11_BLEND_ZERO,
-                D3D11_COLOR_WRITE_ENABLE_ALL, D3D11_COMPARISON_ALWAYS, D3D11_COMPARISON_EQUAL,
-                D3D11_COMPARISON_FUNC, D3D11_COMPARISON_GREATER, D3D11_COMPARISON_GREATER_EQUAL,
-                D3D11_COMPARISON_LESS, D3D11_COMPARISON_LESS_EQUAL, D3D11_COMPARISON_NEVER,
-                D3D11_COMPARISON_NOT_EQUAL, D3D11_CULL_BACK, D3D11_CULL_FRONT, D3D11_CULL_MODE,
-                D3D11_CULL_NONE, D3D11_DEPTH_STENCILOP_DESC, D3D11_DEPTH_STENCIL_DESC,
-                D3D11_DEPTH_WRITE_MASK, D3D11_FILL_MODE, D3D11_FILL_SOLID, D3D11_FILL_WIREFRAME,
+                ID3D11RasterizerState, D3D11_APPEND_ALIGNED_ELEMENT, D3D11_BLEND, D3D11_BLEND_DESC,
+                D3D11_BLEND_OP, D3D11_COMPARISON_FUNC, D3D11_CULL_MODE, D3D11_DEPTH_STENCILOP_DESC,
+                D3D11_DEPTH_STENCIL_DESC, D3D11_DEPTH_WRITE_MASK, D3D11_FILL_MODE,
                 D3D11_INPUT_ELEMENT_DESC, D3D11_INPUT_PER_INSTANCE_DATA,
                 D3D11_INPUT_PER_VERTEX_DATA, D3D11_RASTERIZER_DESC, D3D11_RENDER_TARGET_BLEND_DESC,
-                D3D11_STENCIL_OP, D3D11_STENCIL_OP_INVERT, D3D11_STENCIL_OP_KEEP,
-                D3D11_STENCIL_OP_REPLACE, D3D11_STENCIL_OP_ZERO,
+                D3D11_STENCIL_OP,
             },
             Dxgi::Common::DXGI_FORMAT,
         },
@@ -44,17 +36,17 @@ pub struct RenderStates {
 impl RenderStates {
     pub fn new(device: &ID3D11Device) -> anyhow::Result<Self> {
         let mut blend_states = vec![];
-        assert_eq!(BLEND_STATE_DESCS.len(), 90, "Invalid blend state count");
-        for desc in BLEND_STATE_DESCS.iter() {
+        assert_eq!(BLEND_STATES.len(), 90, "Invalid blend state count");
+        for desc in BLEND_STATES.iter() {
             let mut state = None;
             unsafe {
-                let mut render_targets = desc.RenderTarget.to_vec();
-                render_targets.extend([
-                    desc.RenderTarget[3],
-                    desc.RenderTarget[3],
-                    desc.RenderTarget[3],
-                    desc.RenderTarget[3],
-                ]);
+                let mut render_targets = desc
+                    .render_targets
+                    .iter()
+                    .map(d3d_render_target)
+                    .collect_vec();
+                let last = render_targets[3];
+                render_targets.extend([last, last, last, last]);
 
                 device.CreateBlendState(
                     &D3D11_BLEND_DESC {
@@ -86,14 +78,14 @@ impl RenderStates {
                     device
                         .CreateRasterizerState(
                             &D3D11_RASTERIZER_DESC {
-                                FillMode: rs_desc.fill_mode,
-                                CullMode: rs_desc.cull_mode,
-                                FrontCounterClockwise: rs_desc.front_counter_clockwise,
+                                FillMode: D3D11_FILL_MODE(rs_desc.fill_mode as i32),
+                                CullMode: D3D11_CULL_MODE(rs_desc.cull_mode as i32),
+                                FrontCounterClockwise: rs_desc.front_counter_clockwise.into(),
                                 DepthBias: db_desc.depth_bias,
                                 DepthBiasClamp: db_desc.clamp,
                                 SlopeScaledDepthBias: db_desc.slope_scale,
-                                DepthClipEnable: rs_desc.depth_clip_enable,
-                                ScissorEnable: rs_desc.scissor_enable,
+                                DepthClipEnable: rs_desc.depth_clip_enable.into(),
+                                ScissorEnable: rs_desc.scissor_enable.into(),
                                 MultisampleEnable: false.into(),
                                 AntialiasedLineEnable: false.into(),
                             },
@@ -112,24 +104,14 @@ impl RenderStates {
                 let depth = &DEPTH_STATES[*depth_idx];
                 let stencil = &STENCIL_STATES[*stencil_idx];
                 let mut d3d_desc = D3D11_DEPTH_STENCIL_DESC {
-                    DepthEnable: depth.enable,
+                    DepthEnable: depth.enable.into(),
                     DepthWriteMask: D3D11_DEPTH_WRITE_MASK(depth.write_mask as i32),
-                    DepthFunc: depth.func,
-                    StencilEnable: stencil.stencil_enable,
+                    DepthFunc: D3D11_COMPARISON_FUNC(depth.func as i32),
+                    StencilEnable: stencil.stencil_enable.into(),
                     StencilReadMask: stencil.stencil_read_mask,
                     StencilWriteMask: stencil.stencil_write_mask,
-                    FrontFace: D3D11_DEPTH_STENCILOP_DESC {
-                        StencilFailOp: stencil.front_face.fail_op,
-                        StencilDepthFailOp: stencil.front_face.depth_fail_op,
-                        StencilPassOp: stencil.front_face.pass_op,
-                        StencilFunc: stencil.front_face.func,
-                    },
-                    BackFace: D3D11_DEPTH_STENCILOP_DESC {
-                        StencilFailOp: stencil.back_face.fail_op,
-                        StencilDepthFailOp: stencil.back_face.depth_fail_op,
-                        StencilPassOp: stencil.back_face.pass_op,
-                        StencilFunc: stencil.back_face.func,
-                    },
+                    FrontFace: d3d_stencil_face(&stencil.front_face),
+                    BackFace: d3d_stencil_face(&stencil.back_face),
                 };
 
                 let depth_state1 = unsafe {
@@ -140,7 +122,7 @@ impl RenderStates {
                     state.unwrap()
                 };
 
-                d3d_desc.DepthFunc = depth.func_alt;
+                d3d_desc.DepthFunc = D3D11_COMPARISON_FUNC(depth.func_alt as i32);
                 let depth_state2 = unsafe {
                     let mut state = None;
                     device
@@ -163,7 +145,7 @@ impl RenderStates {
 
     fn create_input_layout(
         device: &ID3D11Device,
-        layout: &TigerInputLayout,
+        layout: &InputLayout,
     ) -> anyhow::Result<ID3D11InputLayout> {
         let mut out = None;
 
@@ -231,11068 +213,24 @@ impl RenderStates {
     }
 }
 
-/// Same as regular D3D11 blend desc, but with only 4 render targets
-#[allow(non_snake_case)]
-struct TigerBlendDesc {
-    RenderTarget: [D3D11_RENDER_TARGET_BLEND_DESC; 4],
+fn d3d_render_target(rt: &RenderTargetBlendDesc) -> D3D11_RENDER_TARGET_BLEND_DESC {
+    D3D11_RENDER_TARGET_BLEND_DESC {
+        BlendEnable: rt.enabled.into(),
+        SrcBlend: D3D11_BLEND(rt.src_color as i32),
+        DestBlend: D3D11_BLEND(rt.dst_color as i32),
+        BlendOp: D3D11_BLEND_OP(rt.color_op as i32),
+        SrcBlendAlpha: D3D11_BLEND(rt.src_alpha as i32),
+        DestBlendAlpha: D3D11_BLEND(rt.dst_alpha as i32),
+        BlendOpAlpha: D3D11_BLEND_OP(rt.alpha_op as i32),
+        RenderTargetWriteMask: rt.write_mask,
+    }
 }
 
-//region Blend states
-const BLEND_STATE_DESCS: [TigerBlendDesc; 0x5a] = [
-    TigerBlendDesc {
-        RenderTarget: [
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-        ],
-    },
-    TigerBlendDesc {
-        RenderTarget: [
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-        ],
-    },
-    TigerBlendDesc {
-        RenderTarget: [
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ONE,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ONE,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ONE,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ONE,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ONE,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ONE,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ONE,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ONE,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-        ],
-    },
-    TigerBlendDesc {
-        RenderTarget: [
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_DEST_COLOR,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_DEST_ALPHA,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_DEST_COLOR,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_DEST_ALPHA,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_DEST_COLOR,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_DEST_ALPHA,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_DEST_COLOR,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_DEST_ALPHA,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-        ],
-    },
-    TigerBlendDesc {
-        RenderTarget: [
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_DEST_COLOR,
-                DestBlend: D3D11_BLEND_SRC_COLOR,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_DEST_ALPHA,
-                DestBlendAlpha: D3D11_BLEND_SRC_ALPHA,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_DEST_COLOR,
-                DestBlend: D3D11_BLEND_SRC_COLOR,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_DEST_ALPHA,
-                DestBlendAlpha: D3D11_BLEND_SRC_ALPHA,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_DEST_COLOR,
-                DestBlend: D3D11_BLEND_SRC_COLOR,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_DEST_ALPHA,
-                DestBlendAlpha: D3D11_BLEND_SRC_ALPHA,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_DEST_COLOR,
-                DestBlend: D3D11_BLEND_SRC_COLOR,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_DEST_ALPHA,
-                DestBlendAlpha: D3D11_BLEND_SRC_ALPHA,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-        ],
-    },
-    TigerBlendDesc {
-        RenderTarget: [
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_SRC_ALPHA,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_SRC_ALPHA,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_SRC_ALPHA,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_SRC_ALPHA,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_SRC_ALPHA,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_SRC_ALPHA,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_SRC_ALPHA,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_SRC_ALPHA,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-        ],
-    },
-    TigerBlendDesc {
-        RenderTarget: [
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_SRC_ALPHA,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_DEST_ALPHA,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_SRC_ALPHA,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_DEST_ALPHA,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_SRC_ALPHA,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_DEST_ALPHA,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_SRC_ALPHA,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_DEST_ALPHA,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-        ],
-    },
-    TigerBlendDesc {
-        RenderTarget: [
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_DEST_ALPHA,
-                DestBlend: D3D11_BLEND_ONE,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_DEST_ALPHA,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_DEST_ALPHA,
-                DestBlend: D3D11_BLEND_ONE,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_DEST_ALPHA,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_DEST_ALPHA,
-                DestBlend: D3D11_BLEND_ONE,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_DEST_ALPHA,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_DEST_ALPHA,
-                DestBlend: D3D11_BLEND_ONE,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_DEST_ALPHA,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-        ],
-    },
-    TigerBlendDesc {
-        RenderTarget: [
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_INV_SRC_ALPHA,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_INV_SRC_ALPHA,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_INV_SRC_ALPHA,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_INV_SRC_ALPHA,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_INV_SRC_ALPHA,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_INV_SRC_ALPHA,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_INV_SRC_ALPHA,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_INV_SRC_ALPHA,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-        ],
-    },
-    TigerBlendDesc {
-        RenderTarget: [
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ONE,
-                BlendOp: D3D11_BLEND_OP_MIN,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ONE,
-                BlendOpAlpha: D3D11_BLEND_OP_MIN,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ONE,
-                BlendOp: D3D11_BLEND_OP_MIN,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ONE,
-                BlendOpAlpha: D3D11_BLEND_OP_MIN,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ONE,
-                BlendOp: D3D11_BLEND_OP_MIN,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ONE,
-                BlendOpAlpha: D3D11_BLEND_OP_MIN,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ONE,
-                BlendOp: D3D11_BLEND_OP_MIN,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ONE,
-                BlendOpAlpha: D3D11_BLEND_OP_MIN,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-        ],
-    },
-    TigerBlendDesc {
-        RenderTarget: [
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ONE,
-                BlendOp: D3D11_BLEND_OP_MAX,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ONE,
-                BlendOpAlpha: D3D11_BLEND_OP_MAX,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ONE,
-                BlendOp: D3D11_BLEND_OP_MAX,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ONE,
-                BlendOpAlpha: D3D11_BLEND_OP_MAX,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ONE,
-                BlendOp: D3D11_BLEND_OP_MAX,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ONE,
-                BlendOpAlpha: D3D11_BLEND_OP_MAX,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ONE,
-                BlendOp: D3D11_BLEND_OP_MAX,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ONE,
-                BlendOpAlpha: D3D11_BLEND_OP_MAX,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-        ],
-    },
-    TigerBlendDesc {
-        RenderTarget: [
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_DEST_COLOR,
-                DestBlend: D3D11_BLEND_ONE,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_DEST_ALPHA,
-                DestBlendAlpha: D3D11_BLEND_ONE,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_DEST_COLOR,
-                DestBlend: D3D11_BLEND_ONE,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_DEST_ALPHA,
-                DestBlendAlpha: D3D11_BLEND_ONE,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_DEST_COLOR,
-                DestBlend: D3D11_BLEND_ONE,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_DEST_ALPHA,
-                DestBlendAlpha: D3D11_BLEND_ONE,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_DEST_COLOR,
-                DestBlend: D3D11_BLEND_ONE,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_DEST_ALPHA,
-                DestBlendAlpha: D3D11_BLEND_ONE,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-        ],
-    },
-    TigerBlendDesc {
-        RenderTarget: [
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_SRC_ALPHA,
-                DestBlend: D3D11_BLEND_INV_SRC_ALPHA,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_SRC_ALPHA,
-                DestBlendAlpha: D3D11_BLEND_INV_SRC_ALPHA,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_SRC_ALPHA,
-                DestBlend: D3D11_BLEND_INV_SRC_ALPHA,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_SRC_ALPHA,
-                DestBlendAlpha: D3D11_BLEND_INV_SRC_ALPHA,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_SRC_ALPHA,
-                DestBlend: D3D11_BLEND_INV_SRC_ALPHA,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_SRC_ALPHA,
-                DestBlendAlpha: D3D11_BLEND_INV_SRC_ALPHA,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_SRC_ALPHA,
-                DestBlend: D3D11_BLEND_INV_SRC_ALPHA,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_SRC_ALPHA,
-                DestBlendAlpha: D3D11_BLEND_INV_SRC_ALPHA,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-        ],
-    },
-    TigerBlendDesc {
-        RenderTarget: [
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_BLEND_FACTOR,
-                DestBlend: D3D11_BLEND_INV_BLEND_FACTOR,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_BLEND_FACTOR,
-                DestBlendAlpha: D3D11_BLEND_INV_BLEND_FACTOR,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_BLEND_FACTOR,
-                DestBlend: D3D11_BLEND_INV_BLEND_FACTOR,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_BLEND_FACTOR,
-                DestBlendAlpha: D3D11_BLEND_INV_BLEND_FACTOR,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_BLEND_FACTOR,
-                DestBlend: D3D11_BLEND_INV_BLEND_FACTOR,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_BLEND_FACTOR,
-                DestBlendAlpha: D3D11_BLEND_INV_BLEND_FACTOR,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_BLEND_FACTOR,
-                DestBlend: D3D11_BLEND_INV_BLEND_FACTOR,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_BLEND_FACTOR,
-                DestBlendAlpha: D3D11_BLEND_INV_BLEND_FACTOR,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-        ],
-    },
-    TigerBlendDesc {
-        RenderTarget: [
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_INV_SRC_ALPHA,
-                DestBlend: D3D11_BLEND_SRC_ALPHA,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_INV_SRC_ALPHA,
-                DestBlendAlpha: D3D11_BLEND_SRC_ALPHA,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_INV_SRC_ALPHA,
-                DestBlend: D3D11_BLEND_SRC_ALPHA,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_INV_SRC_ALPHA,
-                DestBlendAlpha: D3D11_BLEND_SRC_ALPHA,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_INV_SRC_ALPHA,
-                DestBlend: D3D11_BLEND_SRC_ALPHA,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_INV_SRC_ALPHA,
-                DestBlendAlpha: D3D11_BLEND_SRC_ALPHA,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_INV_SRC_ALPHA,
-                DestBlend: D3D11_BLEND_SRC_ALPHA,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_INV_SRC_ALPHA,
-                DestBlendAlpha: D3D11_BLEND_SRC_ALPHA,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-        ],
-    },
-    TigerBlendDesc {
-        RenderTarget: [
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_DEST_ALPHA,
-                DestBlend: D3D11_BLEND_INV_DEST_ALPHA,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_DEST_ALPHA,
-                DestBlend: D3D11_BLEND_INV_DEST_ALPHA,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_DEST_ALPHA,
-                DestBlend: D3D11_BLEND_INV_DEST_ALPHA,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_DEST_ALPHA,
-                DestBlend: D3D11_BLEND_INV_DEST_ALPHA,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-        ],
-    },
-    TigerBlendDesc {
-        RenderTarget: [
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_INV_SRC_ALPHA,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ZERO,
-                DestBlendAlpha: D3D11_BLEND_INV_SRC_ALPHA,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_INV_SRC_ALPHA,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ZERO,
-                DestBlendAlpha: D3D11_BLEND_INV_SRC_ALPHA,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_INV_SRC_ALPHA,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ZERO,
-                DestBlendAlpha: D3D11_BLEND_INV_SRC_ALPHA,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_INV_SRC_ALPHA,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ZERO,
-                DestBlendAlpha: D3D11_BLEND_INV_SRC_ALPHA,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-        ],
-    },
-    TigerBlendDesc {
-        RenderTarget: [
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-        ],
-    },
-    TigerBlendDesc {
-        RenderTarget: [
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-        ],
-    },
-    TigerBlendDesc {
-        RenderTarget: [
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-        ],
-    },
-    TigerBlendDesc {
-        RenderTarget: [
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-        ],
-    },
-    TigerBlendDesc {
-        RenderTarget: [
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-        ],
-    },
-    TigerBlendDesc {
-        RenderTarget: [
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_SRC_ALPHA,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ZERO,
-                DestBlendAlpha: D3D11_BLEND_ONE,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_SRC_ALPHA,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ZERO,
-                DestBlendAlpha: D3D11_BLEND_ONE,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_SRC_ALPHA,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ZERO,
-                DestBlendAlpha: D3D11_BLEND_ONE,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_SRC_ALPHA,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ZERO,
-                DestBlendAlpha: D3D11_BLEND_ONE,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-        ],
-    },
-    TigerBlendDesc {
-        RenderTarget: [
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_SRC_ALPHA,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ZERO,
-                DestBlendAlpha: D3D11_BLEND_ONE,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_SRC_ALPHA,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ZERO,
-                DestBlendAlpha: D3D11_BLEND_ONE,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_SRC_ALPHA,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ZERO,
-                DestBlendAlpha: D3D11_BLEND_ONE,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_SRC_ALPHA,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ZERO,
-                DestBlendAlpha: D3D11_BLEND_ONE,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-        ],
-    },
-    TigerBlendDesc {
-        RenderTarget: [
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_SRC_ALPHA,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ZERO,
-                DestBlendAlpha: D3D11_BLEND_ONE,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_SRC_ALPHA,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ZERO,
-                DestBlendAlpha: D3D11_BLEND_ONE,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_SRC_ALPHA,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ZERO,
-                DestBlendAlpha: D3D11_BLEND_ONE,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_SRC_ALPHA,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ZERO,
-                DestBlendAlpha: D3D11_BLEND_ONE,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-        ],
-    },
-    TigerBlendDesc {
-        RenderTarget: [
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_SRC_ALPHA,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ZERO,
-                DestBlendAlpha: D3D11_BLEND_ONE,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_SRC_ALPHA,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ZERO,
-                DestBlendAlpha: D3D11_BLEND_ONE,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_SRC_ALPHA,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ZERO,
-                DestBlendAlpha: D3D11_BLEND_ONE,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_SRC_ALPHA,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ZERO,
-                DestBlendAlpha: D3D11_BLEND_ONE,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-        ],
-    },
-    TigerBlendDesc {
-        RenderTarget: [
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_SRC_ALPHA,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ZERO,
-                DestBlendAlpha: D3D11_BLEND_ONE,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_SRC_ALPHA,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ZERO,
-                DestBlendAlpha: D3D11_BLEND_ONE,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_SRC_ALPHA,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ZERO,
-                DestBlendAlpha: D3D11_BLEND_ONE,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_SRC_ALPHA,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ZERO,
-                DestBlendAlpha: D3D11_BLEND_ONE,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-        ],
-    },
-    TigerBlendDesc {
-        RenderTarget: [
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_SRC_ALPHA,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ZERO,
-                DestBlendAlpha: D3D11_BLEND_ONE,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_SRC_ALPHA,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ZERO,
-                DestBlendAlpha: D3D11_BLEND_ONE,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_SRC_ALPHA,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ZERO,
-                DestBlendAlpha: D3D11_BLEND_ONE,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_SRC_ALPHA,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ZERO,
-                DestBlendAlpha: D3D11_BLEND_ONE,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-        ],
-    },
-    TigerBlendDesc {
-        RenderTarget: [
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_SRC_ALPHA,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ZERO,
-                DestBlendAlpha: D3D11_BLEND_ONE,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_SRC_ALPHA,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ZERO,
-                DestBlendAlpha: D3D11_BLEND_ONE,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_SRC_ALPHA,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ZERO,
-                DestBlendAlpha: D3D11_BLEND_ONE,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_SRC_ALPHA,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ZERO,
-                DestBlendAlpha: D3D11_BLEND_ONE,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-        ],
-    },
-    TigerBlendDesc {
-        RenderTarget: [
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_SRC_ALPHA,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ZERO,
-                DestBlendAlpha: D3D11_BLEND_ONE,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_SRC_ALPHA,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ZERO,
-                DestBlendAlpha: D3D11_BLEND_ONE,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_SRC_ALPHA,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ZERO,
-                DestBlendAlpha: D3D11_BLEND_ONE,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_SRC_ALPHA,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ZERO,
-                DestBlendAlpha: D3D11_BLEND_ONE,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-        ],
-    },
-    TigerBlendDesc {
-        RenderTarget: [
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_SRC_ALPHA,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ZERO,
-                DestBlendAlpha: D3D11_BLEND_ONE,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_SRC_ALPHA,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ZERO,
-                DestBlendAlpha: D3D11_BLEND_ONE,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_SRC_ALPHA,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ZERO,
-                DestBlendAlpha: D3D11_BLEND_ONE,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_SRC_ALPHA,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ZERO,
-                DestBlendAlpha: D3D11_BLEND_ONE,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-        ],
-    },
-    TigerBlendDesc {
-        RenderTarget: [
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_SRC_ALPHA,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ZERO,
-                DestBlendAlpha: D3D11_BLEND_ONE,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_SRC_ALPHA,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ZERO,
-                DestBlendAlpha: D3D11_BLEND_ONE,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_SRC_ALPHA,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ZERO,
-                DestBlendAlpha: D3D11_BLEND_ONE,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_SRC_ALPHA,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ZERO,
-                DestBlendAlpha: D3D11_BLEND_ONE,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-        ],
-    },
-    TigerBlendDesc {
-        RenderTarget: [
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_SRC_ALPHA,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ZERO,
-                DestBlendAlpha: D3D11_BLEND_ONE,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_SRC_ALPHA,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ZERO,
-                DestBlendAlpha: D3D11_BLEND_ONE,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_SRC_ALPHA,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ZERO,
-                DestBlendAlpha: D3D11_BLEND_ONE,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_SRC_ALPHA,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ZERO,
-                DestBlendAlpha: D3D11_BLEND_ONE,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-        ],
-    },
-    TigerBlendDesc {
-        RenderTarget: [
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_SRC_ALPHA,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ZERO,
-                DestBlendAlpha: D3D11_BLEND_ONE,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_SRC_ALPHA,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ZERO,
-                DestBlendAlpha: D3D11_BLEND_ONE,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_SRC_ALPHA,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ZERO,
-                DestBlendAlpha: D3D11_BLEND_ONE,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_SRC_ALPHA,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ZERO,
-                DestBlendAlpha: D3D11_BLEND_ONE,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-        ],
-    },
-    TigerBlendDesc {
-        RenderTarget: [
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_SRC_ALPHA,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ZERO,
-                DestBlendAlpha: D3D11_BLEND_ONE,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_SRC_ALPHA,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ZERO,
-                DestBlendAlpha: D3D11_BLEND_ONE,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_SRC_ALPHA,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ZERO,
-                DestBlendAlpha: D3D11_BLEND_ONE,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_SRC_ALPHA,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ZERO,
-                DestBlendAlpha: D3D11_BLEND_ONE,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-        ],
-    },
-    TigerBlendDesc {
-        RenderTarget: [
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_SRC_ALPHA,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ZERO,
-                DestBlendAlpha: D3D11_BLEND_ONE,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_SRC_ALPHA,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ZERO,
-                DestBlendAlpha: D3D11_BLEND_ONE,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_SRC_ALPHA,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ZERO,
-                DestBlendAlpha: D3D11_BLEND_ONE,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_SRC_ALPHA,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ZERO,
-                DestBlendAlpha: D3D11_BLEND_ONE,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-        ],
-    },
-    TigerBlendDesc {
-        RenderTarget: [
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-        ],
-    },
-    TigerBlendDesc {
-        RenderTarget: [
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-        ],
-    },
-    TigerBlendDesc {
-        RenderTarget: [
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-        ],
-    },
-    TigerBlendDesc {
-        RenderTarget: [
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-        ],
-    },
-    TigerBlendDesc {
-        RenderTarget: [
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-        ],
-    },
-    TigerBlendDesc {
-        RenderTarget: [
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-        ],
-    },
-    TigerBlendDesc {
-        RenderTarget: [
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-        ],
-    },
-    TigerBlendDesc {
-        RenderTarget: [
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-        ],
-    },
-    TigerBlendDesc {
-        RenderTarget: [
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-        ],
-    },
-    TigerBlendDesc {
-        RenderTarget: [
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-        ],
-    },
-    TigerBlendDesc {
-        RenderTarget: [
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-        ],
-    },
-    TigerBlendDesc {
-        RenderTarget: [
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-        ],
-    },
-    TigerBlendDesc {
-        RenderTarget: [
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-        ],
-    },
-    TigerBlendDesc {
-        RenderTarget: [
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-        ],
-    },
-    TigerBlendDesc {
-        RenderTarget: [
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-        ],
-    },
-    TigerBlendDesc {
-        RenderTarget: [
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-        ],
-    },
-    TigerBlendDesc {
-        RenderTarget: [
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-        ],
-    },
-    TigerBlendDesc {
-        RenderTarget: [
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-        ],
-    },
-    TigerBlendDesc {
-        RenderTarget: [
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-        ],
-    },
-    TigerBlendDesc {
-        RenderTarget: [
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-        ],
-    },
-    TigerBlendDesc {
-        RenderTarget: [
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-        ],
-    },
-    TigerBlendDesc {
-        RenderTarget: [
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-        ],
-    },
-    TigerBlendDesc {
-        RenderTarget: [
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-        ],
-    },
-    TigerBlendDesc {
-        RenderTarget: [
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-        ],
-    },
-    TigerBlendDesc {
-        RenderTarget: [
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-        ],
-    },
-    TigerBlendDesc {
-        RenderTarget: [
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-        ],
-    },
-    TigerBlendDesc {
-        RenderTarget: [
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-        ],
-    },
-    TigerBlendDesc {
-        RenderTarget: [
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-        ],
-    },
-    TigerBlendDesc {
-        RenderTarget: [
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-        ],
-    },
-    TigerBlendDesc {
-        RenderTarget: [
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-        ],
-    },
-    TigerBlendDesc {
-        RenderTarget: [
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-        ],
-    },
-    TigerBlendDesc {
-        RenderTarget: [
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-        ],
-    },
-    TigerBlendDesc {
-        RenderTarget: [
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-        ],
-    },
-    TigerBlendDesc {
-        RenderTarget: [
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-        ],
-    },
-    TigerBlendDesc {
-        RenderTarget: [
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-        ],
-    },
-    TigerBlendDesc {
-        RenderTarget: [
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-        ],
-    },
-    TigerBlendDesc {
-        RenderTarget: [
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-        ],
-    },
-    TigerBlendDesc {
-        RenderTarget: [
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-        ],
-    },
-    TigerBlendDesc {
-        RenderTarget: [
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-        ],
-    },
-    TigerBlendDesc {
-        RenderTarget: [
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-        ],
-    },
-    TigerBlendDesc {
-        RenderTarget: [
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_DEST_COLOR,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ZERO,
-                DestBlendAlpha: D3D11_BLEND_ONE,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_DEST_COLOR,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ZERO,
-                DestBlendAlpha: D3D11_BLEND_ONE,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_DEST_COLOR,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ZERO,
-                DestBlendAlpha: D3D11_BLEND_ONE,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_DEST_COLOR,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ZERO,
-                DestBlendAlpha: D3D11_BLEND_ONE,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-        ],
-    },
-    TigerBlendDesc {
-        RenderTarget: [
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_DEST_COLOR,
-                DestBlend: D3D11_BLEND_SRC_COLOR,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ZERO,
-                DestBlendAlpha: D3D11_BLEND_ONE,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_DEST_COLOR,
-                DestBlend: D3D11_BLEND_SRC_COLOR,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ZERO,
-                DestBlendAlpha: D3D11_BLEND_ONE,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_DEST_COLOR,
-                DestBlend: D3D11_BLEND_SRC_COLOR,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ZERO,
-                DestBlendAlpha: D3D11_BLEND_ONE,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_DEST_COLOR,
-                DestBlend: D3D11_BLEND_SRC_COLOR,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ZERO,
-                DestBlendAlpha: D3D11_BLEND_ONE,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-        ],
-    },
-    TigerBlendDesc {
-        RenderTarget: [
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-        ],
-    },
-    TigerBlendDesc {
-        RenderTarget: [
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_DEST_ALPHA,
-                DestBlend: D3D11_BLEND_ONE,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ZERO,
-                DestBlendAlpha: D3D11_BLEND_ONE,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_DEST_ALPHA,
-                DestBlend: D3D11_BLEND_ONE,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ZERO,
-                DestBlendAlpha: D3D11_BLEND_ONE,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_DEST_ALPHA,
-                DestBlend: D3D11_BLEND_ONE,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ZERO,
-                DestBlendAlpha: D3D11_BLEND_ONE,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_DEST_ALPHA,
-                DestBlend: D3D11_BLEND_ONE,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ZERO,
-                DestBlendAlpha: D3D11_BLEND_ONE,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-        ],
-    },
-    TigerBlendDesc {
-        RenderTarget: [
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_INV_DEST_ALPHA,
-                DestBlend: D3D11_BLEND_ONE,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ZERO,
-                DestBlendAlpha: D3D11_BLEND_ONE,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_INV_DEST_ALPHA,
-                DestBlend: D3D11_BLEND_ONE,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ZERO,
-                DestBlendAlpha: D3D11_BLEND_ONE,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_INV_DEST_ALPHA,
-                DestBlend: D3D11_BLEND_ONE,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ZERO,
-                DestBlendAlpha: D3D11_BLEND_ONE,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_INV_DEST_ALPHA,
-                DestBlend: D3D11_BLEND_ONE,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ZERO,
-                DestBlendAlpha: D3D11_BLEND_ONE,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-        ],
-    },
-    TigerBlendDesc {
-        RenderTarget: [
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_ZERO,
-                DestBlend: D3D11_BLEND_DEST_ALPHA,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ZERO,
-                DestBlendAlpha: D3D11_BLEND_ONE,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_ZERO,
-                DestBlend: D3D11_BLEND_DEST_ALPHA,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ZERO,
-                DestBlendAlpha: D3D11_BLEND_ONE,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_ZERO,
-                DestBlend: D3D11_BLEND_DEST_ALPHA,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ZERO,
-                DestBlendAlpha: D3D11_BLEND_ONE,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_ZERO,
-                DestBlend: D3D11_BLEND_DEST_ALPHA,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ZERO,
-                DestBlendAlpha: D3D11_BLEND_ONE,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-        ],
-    },
-    TigerBlendDesc {
-        RenderTarget: [
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_ZERO,
-                DestBlend: D3D11_BLEND_INV_DEST_ALPHA,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ZERO,
-                DestBlendAlpha: D3D11_BLEND_ONE,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_ZERO,
-                DestBlend: D3D11_BLEND_INV_DEST_ALPHA,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ZERO,
-                DestBlendAlpha: D3D11_BLEND_ONE,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_ZERO,
-                DestBlend: D3D11_BLEND_INV_DEST_ALPHA,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ZERO,
-                DestBlendAlpha: D3D11_BLEND_ONE,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_ZERO,
-                DestBlend: D3D11_BLEND_INV_DEST_ALPHA,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ZERO,
-                DestBlendAlpha: D3D11_BLEND_ONE,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-        ],
-    },
-    TigerBlendDesc {
-        RenderTarget: [
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_ZERO,
-                DestBlend: D3D11_BLEND_INV_SRC_ALPHA,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_INV_SRC_ALPHA,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_ZERO,
-                DestBlend: D3D11_BLEND_INV_SRC_ALPHA,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_INV_SRC_ALPHA,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_ZERO,
-                DestBlend: D3D11_BLEND_INV_SRC_ALPHA,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_INV_SRC_ALPHA,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_ZERO,
-                DestBlend: D3D11_BLEND_INV_SRC_ALPHA,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_INV_SRC_ALPHA,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-        ],
-    },
-    TigerBlendDesc {
-        RenderTarget: [
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_INV_SRC_ALPHA,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_INV_SRC_ALPHA,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_INV_SRC_ALPHA,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_INV_SRC_ALPHA,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_INV_SRC_ALPHA,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_INV_SRC_ALPHA,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_INV_SRC_ALPHA,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_INV_SRC_ALPHA,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-        ],
-    },
-    TigerBlendDesc {
-        RenderTarget: [
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_INV_SRC_ALPHA,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_INV_SRC_ALPHA,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_INV_SRC_ALPHA,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_INV_SRC_ALPHA,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_INV_SRC_ALPHA,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_INV_SRC_ALPHA,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_INV_SRC_ALPHA,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_INV_SRC_ALPHA,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-        ],
-    },
-    TigerBlendDesc {
-        RenderTarget: [
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_SRC1_COLOR,
-                DestBlend: D3D11_BLEND_INV_SRC1_COLOR,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_SRC1_ALPHA,
-                DestBlendAlpha: D3D11_BLEND_INV_SRC1_ALPHA,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(0),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ZERO,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ZERO,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: 0,
-            },
-        ],
-    },
-    TigerBlendDesc {
-        RenderTarget: [
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_INV_SRC_ALPHA,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ZERO,
-                DestBlendAlpha: D3D11_BLEND_INV_SRC_ALPHA,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_INV_SRC_ALPHA,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ZERO,
-                DestBlendAlpha: D3D11_BLEND_INV_SRC_ALPHA,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ONE,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ONE,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ONE,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ONE,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-        ],
-    },
-    TigerBlendDesc {
-        RenderTarget: [
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_INV_SRC_ALPHA,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ZERO,
-                DestBlendAlpha: D3D11_BLEND_INV_SRC_ALPHA,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_INV_SRC_ALPHA,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ZERO,
-                DestBlendAlpha: D3D11_BLEND_INV_SRC_ALPHA,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_ZERO,
-                DestBlend: D3D11_BLEND_INV_SRC_ALPHA,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ZERO,
-                DestBlendAlpha: D3D11_BLEND_INV_SRC_ALPHA,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_ZERO,
-                DestBlend: D3D11_BLEND_INV_SRC_ALPHA,
-                BlendOp: D3D11_BLEND_OP_ADD,
-                SrcBlendAlpha: D3D11_BLEND_ZERO,
-                DestBlendAlpha: D3D11_BLEND_INV_SRC_ALPHA,
-                BlendOpAlpha: D3D11_BLEND_OP_ADD,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-        ],
-    },
-    TigerBlendDesc {
-        RenderTarget: [
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ONE,
-                BlendOp: D3D11_BLEND_OP_REV_SUBTRACT,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ONE,
-                BlendOpAlpha: D3D11_BLEND_OP_REV_SUBTRACT,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ONE,
-                BlendOp: D3D11_BLEND_OP_REV_SUBTRACT,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ONE,
-                BlendOpAlpha: D3D11_BLEND_OP_REV_SUBTRACT,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ONE,
-                BlendOp: D3D11_BLEND_OP_REV_SUBTRACT,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ONE,
-                BlendOpAlpha: D3D11_BLEND_OP_REV_SUBTRACT,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-            D3D11_RENDER_TARGET_BLEND_DESC {
-                BlendEnable: BOOL(1),
-                SrcBlend: D3D11_BLEND_ONE,
-                DestBlend: D3D11_BLEND_ONE,
-                BlendOp: D3D11_BLEND_OP_REV_SUBTRACT,
-                SrcBlendAlpha: D3D11_BLEND_ONE,
-                DestBlendAlpha: D3D11_BLEND_ONE,
-                BlendOpAlpha: D3D11_BLEND_OP_REV_SUBTRACT,
-                RenderTargetWriteMask: (D3D11_COLOR_WRITE_ENABLE_ALL.0) as u8,
-            },
-        ],
-    },
-];
-//endregion
-
-struct TigerInputLayout {
-    pub elements: &'static [TigerInputLayoutElement],
-}
-
-struct TigerInputLayoutElement {
-    pub hlsl_type: &'static str,
-    pub format: DxgiFormat,
-    pub _stride: u32,
-    pub semantic_name: &'static CStr,
-    pub semantic_index: u32,
-    pub buffer_index: u32,
-    pub is_instance_data: bool,
-}
-
-//region Input layouts
-const INPUT_LAYOUTS: [TigerInputLayout; 77] = [
-    // Layout 0
-    TigerInputLayout {
-        elements: &[TigerInputLayoutElement {
-            hlsl_type: "float3",
-            format: DxgiFormat::R32G32B32_FLOAT,
-            _stride: 12,
-            semantic_name: c"POSITION",
-            semantic_index: 0,
-            buffer_index: 0,
-            is_instance_data: false,
-        }],
-    },
-    // Layout 1
-    TigerInputLayout {
-        elements: &[TigerInputLayoutElement {
-            hlsl_type: "float3",
-            format: DxgiFormat::R32G32B32_FLOAT,
-            _stride: 12,
-            semantic_name: c"POSITION",
-            semantic_index: 0,
-            buffer_index: 0,
-            is_instance_data: false,
-        }],
-    },
-    // Layout 2
-    TigerInputLayout {
-        elements: &[
-            TigerInputLayoutElement {
-                hlsl_type: "float2",
-                format: DxgiFormat::R32G32_FLOAT,
-                _stride: 8,
-                semantic_name: c"POSITION",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float2",
-                format: DxgiFormat::R32G32_FLOAT,
-                _stride: 8,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R8G8B8A8_UNORM,
-                _stride: 4,
-                semantic_name: c"COLOR",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-        ],
-    },
-    // Layout 3
-    TigerInputLayout {
-        elements: &[
-            TigerInputLayoutElement {
-                hlsl_type: "float3",
-                format: DxgiFormat::R32G32B32_FLOAT,
-                _stride: 12,
-                semantic_name: c"POSITION",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float2",
-                format: DxgiFormat::R32G32_FLOAT,
-                _stride: 8,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R8G8B8A8_UNORM,
-                _stride: 4,
-                semantic_name: c"COLOR",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-        ],
-    },
-    // Layout 4
-    TigerInputLayout {
-        elements: &[
-            TigerInputLayoutElement {
-                hlsl_type: "float3",
-                format: DxgiFormat::R32G32B32_FLOAT,
-                _stride: 12,
-                semantic_name: c"POSITION",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R8G8B8A8_UNORM,
-                _stride: 4,
-                semantic_name: c"COLOR",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-        ],
-    },
-    // Layout 5
-    TigerInputLayout {
-        elements: &[
-            TigerInputLayoutElement {
-                hlsl_type: "float2",
-                format: DxgiFormat::R32G32_FLOAT,
-                _stride: 8,
-                semantic_name: c"POSITION",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float2",
-                format: DxgiFormat::R32G32_FLOAT,
-                _stride: 8,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-        ],
-    },
-    // Layout 6
-    TigerInputLayout {
-        elements: &[
-            TigerInputLayoutElement {
-                hlsl_type: "float3",
-                format: DxgiFormat::R32G32B32_FLOAT,
-                _stride: 12,
-                semantic_name: c"POSITION",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float3",
-                format: DxgiFormat::R32G32B32_FLOAT,
-                _stride: 12,
-                semantic_name: c"NORMAL",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TANGENT",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float2",
-                format: DxgiFormat::R32G32_FLOAT,
-                _stride: 8,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-        ],
-    },
-    // Layout 7
-    TigerInputLayout {
-        elements: &[
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R16G16B16A16_SNORM,
-                _stride: 8,
-                semantic_name: c"POSITION",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R16G16B16A16_SNORM,
-                _stride: 8,
-                semantic_name: c"NORMAL",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R16G16B16A16_SNORM,
-                _stride: 8,
-                semantic_name: c"TANGENT",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float2",
-                format: DxgiFormat::R16G16_SNORM,
-                _stride: 4,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 0,
-                buffer_index: 1,
-                is_instance_data: false,
-            },
-        ],
-    },
-    // Layout 8
-    TigerInputLayout {
-        elements: &[
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R16G16B16A16_SNORM,
-                _stride: 8,
-                semantic_name: c"POSITION",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R16G16B16A16_SNORM,
-                _stride: 8,
-                semantic_name: c"TANGENT",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float2",
-                format: DxgiFormat::R16G16_SNORM,
-                _stride: 4,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 0,
-                buffer_index: 1,
-                is_instance_data: false,
-            },
-        ],
-    },
-    // Layout 9
-    TigerInputLayout {
-        elements: &[
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R16G16B16A16_SNORM,
-                _stride: 8,
-                semantic_name: c"POSITION",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float2",
-                format: DxgiFormat::R16G16_SNORM,
-                _stride: 4,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R16G16B16A16_SNORM,
-                _stride: 8,
-                semantic_name: c"NORMAL",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R16G16B16A16_SNORM,
-                _stride: 8,
-                semantic_name: c"TANGENT",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R8G8B8A8_UNORM,
-                _stride: 4,
-                semantic_name: c"COLOR",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-        ],
-    },
-    // Layout 10
-    TigerInputLayout {
-        elements: &[
-            TigerInputLayoutElement {
-                hlsl_type: "float3",
-                format: DxgiFormat::R32G32B32_FLOAT,
-                _stride: 12,
-                semantic_name: c"POSITION",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R16G16B16A16_SNORM,
-                _stride: 8,
-                semantic_name: c"NORMAL",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float2",
-                format: DxgiFormat::R32G32_FLOAT,
-                _stride: 8,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 1,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-        ],
-    },
-    // Layout 11
-    TigerInputLayout {
-        elements: &[
-            TigerInputLayoutElement {
-                hlsl_type: "float2",
-                format: DxgiFormat::R32G32_FLOAT,
-                _stride: 8,
-                semantic_name: c"POSITION",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float3",
-                format: DxgiFormat::R32G32B32_FLOAT,
-                _stride: 12,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-        ],
-    },
-    // Layout 12
-    TigerInputLayout {
-        elements: &[
-            TigerInputLayoutElement {
-                hlsl_type: "float3",
-                format: DxgiFormat::R32G32B32_FLOAT,
-                _stride: 12,
-                semantic_name: c"POSITION",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float2",
-                format: DxgiFormat::R32G32_FLOAT,
-                _stride: 8,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float3",
-                format: DxgiFormat::R32G32B32_FLOAT,
-                _stride: 12,
-                semantic_name: c"NORMAL",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-        ],
-    },
-    // Layout 13
-    TigerInputLayout {
-        elements: &[
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"POSITION",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"NORMAL",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TANGENT",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float2",
-                format: DxgiFormat::R16G16_SNORM,
-                _stride: 4,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 0,
-                buffer_index: 1,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R8G8B8A8_UNORM,
-                _stride: 4,
-                semantic_name: c"BLENDWEIGHT",
-                semantic_index: 0,
-                buffer_index: 2,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "uint4",
-                format: DxgiFormat::R8G8B8A8_UINT,
-                _stride: 4,
-                semantic_name: c"BLENDINDICES",
-                semantic_index: 0,
-                buffer_index: 2,
-                is_instance_data: false,
-            },
-        ],
-    },
-    // Layout 14
-    TigerInputLayout {
-        elements: &[
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"POSITION",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float3",
-                format: DxgiFormat::R32G32B32_FLOAT,
-                _stride: 12,
-                semantic_name: c"NORMAL",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float3",
-                format: DxgiFormat::R32G32B32_FLOAT,
-                _stride: 12,
-                semantic_name: c"TANGENT",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 1,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 2,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float3",
-                format: DxgiFormat::R32G32B32_FLOAT,
-                _stride: 12,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 3,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float3",
-                format: DxgiFormat::R32G32B32_FLOAT,
-                _stride: 12,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 4,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R16G16B16A16_SNORM,
-                _stride: 8,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 5,
-                buffer_index: 1,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R8G8B8A8_UNORM,
-                _stride: 4,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 6,
-                buffer_index: 1,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R8G8B8A8_UNORM,
-                _stride: 4,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 7,
-                buffer_index: 1,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"BLENDINDICES",
-                semantic_index: 0,
-                buffer_index: 2,
-                is_instance_data: false,
-            },
-        ],
-    },
-    // Layout 15
-    TigerInputLayout {
-        elements: &[
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"POSITION",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float2",
-                format: DxgiFormat::R32G32_FLOAT,
-                _stride: 8,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R16G16B16A16_SNORM,
-                _stride: 8,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 5,
-                buffer_index: 1,
-                is_instance_data: true,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R8G8B8A8_UNORM,
-                _stride: 4,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 6,
-                buffer_index: 1,
-                is_instance_data: true,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R8G8B8A8_UNORM,
-                _stride: 4,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 7,
-                buffer_index: 1,
-                is_instance_data: true,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float2",
-                format: DxgiFormat::R32G32_FLOAT,
-                _stride: 8,
-                semantic_name: c"BINORMAL",
-                semantic_index: 0,
-                buffer_index: 2,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"BLENDINDICES",
-                semantic_index: 0,
-                buffer_index: 3,
-                is_instance_data: false,
-            },
-        ],
-    },
-    // Layout 16
-    TigerInputLayout {
-        elements: &[TigerInputLayoutElement {
-            hlsl_type: "float3",
-            format: DxgiFormat::R32G32B32_FLOAT,
-            _stride: 12,
-            semantic_name: c"POSITION",
-            semantic_index: 0,
-            buffer_index: 0,
-            is_instance_data: false,
-        }],
-    },
-    // Layout 17
-    TigerInputLayout {
-        elements: &[
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R8G8B8A8_UNORM,
-                _stride: 4,
-                semantic_name: c"POSITION",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float3",
-                format: DxgiFormat::R32G32B32_FLOAT,
-                _stride: 12,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 0,
-                buffer_index: 1,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R16G16B16A16_SNORM,
-                _stride: 8,
-                semantic_name: c"NORMAL",
-                semantic_index: 0,
-                buffer_index: 1,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R16G16B16A16_FLOAT,
-                _stride: 8,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 1,
-                buffer_index: 1,
-                is_instance_data: false,
-            },
-        ],
-    },
-    // Layout 18
-    TigerInputLayout {
-        elements: &[
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R16G16B16A16_SNORM,
-                _stride: 8,
-                semantic_name: c"POSITION",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float2",
-                format: DxgiFormat::R16G16_SNORM,
-                _stride: 4,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R16G16B16A16_SNORM,
-                _stride: 8,
-                semantic_name: c"NORMAL",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R16G16B16A16_SNORM,
-                _stride: 8,
-                semantic_name: c"TANGENT",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R16G16B16A16_SNORM,
-                _stride: 8,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 5,
-                buffer_index: 1,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R8G8B8A8_UNORM,
-                _stride: 4,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 6,
-                buffer_index: 1,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R8G8B8A8_UNORM,
-                _stride: 4,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 7,
-                buffer_index: 1,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R8G8B8A8_UNORM,
-                _stride: 4,
-                semantic_name: c"BLENDINDICES",
-                semantic_index: 0,
-                buffer_index: 3,
-                is_instance_data: false,
-            },
-        ],
-    },
-    // Layout 19
-    TigerInputLayout {
-        elements: &[
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R16G16B16A16_SNORM,
-                _stride: 8,
-                semantic_name: c"POSITION",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R8G8B8A8_UNORM,
-                _stride: 4,
-                semantic_name: c"NORMAL",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R8G8B8A8_UNORM,
-                _stride: 4,
-                semantic_name: c"TANGENT",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float2",
-                format: DxgiFormat::R16G16_SNORM,
-                _stride: 4,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R16G16B16A16_FLOAT,
-                _stride: 8,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 1,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R16G16B16A16_SNORM,
-                _stride: 8,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 5,
-                buffer_index: 1,
-                is_instance_data: true,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R8G8B8A8_UNORM,
-                _stride: 4,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 6,
-                buffer_index: 1,
-                is_instance_data: true,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R8G8B8A8_UNORM,
-                _stride: 4,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 7,
-                buffer_index: 1,
-                is_instance_data: true,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float2",
-                format: DxgiFormat::R32G32_FLOAT,
-                _stride: 8,
-                semantic_name: c"BINORMAL",
-                semantic_index: 0,
-                buffer_index: 2,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"BLENDINDICES",
-                semantic_index: 0,
-                buffer_index: 3,
-                is_instance_data: false,
-            },
-        ],
-    },
-    // Layout 20
-    TigerInputLayout {
-        elements: &[
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R16G16B16A16_SNORM,
-                _stride: 8,
-                semantic_name: c"POSITION",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R8G8B8A8_UNORM,
-                _stride: 4,
-                semantic_name: c"NORMAL",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R8G8B8A8_UNORM,
-                _stride: 4,
-                semantic_name: c"TANGENT",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float2",
-                format: DxgiFormat::R16G16_SNORM,
-                _stride: 4,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R16G16B16A16_FLOAT,
-                _stride: 8,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 1,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R16G16B16A16_FLOAT,
-                _stride: 8,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 2,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R8G8B8A8_UNORM,
-                _stride: 4,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 3,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R16G16B16A16_SNORM,
-                _stride: 8,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 5,
-                buffer_index: 1,
-                is_instance_data: true,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R8G8B8A8_UNORM,
-                _stride: 4,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 6,
-                buffer_index: 1,
-                is_instance_data: true,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R8G8B8A8_UNORM,
-                _stride: 4,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 7,
-                buffer_index: 1,
-                is_instance_data: true,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float2",
-                format: DxgiFormat::R32G32_FLOAT,
-                _stride: 8,
-                semantic_name: c"BINORMAL",
-                semantic_index: 0,
-                buffer_index: 2,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"BLENDINDICES",
-                semantic_index: 0,
-                buffer_index: 3,
-                is_instance_data: false,
-            },
-        ],
-    },
-    // Layout 21
-    TigerInputLayout {
-        elements: &[
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R8G8B8A8_UNORM,
-                _stride: 4,
-                semantic_name: c"POSITION",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float3",
-                format: DxgiFormat::R32G32B32_FLOAT,
-                _stride: 12,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 0,
-                buffer_index: 1,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"NORMAL",
-                semantic_index: 0,
-                buffer_index: 1,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 1,
-                buffer_index: 1,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float3",
-                format: DxgiFormat::R32G32B32_FLOAT,
-                _stride: 12,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 2,
-                buffer_index: 1,
-                is_instance_data: false,
-            },
-        ],
-    },
-    // Layout 22
-    TigerInputLayout {
-        elements: &[
-            TigerInputLayoutElement {
-                hlsl_type: "int4",
-                format: DxgiFormat::R16G16B16A16_SINT,
-                _stride: 8,
-                semantic_name: c"POSITION",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R16G16B16A16_SNORM,
-                _stride: 8,
-                semantic_name: c"NORMAL",
-                semantic_index: 0,
-                buffer_index: 1,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float2",
-                format: DxgiFormat::R16G16_FLOAT,
-                _stride: 4,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 1,
-                buffer_index: 1,
-                is_instance_data: false,
-            },
-        ],
-    },
-    // Layout 23
-    TigerInputLayout {
-        elements: &[TigerInputLayoutElement {
-            hlsl_type: "int4",
-            format: DxgiFormat::R16G16B16A16_SINT,
-            _stride: 8,
-            semantic_name: c"POSITION",
-            semantic_index: 0,
-            buffer_index: 0,
-            is_instance_data: false,
-        }],
-    },
-    // Layout 24
-    TigerInputLayout {
-        elements: &[
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R16G16B16A16_SNORM,
-                _stride: 8,
-                semantic_name: c"POSITION",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R8G8B8A8_UNORM,
-                _stride: 4,
-                semantic_name: c"NORMAL",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float2",
-                format: DxgiFormat::R16G16_SNORM,
-                _stride: 4,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R16G16B16A16_SNORM,
-                _stride: 8,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 5,
-                buffer_index: 1,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R8G8B8A8_UNORM,
-                _stride: 4,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 6,
-                buffer_index: 1,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R8G8B8A8_UNORM,
-                _stride: 4,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 7,
-                buffer_index: 1,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float2",
-                format: DxgiFormat::R32G32_FLOAT,
-                _stride: 8,
-                semantic_name: c"BINORMAL",
-                semantic_index: 0,
-                buffer_index: 2,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"BLENDINDICES",
-                semantic_index: 0,
-                buffer_index: 3,
-                is_instance_data: false,
-            },
-        ],
-    },
-    // Layout 25
-    TigerInputLayout {
-        elements: &[
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R16G16B16A16_SNORM,
-                _stride: 8,
-                semantic_name: c"POSITION",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R8G8B8A8_UNORM,
-                _stride: 4,
-                semantic_name: c"NORMAL",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float2",
-                format: DxgiFormat::R16G16_SNORM,
-                _stride: 4,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R16G16B16A16_SNORM,
-                _stride: 8,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 5,
-                buffer_index: 1,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R8G8B8A8_UNORM,
-                _stride: 4,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 6,
-                buffer_index: 1,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R8G8B8A8_UNORM,
-                _stride: 4,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 7,
-                buffer_index: 1,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float2",
-                format: DxgiFormat::R32G32_FLOAT,
-                _stride: 8,
-                semantic_name: c"BINORMAL",
-                semantic_index: 0,
-                buffer_index: 2,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"BLENDINDICES",
-                semantic_index: 0,
-                buffer_index: 3,
-                is_instance_data: false,
-            },
-        ],
-    },
-    // Layout 26
-    TigerInputLayout {
-        elements: &[
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R16G16B16A16_SNORM,
-                _stride: 8,
-                semantic_name: c"POSITION",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float2",
-                format: DxgiFormat::R16G16_SNORM,
-                _stride: 4,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R16G16B16A16_SNORM,
-                _stride: 8,
-                semantic_name: c"NORMAL",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R16G16B16A16_SNORM,
-                _stride: 8,
-                semantic_name: c"TANGENT",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R8G8B8A8_UNORM,
-                _stride: 4,
-                semantic_name: c"COLOR",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R16G16B16A16_SNORM,
-                _stride: 8,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 5,
-                buffer_index: 1,
-                is_instance_data: true,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R8G8B8A8_UNORM,
-                _stride: 4,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 6,
-                buffer_index: 1,
-                is_instance_data: true,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R8G8B8A8_UNORM,
-                _stride: 4,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 7,
-                buffer_index: 1,
-                is_instance_data: true,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R8G8B8A8_UNORM,
-                _stride: 4,
-                semantic_name: c"BLENDINDICES",
-                semantic_index: 0,
-                buffer_index: 3,
-                is_instance_data: false,
-            },
-        ],
-    },
-    // Layout 27
-    TigerInputLayout {
-        elements: &[TigerInputLayoutElement {
-            hlsl_type: "float4",
-            format: DxgiFormat::R32G32B32A32_FLOAT,
-            _stride: 16,
-            semantic_name: c"TEXCOORD",
-            semantic_index: 0,
-            buffer_index: 0,
-            is_instance_data: false,
-        }],
-    },
-    // Layout 28
-    TigerInputLayout {
-        elements: &[
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 1,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-        ],
-    },
-    // Layout 29
-    TigerInputLayout {
-        elements: &[
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 1,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 2,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-        ],
-    },
-    // Layout 30
-    TigerInputLayout {
-        elements: &[
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 1,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 2,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 3,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-        ],
-    },
-    // Layout 31
-    TigerInputLayout {
-        elements: &[
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 1,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 2,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 3,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 4,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-        ],
-    },
-    // Layout 32
-    TigerInputLayout {
-        elements: &[
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 1,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 2,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 3,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 4,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 5,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-        ],
-    },
-    // Layout 33
-    TigerInputLayout {
-        elements: &[
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 1,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 2,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 3,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 4,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 5,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 6,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-        ],
-    },
-    // Layout 34
-    TigerInputLayout {
-        elements: &[
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 1,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 2,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 3,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 4,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 5,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 6,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 7,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-        ],
-    },
-    // Layout 35
-    TigerInputLayout {
-        elements: &[
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 1,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 2,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 3,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 4,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 5,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 6,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 7,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 8,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-        ],
-    },
-    // Layout 36
-    TigerInputLayout {
-        elements: &[
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 1,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 2,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 3,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 4,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 5,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 6,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 7,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 8,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 9,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-        ],
-    },
-    // Layout 37
-    TigerInputLayout {
-        elements: &[
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 1,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 2,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 3,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 4,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 5,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 6,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 7,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 8,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 9,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 10,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-        ],
-    },
-    // Layout 38
-    TigerInputLayout {
-        elements: &[
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 1,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 2,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 3,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 4,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 5,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 6,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 7,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 8,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 9,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 10,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 11,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-        ],
-    },
-    // Layout 39
-    TigerInputLayout {
-        elements: &[
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 1,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 2,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 3,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 4,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 5,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 6,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 7,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 8,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 9,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 10,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 11,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 12,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-        ],
-    },
-    // Layout 40
-    TigerInputLayout {
-        elements: &[
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 1,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 2,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 3,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 4,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 5,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 6,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 7,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 8,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 9,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 10,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 11,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 12,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 13,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-        ],
-    },
-    // Layout 41
-    TigerInputLayout {
-        elements: &[
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 1,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 2,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 3,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 4,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 5,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 6,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 7,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 8,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 9,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 10,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 11,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 12,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 13,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 14,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-        ],
-    },
-    // Layout 42
-    TigerInputLayout {
-        elements: &[
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 1,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 2,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 3,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 4,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 5,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 6,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 7,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 8,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 9,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 10,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 11,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 12,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 13,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 14,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 15,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-        ],
-    },
-    // Layout 43
-    TigerInputLayout {
-        elements: &[TigerInputLayoutElement {
-            hlsl_type: "float4",
-            format: DxgiFormat::R32G32B32A32_FLOAT,
-            _stride: 16,
-            semantic_name: c"TEXCOORD",
-            semantic_index: 0,
-            buffer_index: 0,
-            is_instance_data: false,
-        }],
-    },
-    // Layout 44
-    TigerInputLayout {
-        elements: &[
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 1,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-        ],
-    },
-    // Layout 45
-    TigerInputLayout {
-        elements: &[
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 1,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 2,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-        ],
-    },
-    // Layout 46
-    TigerInputLayout {
-        elements: &[
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 1,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 2,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 3,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-        ],
-    },
-    // Layout 47
-    TigerInputLayout {
-        elements: &[
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 1,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 2,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 3,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 4,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-        ],
-    },
-    // Layout 48
-    TigerInputLayout {
-        elements: &[
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 1,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 2,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 3,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 4,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 5,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-        ],
-    },
-    // Layout 49
-    TigerInputLayout {
-        elements: &[
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 1,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 2,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 3,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 4,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 5,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 6,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-        ],
-    },
-    // Layout 50
-    TigerInputLayout {
-        elements: &[
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 1,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 2,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 3,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 4,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 5,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 6,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 7,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-        ],
-    },
-    // Layout 51
-    TigerInputLayout {
-        elements: &[
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 1,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 2,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 3,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 4,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 5,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 6,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 7,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 8,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-        ],
-    },
-    // Layout 52
-    TigerInputLayout {
-        elements: &[
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 1,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 2,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 3,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 4,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 5,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 6,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 7,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 8,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 9,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-        ],
-    },
-    // Layout 53
-    TigerInputLayout {
-        elements: &[
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 1,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 2,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 3,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 4,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 5,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 6,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 7,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 8,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 9,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 10,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-        ],
-    },
-    // Layout 54
-    TigerInputLayout {
-        elements: &[
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 1,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 2,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 3,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 4,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 5,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 6,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 7,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 8,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 9,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 10,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 11,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-        ],
-    },
-    // Layout 55
-    TigerInputLayout {
-        elements: &[
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 1,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 2,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 3,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 4,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 5,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 6,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 7,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 8,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 9,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 10,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 11,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 12,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-        ],
-    },
-    // Layout 56
-    TigerInputLayout {
-        elements: &[
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 1,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 2,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 3,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 4,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 5,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 6,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 7,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 8,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 9,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 10,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 11,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 12,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 13,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-        ],
-    },
-    // Layout 57
-    TigerInputLayout {
-        elements: &[
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 1,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 2,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 3,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 4,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 5,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 6,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 7,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 8,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 9,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 10,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 11,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 12,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 13,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 14,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-        ],
-    },
-    // Layout 58
-    TigerInputLayout {
-        elements: &[
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 1,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 2,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 3,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 4,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 5,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 6,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 7,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 8,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 9,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 10,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 11,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 12,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 13,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 14,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 15,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-        ],
-    },
-    // Layout 59
-    TigerInputLayout {
-        elements: &[
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float3",
-                format: DxgiFormat::R32G32B32_FLOAT,
-                _stride: 12,
-                semantic_name: c"POSITION",
-                semantic_index: 0,
-                buffer_index: 1,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float2",
-                format: DxgiFormat::R32G32_FLOAT,
-                _stride: 8,
-                semantic_name: c"POSITION",
-                semantic_index: 1,
-                buffer_index: 1,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float3",
-                format: DxgiFormat::R32G32B32_FLOAT,
-                _stride: 12,
-                semantic_name: c"POSITION",
-                semantic_index: 2,
-                buffer_index: 1,
-                is_instance_data: false,
-            },
-        ],
-    },
-    // Layout 60
-    TigerInputLayout {
-        elements: &[
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 1,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float3",
-                format: DxgiFormat::R32G32B32_FLOAT,
-                _stride: 12,
-                semantic_name: c"POSITION",
-                semantic_index: 0,
-                buffer_index: 1,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float2",
-                format: DxgiFormat::R32G32_FLOAT,
-                _stride: 8,
-                semantic_name: c"POSITION",
-                semantic_index: 1,
-                buffer_index: 1,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float3",
-                format: DxgiFormat::R32G32B32_FLOAT,
-                _stride: 12,
-                semantic_name: c"POSITION",
-                semantic_index: 2,
-                buffer_index: 1,
-                is_instance_data: false,
-            },
-        ],
-    },
-    // Layout 61
-    TigerInputLayout {
-        elements: &[
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 1,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 2,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float3",
-                format: DxgiFormat::R32G32B32_FLOAT,
-                _stride: 12,
-                semantic_name: c"POSITION",
-                semantic_index: 0,
-                buffer_index: 1,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float2",
-                format: DxgiFormat::R32G32_FLOAT,
-                _stride: 8,
-                semantic_name: c"POSITION",
-                semantic_index: 1,
-                buffer_index: 1,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float3",
-                format: DxgiFormat::R32G32B32_FLOAT,
-                _stride: 12,
-                semantic_name: c"POSITION",
-                semantic_index: 2,
-                buffer_index: 1,
-                is_instance_data: false,
-            },
-        ],
-    },
-    // Layout 62
-    TigerInputLayout {
-        elements: &[
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 1,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 2,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 3,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float3",
-                format: DxgiFormat::R32G32B32_FLOAT,
-                _stride: 12,
-                semantic_name: c"POSITION",
-                semantic_index: 0,
-                buffer_index: 1,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float2",
-                format: DxgiFormat::R32G32_FLOAT,
-                _stride: 8,
-                semantic_name: c"POSITION",
-                semantic_index: 1,
-                buffer_index: 1,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float3",
-                format: DxgiFormat::R32G32B32_FLOAT,
-                _stride: 12,
-                semantic_name: c"POSITION",
-                semantic_index: 2,
-                buffer_index: 1,
-                is_instance_data: false,
-            },
-        ],
-    },
-    // Layout 63
-    TigerInputLayout {
-        elements: &[
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 1,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 2,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 3,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 4,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float3",
-                format: DxgiFormat::R32G32B32_FLOAT,
-                _stride: 12,
-                semantic_name: c"POSITION",
-                semantic_index: 0,
-                buffer_index: 1,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float2",
-                format: DxgiFormat::R32G32_FLOAT,
-                _stride: 8,
-                semantic_name: c"POSITION",
-                semantic_index: 1,
-                buffer_index: 1,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float3",
-                format: DxgiFormat::R32G32B32_FLOAT,
-                _stride: 12,
-                semantic_name: c"POSITION",
-                semantic_index: 2,
-                buffer_index: 1,
-                is_instance_data: false,
-            },
-        ],
-    },
-    // Layout 64
-    TigerInputLayout {
-        elements: &[
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 1,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 2,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 3,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 4,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 5,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float3",
-                format: DxgiFormat::R32G32B32_FLOAT,
-                _stride: 12,
-                semantic_name: c"POSITION",
-                semantic_index: 0,
-                buffer_index: 1,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float2",
-                format: DxgiFormat::R32G32_FLOAT,
-                _stride: 8,
-                semantic_name: c"POSITION",
-                semantic_index: 1,
-                buffer_index: 1,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float3",
-                format: DxgiFormat::R32G32B32_FLOAT,
-                _stride: 12,
-                semantic_name: c"POSITION",
-                semantic_index: 2,
-                buffer_index: 1,
-                is_instance_data: false,
-            },
-        ],
-    },
-    // Layout 65
-    TigerInputLayout {
-        elements: &[
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 1,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 2,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 3,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 4,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 5,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 6,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float3",
-                format: DxgiFormat::R32G32B32_FLOAT,
-                _stride: 12,
-                semantic_name: c"POSITION",
-                semantic_index: 0,
-                buffer_index: 1,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float2",
-                format: DxgiFormat::R32G32_FLOAT,
-                _stride: 8,
-                semantic_name: c"POSITION",
-                semantic_index: 1,
-                buffer_index: 1,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float3",
-                format: DxgiFormat::R32G32B32_FLOAT,
-                _stride: 12,
-                semantic_name: c"POSITION",
-                semantic_index: 2,
-                buffer_index: 1,
-                is_instance_data: false,
-            },
-        ],
-    },
-    // Layout 66
-    TigerInputLayout {
-        elements: &[
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 1,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 2,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 3,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 4,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 5,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 6,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 7,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float3",
-                format: DxgiFormat::R32G32B32_FLOAT,
-                _stride: 12,
-                semantic_name: c"POSITION",
-                semantic_index: 0,
-                buffer_index: 1,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float2",
-                format: DxgiFormat::R32G32_FLOAT,
-                _stride: 8,
-                semantic_name: c"POSITION",
-                semantic_index: 1,
-                buffer_index: 1,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float3",
-                format: DxgiFormat::R32G32B32_FLOAT,
-                _stride: 12,
-                semantic_name: c"POSITION",
-                semantic_index: 2,
-                buffer_index: 1,
-                is_instance_data: false,
-            },
-        ],
-    },
-    // Layout 67
-    TigerInputLayout {
-        elements: &[
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 1,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 2,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 3,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 4,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 5,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 6,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 7,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 8,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float3",
-                format: DxgiFormat::R32G32B32_FLOAT,
-                _stride: 12,
-                semantic_name: c"POSITION",
-                semantic_index: 0,
-                buffer_index: 1,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float2",
-                format: DxgiFormat::R32G32_FLOAT,
-                _stride: 8,
-                semantic_name: c"POSITION",
-                semantic_index: 1,
-                buffer_index: 1,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float3",
-                format: DxgiFormat::R32G32B32_FLOAT,
-                _stride: 12,
-                semantic_name: c"POSITION",
-                semantic_index: 2,
-                buffer_index: 1,
-                is_instance_data: false,
-            },
-        ],
-    },
-    // Layout 68
-    TigerInputLayout {
-        elements: &[
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 1,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 2,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 3,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 4,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 5,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 6,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 7,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 8,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 9,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float3",
-                format: DxgiFormat::R32G32B32_FLOAT,
-                _stride: 12,
-                semantic_name: c"POSITION",
-                semantic_index: 0,
-                buffer_index: 1,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float2",
-                format: DxgiFormat::R32G32_FLOAT,
-                _stride: 8,
-                semantic_name: c"POSITION",
-                semantic_index: 1,
-                buffer_index: 1,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float3",
-                format: DxgiFormat::R32G32B32_FLOAT,
-                _stride: 12,
-                semantic_name: c"POSITION",
-                semantic_index: 2,
-                buffer_index: 1,
-                is_instance_data: false,
-            },
-        ],
-    },
-    // Layout 69
-    TigerInputLayout {
-        elements: &[
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 1,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 2,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 3,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 4,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 5,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 6,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 7,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 8,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 9,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 10,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float3",
-                format: DxgiFormat::R32G32B32_FLOAT,
-                _stride: 12,
-                semantic_name: c"POSITION",
-                semantic_index: 0,
-                buffer_index: 1,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float2",
-                format: DxgiFormat::R32G32_FLOAT,
-                _stride: 8,
-                semantic_name: c"POSITION",
-                semantic_index: 1,
-                buffer_index: 1,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float3",
-                format: DxgiFormat::R32G32B32_FLOAT,
-                _stride: 12,
-                semantic_name: c"POSITION",
-                semantic_index: 2,
-                buffer_index: 1,
-                is_instance_data: false,
-            },
-        ],
-    },
-    // Layout 70
-    TigerInputLayout {
-        elements: &[
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 1,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 2,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 3,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 4,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 5,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 6,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 7,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 8,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 9,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 10,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 11,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float3",
-                format: DxgiFormat::R32G32B32_FLOAT,
-                _stride: 12,
-                semantic_name: c"POSITION",
-                semantic_index: 0,
-                buffer_index: 1,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float2",
-                format: DxgiFormat::R32G32_FLOAT,
-                _stride: 8,
-                semantic_name: c"POSITION",
-                semantic_index: 1,
-                buffer_index: 1,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float3",
-                format: DxgiFormat::R32G32B32_FLOAT,
-                _stride: 12,
-                semantic_name: c"POSITION",
-                semantic_index: 2,
-                buffer_index: 1,
-                is_instance_data: false,
-            },
-        ],
-    },
-    // Layout 71
-    TigerInputLayout {
-        elements: &[
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 1,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 2,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 3,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 4,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 5,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 6,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 7,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 8,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 9,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 10,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 11,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 12,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float3",
-                format: DxgiFormat::R32G32B32_FLOAT,
-                _stride: 12,
-                semantic_name: c"POSITION",
-                semantic_index: 0,
-                buffer_index: 1,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float2",
-                format: DxgiFormat::R32G32_FLOAT,
-                _stride: 8,
-                semantic_name: c"POSITION",
-                semantic_index: 1,
-                buffer_index: 1,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float3",
-                format: DxgiFormat::R32G32B32_FLOAT,
-                _stride: 12,
-                semantic_name: c"POSITION",
-                semantic_index: 2,
-                buffer_index: 1,
-                is_instance_data: false,
-            },
-        ],
-    },
-    // Layout 72
-    TigerInputLayout {
-        elements: &[
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 1,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 2,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 3,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 4,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 5,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 6,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 7,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 8,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 9,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 10,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 11,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 12,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 13,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float3",
-                format: DxgiFormat::R32G32B32_FLOAT,
-                _stride: 12,
-                semantic_name: c"POSITION",
-                semantic_index: 0,
-                buffer_index: 1,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float2",
-                format: DxgiFormat::R32G32_FLOAT,
-                _stride: 8,
-                semantic_name: c"POSITION",
-                semantic_index: 1,
-                buffer_index: 1,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float3",
-                format: DxgiFormat::R32G32B32_FLOAT,
-                _stride: 12,
-                semantic_name: c"POSITION",
-                semantic_index: 2,
-                buffer_index: 1,
-                is_instance_data: false,
-            },
-        ],
-    },
-    // Layout 73
-    TigerInputLayout {
-        elements: &[
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 1,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 2,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 3,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 4,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 5,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 6,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 7,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 8,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 9,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 10,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 11,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 12,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 13,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 14,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float3",
-                format: DxgiFormat::R32G32B32_FLOAT,
-                _stride: 12,
-                semantic_name: c"POSITION",
-                semantic_index: 0,
-                buffer_index: 1,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float2",
-                format: DxgiFormat::R32G32_FLOAT,
-                _stride: 8,
-                semantic_name: c"POSITION",
-                semantic_index: 1,
-                buffer_index: 1,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float3",
-                format: DxgiFormat::R32G32B32_FLOAT,
-                _stride: 12,
-                semantic_name: c"POSITION",
-                semantic_index: 2,
-                buffer_index: 1,
-                is_instance_data: false,
-            },
-        ],
-    },
-    // Layout 74
-    TigerInputLayout {
-        elements: &[
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 1,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 2,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 3,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 4,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 5,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 6,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 7,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 8,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 9,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 10,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 11,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 12,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 13,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 14,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 15,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float3",
-                format: DxgiFormat::R32G32B32_FLOAT,
-                _stride: 12,
-                semantic_name: c"POSITION",
-                semantic_index: 0,
-                buffer_index: 1,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float2",
-                format: DxgiFormat::R32G32_FLOAT,
-                _stride: 8,
-                semantic_name: c"POSITION",
-                semantic_index: 1,
-                buffer_index: 1,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float3",
-                format: DxgiFormat::R32G32B32_FLOAT,
-                _stride: 12,
-                semantic_name: c"POSITION",
-                semantic_index: 2,
-                buffer_index: 1,
-                is_instance_data: false,
-            },
-        ],
-    },
-    // Layout 75
-    TigerInputLayout {
-        elements: &[
-            TigerInputLayoutElement {
-                hlsl_type: "float3",
-                format: DxgiFormat::R32G32B32_FLOAT,
-                _stride: 12,
-                semantic_name: c"POSITION",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float3",
-                format: DxgiFormat::R32G32B32_FLOAT,
-                _stride: 12,
-                semantic_name: c"NORMAL",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TANGENT",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float2",
-                format: DxgiFormat::R32G32_FLOAT,
-                _stride: 8,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R8G8B8A8_UNORM,
-                _stride: 4,
-                semantic_name: c"COLOR",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-        ],
-    },
-    // Layout 76
-    TigerInputLayout {
-        elements: &[
-            TigerInputLayoutElement {
-                hlsl_type: "float3",
-                format: DxgiFormat::R32G32B32_FLOAT,
-                _stride: 12,
-                semantic_name: c"POSITION",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float3",
-                format: DxgiFormat::R32G32B32_FLOAT,
-                _stride: 12,
-                semantic_name: c"NORMAL",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TANGENT",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float2",
-                format: DxgiFormat::R32G32_FLOAT,
-                _stride: 8,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R8G8B8A8_UNORM,
-                _stride: 4,
-                semantic_name: c"COLOR",
-                semantic_index: 0,
-                buffer_index: 0,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 4,
-                buffer_index: 1,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 5,
-                buffer_index: 1,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 6,
-                buffer_index: 1,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 7,
-                buffer_index: 1,
-                is_instance_data: false,
-            },
-            TigerInputLayoutElement {
-                hlsl_type: "float4",
-                format: DxgiFormat::R32G32B32A32_FLOAT,
-                _stride: 16,
-                semantic_name: c"TEXCOORD",
-                semantic_index: 8,
-                buffer_index: 1,
-                is_instance_data: false,
-            },
-        ],
-    },
-];
-//endregion
-
-struct BungieDepthBiasDesc {
-    depth_bias: i32,
-    slope_scale: f32,
-    clamp: f32,
-}
-
-//region Depth Biases
-const DEPTH_BIASES: [BungieDepthBiasDesc; 9] = [
-    // DepthBias 0
-    BungieDepthBiasDesc {
-        depth_bias: 0,
-        slope_scale: 0.0,
-        clamp: 0.0,
-    },
-    // DepthBias 1
-    BungieDepthBiasDesc {
-        depth_bias: 0,
-        slope_scale: 0.0,
-        clamp: 0.0,
-    },
-    // DepthBias 2
-    BungieDepthBiasDesc {
-        depth_bias: 5,
-        slope_scale: 2.0,
-        clamp: 10000000000.0,
-    },
-    // DepthBias 3
-    BungieDepthBiasDesc {
-        depth_bias: 10,
-        slope_scale: 4.0,
-        clamp: 10000000000.0,
-    },
-    // DepthBias 4
-    BungieDepthBiasDesc {
-        depth_bias: 15,
-        slope_scale: 6.0,
-        clamp: 10000000000.0,
-    },
-    // DepthBias 5
-    BungieDepthBiasDesc {
-        depth_bias: 20,
-        slope_scale: 8.0,
-        clamp: 10000000000.0,
-    },
-    // DepthBias 6
-    BungieDepthBiasDesc {
-        depth_bias: 2,
-        slope_scale: 2.0,
-        clamp: 10000000000.0,
-    },
-    // DepthBias 7
-    BungieDepthBiasDesc {
-        depth_bias: -1,
-        slope_scale: -2.0,
-        clamp: 10000000000.0,
-    },
-    // DepthBias 8
-    BungieDepthBiasDesc {
-        depth_bias: 51,
-        slope_scale: 2.0,
-        clamp: 10000000000.0,
-    },
-];
-//endregion
-
-struct BungieRasterizerDesc {
-    fill_mode: D3D11_FILL_MODE,
-    cull_mode: D3D11_CULL_MODE,
-    front_counter_clockwise: BOOL,
-    depth_clip_enable: BOOL,
-    scissor_enable: BOOL,
-}
-
-//region Rasterizer States
-const RASTERIZER_STATES: [BungieRasterizerDesc; 9] = [
-    // Rasterizer 0
-    BungieRasterizerDesc {
-        fill_mode: D3D11_FILL_SOLID,
-        cull_mode: D3D11_CULL_NONE,
-        front_counter_clockwise: BOOL(1),
-        depth_clip_enable: BOOL(1),
-        scissor_enable: BOOL(0),
-    },
-    // Rasterizer 1
-    BungieRasterizerDesc {
-        fill_mode: D3D11_FILL_SOLID,
-        cull_mode: D3D11_CULL_NONE,
-        front_counter_clockwise: BOOL(1),
-        depth_clip_enable: BOOL(1),
-        scissor_enable: BOOL(0),
-    },
-    // Rasterizer 2
-    BungieRasterizerDesc {
-        fill_mode: D3D11_FILL_SOLID,
-        cull_mode: D3D11_CULL_BACK,
-        front_counter_clockwise: BOOL(1),
-        depth_clip_enable: BOOL(1),
-        scissor_enable: BOOL(0),
-    },
-    // Rasterizer 3
-    BungieRasterizerDesc {
-        fill_mode: D3D11_FILL_SOLID,
-        cull_mode: D3D11_CULL_FRONT,
-        front_counter_clockwise: BOOL(1),
-        depth_clip_enable: BOOL(1),
-        scissor_enable: BOOL(0),
-    },
-    // Rasterizer 4
-    BungieRasterizerDesc {
-        fill_mode: D3D11_FILL_WIREFRAME,
-        cull_mode: D3D11_CULL_BACK,
-        front_counter_clockwise: BOOL(1),
-        depth_clip_enable: BOOL(1),
-        scissor_enable: BOOL(0),
-    },
-    // Rasterizer 5
-    BungieRasterizerDesc {
-        fill_mode: D3D11_FILL_WIREFRAME,
-        cull_mode: D3D11_CULL_NONE,
-        front_counter_clockwise: BOOL(1),
-        depth_clip_enable: BOOL(1),
-        scissor_enable: BOOL(0),
-    },
-    // Rasterizer 6
-    BungieRasterizerDesc {
-        fill_mode: D3D11_FILL_SOLID,
-        cull_mode: D3D11_CULL_BACK,
-        front_counter_clockwise: BOOL(1),
-        depth_clip_enable: BOOL(0),
-        scissor_enable: BOOL(0),
-    },
-    // Rasterizer 7
-    BungieRasterizerDesc {
-        fill_mode: D3D11_FILL_SOLID,
-        cull_mode: D3D11_CULL_NONE,
-        front_counter_clockwise: BOOL(1),
-        depth_clip_enable: BOOL(0),
-        scissor_enable: BOOL(0),
-    },
-    // Rasterizer 8
-    BungieRasterizerDesc {
-        fill_mode: D3D11_FILL_SOLID,
-        cull_mode: D3D11_CULL_FRONT,
-        front_counter_clockwise: BOOL(1),
-        depth_clip_enable: BOOL(0),
-        scissor_enable: BOOL(0),
-    },
-];
-
-//endregion
-
-struct BungieStencilDesc {
-    stencil_enable: BOOL,
-    stencil_read_mask: u8,
-    stencil_write_mask: u8,
-    front_face: BungieStencilOpDesc,
-    back_face: BungieStencilOpDesc,
-}
-
-struct BungieStencilOpDesc {
-    func: D3D11_COMPARISON_FUNC,
-    pass_op: D3D11_STENCIL_OP,
-    fail_op: D3D11_STENCIL_OP,
-    depth_fail_op: D3D11_STENCIL_OP,
-}
-
-struct BungieDepthDesc {
-    enable: BOOL,
-    write_mask: u32,
-    func: D3D11_COMPARISON_FUNC,
-    _enable_alt: BOOL,
-    _write_mask_alt: u32,
-    func_alt: D3D11_COMPARISON_FUNC,
+fn d3d_stencil_face(face: &StencilFaceDesc) -> D3D11_DEPTH_STENCILOP_DESC {
+    D3D11_DEPTH_STENCILOP_DESC {
+        StencilFailOp: D3D11_STENCIL_OP(face.fail_op as i32),
+        StencilDepthFailOp: D3D11_STENCIL_OP(face.depth_fail_op as i32),
+        StencilPassOp: D3D11_STENCIL_OP(face.pass_op as i32),
+        StencilFunc: D3D11_COMPARISON_FUNC(face.func as i32),
+    }
 }
-
-//region Depth/Stencil States
-
-const DEPTH_STENCIL_COMBOS: [(usize, usize); 89] = [
-    (0, 0), // 0
-    (1, 1),
-    (2, 1), // 2
-    (8, 1),
-    (2, 2), // 4
-    (1, 3),
-    (1, 4), // 6
-    (2, 5),
-    (2, 6), // 8
-    (2, 9),
-    (2, 10), // 10
-    (2, 0xb),
-    (2, 0xc), // 12
-    (4, 1),
-    (6, 1), // 14
-    (3, 1),
-    (7, 1), // 16
-    (3, 0x10),
-    (9, 0x10),
-    (3, 0x11),
-    (3, 0x12),
-    (7, 0x13),
-    (7, 0x1b),
-    (3, 0x13),
-    (3, 0x19),
-    (3, 0x1b),
-    (6, 0x14),
-    (2, 0x15),
-    (3, 0x15),
-    (3, 0x18),
-    (3, 0x1a),
-    (1, 0x1d),
-    (1, 0x12),
-    (1, 0x13),
-    (10, 1),
-    (0xb, 1),
-    (3, 0x1e),
-    (0xc, 0x1f),
-    (1, 0x1f),
-    (1, 0x20),
-    (1, 0x21),
-    (3, 0x21),
-    (2, 0x21),
-    (6, 0x20),
-    (3, 0x20),
-    (3, 6),
-    (3, 10),
-    (3, 0xb),
-    (3, 0xc),
-    (3, 9),
-    (0xd, 0x22),
-    (1, 0x23),
-    (3, 0x1c),
-    (7, 0x1c),
-    (0xd, 0x10),
-    (0xd, 0x25),
-    (9, 0x24),
-    (3, 0x26),
-    (1, 0x26),
-    (3, 0x27),
-    (1, 0x27),
-    (3, 0x14),
-    (1, 0x14),
-    (3, 0x28),
-    (3, 8),
-    (2, 8),
-    (1, 2),
-    (1, 8),
-    (3, 7),
-    (3, 0x17),
-    (3, 0xd),
-    (3, 0xe),
-    (3, 0xf),
-    (1, 0x29),
-    (1, 0x2a),
-    (1, 0x2b),
-    (1, 0x2c),
-    (1, 0x2d),
-    (1, 0x2e),
-    (1, 0x2f),
-    (1, 0x30),
-    (1, 0x1a),
-    (2, 0x16),
-    (5, 1),
-    (5, 0x29),
-    (5, 0x2a),
-    (10, 0x16),
-    (1, 0x16),
-    (14, 1), // CUSTOM ALKAHEST DEPTH STATE
-];
-
-const DEPTH_STATES: [BungieDepthDesc; 15] = [
-    // Depth 0
-    BungieDepthDesc {
-        enable: BOOL(0),
-        write_mask: 0,
-        func: D3D11_COMPARISON_ALWAYS,
-        _enable_alt: BOOL(0),
-        _write_mask_alt: 0,
-        func_alt: D3D11_COMPARISON_ALWAYS,
-    },
-    // Depth 1
-    BungieDepthDesc {
-        enable: BOOL(0),
-        write_mask: 0,
-        func: D3D11_COMPARISON_ALWAYS,
-        _enable_alt: BOOL(0),
-        _write_mask_alt: 0,
-        func_alt: D3D11_COMPARISON_ALWAYS,
-    },
-    // Depth 2
-    BungieDepthDesc {
-        enable: BOOL(1),
-        write_mask: 1,
-        func: D3D11_COMPARISON_GREATER_EQUAL,
-        _enable_alt: BOOL(1),
-        _write_mask_alt: 1,
-        func_alt: D3D11_COMPARISON_LESS_EQUAL,
-    },
-    // Depth 3
-    BungieDepthDesc {
-        enable: BOOL(1),
-        write_mask: 0,
-        func: D3D11_COMPARISON_GREATER_EQUAL,
-        _enable_alt: BOOL(1),
-        _write_mask_alt: 0,
-        func_alt: D3D11_COMPARISON_LESS_EQUAL,
-    },
-    // Depth 4
-    BungieDepthDesc {
-        enable: BOOL(1),
-        write_mask: 1,
-        func: D3D11_COMPARISON_LESS_EQUAL,
-        _enable_alt: BOOL(1),
-        _write_mask_alt: 1,
-        func_alt: D3D11_COMPARISON_GREATER_EQUAL,
-    },
-    // Depth 5
-    BungieDepthDesc {
-        enable: BOOL(1),
-        write_mask: 1,
-        func: D3D11_COMPARISON_LESS,
-        _enable_alt: BOOL(1),
-        _write_mask_alt: 1,
-        func_alt: D3D11_COMPARISON_GREATER,
-    },
-    // Depth 6
-    BungieDepthDesc {
-        enable: BOOL(1),
-        write_mask: 0,
-        func: D3D11_COMPARISON_LESS_EQUAL,
-        _enable_alt: BOOL(1),
-        _write_mask_alt: 0,
-        func_alt: D3D11_COMPARISON_GREATER_EQUAL,
-    },
-    // Depth 7
-    BungieDepthDesc {
-        enable: BOOL(1),
-        write_mask: 0,
-        func: D3D11_COMPARISON_LESS,
-        _enable_alt: BOOL(1),
-        _write_mask_alt: 0,
-        func_alt: D3D11_COMPARISON_GREATER,
-    },
-    // Depth 8
-    BungieDepthDesc {
-        enable: BOOL(1),
-        write_mask: 1,
-        func: D3D11_COMPARISON_GREATER_EQUAL,
-        _enable_alt: BOOL(1),
-        _write_mask_alt: 1,
-        func_alt: D3D11_COMPARISON_LESS_EQUAL,
-    },
-    // Depth 9
-    BungieDepthDesc {
-        enable: BOOL(1),
-        write_mask: 0,
-        func: D3D11_COMPARISON_GREATER_EQUAL,
-        _enable_alt: BOOL(1),
-        _write_mask_alt: 0,
-        func_alt: D3D11_COMPARISON_LESS_EQUAL,
-    },
-    // Depth 10
-    BungieDepthDesc {
-        enable: BOOL(1),
-        write_mask: 1,
-        func: D3D11_COMPARISON_ALWAYS,
-        _enable_alt: BOOL(1),
-        _write_mask_alt: 1,
-        func_alt: D3D11_COMPARISON_ALWAYS,
-    },
-    // Depth 11
-    BungieDepthDesc {
-        enable: BOOL(1),
-        write_mask: 0,
-        func: D3D11_COMPARISON_NEVER,
-        _enable_alt: BOOL(1),
-        _write_mask_alt: 0,
-        func_alt: D3D11_COMPARISON_NEVER,
-    },
-    // Depth 12
-    BungieDepthDesc {
-        enable: BOOL(1),
-        write_mask: 0,
-        func: D3D11_COMPARISON_ALWAYS,
-        _enable_alt: BOOL(1),
-        _write_mask_alt: 0,
-        func_alt: D3D11_COMPARISON_ALWAYS,
-    },
-    // Depth 13
-    BungieDepthDesc {
-        enable: BOOL(1),
-        write_mask: 0,
-        func: D3D11_COMPARISON_GREATER_EQUAL,
-        _enable_alt: BOOL(1),
-        _write_mask_alt: 0,
-        func_alt: D3D11_COMPARISON_LESS_EQUAL,
-    },
-    // Depth 14 (CUSTOM FOR ALKAHEST)
-    BungieDepthDesc {
-        enable: BOOL(1),
-        write_mask: 0,
-        func: D3D11_COMPARISON_EQUAL,
-        _enable_alt: BOOL(1),
-        _write_mask_alt: 0,
-        func_alt: D3D11_COMPARISON_EQUAL,
-    },
-];
-
-const STENCIL_STATES: [BungieStencilDesc; 49] = [
-    // Stencil 0
-    BungieStencilDesc {
-        stencil_enable: BOOL(0),
-        stencil_read_mask: 0,
-        stencil_write_mask: 0,
-        front_face: BungieStencilOpDesc {
-            func: D3D11_COMPARISON_ALWAYS,
-            pass_op: D3D11_STENCIL_OP_KEEP,
-            fail_op: D3D11_STENCIL_OP_KEEP,
-            depth_fail_op: D3D11_STENCIL_OP_KEEP,
-        },
-        back_face: BungieStencilOpDesc {
-            func: D3D11_COMPARISON_ALWAYS,
-            pass_op: D3D11_STENCIL_OP_KEEP,
-            fail_op: D3D11_STENCIL_OP_KEEP,
-            depth_fail_op: D3D11_STENCIL_OP_KEEP,
-        },
-    },
-    // Stencil 1
-    BungieStencilDesc {
-        stencil_enable: BOOL(0),
-        stencil_read_mask: 0,
-        stencil_write_mask: 0,
-        front_face: BungieStencilOpDesc {
-            func: D3D11_COMPARISON_ALWAYS,
-            pass_op: D3D11_STENCIL_OP_KEEP,
-            fail_op: D3D11_STENCIL_OP_KEEP,
-            depth_fail_op: D3D11_STENCIL_OP_KEEP,
-        },
-        back_face: BungieStencilOpDesc {
-            func: D3D11_COMPARISON_ALWAYS,
-            pass_op: D3D11_STENCIL_OP_KEEP,
-            fail_op: D3D11_STENCIL_OP_KEEP,
-            depth_fail_op: D3D11_STENCIL_OP_KEEP,
-        },
-    },
-    // Stencil 2
-    BungieStencilDesc {
-        stencil_enable: BOOL(1),
-        stencil_read_mask: 0,
-        stencil_write_mask: 175,
-        front_face: BungieStencilOpDesc {
-            func: D3D11_COMPARISON_ALWAYS,
-            pass_op: D3D11_STENCIL_OP_REPLACE,
-            fail_op: D3D11_STENCIL_OP_KEEP,
-            depth_fail_op: D3D11_STENCIL_OP_KEEP,
-        },
-        back_face: BungieStencilOpDesc {
-            func: D3D11_COMPARISON_ALWAYS,
-            pass_op: D3D11_STENCIL_OP_REPLACE,
-            fail_op: D3D11_STENCIL_OP_KEEP,
-            depth_fail_op: D3D11_STENCIL_OP_KEEP,
-        },
-    },
-    // Stencil 3
-    BungieStencilDesc {
-        stencil_enable: BOOL(1),
-        stencil_read_mask: 0,
-        stencil_write_mask: 2,
-        front_face: BungieStencilOpDesc {
-            func: D3D11_COMPARISON_ALWAYS,
-            pass_op: D3D11_STENCIL_OP_REPLACE,
-            fail_op: D3D11_STENCIL_OP_KEEP,
-            depth_fail_op: D3D11_STENCIL_OP_KEEP,
-        },
-        back_face: BungieStencilOpDesc {
-            func: D3D11_COMPARISON_ALWAYS,
-            pass_op: D3D11_STENCIL_OP_REPLACE,
-            fail_op: D3D11_STENCIL_OP_KEEP,
-            depth_fail_op: D3D11_STENCIL_OP_KEEP,
-        },
-    },
-    // Stencil 4
-    BungieStencilDesc {
-        stencil_enable: BOOL(1),
-        stencil_read_mask: 0,
-        stencil_write_mask: 1,
-        front_face: BungieStencilOpDesc {
-            func: D3D11_COMPARISON_ALWAYS,
-            pass_op: D3D11_STENCIL_OP_REPLACE,
-            fail_op: D3D11_STENCIL_OP_KEEP,
-            depth_fail_op: D3D11_STENCIL_OP_KEEP,
-        },
-        back_face: BungieStencilOpDesc {
-            func: D3D11_COMPARISON_ALWAYS,
-            pass_op: D3D11_STENCIL_OP_REPLACE,
-            fail_op: D3D11_STENCIL_OP_KEEP,
-            depth_fail_op: D3D11_STENCIL_OP_KEEP,
-        },
-    },
-    // Stencil 5
-    BungieStencilDesc {
-        stencil_enable: BOOL(1),
-        stencil_read_mask: 0,
-        stencil_write_mask: 16,
-        front_face: BungieStencilOpDesc {
-            func: D3D11_COMPARISON_ALWAYS,
-            pass_op: D3D11_STENCIL_OP_REPLACE,
-            fail_op: D3D11_STENCIL_OP_KEEP,
-            depth_fail_op: D3D11_STENCIL_OP_KEEP,
-        },
-        back_face: BungieStencilOpDesc {
-            func: D3D11_COMPARISON_ALWAYS,
-            pass_op: D3D11_STENCIL_OP_REPLACE,
-            fail_op: D3D11_STENCIL_OP_KEEP,
-            depth_fail_op: D3D11_STENCIL_OP_KEEP,
-        },
-    },
-    // Stencil 6
-    BungieStencilDesc {
-        stencil_enable: BOOL(1),
-        stencil_read_mask: 4,
-        stencil_write_mask: 184,
-        front_face: BungieStencilOpDesc {
-            func: D3D11_COMPARISON_EQUAL,
-            pass_op: D3D11_STENCIL_OP_REPLACE,
-            fail_op: D3D11_STENCIL_OP_KEEP,
-            depth_fail_op: D3D11_STENCIL_OP_KEEP,
-        },
-        back_face: BungieStencilOpDesc {
-            func: D3D11_COMPARISON_EQUAL,
-            pass_op: D3D11_STENCIL_OP_REPLACE,
-            fail_op: D3D11_STENCIL_OP_KEEP,
-            depth_fail_op: D3D11_STENCIL_OP_KEEP,
-        },
-    },
-    // Stencil 7
-    BungieStencilDesc {
-        stencil_enable: BOOL(1),
-        stencil_read_mask: 20,
-        stencil_write_mask: 184,
-        front_face: BungieStencilOpDesc {
-            func: D3D11_COMPARISON_EQUAL,
-            pass_op: D3D11_STENCIL_OP_ZERO,
-            fail_op: D3D11_STENCIL_OP_KEEP,
-            depth_fail_op: D3D11_STENCIL_OP_KEEP,
-        },
-        back_face: BungieStencilOpDesc {
-            func: D3D11_COMPARISON_EQUAL,
-            pass_op: D3D11_STENCIL_OP_ZERO,
-            fail_op: D3D11_STENCIL_OP_KEEP,
-            depth_fail_op: D3D11_STENCIL_OP_KEEP,
-        },
-    },
-    // Stencil 8
-    BungieStencilDesc {
-        stencil_enable: BOOL(1),
-        stencil_read_mask: 4,
-        stencil_write_mask: 0,
-        front_face: BungieStencilOpDesc {
-            func: D3D11_COMPARISON_EQUAL,
-            pass_op: D3D11_STENCIL_OP_REPLACE,
-            fail_op: D3D11_STENCIL_OP_KEEP,
-            depth_fail_op: D3D11_STENCIL_OP_KEEP,
-        },
-        back_face: BungieStencilOpDesc {
-            func: D3D11_COMPARISON_EQUAL,
-            pass_op: D3D11_STENCIL_OP_REPLACE,
-            fail_op: D3D11_STENCIL_OP_KEEP,
-            depth_fail_op: D3D11_STENCIL_OP_KEEP,
-        },
-    },
-    // Stencil 9
-    BungieStencilDesc {
-        stencil_enable: BOOL(1),
-        stencil_read_mask: 16,
-        stencil_write_mask: 16,
-        front_face: BungieStencilOpDesc {
-            func: D3D11_COMPARISON_NOT_EQUAL,
-            pass_op: D3D11_STENCIL_OP_ZERO,
-            fail_op: D3D11_STENCIL_OP_KEEP,
-            depth_fail_op: D3D11_STENCIL_OP_KEEP,
-        },
-        back_face: BungieStencilOpDesc {
-            func: D3D11_COMPARISON_NOT_EQUAL,
-            pass_op: D3D11_STENCIL_OP_ZERO,
-            fail_op: D3D11_STENCIL_OP_KEEP,
-            depth_fail_op: D3D11_STENCIL_OP_KEEP,
-        },
-    },
-    // Stencil 10
-    BungieStencilDesc {
-        stencil_enable: BOOL(1),
-        stencil_read_mask: 6,
-        stencil_write_mask: 184,
-        front_face: BungieStencilOpDesc {
-            func: D3D11_COMPARISON_EQUAL,
-            pass_op: D3D11_STENCIL_OP_REPLACE,
-            fail_op: D3D11_STENCIL_OP_KEEP,
-            depth_fail_op: D3D11_STENCIL_OP_KEEP,
-        },
-        back_face: BungieStencilOpDesc {
-            func: D3D11_COMPARISON_EQUAL,
-            pass_op: D3D11_STENCIL_OP_REPLACE,
-            fail_op: D3D11_STENCIL_OP_KEEP,
-            depth_fail_op: D3D11_STENCIL_OP_KEEP,
-        },
-    },
-    // Stencil 11
-    BungieStencilDesc {
-        stencil_enable: BOOL(1),
-        stencil_read_mask: 7,
-        stencil_write_mask: 184,
-        front_face: BungieStencilOpDesc {
-            func: D3D11_COMPARISON_LESS_EQUAL,
-            pass_op: D3D11_STENCIL_OP_REPLACE,
-            fail_op: D3D11_STENCIL_OP_KEEP,
-            depth_fail_op: D3D11_STENCIL_OP_KEEP,
-        },
-        back_face: BungieStencilOpDesc {
-            func: D3D11_COMPARISON_LESS_EQUAL,
-            pass_op: D3D11_STENCIL_OP_REPLACE,
-            fail_op: D3D11_STENCIL_OP_KEEP,
-            depth_fail_op: D3D11_STENCIL_OP_KEEP,
-        },
-    },
-    // Stencil 12
-    BungieStencilDesc {
-        stencil_enable: BOOL(1),
-        stencil_read_mask: 3,
-        stencil_write_mask: 184,
-        front_face: BungieStencilOpDesc {
-            func: D3D11_COMPARISON_GREATER,
-            pass_op: D3D11_STENCIL_OP_REPLACE,
-            fail_op: D3D11_STENCIL_OP_KEEP,
-            depth_fail_op: D3D11_STENCIL_OP_KEEP,
-        },
-        back_face: BungieStencilOpDesc {
-            func: D3D11_COMPARISON_GREATER,
-            pass_op: D3D11_STENCIL_OP_REPLACE,
-            fail_op: D3D11_STENCIL_OP_KEEP,
-            depth_fail_op: D3D11_STENCIL_OP_KEEP,
-        },
-    },
-    // Stencil 13
-    BungieStencilDesc {
-        stencil_enable: BOOL(1),
-        stencil_read_mask: 22,
-        stencil_write_mask: 184,
-        front_face: BungieStencilOpDesc {
-            func: D3D11_COMPARISON_EQUAL,
-            pass_op: D3D11_STENCIL_OP_ZERO,
-            fail_op: D3D11_STENCIL_OP_KEEP,
-            depth_fail_op: D3D11_STENCIL_OP_KEEP,
-        },
-        back_face: BungieStencilOpDesc {
-            func: D3D11_COMPARISON_EQUAL,
-            pass_op: D3D11_STENCIL_OP_ZERO,
-            fail_op: D3D11_STENCIL_OP_KEEP,
-            depth_fail_op: D3D11_STENCIL_OP_KEEP,
-        },
-    },
-    // Stencil 14
-    BungieStencilDesc {
-        stencil_enable: BOOL(1),
-        stencil_read_mask: 23,
-        stencil_write_mask: 184,
-        front_face: BungieStencilOpDesc {
-            func: D3D11_COMPARISON_LESS_EQUAL,
-            pass_op: D3D11_STENCIL_OP_ZERO,
-            fail_op: D3D11_STENCIL_OP_KEEP,
-            depth_fail_op: D3D11_STENCIL_OP_KEEP,
-        },
-        back_face: BungieStencilOpDesc {
-            func: D3D11_COMPARISON_LESS_EQUAL,
-            pass_op: D3D11_STENCIL_OP_ZERO,
-            fail_op: D3D11_STENCIL_OP_KEEP,
-            depth_fail_op: D3D11_STENCIL_OP_KEEP,
-        },
-    },
-    // Stencil 15
-    BungieStencilDesc {
-        stencil_enable: BOOL(1),
-        stencil_read_mask: 19,
-        stencil_write_mask: 184,
-        front_face: BungieStencilOpDesc {
-            func: D3D11_COMPARISON_GREATER,
-            pass_op: D3D11_STENCIL_OP_ZERO,
-            fail_op: D3D11_STENCIL_OP_KEEP,
-            depth_fail_op: D3D11_STENCIL_OP_KEEP,
-        },
-        back_face: BungieStencilOpDesc {
-            func: D3D11_COMPARISON_GREATER,
-            pass_op: D3D11_STENCIL_OP_ZERO,
-            fail_op: D3D11_STENCIL_OP_KEEP,
-            depth_fail_op: D3D11_STENCIL_OP_KEEP,
-        },
-    },
-    // Stencil 16
-    BungieStencilDesc {
-        stencil_enable: BOOL(1),
-        stencil_read_mask: 0,
-        stencil_write_mask: 16,
-        front_face: BungieStencilOpDesc {
-            func: D3D11_COMPARISON_ALWAYS,
-            pass_op: D3D11_STENCIL_OP_ZERO,
-            fail_op: D3D11_STENCIL_OP_KEEP,
-            depth_fail_op: D3D11_STENCIL_OP_KEEP,
-        },
-        back_face: BungieStencilOpDesc {
-            func: D3D11_COMPARISON_ALWAYS,
-            pass_op: D3D11_STENCIL_OP_ZERO,
-            fail_op: D3D11_STENCIL_OP_KEEP,
-            depth_fail_op: D3D11_STENCIL_OP_KEEP,
-        },
-    },
-    // Stencil 17
-    BungieStencilDesc {
-        stencil_enable: BOOL(1),
-        stencil_read_mask: 16,
-        stencil_write_mask: 16,
-        front_face: BungieStencilOpDesc {
-            func: D3D11_COMPARISON_EQUAL,
-            pass_op: D3D11_STENCIL_OP_KEEP,
-            fail_op: D3D11_STENCIL_OP_KEEP,
-            depth_fail_op: D3D11_STENCIL_OP_ZERO,
-        },
-        back_face: BungieStencilOpDesc {
-            func: D3D11_COMPARISON_EQUAL,
-            pass_op: D3D11_STENCIL_OP_KEEP,
-            fail_op: D3D11_STENCIL_OP_KEEP,
-            depth_fail_op: D3D11_STENCIL_OP_ZERO,
-        },
-    },
-    // Stencil 18
-    BungieStencilDesc {
-        stencil_enable: BOOL(1),
-        stencil_read_mask: 16,
-        stencil_write_mask: 16,
-        front_face: BungieStencilOpDesc {
-            func: D3D11_COMPARISON_EQUAL,
-            pass_op: D3D11_STENCIL_OP_KEEP,
-            fail_op: D3D11_STENCIL_OP_KEEP,
-            depth_fail_op: D3D11_STENCIL_OP_KEEP,
-        },
-        back_face: BungieStencilOpDesc {
-            func: D3D11_COMPARISON_EQUAL,
-            pass_op: D3D11_STENCIL_OP_KEEP,
-            fail_op: D3D11_STENCIL_OP_KEEP,
-            depth_fail_op: D3D11_STENCIL_OP_KEEP,
-        },
-    },
-    // Stencil 19
-    BungieStencilDesc {
-        stencil_enable: BOOL(1),
-        stencil_read_mask: 16,
-        stencil_write_mask: 0,
-        front_face: BungieStencilOpDesc {
-            func: D3D11_COMPARISON_EQUAL,
-            pass_op: D3D11_STENCIL_OP_KEEP,
-            fail_op: D3D11_STENCIL_OP_KEEP,
-            depth_fail_op: D3D11_STENCIL_OP_KEEP,
-        },
-        back_face: BungieStencilOpDesc {
-            func: D3D11_COMPARISON_EQUAL,
-            pass_op: D3D11_STENCIL_OP_KEEP,
-            fail_op: D3D11_STENCIL_OP_KEEP,
-            depth_fail_op: D3D11_STENCIL_OP_KEEP,
-        },
-    },
-    // Stencil 20
-    BungieStencilDesc {
-        stencil_enable: BOOL(1),
-        stencil_read_mask: 32,
-        stencil_write_mask: 0,
-        front_face: BungieStencilOpDesc {
-            func: D3D11_COMPARISON_NOT_EQUAL,
-            pass_op: D3D11_STENCIL_OP_KEEP,
-            fail_op: D3D11_STENCIL_OP_KEEP,
-            depth_fail_op: D3D11_STENCIL_OP_KEEP,
-        },
-        back_face: BungieStencilOpDesc {
-            func: D3D11_COMPARISON_NOT_EQUAL,
-            pass_op: D3D11_STENCIL_OP_KEEP,
-            fail_op: D3D11_STENCIL_OP_KEEP,
-            depth_fail_op: D3D11_STENCIL_OP_KEEP,
-        },
-    },
-    // Stencil 21
-    BungieStencilDesc {
-        stencil_enable: BOOL(1),
-        stencil_read_mask: 0,
-        stencil_write_mask: 184,
-        front_face: BungieStencilOpDesc {
-            func: D3D11_COMPARISON_ALWAYS,
-            pass_op: D3D11_STENCIL_OP_REPLACE,
-            fail_op: D3D11_STENCIL_OP_KEEP,
-            depth_fail_op: D3D11_STENCIL_OP_KEEP,
-        },
-        back_face: BungieStencilOpDesc {
-            func: D3D11_COMPARISON_ALWAYS,
-            pass_op: D3D11_STENCIL_OP_REPLACE,
-            fail_op: D3D11_STENCIL_OP_KEEP,
-            depth_fail_op: D3D11_STENCIL_OP_KEEP,
-        },
-    },
-    // Stencil 22
-    BungieStencilDesc {
-        stencil_enable: BOOL(1),
-        stencil_read_mask: 0,
-        stencil_write_mask: 255,
-        front_face: BungieStencilOpDesc {
-            func: D3D11_COMPARISON_ALWAYS,
-            pass_op: D3D11_STENCIL_OP_REPLACE,
-            fail_op: D3D11_STENCIL_OP_KEEP,
-            depth_fail_op: D3D11_STENCIL_OP_KEEP,
-        },
-        back_face: BungieStencilOpDesc {
-            func: D3D11_COMPARISON_ALWAYS,
-            pass_op: D3D11_STENCIL_OP_REPLACE,
-            fail_op: D3D11_STENCIL_OP_KEEP,
-            depth_fail_op: D3D11_STENCIL_OP_KEEP,
-        },
-    },
-    // Stencil 23
-    BungieStencilDesc {
-        stencil_enable: BOOL(1),
-        stencil_read_mask: 0,
-        stencil_write_mask: 184,
-        front_face: BungieStencilOpDesc {
-            func: D3D11_COMPARISON_ALWAYS,
-            pass_op: D3D11_STENCIL_OP_ZERO,
-            fail_op: D3D11_STENCIL_OP_KEEP,
-            depth_fail_op: D3D11_STENCIL_OP_KEEP,
-        },
-        back_face: BungieStencilOpDesc {
-            func: D3D11_COMPARISON_ALWAYS,
-            pass_op: D3D11_STENCIL_OP_ZERO,
-            fail_op: D3D11_STENCIL_OP_KEEP,
-            depth_fail_op: D3D11_STENCIL_OP_KEEP,
-        },
-    },
-    // Stencil 24
-    BungieStencilDesc {
-        stencil_enable: BOOL(1),
-        stencil_read_mask: 0,
-        stencil_write_mask: 16,
-        front_face: BungieStencilOpDesc {
-            func: D3D11_COMPARISON_ALWAYS,
-            pass_op: D3D11_STENCIL_OP_INVERT,
-            fail_op: D3D11_STENCIL_OP_KEEP,
-            depth_fail_op: D3D11_STENCIL_OP_KEEP,
-        },
-        back_face: BungieStencilOpDesc {
-            func: D3D11_COMPARISON_ALWAYS,
-            pass_op: D3D11_STENCIL_OP_INVERT,
-            fail_op: D3D11_STENCIL_OP_KEEP,
-            depth_fail_op: D3D11_STENCIL_OP_KEEP,
-        },
-    },
-    // Stencil 25
-    BungieStencilDesc {
-        stencil_enable: BOOL(1),
-        stencil_read_mask: 0,
-        stencil_write_mask: 16,
-        front_face: BungieStencilOpDesc {
-            func: D3D11_COMPARISON_ALWAYS,
-            pass_op: D3D11_STENCIL_OP_KEEP,
-            fail_op: D3D11_STENCIL_OP_KEEP,
-            depth_fail_op: D3D11_STENCIL_OP_INVERT,
-        },
-        back_face: BungieStencilOpDesc {
-            func: D3D11_COMPARISON_ALWAYS,
-            pass_op: D3D11_STENCIL_OP_KEEP,
-            fail_op: D3D11_STENCIL_OP_KEEP,
-            depth_fail_op: D3D11_STENCIL_OP_INVERT,
-        },
-    },
-    // Stencil 26
-    BungieStencilDesc {
-        stencil_enable: BOOL(1),
-        stencil_read_mask: 16,
-        stencil_write_mask: 16,
-        front_face: BungieStencilOpDesc {
-            func: D3D11_COMPARISON_NOT_EQUAL,
-            pass_op: D3D11_STENCIL_OP_ZERO,
-            fail_op: D3D11_STENCIL_OP_KEEP,
-            depth_fail_op: D3D11_STENCIL_OP_KEEP,
-        },
-        back_face: BungieStencilOpDesc {
-            func: D3D11_COMPARISON_NOT_EQUAL,
-            pass_op: D3D11_STENCIL_OP_ZERO,
-            fail_op: D3D11_STENCIL_OP_KEEP,
-            depth_fail_op: D3D11_STENCIL_OP_KEEP,
-        },
-    },
-    // Stencil 27
-    BungieStencilDesc {
-        stencil_enable: BOOL(1),
-        stencil_read_mask: 16,
-        stencil_write_mask: 16,
-        front_face: BungieStencilOpDesc {
-            func: D3D11_COMPARISON_NOT_EQUAL,
-            pass_op: D3D11_STENCIL_OP_KEEP,
-            fail_op: D3D11_STENCIL_OP_KEEP,
-            depth_fail_op: D3D11_STENCIL_OP_KEEP,
-        },
-        back_face: BungieStencilOpDesc {
-            func: D3D11_COMPARISON_NOT_EQUAL,
-            pass_op: D3D11_STENCIL_OP_ZERO,
-            fail_op: D3D11_STENCIL_OP_KEEP,
-            depth_fail_op: D3D11_STENCIL_OP_KEEP,
-        },
-    },
-    // Stencil 28
-    BungieStencilDesc {
-        stencil_enable: BOOL(1),
-        stencil_read_mask: 16,
-        stencil_write_mask: 0,
-        front_face: BungieStencilOpDesc {
-            func: D3D11_COMPARISON_NOT_EQUAL,
-            pass_op: D3D11_STENCIL_OP_KEEP,
-            fail_op: D3D11_STENCIL_OP_KEEP,
-            depth_fail_op: D3D11_STENCIL_OP_KEEP,
-        },
-        back_face: BungieStencilOpDesc {
-            func: D3D11_COMPARISON_NOT_EQUAL,
-            pass_op: D3D11_STENCIL_OP_KEEP,
-            fail_op: D3D11_STENCIL_OP_KEEP,
-            depth_fail_op: D3D11_STENCIL_OP_KEEP,
-        },
-    },
-    // Stencil 29
-    BungieStencilDesc {
-        stencil_enable: BOOL(1),
-        stencil_read_mask: 16,
-        stencil_write_mask: 0,
-        front_face: BungieStencilOpDesc {
-            func: D3D11_COMPARISON_NOT_EQUAL,
-            pass_op: D3D11_STENCIL_OP_KEEP,
-            fail_op: D3D11_STENCIL_OP_KEEP,
-            depth_fail_op: D3D11_STENCIL_OP_KEEP,
-        },
-        back_face: BungieStencilOpDesc {
-            func: D3D11_COMPARISON_NOT_EQUAL,
-            pass_op: D3D11_STENCIL_OP_KEEP,
-            fail_op: D3D11_STENCIL_OP_KEEP,
-            depth_fail_op: D3D11_STENCIL_OP_KEEP,
-        },
-    },
-    // Stencil 30
-    BungieStencilDesc {
-        stencil_enable: BOOL(1),
-        stencil_read_mask: 0,
-        stencil_write_mask: 16,
-        front_face: BungieStencilOpDesc {
-            func: D3D11_COMPARISON_ALWAYS,
-            pass_op: D3D11_STENCIL_OP_REPLACE,
-            fail_op: D3D11_STENCIL_OP_KEEP,
-            depth_fail_op: D3D11_STENCIL_OP_KEEP,
-        },
-        back_face: BungieStencilOpDesc {
-            func: D3D11_COMPARISON_ALWAYS,
-            pass_op: D3D11_STENCIL_OP_REPLACE,
-            fail_op: D3D11_STENCIL_OP_KEEP,
-            depth_fail_op: D3D11_STENCIL_OP_KEEP,
-        },
-    },
-    // Stencil 31
-    BungieStencilDesc {
-        stencil_enable: BOOL(1),
-        stencil_read_mask: 255,
-        stencil_write_mask: 255,
-        front_face: BungieStencilOpDesc {
-            func: D3D11_COMPARISON_ALWAYS,
-            pass_op: D3D11_STENCIL_OP_REPLACE,
-            fail_op: D3D11_STENCIL_OP_KEEP,
-            depth_fail_op: D3D11_STENCIL_OP_KEEP,
-        },
-        back_face: BungieStencilOpDesc {
-            func: D3D11_COMPARISON_ALWAYS,
-            pass_op: D3D11_STENCIL_OP_REPLACE,
-            fail_op: D3D11_STENCIL_OP_KEEP,
-            depth_fail_op: D3D11_STENCIL_OP_KEEP,
-        },
-    },
-    // Stencil 32
-    BungieStencilDesc {
-        stencil_enable: BOOL(1),
-        stencil_read_mask: 255,
-        stencil_write_mask: 255,
-        front_face: BungieStencilOpDesc {
-            func: D3D11_COMPARISON_NOT_EQUAL,
-            pass_op: D3D11_STENCIL_OP_ZERO,
-            fail_op: D3D11_STENCIL_OP_KEEP,
-            depth_fail_op: D3D11_STENCIL_OP_KEEP,
-        },
-        back_face: BungieStencilOpDesc {
-            func: D3D11_COMPARISON_NOT_EQUAL,
-            pass_op: D3D11_STENCIL_OP_ZERO,
-            fail_op: D3D11_STENCIL_OP_KEEP,
-            depth_fail_op: D3D11_STENCIL_OP_KEEP,
-        },
-    },
-    // Stencil 33
-    BungieStencilDesc {
-        stencil_enable: BOOL(1),
-        stencil_read_mask: 255,
-        stencil_write_mask: 0,
-        front_face: BungieStencilOpDesc {
-            func: D3D11_COMPARISON_NOT_EQUAL,
-            pass_op: D3D11_STENCIL_OP_KEEP,
-            fail_op: D3D11_STENCIL_OP_KEEP,
-            depth_fail_op: D3D11_STENCIL_OP_KEEP,
-        },
-        back_face: BungieStencilOpDesc {
-            func: D3D11_COMPARISON_NOT_EQUAL,
-            pass_op: D3D11_STENCIL_OP_KEEP,
-            fail_op: D3D11_STENCIL_OP_KEEP,
-            depth_fail_op: D3D11_STENCIL_OP_KEEP,
-        },
-    },
-    // Stencil 34
-    BungieStencilDesc {
-        stencil_enable: BOOL(1),
-        stencil_read_mask: 16,
-        stencil_write_mask: 16,
-        front_face: BungieStencilOpDesc {
-            func: D3D11_COMPARISON_ALWAYS,
-            pass_op: D3D11_STENCIL_OP_ZERO,
-            fail_op: D3D11_STENCIL_OP_ZERO,
-            depth_fail_op: D3D11_STENCIL_OP_KEEP,
-        },
-        back_face: BungieStencilOpDesc {
-            func: D3D11_COMPARISON_ALWAYS,
-            pass_op: D3D11_STENCIL_OP_ZERO,
-            fail_op: D3D11_STENCIL_OP_ZERO,
-            depth_fail_op: D3D11_STENCIL_OP_KEEP,
-        },
-    },
-    // Stencil 35
-    BungieStencilDesc {
-        stencil_enable: BOOL(1),
-        stencil_read_mask: 16,
-        stencil_write_mask: 0,
-        front_face: BungieStencilOpDesc {
-            func: D3D11_COMPARISON_NOT_EQUAL,
-            pass_op: D3D11_STENCIL_OP_KEEP,
-            fail_op: D3D11_STENCIL_OP_KEEP,
-            depth_fail_op: D3D11_STENCIL_OP_KEEP,
-        },
-        back_face: BungieStencilOpDesc {
-            func: D3D11_COMPARISON_NOT_EQUAL,
-            pass_op: D3D11_STENCIL_OP_KEEP,
-            fail_op: D3D11_STENCIL_OP_KEEP,
-            depth_fail_op: D3D11_STENCIL_OP_KEEP,
-        },
-    },
-    // Stencil 36
-    BungieStencilDesc {
-        stencil_enable: BOOL(1),
-        stencil_read_mask: 0,
-        stencil_write_mask: 16,
-        front_face: BungieStencilOpDesc {
-            func: D3D11_COMPARISON_ALWAYS,
-            pass_op: D3D11_STENCIL_OP_ZERO,
-            fail_op: D3D11_STENCIL_OP_KEEP,
-            depth_fail_op: D3D11_STENCIL_OP_REPLACE,
-        },
-        back_face: BungieStencilOpDesc {
-            func: D3D11_COMPARISON_ALWAYS,
-            pass_op: D3D11_STENCIL_OP_ZERO,
-            fail_op: D3D11_STENCIL_OP_KEEP,
-            depth_fail_op: D3D11_STENCIL_OP_REPLACE,
-        },
-    },
-    // Stencil 37
-    BungieStencilDesc {
-        stencil_enable: BOOL(1),
-        stencil_read_mask: 16,
-        stencil_write_mask: 16,
-        front_face: BungieStencilOpDesc {
-            func: D3D11_COMPARISON_EQUAL,
-            pass_op: D3D11_STENCIL_OP_ZERO,
-            fail_op: D3D11_STENCIL_OP_KEEP,
-            depth_fail_op: D3D11_STENCIL_OP_KEEP,
-        },
-        back_face: BungieStencilOpDesc {
-            func: D3D11_COMPARISON_EQUAL,
-            pass_op: D3D11_STENCIL_OP_ZERO,
-            fail_op: D3D11_STENCIL_OP_KEEP,
-            depth_fail_op: D3D11_STENCIL_OP_KEEP,
-        },
-    },
-    // Stencil 38
-    BungieStencilDesc {
-        stencil_enable: BOOL(1),
-        stencil_read_mask: 16,
-        stencil_write_mask: 16,
-        front_face: BungieStencilOpDesc {
-            func: D3D11_COMPARISON_EQUAL,
-            pass_op: D3D11_STENCIL_OP_KEEP,
-            fail_op: D3D11_STENCIL_OP_KEEP,
-            depth_fail_op: D3D11_STENCIL_OP_KEEP,
-        },
-        back_face: BungieStencilOpDesc {
-            func: D3D11_COMPARISON_EQUAL,
-            pass_op: D3D11_STENCIL_OP_KEEP,
-            fail_op: D3D11_STENCIL_OP_KEEP,
-            depth_fail_op: D3D11_STENCIL_OP_KEEP,
-        },
-    },
-    // Stencil 39
-    BungieStencilDesc {
-        stencil_enable: BOOL(1),
-        stencil_read_mask: 16,
-        stencil_write_mask: 16,
-        front_face: BungieStencilOpDesc {
-            func: D3D11_COMPARISON_EQUAL,
-            pass_op: D3D11_STENCIL_OP_ZERO,
-            fail_op: D3D11_STENCIL_OP_KEEP,
-            depth_fail_op: D3D11_STENCIL_OP_KEEP,
-        },
-        back_face: BungieStencilOpDesc {
-            func: D3D11_COMPARISON_EQUAL,
-            pass_op: D3D11_STENCIL_OP_ZERO,
-            fail_op: D3D11_STENCIL_OP_KEEP,
-            depth_fail_op: D3D11_STENCIL_OP_KEEP,
-        },
-    },
-    // Stencil 40
-    BungieStencilDesc {
-        stencil_enable: BOOL(1),
-        stencil_read_mask: 0,
-        stencil_write_mask: 64,
-        front_face: BungieStencilOpDesc {
-            func: D3D11_COMPARISON_ALWAYS,
-            pass_op: D3D11_STENCIL_OP_REPLACE,
-            fail_op: D3D11_STENCIL_OP_KEEP,
-            depth_fail_op: D3D11_STENCIL_OP_KEEP,
-        },
-        back_face: BungieStencilOpDesc {
-            func: D3D11_COMPARISON_ALWAYS,
-            pass_op: D3D11_STENCIL_OP_REPLACE,
-            fail_op: D3D11_STENCIL_OP_KEEP,
-            depth_fail_op: D3D11_STENCIL_OP_KEEP,
-        },
-    },
-    // Stencil 41
-    BungieStencilDesc {
-        stencil_enable: BOOL(1),
-        stencil_read_mask: 0,
-        stencil_write_mask: 1,
-        front_face: BungieStencilOpDesc {
-            func: D3D11_COMPARISON_ALWAYS,
-            pass_op: D3D11_STENCIL_OP_REPLACE,
-            fail_op: D3D11_STENCIL_OP_KEEP,
-            depth_fail_op: D3D11_STENCIL_OP_KEEP,
-        },
-        back_face: BungieStencilOpDesc {
-            func: D3D11_COMPARISON_ALWAYS,
-            pass_op: D3D11_STENCIL_OP_REPLACE,
-            fail_op: D3D11_STENCIL_OP_KEEP,
-            depth_fail_op: D3D11_STENCIL_OP_KEEP,
-        },
-    },
-    // Stencil 42
-    BungieStencilDesc {
-        stencil_enable: BOOL(1),
-        stencil_read_mask: 0,
-        stencil_write_mask: 2,
-        front_face: BungieStencilOpDesc {
-            func: D3D11_COMPARISON_ALWAYS,
-            pass_op: D3D11_STENCIL_OP_REPLACE,
-            fail_op: D3D11_STENCIL_OP_KEEP,
-            depth_fail_op: D3D11_STENCIL_OP_KEEP,
-        },
-        back_face: BungieStencilOpDesc {
-            func: D3D11_COMPARISON_ALWAYS,
-            pass_op: D3D11_STENCIL_OP_REPLACE,
-            fail_op: D3D11_STENCIL_OP_KEEP,
-            depth_fail_op: D3D11_STENCIL_OP_KEEP,
-        },
-    },
-    // Stencil 43
-    BungieStencilDesc {
-        stencil_enable: BOOL(1),
-        stencil_read_mask: 0,
-        stencil_write_mask: 4,
-        front_face: BungieStencilOpDesc {
-            func: D3D11_COMPARISON_ALWAYS,
-            pass_op: D3D11_STENCIL_OP_REPLACE,
-            fail_op: D3D11_STENCIL_OP_KEEP,
-            depth_fail_op: D3D11_STENCIL_OP_KEEP,
-        },
-        back_face: BungieStencilOpDesc {
-            func: D3D11_COMPARISON_ALWAYS,
-            pass_op: D3D11_STENCIL_OP_REPLACE,
-            fail_op: D3D11_STENCIL_OP_KEEP,
-            depth_fail_op: D3D11_STENCIL_OP_KEEP,
-        },
-    },
-    // Stencil 44
-    BungieStencilDesc {
-        stencil_enable: BOOL(1),
-        stencil_read_mask: 0,
-        stencil_write_mask: 8,
-        front_face: BungieStencilOpDesc {
-            func: D3D11_COMPARISON_ALWAYS,
-            pass_op: D3D11_STENCIL_OP_REPLACE,
-            fail_op: D3D11_STENCIL_OP_KEEP,
-            depth_fail_op: D3D11_STENCIL_OP_KEEP,
-        },
-        back_face: BungieStencilOpDesc {
-            func: D3D11_COMPARISON_ALWAYS,
-            pass_op: D3D11_STENCIL_OP_REPLACE,
-            fail_op: D3D11_STENCIL_OP_KEEP,
-            depth_fail_op: D3D11_STENCIL_OP_KEEP,
-        },
-    },
-    // Stencil 45
-    BungieStencilDesc {
-        stencil_enable: BOOL(1),
-        stencil_read_mask: 0,
-        stencil_write_mask: 16,
-        front_face: BungieStencilOpDesc {
-            func: D3D11_COMPARISON_ALWAYS,
-            pass_op: D3D11_STENCIL_OP_REPLACE,
-            fail_op: D3D11_STENCIL_OP_KEEP,
-            depth_fail_op: D3D11_STENCIL_OP_KEEP,
-        },
-        back_face: BungieStencilOpDesc {
-            func: D3D11_COMPARISON_ALWAYS,
-            pass_op: D3D11_STENCIL_OP_REPLACE,
-            fail_op: D3D11_STENCIL_OP_KEEP,
-            depth_fail_op: D3D11_STENCIL_OP_KEEP,
-        },
-    },
-    // Stencil 46
-    BungieStencilDesc {
-        stencil_enable: BOOL(1),
-        stencil_read_mask: 0,
-        stencil_write_mask: 32,
-        front_face: BungieStencilOpDesc {
-            func: D3D11_COMPARISON_ALWAYS,
-            pass_op: D3D11_STENCIL_OP_REPLACE,
-            fail_op: D3D11_STENCIL_OP_KEEP,
-            depth_fail_op: D3D11_STENCIL_OP_KEEP,
-        },
-        back_face: BungieStencilOpDesc {
-            func: D3D11_COMPARISON_ALWAYS,
-            pass_op: D3D11_STENCIL_OP_REPLACE,
-            fail_op: D3D11_STENCIL_OP_KEEP,
-            depth_fail_op: D3D11_STENCIL_OP_KEEP,
-        },
-    },
-    // Stencil 47
-    BungieStencilDesc {
-        stencil_enable: BOOL(1),
-        stencil_read_mask: 0,
-        stencil_write_mask: 64,
-        front_face: BungieStencilOpDesc {
-            func: D3D11_COMPARISON_ALWAYS,
-            pass_op: D3D11_STENCIL_OP_REPLACE,
-            fail_op: D3D11_STENCIL_OP_KEEP,
-            depth_fail_op: D3D11_STENCIL_OP_KEEP,
-        },
-        back_face: BungieStencilOpDesc {
-            func: D3D11_COMPARISON_ALWAYS,
-            pass_op: D3D11_STENCIL_OP_REPLACE,
-            fail_op: D3D11_STENCIL_OP_KEEP,
-            depth_fail_op: D3D11_STENCIL_OP_KEEP,
-        },
-    },
-    // Stencil 48
-    BungieStencilDesc {
-        stencil_enable: BOOL(1),
-        stencil_read_mask: 0,
-        stencil_write_mask: 128,
-        front_face: BungieStencilOpDesc {
-            func: D3D11_COMPARISON_ALWAYS,
-            pass_op: D3D11_STENCIL_OP_REPLACE,
-            fail_op: D3D11_STENCIL_OP_KEEP,
-            depth_fail_op: D3D11_STENCIL_OP_KEEP,
-        },
-        back_face: BungieStencilOpDesc {
-            func: D3D11_COMPARISON_ALWAYS,
-            pass_op: D3D11_STENCIL_OP_REPLACE,
-            fail_op: D3D11_STENCIL_OP_KEEP,
-            depth_fail_op: D3D11_STENCIL_OP_KEEP,
-        },
-    },
-];
-
-//endregion
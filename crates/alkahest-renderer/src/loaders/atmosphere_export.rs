@@ -0,0 +1,100 @@
+//! Exports a map's atmosphere parameters along with its decoded lookup textures
+
+use std::{
+    io::{Seek, SeekFrom},
+    path::Path,
+};
+
+use alkahest_data::map::SMapAtmosphere;
+use anyhow::Context;
+use destiny_pkg::TagHash;
+use serde_json::json;
+use tiger_parse::TigerReadable;
+
+use crate::loaders::{map_data::MapDataTables, texture_decode::DecodedTexture};
+
+/// Finds the atmosphere definitions in a map's data tables. Maps usually have exactly one
+pub fn find_map_atmospheres(map_hash: TagHash) -> anyhow::Result<Vec<SMapAtmosphere>> {
+    let mut atmospheres = vec![];
+    for entry in MapDataTables::load(map_hash)?.resources(0x80806BC1) {
+        let (_, _, mut cur) = match entry {
+            Ok(entry) => entry,
+            Err(e) => {
+                error!("{e:?}");
+                continue;
+            }
+        };
+        cur.seek(SeekFrom::Current(16))?;
+        atmospheres.push(TigerReadable::read_ds(&mut cur)?);
+    }
+
+    Ok(atmospheres)
+}
+
+/// Writes `atmosphere.json` and the lookup textures to `output`. Returns the number of textures written
+pub fn export_atmosphere(atmosphere: &SMapAtmosphere, output: &Path) -> anyhow::Result<usize> {
+    std::fs::create_dir_all(output)
+        .with_context(|| format!("Failed to create {}", output.display()))?;
+
+    let mut textures = vec![];
+    for (name, texture) in [
+        ("lookup_texture_0", atmosphere.lookup_texture_0),
+        ("lookup_texture_1", atmosphere.lookup_texture_1),
+        ("lookup_texture_2", atmosphere.lookup_texture_2),
+        ("lookup_texture_3", atmosphere.lookup_texture_3),
+        ("light_shaft_optical_depth", atmosphere.unkd0),
+    ] {
+        let Some(hash) = texture.hash32_checked() else {
+            continue;
+        };
+
+        let decoded = match DecodedTexture::load(hash) {
+            Ok(d) => d,
+            Err(e) => {
+                error!("Failed to decode atmosphere texture {name} ({hash}): {e:?}");
+                textures.push(
+                    json!({ "name": name, "hash": hash.to_string(), "error": e.to_string() }),
+                );
+                continue;
+            }
+        };
+
+        // Lookup textures are HDR, so they're normalized to their largest value
+        let scale = decoded.max_value().max(f32::EPSILON);
+        let mut files = vec![];
+        for layer in 0..decoded.layers {
+            let file = if decoded.layers > 1 {
+                format!("{name}_{layer}.png")
+            } else {
+                format!("{name}.png")
+            };
+
+            std::fs::write(output.join(&file), decoded.to_png16(layer, scale)?)
+                .with_context(|| format!("Failed to write {file}"))?;
+            files.push(file);
+        }
+
+        textures.push(json!({
+            "name": name,
+            "hash": hash.to_string(),
+            "format": format!("{:?}", decoded.format),
+            "width": decoded.width,
+            "height": decoded.height,
+            "layers": decoded.layers,
+            "scale": scale,
+            "files": files,
+        }));
+    }
+
+    let written = textures.iter().filter(|t| t.get("files").is_some()).count();
+    let json = json!({
+        "parameters": atmosphere.parameters(),
+        "textures": textures,
+    });
+
+    let path = output.join("atmosphere.json");
+    std::fs::write(&path, serde_json::to_string_pretty(&json)?)
+        .with_context(|| format!("Failed to write {}", path.display()))?;
+
+    Ok(written)
+}
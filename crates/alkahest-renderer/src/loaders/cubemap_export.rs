@@ -0,0 +1,280 @@
+//! Exports map cubemap volumes (reflection probes) as HDR cube faces, with a JSON manifest holding
+//! the influence volumes
+//!
+//! BC6H cubemaps are passed through as KTX2 cubemaps with every mip level, as the lower mips hold
+//! the prefiltered reflections for rougher surfaces. Cubemaps in any other format are decoded and
+//! written as an EXR per face of the top mip. The voxel IBL volume is decoded and written as a
+//! single EXR with its depth slices stacked vertically.
+//!
+//! Positions and extents are left in Destiny's Z-up coordinate space. The influence volume is the
+//! box placed by the probe transform and the volume extents. The blend distances haven't been
+//! located in [`SCubemapVolume`] yet, so no blend region is written. The unverified volume fields
+//! are written under `unknown`, keyed by their field name.
+
+use std::path::{Path, PathBuf};
+
+use alkahest_data::{map::SCubemapVolume, text::StringContainer, WideHash};
+use anyhow::Context;
+use destiny_pkg::TagHash;
+use glam::{Quat, Vec3, Vec4Swizzles};
+use rustc_hash::FxHashMap;
+use serde_json::{json, Value};
+use tiger_parse::TigerReadable;
+
+use crate::{
+    gpu::texture::Texture,
+    loaders::{
+        exr::encode_exr,
+        ktx2::{encode_ktx2, is_ktx2_supported},
+        map_data::MapDataTables,
+        texture_decode::DecodedTexture,
+    },
+};
+
+/// Cube face suffixes, in D3D (and KTX2) face order
+pub const CUBE_FACES: [&str; 6] = ["px", "nx", "py", "ny", "pz", "nz"];
+
+pub struct ReflectionProbe {
+    pub table: TagHash,
+    pub resource_offset: u64,
+    pub translation: Vec3,
+    pub rotation: Quat,
+    pub volume: SCubemapVolume,
+}
+
+#[derive(Default)]
+pub struct MapCubemaps {
+    pub map_hash: TagHash,
+    pub map_name: String,
+    pub probes: Vec<ReflectionProbe>,
+}
+
+impl MapCubemaps {
+    pub fn gather(map_hash: TagHash, stringmap: &StringContainer) -> anyhow::Result<Self> {
+        let tables = MapDataTables::load(map_hash)?;
+        let mut cubemaps = MapCubemaps {
+            map_hash,
+            map_name: stringmap.get(tables.map_name),
+            ..Default::default()
+        };
+
+        // Cubemap volume
+        for entry in tables.resources(0x80806695) {
+            let (table_hash, data, mut cur) = match entry {
+                Ok(entry) => entry,
+                Err(e) => {
+                    error!("{e:?}");
+                    continue;
+                }
+            };
+            match SCubemapVolume::read_ds(&mut cur) {
+                Ok(volume) => cubemaps.probes.push(ReflectionProbe {
+                    table: table_hash,
+                    resource_offset: data.data_resource.offset,
+                    translation: data.translation.xyz(),
+                    rotation: data.rotation,
+                    volume,
+                }),
+                Err(e) => error!(
+                    "Failed to read cubemap volume at {table_hash}@0x{:X}: {e:?}",
+                    data.data_resource.offset
+                ),
+            }
+        }
+
+        Ok(cubemaps)
+    }
+
+    /// Writes the cubemap and voxel IBL textures of every probe to `dir`, along with a
+    /// `cubemaps.json` manifest. Textures shared between probes are written once. Returns the path
+    /// of the manifest
+    pub fn write(&self, dir: &Path) -> anyhow::Result<PathBuf> {
+        std::fs::create_dir_all(dir)
+            .with_context(|| format!("Failed to create {}", dir.display()))?;
+
+        let mut written: FxHashMap<TagHash, Value> = FxHashMap::default();
+        let mut probes = vec![];
+        for (i, probe) in self.probes.iter().enumerate() {
+            let v = &probe.volume;
+            let mut texture_json =
+                |hash: TagHash, write: fn(TagHash, &Path) -> anyhow::Result<Value>| {
+                    if hash.is_none() {
+                        return Value::Null;
+                    }
+
+                    written
+                        .entry(hash)
+                        .or_insert_with(|| {
+                            write(hash, dir).unwrap_or_else(|e| {
+                                error!("Failed to export probe texture {hash}: {e:?}");
+                                json!({
+                                    "texture": hash.to_string(),
+                                    "error": e.to_string(),
+                                })
+                            })
+                        })
+                        .clone()
+                };
+
+            let cubemap = texture_json(v.cubemap_texture, write_cubemap);
+            let voxel_ibl = texture_json(v.voxel_ibl_texture, write_voxel_ibl);
+
+            probes.push(json!({
+                "index": i,
+                "table": probe.table.to_string(),
+                "resource_offset": probe.resource_offset,
+                "influence_volume": {
+                    "shape": "box",
+                    "center": probe.translation.to_array(),
+                    "rotation": probe.rotation.to_array(),
+                    "half_extents": v.cubemap_extents.xyz().to_array(),
+                },
+                "cubemap_center": v.cubemap_center.to_array(),
+                "cubemap": cubemap,
+                "voxel_ibl": voxel_ibl,
+                "skymask_texture": v._unk_cubemap_skymask.to_string(),
+                "unknown": {
+                    "unk40": v.unk40,
+                    "unk44": v.unk44,
+                    "unk50": v.unk50.to_array(),
+                    "unk60": v.unk60.to_array(),
+                    "unk70": v.unk70,
+                    "unkc0": v.unkc0.to_cols_array(),
+                    "unk100": v.unk100.to_array(),
+                    "unk110": v.unk110.to_array(),
+                    "unk120": v.unk120.to_cols_array(),
+                    "unk160": v.unk160.to_array(),
+                    "unk170": v.unk170.to_array(),
+                    "unk180": v.unk180.to_array(),
+                    "unk190": v.unk190.to_array(),
+                    "unk1a0": v.unk1a0,
+                    "unk1c4": v.unk1c4,
+                },
+            }));
+        }
+
+        let manifest = dir.join("cubemaps.json");
+        std::fs::write(
+            &manifest,
+            serde_json::to_string_pretty(&json!({
+                "map": self.map_hash.to_string(),
+                "map_name": self.map_name,
+                "coordinate_system": "z_up",
+                "probes": probes,
+            }))?,
+        )
+        .with_context(|| format!("Failed to write {}", manifest.display()))?;
+
+        Ok(manifest)
+    }
+}
+
+/// Writes a cubemap as a KTX2 file when it can be passed through, or as EXR faces otherwise
+fn write_cubemap(hash: TagHash, dir: &Path) -> anyhow::Result<Value> {
+    let (header, data) = Texture::load_data(WideHash::Hash32(hash), true)?;
+    let name = format!("cubemap_{hash}");
+    anyhow::ensure!(
+        header.array_size >= 6 && header.array_size % 6 == 0,
+        "Texture {hash} is not a cubemap (array size {})",
+        header.array_size
+    );
+
+    if is_ktx2_supported(header.format) {
+        let width = header.width as usize;
+        let height = header.height as usize;
+        let layers = header.array_size as usize;
+
+        // Mips are stored largest first, with every face of a mip back to back
+        let mut levels = vec![];
+        let mut offset = 0;
+        for mip in 0..header.mip_count.max(1) as usize {
+            let (_, slice_pitch) = header
+                .format
+                .calculate_pitch((width >> mip).max(1), (height >> mip).max(1));
+            let Some(level) = data.get(offset..offset + slice_pitch * layers) else {
+                warn!(
+                    "Cubemap {hash} only has data for {mip} of {} mips",
+                    header.mip_count
+                );
+                break;
+            };
+
+            levels.push(level);
+            offset += slice_pitch * layers;
+        }
+
+        let file = format!("{name}.ktx2");
+        std::fs::write(
+            dir.join(&file),
+            encode_ktx2(header.format, width, height, layers / 6, 6, &levels)?,
+        )?;
+
+        return Ok(json!({
+            "texture": hash.to_string(),
+            "format": format!("{:?}", header.format),
+            "container": "ktx2",
+            "width": width,
+            "height": height,
+            "mip_count": levels.len(),
+            "files": [file],
+        }));
+    }
+
+    let decoded = DecodedTexture::decode(&header, &data)
+        .with_context(|| format!("Failed to decode cubemap {hash}"))?;
+    let mut files = vec![];
+    for (face, suffix) in CUBE_FACES.iter().enumerate() {
+        let file = format!("{name}_{suffix}.exr");
+        std::fs::write(dir.join(&file), layer_to_exr(&decoded, face)?)?;
+        files.push(file);
+    }
+
+    Ok(json!({
+        "texture": hash.to_string(),
+        "format": format!("{:?}", decoded.format),
+        "container": "exr",
+        "width": decoded.width,
+        "height": decoded.height,
+        "mip_count": 1,
+        "files": files,
+    }))
+}
+
+/// Writes the voxel IBL volume as an EXR with the depth slices stacked from top to bottom
+fn write_voxel_ibl(hash: TagHash, dir: &Path) -> anyhow::Result<Value> {
+    let decoded = DecodedTexture::load(hash)?;
+    let file = format!("voxel_ibl_{hash}.exr");
+
+    let channel = |c: usize| -> Vec<f32> { decoded.pixels.iter().map(|p| p[c]).collect() };
+    let (r, g, b, a) = (channel(0), channel(1), channel(2), channel(3));
+    std::fs::write(
+        dir.join(&file),
+        encode_exr(
+            decoded.width,
+            decoded.height * decoded.layers,
+            &[("R", &r), ("G", &g), ("B", &b), ("A", &a)],
+        )?,
+    )?;
+
+    Ok(json!({
+        "texture": hash.to_string(),
+        "format": format!("{:?}", decoded.format),
+        "container": "exr",
+        "width": decoded.width,
+        "height": decoded.height,
+        "depth": decoded.layers,
+        "layout": "depth slices stacked vertically, slice 0 at the top",
+        "files": [file],
+    }))
+}
+
+fn layer_to_exr(decoded: &DecodedTexture, layer: usize) -> anyhow::Result<Vec<u8>> {
+    let pixels = decoded.layer(layer);
+    let channel = |c: usize| -> Vec<f32> { pixels.iter().map(|p| p[c]).collect() };
+    let (r, g, b, a) = (channel(0), channel(1), channel(2), channel(3));
+    encode_exr(
+        decoded.width,
+        decoded.height,
+        &[("R", &r), ("G", &g), ("B", &b), ("A", &a)],
+    )
+}
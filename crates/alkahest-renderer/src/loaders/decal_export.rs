@@ -0,0 +1,342 @@
+//! Exports map decals as axis aligned projector boxes in a glTF document, with a material per decal
+//! technique and a JSON sidecar listing every decal, see [`alkahest_data::decal`] for the layout
+
+use std::{
+    io::{Seek, SeekFrom},
+    path::{Path, PathBuf},
+};
+
+use alkahest_data::{
+    decal::{DecalInstance, DecalProjector, SMapDecals},
+    technique::STechnique,
+    text::StringContainer,
+};
+use alkahest_pm::package_manager;
+use anyhow::Context;
+use binrw::BinReaderExt;
+use destiny_pkg::TagHash;
+use glam::{Quat, Vec3, Vec4};
+use indexmap::IndexMap;
+use rustc_hash::FxHashSet;
+use serde_json::{json, Value};
+use tiger_parse::PackageManagerExt;
+
+use crate::{
+    ecs::tags::NodeFilter,
+    loaders::{
+        gltf::{to_gltf_space, GltfBuilder, GLTF_FLOAT, GLTF_UNSIGNED_SHORT},
+        map_data::MapDataTables,
+        texture_decode::f16_to_f32,
+        vertex_buffer::read_vertex_buffer,
+    },
+};
+
+pub struct MapDecal {
+    pub collection: TagHash,
+    pub instance: DecalInstance,
+    pub projector: DecalProjector,
+}
+
+/// Pixel stage textures of a decal technique, by slot
+pub struct DecalMaterial {
+    pub textures: Vec<(u32, TagHash)>,
+}
+
+impl DecalMaterial {
+    pub fn load(technique: TagHash) -> anyhow::Result<Self> {
+        let technique: STechnique = package_manager().read_tag_struct(technique)?;
+        Ok(Self {
+            textures: technique
+                .shader_pixel
+                .textures
+                .iter()
+                .map(|t| (t.slot, t.texture.hash32()))
+                .collect(),
+        })
+    }
+}
+
+/// Reads a decal collection along with its decals
+pub fn load_decal_collection(hash: TagHash) -> anyhow::Result<(SMapDecals, Vec<DecalInstance>)> {
+    let decals: SMapDecals = package_manager()
+        .read_tag_struct(hash)
+        .context("Failed to read decal collection")?;
+    let points = read_decal_points(decals.instance_points)
+        .with_context(|| format!("Failed to read decal points {}", decals.instance_points))?;
+    let instances = decals.instances(&points);
+
+    Ok((decals, instances))
+}
+
+fn read_decal_points(hash: TagHash) -> anyhow::Result<Vec<Vec4>> {
+    let (data, stride) = read_vertex_buffer(hash)?;
+    let points = match stride {
+        16 => data
+            .chunks_exact(16)
+            .map(|p| {
+                Vec4::from_array(std::array::from_fn(|i| {
+                    f32::from_le_bytes([p[i * 4], p[i * 4 + 1], p[i * 4 + 2], p[i * 4 + 3]])
+                }))
+            })
+            .collect(),
+        8 => data
+            .chunks_exact(8)
+            .map(|p| {
+                Vec4::from_array(std::array::from_fn(|i| {
+                    f16_to_f32(u16::from_le_bytes([p[i * 2], p[i * 2 + 1]]))
+                }))
+            })
+            .collect(),
+        s => anyhow::bail!("Unsupported decal point stride {s}"),
+    };
+
+    Ok(points)
+}
+
+#[derive(Default)]
+pub struct MapDecals {
+    pub map_hash: TagHash,
+    pub map_name: String,
+    pub decals: Vec<MapDecal>,
+    /// Materials in the order they are first used
+    pub materials: IndexMap<TagHash, DecalMaterial>,
+}
+
+impl MapDecals {
+    pub fn gather(map_hash: TagHash, stringmap: &StringContainer) -> anyhow::Result<Self> {
+        let tables = MapDataTables::load(map_hash)?;
+        let mut decals = MapDecals {
+            map_hash,
+            map_name: stringmap.get(tables.map_name),
+            ..Default::default()
+        };
+
+        let mut seen = FxHashSet::default();
+        // Decal collection
+        for entry in tables.resources(0x80806e62) {
+            let (_, _, mut cur) = match entry {
+                Ok(entry) => entry,
+                Err(e) => {
+                    error!("{e:?}");
+                    continue;
+                }
+            };
+            cur.seek(SeekFrom::Current(16))?;
+            let tag: TagHash = cur.read_le()?;
+            if tag.is_none() || !seen.insert(tag) {
+                continue;
+            }
+
+            match load_decal_collection(tag) {
+                Ok((_, instances)) => decals.add_collection(tag, instances),
+                Err(e) => error!("Failed to read decal collection {tag}: {e:?}"),
+            }
+        }
+
+        Ok(decals)
+    }
+
+    fn add_collection(&mut self, collection: TagHash, instances: Vec<DecalInstance>) {
+        for instance in instances {
+            if !self.materials.contains_key(&instance.material) {
+                let material = DecalMaterial::load(instance.material).unwrap_or_else(|e| {
+                    error!(
+                        "Failed to read decal technique {}: {e:?}",
+                        instance.material
+                    );
+                    DecalMaterial { textures: vec![] }
+                });
+                self.materials.insert(instance.material, material);
+            }
+
+            self.decals.push(MapDecal {
+                collection,
+                projector: instance.projector(),
+                instance,
+            });
+        }
+    }
+
+    /// Writes the glTF document to `path` with its buffer as `<name>.bin`, and the JSON decal list as
+    /// `<name>.decals.json`. Returns the JSON path
+    pub fn write(&self, path: &Path) -> anyhow::Result<PathBuf> {
+        let bin_path = path.with_extension("bin");
+        let (gltf, buffer) = self.to_gltf(
+            &bin_path
+                .file_name()
+                .context("Invalid output path")?
+                .to_string_lossy(),
+        );
+
+        std::fs::write(path, serde_json::to_string_pretty(&gltf)?)
+            .with_context(|| format!("Failed to write {}", path.display()))?;
+        std::fs::write(&bin_path, buffer)
+            .with_context(|| format!("Failed to write {}", bin_path.display()))?;
+
+        let json_path = path.with_extension("decals.json");
+        std::fs::write(&json_path, serde_json::to_string_pretty(&self.to_json())?)
+            .with_context(|| format!("Failed to write {}", json_path.display()))?;
+
+        Ok(json_path)
+    }
+
+    fn material_json(&self, technique: TagHash) -> Value {
+        let textures = self
+            .materials
+            .get(&technique)
+            .map(|m| {
+                m.textures
+                    .iter()
+                    .map(|(slot, texture)| json!({ "slot": slot, "texture": texture.to_string() }))
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+
+        json!({
+            "technique": technique.to_string(),
+            "textures": textures,
+        })
+    }
+
+    /// Decal list with world space projector boxes, in Destiny's Z-up coordinate space
+    pub fn to_json(&self) -> Value {
+        json!({
+            "map": self.map_hash.to_string(),
+            "map_name": self.map_name,
+            "materials": self.materials.keys().map(|t| self.material_json(*t)).collect::<Vec<_>>(),
+            "decals": self.decals.iter().map(|d| {
+                let i = &d.instance;
+                json!({
+                    "collection": d.collection.to_string(),
+                    "index": i.index,
+                    "technique": i.material.to_string(),
+                    "position": i.position.to_array(),
+                    "scale": i.scale,
+                    "bounds": i.bounds.map(|b| json!({
+                        "min": b.min.to_array(),
+                        "max": b.max.to_array(),
+                    })),
+                    "projector": {
+                        "center": d.projector.center.to_array(),
+                        "half_extents": d.projector.half_extents.to_array(),
+                        "projection_axis": d.projector.projection_axis().to_array(),
+                    },
+                })
+            }).collect::<Vec<_>>(),
+        })
+    }
+
+    /// A node per decal, scaling a shared unit cube into its projector box. Every technique gets its own
+    /// material, named after the technique hash
+    pub fn to_gltf(&self, buffer_uri: &str) -> (Value, Vec<u8>) {
+        let mut gltf = GltfBuilder::default();
+
+        let corners: Vec<[f32; 3]> = (0..8)
+            .map(|i| {
+                let corner = Vec3::new(
+                    if i & 1 == 0 { -1.0 } else { 1.0 },
+                    if i & 2 == 0 { -1.0 } else { 1.0 },
+                    if i & 4 == 0 { -1.0 } else { 1.0 },
+                );
+                to_gltf_space(corner, Quat::IDENTITY).0.to_array()
+            })
+            .collect();
+        // Corner bits 0, 1 and 2 select +X, +Y and +Z. Two triangles per face, wound outwards
+        let indices: [u16; 36] = [
+            0, 2, 1, 1, 2, 3, //
+            4, 5, 6, 5, 7, 6, //
+            0, 1, 4, 1, 5, 4, //
+            2, 6, 3, 3, 6, 7, //
+            0, 4, 2, 2, 4, 6, //
+            1, 3, 5, 3, 7, 5, //
+        ];
+
+        let position = gltf.accessor(
+            bytemuck::cast_slice(&corners),
+            corners.len(),
+            GLTF_FLOAT,
+            "VEC3",
+            false,
+            Some(([-1.0; 3], [1.0; 3])),
+        );
+        let indices = gltf.accessor(
+            bytemuck::cast_slice(&indices),
+            indices.len(),
+            GLTF_UNSIGNED_SHORT,
+            "SCALAR",
+            false,
+            None,
+        );
+
+        let [r, g, b, _] = NodeFilter::Decal.color().to_array();
+        let mut materials = vec![];
+        let mut meshes = vec![];
+        for technique in self.materials.keys() {
+            materials.push(json!({
+                "name": technique.to_string(),
+                "pbrMetallicRoughness": {
+                    "baseColorFactor": [r, g, b, 0.5],
+                    "metallicFactor": 0.0,
+                    "roughnessFactor": 1.0,
+                },
+                "alphaMode": "BLEND",
+                "doubleSided": true,
+                "extras": self.material_json(*technique),
+            }));
+            meshes.push(json!({
+                "name": format!("Decal {technique}"),
+                "primitives": [{
+                    "attributes": { "POSITION": position },
+                    "indices": indices,
+                    "material": materials.len() - 1,
+                }],
+            }));
+        }
+
+        let nodes: Vec<Value> = self
+            .decals
+            .iter()
+            .map(|d| {
+                let p = &d.projector;
+                let (translation, rotation) = to_gltf_space(p.center, Quat::IDENTITY);
+                let (axis, _) = to_gltf_space(p.projection_axis(), Quat::IDENTITY);
+                let mesh = self
+                    .materials
+                    .get_index_of(&d.instance.material)
+                    .unwrap_or_default();
+
+                json!({
+                    "name": format!("Decal {}@{}", d.collection, d.instance.index),
+                    "mesh": mesh,
+                    "translation": translation.to_array(),
+                    "rotation": rotation.to_array(),
+                    // The cube is already in glTF space, so the box's Y and Z extents swap places
+                    "scale": [p.half_extents.x, p.half_extents.z, p.half_extents.y],
+                    "extras": {
+                        "collection": d.collection.to_string(),
+                        "index": d.instance.index,
+                        "technique": d.instance.material.to_string(),
+                        "projection_axis": axis.to_array(),
+                    },
+                })
+            })
+            .collect();
+
+        let document = json!({
+            "asset": {
+                "version": "2.0",
+                "generator": concat!("alkahest ", env!("CARGO_PKG_VERSION")),
+            },
+            "scene": 0,
+            "scenes": [{
+                "name": self.map_name,
+                "nodes": (0..nodes.len()).collect::<Vec<_>>(),
+            }],
+            "nodes": nodes,
+            "meshes": meshes,
+            "materials": materials,
+        });
+
+        gltf.finish(document, buffer_uri)
+    }
+}
@@ -0,0 +1,322 @@
+//! Exports decorator (foliage scatter) instances as CSV, PLY point clouds or glTF `EXT_mesh_gpu_instancing` nodes
+
+use std::{
+    fmt::Write,
+    io::{Seek, SeekFrom},
+    path::{Path, PathBuf},
+};
+
+use alkahest_data::{
+    decorator::{DecoratorInstance, SDecorator},
+    occlusion::Aabb,
+    text::StringContainer,
+};
+use alkahest_pm::package_manager;
+use anyhow::Context;
+use binrw::BinReaderExt;
+use destiny_pkg::TagHash;
+use glam::{Quat, Vec3};
+use rustc_hash::FxHashSet;
+use serde_json::{json, Value};
+use tiger_parse::PackageManagerExt;
+
+use crate::loaders::{
+    gltf::{to_gltf_space, GltfBuilder, GLTF_FLOAT, GLTF_UNSIGNED_BYTE, GLTF_UNSIGNED_SHORT},
+    map_data::MapDataTables,
+};
+
+pub struct MapDecorator {
+    pub hash: TagHash,
+    /// Entity model and its local bounds, per model index
+    pub models: Vec<(TagHash, Aabb)>,
+    pub instances: Vec<DecoratorInstance>,
+}
+
+impl MapDecorator {
+    pub fn new(hash: TagHash, decorator: &SDecorator) -> Self {
+        Self {
+            hash,
+            models: decorator
+                .unk8
+                .iter()
+                .map(|m| (m.entity_model, m.bb))
+                .collect(),
+            instances: decorator.instances(),
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct MapDecorators {
+    pub map_hash: TagHash,
+    pub map_name: String,
+    pub decorators: Vec<MapDecorator>,
+}
+
+impl MapDecorators {
+    pub fn gather(map_hash: TagHash, stringmap: &StringContainer) -> anyhow::Result<Self> {
+        let tables = MapDataTables::load(map_hash)?;
+        let mut decorators = MapDecorators {
+            map_hash,
+            map_name: stringmap.get(tables.map_name),
+            ..Default::default()
+        };
+
+        let mut seen = FxHashSet::default();
+        for entry in tables.resources(0x80806cc3) {
+            let (_, _, mut cur) = match entry {
+                Ok(entry) => entry,
+                Err(e) => {
+                    error!("{e:?}");
+                    continue;
+                }
+            };
+            cur.seek(SeekFrom::Current(16))?;
+            let tag: TagHash = cur.read_le()?;
+            if tag.is_none() || !seen.insert(tag) {
+                continue;
+            }
+
+            match package_manager().read_tag_struct::<SDecorator>(tag) {
+                Ok(decorator) => decorators
+                    .decorators
+                    .push(MapDecorator::new(tag, &decorator)),
+                Err(e) => error!("Failed to read decorator {tag}: {e}"),
+            }
+        }
+
+        Ok(decorators)
+    }
+
+    pub fn instance_count(&self) -> usize {
+        self.decorators.iter().map(|d| d.instances.len()).sum()
+    }
+
+    /// One row per instance, in Destiny's Z-up coordinate space
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from(
+            "decorator,model_index,entity_model,identifier,x,y,z,qx,qy,qz,qw,r,g,b,a\n",
+        );
+        for decorator in &self.decorators {
+            for i in &decorator.instances {
+                let [r, g, b, a] = i.color;
+                writeln!(
+                    csv,
+                    "{},{},{},{},{},{},{},{},{},{},{},{r},{g},{b},{a}",
+                    decorator.hash,
+                    i.model_index,
+                    i.entity_model,
+                    i.identifier,
+                    i.translation.x,
+                    i.translation.y,
+                    i.translation.z,
+                    i.rotation.x,
+                    i.rotation.y,
+                    i.rotation.z,
+                    i.rotation.w,
+                )
+                .unwrap();
+            }
+        }
+
+        csv
+    }
+
+    /// ASCII PLY point cloud with instance colors, in Destiny's Z-up coordinate space
+    pub fn to_ply(&self) -> String {
+        let mut ply = String::new();
+        writeln!(ply, "ply\nformat ascii 1.0").unwrap();
+        writeln!(ply, "comment alkahest decorators of {}", self.map_hash).unwrap();
+        writeln!(ply, "element vertex {}", self.instance_count()).unwrap();
+        for p in ["x", "y", "z"] {
+            writeln!(ply, "property float {p}").unwrap();
+        }
+        for p in ["red", "green", "blue", "alpha"] {
+            writeln!(ply, "property uchar {p}").unwrap();
+        }
+        writeln!(ply, "end_header").unwrap();
+
+        for i in self.decorators.iter().flat_map(|d| &d.instances) {
+            let [r, g, b, a] = i.color;
+            writeln!(
+                ply,
+                "{} {} {} {r} {g} {b} {a}",
+                i.translation.x, i.translation.y, i.translation.z
+            )
+            .unwrap();
+        }
+
+        ply
+    }
+
+    /// Writes a glTF document with one `EXT_mesh_gpu_instancing` node per decorator model, and its
+    /// buffer next to it as `<name>.bin`. Models are exported as boxes matching their bounds, with the
+    /// entity model hash as the mesh name so they can be swapped out for the real models
+    pub fn write_gltf(&self, path: &Path) -> anyhow::Result<PathBuf> {
+        let bin_path = path.with_extension("bin");
+        let (gltf, buffer) = self.to_gltf(
+            &bin_path
+                .file_name()
+                .context("Invalid output path")?
+                .to_string_lossy(),
+        );
+
+        std::fs::write(path, serde_json::to_string_pretty(&gltf)?)
+            .with_context(|| format!("Failed to write {}", path.display()))?;
+        std::fs::write(&bin_path, buffer)
+            .with_context(|| format!("Failed to write {}", bin_path.display()))?;
+
+        Ok(bin_path)
+    }
+
+    pub fn to_gltf(&self, buffer_uri: &str) -> (Value, Vec<u8>) {
+        let mut gltf = GltfBuilder::default();
+        let mut meshes = vec![];
+        let mut nodes = vec![];
+        for decorator in &self.decorators {
+            for (model_index, (entity_model, bounds)) in decorator.models.iter().enumerate() {
+                let instances: Vec<&DecoratorInstance> = decorator
+                    .instances
+                    .iter()
+                    .filter(|i| i.model_index == model_index)
+                    .collect();
+                if instances.is_empty() {
+                    continue;
+                }
+
+                let (positions, indices) = box_mesh(bounds);
+                let position = gltf.accessor(
+                    bytemuck::cast_slice(&positions),
+                    positions.len(),
+                    GLTF_FLOAT,
+                    "VEC3",
+                    false,
+                    Some(min_max(&positions)),
+                );
+                let indices = gltf.accessor(
+                    bytemuck::cast_slice(&indices),
+                    indices.len(),
+                    GLTF_UNSIGNED_SHORT,
+                    "SCALAR",
+                    false,
+                    None,
+                );
+
+                let (translations, rotations): (Vec<[f32; 3]>, Vec<[f32; 4]>) = instances
+                    .iter()
+                    .map(|i| {
+                        let (t, r) = to_gltf_space(i.translation, i.rotation);
+                        (t.to_array(), r.to_array())
+                    })
+                    .unzip();
+                let colors: Vec<[u8; 4]> = instances.iter().map(|i| i.color).collect();
+
+                let translation = gltf.accessor(
+                    bytemuck::cast_slice(&translations),
+                    instances.len(),
+                    GLTF_FLOAT,
+                    "VEC3",
+                    false,
+                    None,
+                );
+                let rotation = gltf.accessor(
+                    bytemuck::cast_slice(&rotations),
+                    instances.len(),
+                    GLTF_FLOAT,
+                    "VEC4",
+                    false,
+                    None,
+                );
+                let color = gltf.accessor(
+                    bytemuck::cast_slice(&colors),
+                    instances.len(),
+                    GLTF_UNSIGNED_BYTE,
+                    "VEC4",
+                    true,
+                    None,
+                );
+
+                nodes.push(json!({
+                    "name": format!("{} {entity_model}", decorator.hash),
+                    "mesh": meshes.len(),
+                    "extensions": {
+                        "EXT_mesh_gpu_instancing": {
+                            "attributes": {
+                                "TRANSLATION": translation,
+                                "ROTATION": rotation,
+                                "_COLOR": color,
+                            }
+                        }
+                    },
+                    "extras": {
+                        "decorator": decorator.hash.to_string(),
+                        "entity_model": entity_model.to_string(),
+                        "model_index": model_index,
+                    },
+                }));
+                meshes.push(json!({
+                    "name": entity_model.to_string(),
+                    "primitives": [{
+                        "attributes": { "POSITION": position },
+                        "indices": indices,
+                    }],
+                }));
+            }
+        }
+
+        let gltf_json = json!({
+            "asset": {
+                "version": "2.0",
+                "generator": concat!("alkahest ", env!("CARGO_PKG_VERSION")),
+            },
+            "extensionsUsed": ["EXT_mesh_gpu_instancing"],
+            "scene": 0,
+            "scenes": [{
+                "name": self.map_name,
+                "nodes": (0..nodes.len()).collect::<Vec<_>>(),
+            }],
+            "nodes": nodes,
+            "meshes": meshes,
+        });
+
+        gltf.finish(gltf_json, buffer_uri)
+    }
+}
+
+/// Box spanning `bounds`, in glTF space
+fn box_mesh(bounds: &Aabb) -> (Vec<[f32; 3]>, Vec<u16>) {
+    let positions = bounds
+        .corners()
+        .map(|c| to_gltf_space(c, Quat::IDENTITY).0.to_array())
+        .to_vec();
+
+    // Faces are emitted with both windings so the proxies show up regardless of backface culling
+    let mut indices = vec![];
+    for face in [
+        [0, 1, 3, 2],
+        [4, 5, 7, 6],
+        [0, 1, 5, 4],
+        [2, 3, 7, 6],
+        [0, 2, 6, 4],
+        [1, 3, 7, 5],
+    ] {
+        for [a, b, c] in [[face[0], face[1], face[2]], [face[0], face[2], face[3]]] {
+            indices.extend_from_slice(&[a, b, c, a, c, b]);
+        }
+    }
+
+    (positions, indices)
+}
+
+fn min_max(positions: &[[f32; 3]]) -> ([f32; 3], [f32; 3]) {
+    let min = positions
+        .iter()
+        .fold(Vec3::splat(f32::INFINITY), |acc, p| acc.min(Vec3::from(*p)));
+    let max = positions
+        .iter()
+        .fold(Vec3::splat(f32::NEG_INFINITY), |acc, p| {
+            acc.max(Vec3::from(*p))
+        });
+
+    (min.to_array(), max.to_array())
+}
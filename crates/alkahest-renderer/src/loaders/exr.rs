@@ -0,0 +1,102 @@
+//! Minimal OpenEXR writer for exporting float data, such as heightmaps and HDR cubemaps
+//!
+//! Only writes uncompressed scanline images with 32-bit float channels, which every EXR reader
+//! supports.
+
+use anyhow::ensure;
+
+/// Encodes an uncompressed EXR image. Every channel must hold `width * height` values
+pub fn encode_exr(
+    width: usize,
+    height: usize,
+    channels: &[(&str, &[f32])],
+) -> anyhow::Result<Vec<u8>> {
+    ensure!(width > 0 && height > 0, "Image is empty");
+    ensure!(!channels.is_empty(), "Image has no channels");
+    for (name, data) in channels {
+        ensure!(
+            data.len() == width * height,
+            "Channel {name} has {} values, expected {}",
+            data.len(),
+            width * height
+        );
+    }
+
+    // Channels have to be stored in alphabetical order
+    let mut channels = channels.to_vec();
+    channels.sort_by_key(|(name, _)| *name);
+
+    let mut header = vec![];
+    let mut chlist = vec![];
+    for (name, _) in &channels {
+        chlist.extend_from_slice(name.as_bytes());
+        chlist.push(0);
+        // Pixel type FLOAT
+        chlist.extend_from_slice(&2i32.to_le_bytes());
+        // pLinear + reserved
+        chlist.extend_from_slice(&[0; 4]);
+        // x/y sampling
+        chlist.extend_from_slice(&1i32.to_le_bytes());
+        chlist.extend_from_slice(&1i32.to_le_bytes());
+    }
+    chlist.push(0);
+    write_attribute(&mut header, "channels", "chlist", &chlist);
+
+    // NO_COMPRESSION
+    write_attribute(&mut header, "compression", "compression", &[0]);
+
+    let window: Vec<u8> = [0, 0, width as i32 - 1, height as i32 - 1]
+        .iter()
+        .flat_map(|v| v.to_le_bytes())
+        .collect();
+    write_attribute(&mut header, "dataWindow", "box2i", &window);
+    write_attribute(&mut header, "displayWindow", "box2i", &window);
+
+    // INCREASING_Y
+    write_attribute(&mut header, "lineOrder", "lineOrder", &[0]);
+    write_attribute(
+        &mut header,
+        "pixelAspectRatio",
+        "float",
+        &1.0f32.to_le_bytes(),
+    );
+    write_attribute(&mut header, "screenWindowCenter", "v2f", &[0; 8]);
+    write_attribute(
+        &mut header,
+        "screenWindowWidth",
+        "float",
+        &1.0f32.to_le_bytes(),
+    );
+    header.push(0);
+
+    let mut data = vec![0x76, 0x2f, 0x31, 0x01, 2, 0, 0, 0];
+    data.extend_from_slice(&header);
+
+    // One scanline per block, so the offset table has an entry per line
+    let line_size = 8 + width * 4 * channels.len();
+    let table_end = data.len() + height * 8;
+    for y in 0..height {
+        data.extend_from_slice(&((table_end + y * line_size) as u64).to_le_bytes());
+    }
+
+    for y in 0..height {
+        data.extend_from_slice(&(y as i32).to_le_bytes());
+        data.extend_from_slice(&((width * 4 * channels.len()) as i32).to_le_bytes());
+        for (_, values) in &channels {
+            for v in &values[y * width..(y + 1) * width] {
+                data.extend_from_slice(&v.to_le_bytes());
+            }
+        }
+    }
+
+    Ok(data)
+}
+
+fn write_attribute(header: &mut Vec<u8>, name: &str, kind: &str, value: &[u8]) {
+    header.extend_from_slice(name.as_bytes());
+    header.push(0);
+    header.extend_from_slice(kind.as_bytes());
+    header.push(0);
+    header.extend_from_slice(&(value.len() as i32).to_le_bytes());
+    header.extend_from_slice(value);
+}
@@ -0,0 +1,95 @@
+//! Loads gear dyes and evaluates their constants, see [`alkahest_data::dye`] for the layout
+
+use alkahest_data::{
+    dye::{find_entity_dyes, GearDyeParameters, GearDyeSlot, SGearDye},
+    entity::{SDynamicMeshPart, SEntity},
+};
+use alkahest_pm::package_manager;
+use destiny_pkg::TagHash;
+use glam::Vec4;
+use serde::Serialize;
+use tiger_parse::PackageManagerExt;
+
+use crate::{loaders::technique::evaluate_cbuffer_cpu, tfx::externs::ExternStorage};
+
+#[derive(Debug, Clone, Serialize)]
+pub struct GearDyeTexture {
+    pub slot: u32,
+    #[serde(serialize_with = "alkahest_data::tag::serialize_taghash")]
+    pub texture: TagHash,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct GearDye {
+    #[serde(serialize_with = "alkahest_data::tag::serialize_taghash")]
+    pub hash: TagHash,
+    /// Detail textures (diffuse, normal and wear masks) of the dye
+    pub textures: Vec<GearDyeTexture>,
+    pub parameters: GearDyeParameters,
+    /// Constant buffer after evaluating the dye bytecode with default externs
+    #[serde(skip)]
+    pub cbuffer: Vec<Vec4>,
+}
+
+impl GearDye {
+    pub fn load(hash: TagHash) -> anyhow::Result<Self> {
+        let dye: SGearDye = package_manager().read_tag_struct(hash)?;
+        let (mut cbuffer, _) = evaluate_cbuffer_cpu(&dye.shader, &ExternStorage::default())?;
+        if cbuffer.len() < GearDyeParameters::CBUFFER_SIZE {
+            cbuffer.resize(GearDyeParameters::CBUFFER_SIZE, Vec4::ZERO);
+        }
+
+        Ok(Self {
+            hash,
+            textures: dye
+                .shader
+                .textures
+                .iter()
+                .map(|t| GearDyeTexture {
+                    slot: t.slot,
+                    texture: t.texture.hash32(),
+                })
+                .collect(),
+            parameters: GearDyeParameters::from_cbuffer(&cbuffer),
+            cbuffer,
+        })
+    }
+}
+
+/// The dyes referenced by an entity, indexed by [`GearDyeSlot::dye_index`]
+///
+/// Loading these scans the entity resources (see [`find_entity_dyes`]), so it's done on demand
+#[derive(Debug, Clone, Default)]
+pub struct EntityDyes {
+    /// Dyes that failed to load are kept as `None`, so the remaining dyes keep their index
+    pub dyes: Vec<Option<GearDye>>,
+}
+
+impl EntityDyes {
+    pub fn load(entity: &SEntity) -> Self {
+        let dyes = find_entity_dyes(entity)
+            .into_iter()
+            .map(|hash| {
+                GearDye::load(hash)
+                    .map_err(|e| error!("Failed to load gear dye {hash}: {e:?}"))
+                    .ok()
+            })
+            .collect();
+
+        Self { dyes }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.dyes.is_empty()
+    }
+
+    pub fn dye(&self, slot: GearDyeSlot) -> Option<&GearDye> {
+        self.dyes.get(slot.dye_index())?.as_ref()
+    }
+
+    /// Resolves the dye slot of a part, along with the dye coloring it
+    pub fn part_dye(&self, part: &SDynamicMeshPart) -> Option<(GearDyeSlot, &GearDye)> {
+        let slot = GearDyeSlot::from_change_color_index(part.gear_dye_change_color_index)?;
+        Some((slot, self.dye(slot)?))
+    }
+}
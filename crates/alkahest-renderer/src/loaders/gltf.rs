@@ -0,0 +1,76 @@
+//! Shared helpers for the glTF exporters
+
+use glam::{Quat, Vec3};
+use serde_json::{json, Value};
+
+pub const GLTF_UNSIGNED_BYTE: u32 = 5121;
+pub const GLTF_UNSIGNED_SHORT: u32 = 5123;
+pub const GLTF_UNSIGNED_INT: u32 = 5125;
+pub const GLTF_FLOAT: u32 = 5126;
+
+/// Converts a transform from Destiny's Z-up space to glTF's Y-up space
+pub fn to_gltf_space(translation: Vec3, rotation: Quat) -> (Vec3, Quat) {
+    let basis = Quat::from_rotation_x(-std::f32::consts::FRAC_PI_2);
+    (
+        basis * translation,
+        (basis * rotation * basis.inverse()).normalize(),
+    )
+}
+
+/// Builds up the single binary buffer of a glTF document
+#[derive(Default)]
+pub struct GltfBuilder {
+    pub buffer: Vec<u8>,
+    pub buffer_views: Vec<Value>,
+    pub accessors: Vec<Value>,
+}
+
+impl GltfBuilder {
+    /// Appends `data` as a new buffer view and returns the index of the accessor describing it
+    pub fn accessor(
+        &mut self,
+        data: &[u8],
+        count: usize,
+        component_type: u32,
+        kind: &str,
+        normalized: bool,
+        min_max: Option<([f32; 3], [f32; 3])>,
+    ) -> usize {
+        while self.buffer.len() % 4 != 0 {
+            self.buffer.push(0);
+        }
+
+        self.buffer_views.push(json!({
+            "buffer": 0,
+            "byteOffset": self.buffer.len(),
+            "byteLength": data.len(),
+        }));
+        self.buffer.extend_from_slice(data);
+
+        let mut accessor = json!({
+            "bufferView": self.buffer_views.len() - 1,
+            "componentType": component_type,
+            "count": count,
+            "type": kind,
+        });
+        if normalized {
+            accessor["normalized"] = json!(true);
+        }
+        if let Some((min, max)) = min_max {
+            accessor["min"] = json!(min);
+            accessor["max"] = json!(max);
+        }
+
+        self.accessors.push(accessor);
+        self.accessors.len() - 1
+    }
+
+    /// Adds the accessors, buffer views and the buffer itself to `document`, and returns it along with the buffer data
+    pub fn finish(self, mut document: Value, buffer_uri: &str) -> (Value, Vec<u8>) {
+        document["accessors"] = json!(self.accessors);
+        document["bufferViews"] = json!(self.buffer_views);
+        document["buffers"] = json!([{ "uri": buffer_uri, "byteLength": self.buffer.len() }]);
+
+        (document, self.buffer)
+    }
+}
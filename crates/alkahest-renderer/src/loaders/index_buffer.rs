@@ -106,3 +106,58 @@ pub(crate) fn load_index_buffer(
         },
     })
 }
+
+/// Reads an index buffer on the CPU, widening 16-bit indices. Strip cuts are mapped to `u32::MAX`
+pub fn read_index_buffer(hash: TagHash) -> anyhow::Result<Vec<u32>> {
+    let entry = package_manager()
+        .get_entry(hash)
+        .context("Entry not found")?;
+
+    let header: IndexBufferHeader = package_manager()
+        .read_tag_struct(hash)
+        .context("Failed to read header data")?;
+    let data = package_manager()
+        .read_tag(entry.reference)
+        .context("Failed to read buffer data")?;
+
+    Ok(if header.is_32bit {
+        data.chunks_exact(4)
+            .map(|c| u32::from_le_bytes(c.try_into().unwrap()))
+            .collect()
+    } else {
+        data.chunks_exact(2)
+            .map(|c| match u16::from_le_bytes(c.try_into().unwrap()) {
+                u16::MAX => u32::MAX,
+                i => i as u32,
+            })
+            .collect()
+    })
+}
+
+/// Unrolls a triangle strip into a triangle list, restarting at strip cuts (`u32::MAX`) and
+/// dropping degenerate triangles
+pub fn unroll_triangle_strip(strip: &[u32]) -> Vec<[u32; 3]> {
+    let mut triangles = vec![];
+    let mut strip_start = 0;
+    for i in 0..strip.len().saturating_sub(2) {
+        let [a, b, c] = [strip[i], strip[i + 1], strip[i + 2]];
+        if a == u32::MAX || b == u32::MAX || c == u32::MAX {
+            if a == u32::MAX {
+                strip_start = i + 1;
+            }
+            continue;
+        }
+
+        if a == b || b == c || a == c {
+            continue;
+        }
+
+        if (i - strip_start) % 2 == 0 {
+            triangles.push([a, b, c]);
+        } else {
+            triangles.push([a, c, b]);
+        }
+    }
+
+    triangles
+}
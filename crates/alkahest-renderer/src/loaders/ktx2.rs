@@ -0,0 +1,138 @@
+//! Minimal KTX2 writer for passing block compressed HDR textures through without decoding them
+//!
+//! Only BC6H is supported, as that's what HDR cubemaps are stored as. Mip levels and cube faces
+//! are copied as-is, without supercompression.
+
+use alkahest_data::dxgi::DxgiFormat;
+use anyhow::ensure;
+
+const KTX2_IDENTIFIER: [u8; 12] = [
+    0xAB, 0x4B, 0x54, 0x58, 0x20, 0x32, 0x30, 0xBB, 0x0D, 0x0A, 0x1A, 0x0A,
+];
+
+const VK_FORMAT_BC6H_UFLOAT_BLOCK: u32 = 143;
+const VK_FORMAT_BC6H_SFLOAT_BLOCK: u32 = 144;
+
+const KHR_DF_MODEL_BC6H: u32 = 133;
+const KHR_DF_PRIMARIES_BT709: u32 = 1;
+const KHR_DF_TRANSFER_LINEAR: u32 = 1;
+const KHR_DF_SAMPLE_DATATYPE_SIGNED: u32 = 1 << 30;
+const KHR_DF_SAMPLE_DATATYPE_FLOAT: u32 = 1 << 31;
+
+/// Size of a BC6H block in bytes, also the required alignment of every mip level
+const BLOCK_SIZE: usize = 16;
+
+pub fn is_ktx2_supported(format: DxgiFormat) -> bool {
+    matches!(
+        format,
+        DxgiFormat::BC6H_TYPELESS | DxgiFormat::BC6H_UF16 | DxgiFormat::BC6H_SF16
+    )
+}
+
+/// Encodes a KTX2 texture. `levels` holds the data of every mip level, largest first, with the
+/// faces of every array layer of a level stored back to back (`+X, -X, +Y, -Y, +Z, -Z` for cubes)
+pub fn encode_ktx2(
+    format: DxgiFormat,
+    width: usize,
+    height: usize,
+    layers: usize,
+    faces: usize,
+    levels: &[&[u8]],
+) -> anyhow::Result<Vec<u8>> {
+    ensure!(
+        is_ktx2_supported(format),
+        "Format {format:?} can't be written to KTX2"
+    );
+    ensure!(width > 0 && height > 0, "Texture is empty");
+    ensure!(matches!(faces, 1 | 6), "Textures have either 1 or 6 faces");
+    ensure!(!levels.is_empty(), "Texture has no mip levels");
+
+    let signed = format == DxgiFormat::BC6H_SF16;
+    let vk_format = if signed {
+        VK_FORMAT_BC6H_SFLOAT_BLOCK
+    } else {
+        VK_FORMAT_BC6H_UFLOAT_BLOCK
+    };
+
+    // Basic data format descriptor with a single sample covering the whole block
+    let mut dfd = vec![];
+    let descriptor_size = 24 + 16;
+    dfd.extend_from_slice(&(4u32 + descriptor_size).to_le_bytes());
+    // Khronos vendor, basic descriptor type
+    dfd.extend_from_slice(&0u32.to_le_bytes());
+    // Version 2
+    dfd.extend_from_slice(&(2 | (descriptor_size << 16)).to_le_bytes());
+    dfd.extend_from_slice(
+        &(KHR_DF_MODEL_BC6H | (KHR_DF_PRIMARIES_BT709 << 8) | (KHR_DF_TRANSFER_LINEAR << 16))
+            .to_le_bytes(),
+    );
+    // 4x4 texel blocks, stored as dimension - 1
+    dfd.extend_from_slice(&(3u32 | (3 << 8)).to_le_bytes());
+    // 16 bytes per block in plane 0
+    dfd.extend_from_slice(&(BLOCK_SIZE as u32).to_le_bytes());
+    dfd.extend_from_slice(&0u32.to_le_bytes());
+
+    let mut qualifiers = KHR_DF_SAMPLE_DATATYPE_FLOAT;
+    if signed {
+        qualifiers |= KHR_DF_SAMPLE_DATATYPE_SIGNED;
+    }
+    // Bit offset 0, 128 bits (stored as length - 1), color channel
+    dfd.extend_from_slice(&((127 << 16) | qualifiers).to_le_bytes());
+    dfd.extend_from_slice(&0u32.to_le_bytes());
+    let (lower, upper) = if signed {
+        ((-1f32).to_bits(), 1f32.to_bits())
+    } else {
+        (0f32.to_bits(), 1f32.to_bits())
+    };
+    dfd.extend_from_slice(&lower.to_le_bytes());
+    dfd.extend_from_slice(&upper.to_le_bytes());
+
+    let level_index_offset = KTX2_IDENTIFIER.len() + 9 * 4 + 4 * 4 + 2 * 8;
+    let dfd_offset = level_index_offset + levels.len() * 3 * 8;
+    let mut data_offset = (dfd_offset + dfd.len()).next_multiple_of(BLOCK_SIZE);
+
+    // Levels are stored smallest first
+    let mut level_index = vec![(0, 0); levels.len()];
+    for (i, level) in levels.iter().enumerate().rev() {
+        level_index[i] = (data_offset, level.len());
+        data_offset = (data_offset + level.len()).next_multiple_of(BLOCK_SIZE);
+    }
+
+    let mut out = Vec::with_capacity(data_offset);
+    out.extend_from_slice(&KTX2_IDENTIFIER);
+    for v in [
+        vk_format,
+        // Type size, 1 for block compressed formats
+        1,
+        width as u32,
+        height as u32,
+        // Depth
+        0,
+        if layers > 1 { layers as u32 } else { 0 },
+        faces as u32,
+        levels.len() as u32,
+        // No supercompression
+        0,
+    ] {
+        out.extend_from_slice(&v.to_le_bytes());
+    }
+
+    out.extend_from_slice(&(dfd_offset as u32).to_le_bytes());
+    out.extend_from_slice(&(dfd.len() as u32).to_le_bytes());
+    // No key/value data or supercompression global data
+    out.extend_from_slice(&[0; 4 * 2 + 8 * 2]);
+
+    for &(offset, length) in &level_index {
+        out.extend_from_slice(&(offset as u64).to_le_bytes());
+        out.extend_from_slice(&(length as u64).to_le_bytes());
+        out.extend_from_slice(&(length as u64).to_le_bytes());
+    }
+
+    out.extend_from_slice(&dfd);
+    for (i, level) in levels.iter().enumerate().rev() {
+        out.resize(level_index[i].0, 0);
+        out.extend_from_slice(level);
+    }
+
+    Ok(out)
+}
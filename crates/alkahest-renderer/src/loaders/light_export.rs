@@ -0,0 +1,327 @@
+//! Exports map lights as glTF `KHR_lights_punctual` nodes, with a JSON sidecar containing the raw light data
+
+use std::{
+    io::{Read, Seek, SeekFrom},
+    path::{Path, PathBuf},
+};
+
+use alkahest_data::{
+    map::{SLight, SLightCollection, SShadowingLight, SUnk80809885, SUnk80809f4f},
+    text::StringContainer,
+};
+use alkahest_pm::package_manager;
+use anyhow::Context;
+use binrw::BinReaderExt;
+use destiny_pkg::TagHash;
+use glam::{Quat, Vec3};
+use serde_json::{json, Value};
+use tiger_parse::PackageManagerExt;
+
+use crate::{
+    ecs::render::light::LightShape,
+    loaders::{gltf::to_gltf_space, light_params::LightParameters, map_data::MapDataTables},
+};
+
+pub struct MapLight {
+    pub name: String,
+    /// Light collection or shadowing light tag
+    pub tag: TagHash,
+    /// Index in the light collection
+    pub index: Option<usize>,
+    pub shape: LightShape,
+    pub translation: Vec3,
+    pub rotation: Quat,
+    pub parameters: Option<LightParameters>,
+    pub techniques: Vec<(&'static str, TagHash)>,
+    /// Every light struct field, including the ones we don't know the meaning of
+    pub raw: Value,
+}
+
+#[derive(Default)]
+pub struct MapLights {
+    pub map_hash: TagHash,
+    pub map_name: String,
+    pub lights: Vec<MapLight>,
+}
+
+impl MapLights {
+    pub fn gather(map_hash: TagHash, stringmap: &StringContainer) -> anyhow::Result<Self> {
+        let tables = MapDataTables::load(map_hash)?;
+        let mut lights = MapLights {
+            map_hash,
+            map_name: stringmap.get(tables.map_name),
+            ..Default::default()
+        };
+
+        for entry in tables.entries() {
+            let (table_hash, entry, mut cur) = match entry {
+                Ok(entry) => entry,
+                Err(e) => {
+                    error!("{e:?}");
+                    continue;
+                }
+            };
+            if let Err(e) = lights.gather_entry(&entry, &mut cur) {
+                error!("Failed to gather lights from data table {table_hash}: {e}");
+            }
+        }
+
+        Ok(lights)
+    }
+
+    fn gather_entry<R: Read + Seek>(
+        &mut self,
+        data: &SUnk80809885,
+        table_data: &mut R,
+    ) -> anyhow::Result<()> {
+        match data.data_resource.resource_type {
+            // Light collection
+            0x80806a63 => {
+                table_data.seek(SeekFrom::Current(16))?;
+                let tag: TagHash = table_data.read_le()?;
+                if tag.is_none() {
+                    return Ok(());
+                }
+
+                let collection: SLightCollection = package_manager().read_tag_struct(tag)?;
+                for (i, (light, transform)) in
+                    collection.unk30.iter().zip(&collection.unk40).enumerate()
+                {
+                    self.lights
+                        .push(MapLight::from_light(tag, i, light, transform));
+                }
+            }
+            // Shadowing spotlight
+            0x80806c5e => {
+                table_data.seek(SeekFrom::Current(16))?;
+                let tag: TagHash = table_data.read_le()?;
+                let light: SShadowingLight = package_manager().read_tag_struct(tag)?;
+                self.lights.push(MapLight::from_shadowing_light(
+                    tag,
+                    &light,
+                    data.translation.truncate(),
+                    data.rotation,
+                    data.translation.w,
+                ));
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    pub fn write(&self, path: &Path) -> anyhow::Result<PathBuf> {
+        std::fs::write(path, serde_json::to_string_pretty(&self.to_gltf())?)
+            .with_context(|| format!("Failed to write {}", path.display()))?;
+
+        let sidecar = path.with_extension("lights.json");
+        std::fs::write(&sidecar, serde_json::to_string_pretty(&self.to_json())?)
+            .with_context(|| format!("Failed to write {}", sidecar.display()))?;
+
+        Ok(sidecar)
+    }
+
+    /// Builds a glTF document with one `KHR_lights_punctual` node per light. Positions are converted to glTF's Y-up space
+    pub fn to_gltf(&self) -> Value {
+        let mut lights = vec![];
+        let mut nodes = vec![];
+        for light in &self.lights {
+            let mut gltf_light = json!({
+                "name": light.name,
+                "type": match light.shape {
+                    LightShape::Spot => "spot",
+                    // Line lights have no punctual equivalent, their length is kept in the sidecar
+                    LightShape::Omni | LightShape::Line => "point",
+                },
+                "color": [1.0, 1.0, 1.0],
+                "intensity": 1.0,
+            });
+
+            let mut local_rotation = Quat::IDENTITY;
+            if let Some(params) = &light.parameters {
+                gltf_light["color"] = json!(params.color);
+                gltf_light["intensity"] = json!(params.intensity);
+                if params.range > 0.0 && params.range.is_finite() {
+                    gltf_light["range"] = json!(params.range);
+                }
+
+                if light.shape == LightShape::Spot {
+                    let outer = params
+                        .outer_cone_angle
+                        .unwrap_or(std::f32::consts::FRAC_PI_4)
+                        .clamp(0.001, std::f32::consts::FRAC_PI_2);
+                    // The inner cone is left at its default of 0
+                    gltf_light["spot"] = json!({ "outerConeAngle": outer });
+                }
+
+                // glTF lights point down -Z, which is +Y in Destiny's Z-up space
+                if let Some(direction) = params.direction {
+                    local_rotation = Quat::from_rotation_arc(Vec3::Y, Vec3::from(direction));
+                }
+            }
+
+            let (translation, rotation) =
+                to_gltf_space(light.translation, light.rotation * local_rotation);
+            nodes.push(json!({
+                "name": light.name,
+                "translation": translation.to_array(),
+                "rotation": rotation.to_array(),
+                "extensions": {
+                    "KHR_lights_punctual": { "light": lights.len() }
+                },
+                "extras": {
+                    "tag": light.tag.to_string(),
+                    "index": light.index,
+                },
+            }));
+            lights.push(gltf_light);
+        }
+
+        json!({
+            "asset": {
+                "version": "2.0",
+                "generator": concat!("alkahest ", env!("CARGO_PKG_VERSION")),
+            },
+            "extensionsUsed": ["KHR_lights_punctual"],
+            "extensions": {
+                "KHR_lights_punctual": { "lights": lights }
+            },
+            "scene": 0,
+            "scenes": [{
+                "name": self.map_name,
+                "nodes": (0..nodes.len()).collect::<Vec<_>>(),
+            }],
+            "nodes": nodes,
+        })
+    }
+
+    /// Raw light data in Destiny's coordinate space, keyed by the same names as the glTF nodes
+    pub fn to_json(&self) -> Value {
+        json!({
+            "map": self.map_hash.to_string(),
+            "map_name": self.map_name,
+            "lights": self.lights.iter().map(|light| json!({
+                "name": light.name,
+                "tag": light.tag.to_string(),
+                "index": light.index,
+                "shape": light.shape,
+                "translation": light.translation.to_array(),
+                "rotation": light.rotation.to_array(),
+                "parameters": light.parameters,
+                "techniques": light
+                    .techniques
+                    .iter()
+                    .map(|(name, hash)| (name.to_string(), json!(hash.to_string())))
+                    .collect::<serde_json::Map<_, _>>(),
+                "raw": light.raw,
+            })).collect::<Vec<_>>(),
+        })
+    }
+}
+
+impl MapLight {
+    fn from_light(tag: TagHash, index: usize, light: &SLight, transform: &SUnk80809f4f) -> Self {
+        let shape = LightShape::from_volume_matrix(light.light_to_world);
+        Self {
+            name: format!("{} Light {tag}[{index}]", shape.name()),
+            tag,
+            index: Some(index),
+            shape,
+            translation: transform.translation.truncate(),
+            rotation: transform.rotation,
+            parameters: LightParameters::from_light(light)
+                .map_err(|e| warn!("Failed to decode parameters of light {tag}[{index}]: {e:?}"))
+                .ok(),
+            techniques: vec![
+                ("shading", light.technique_shading),
+                ("volumetrics", light.technique_volumetrics),
+                ("compute_lightprobe", light.technique_compute_lightprobe),
+            ],
+            raw: json!({
+                "unk0": light.unk0.to_array(),
+                "unk10": light.unk10.to_array(),
+                "unk20": light.unk20.to_array(),
+                "unk30": light.unk30.to_array(),
+                "unk40": light.unk40,
+                "unk50": light.unk50.to_array(),
+                "light_to_world": light.light_to_world.to_cols_array(),
+                "unka0": light.unka0,
+                "unka4": light.unka4,
+                "unka8": light.unka8,
+                "unkac": light.unkac,
+                "unkb0": light.unkb0,
+                "unkb4": light.unkb4,
+                "unkb8": light.unkb8,
+                "unkbc": light.unkbc,
+                "unkc0": light.unkc0,
+                "unkd0": light.unkd0.to_string(),
+                "unkd4": light.unkd4.to_string(),
+                "unkd8": light.unkd8,
+                "transform": {
+                    "rotation": transform.rotation.to_array(),
+                    "translation": transform.translation.to_array(),
+                },
+            }),
+        }
+    }
+
+    fn from_shadowing_light(
+        tag: TagHash,
+        light: &SShadowingLight,
+        translation: Vec3,
+        rotation: Quat,
+        scale: f32,
+    ) -> Self {
+        Self {
+            name: format!("Shadowing Spotlight {tag}"),
+            tag,
+            index: None,
+            shape: LightShape::from_volume_matrix(light.light_to_world),
+            translation,
+            rotation,
+            parameters: LightParameters::from_shadowing_light(light)
+                .map_err(|e| warn!("Failed to decode parameters of shadowing light {tag}: {e:?}"))
+                .ok(),
+            techniques: vec![
+                ("shading", light.technique_shading),
+                ("shading_shadowing", light.technique_shading_shadowing),
+                ("volumetrics", light.technique_volumetrics),
+                (
+                    "volumetrics_shadowing",
+                    light.technique_volumetrics_shadowing,
+                ),
+                ("compute_lightprobe", light.technique_compute_lightprobe),
+                (
+                    "compute_lightprobe_shadowing",
+                    light.technique_compute_lightprobe_shadowing,
+                ),
+            ],
+            raw: json!({
+                "unk0": light.unk0.to_array(),
+                "unk10": light.unk10.to_array(),
+                "unk20": light.unk20.to_array(),
+                "unk30": light.unk30.to_array(),
+                "unk40": light.unk40,
+                "unk50": light.unk50.to_array(),
+                "light_to_world": light.light_to_world.to_cols_array(),
+                "unka0": light.unka0,
+                "unka4": light.unka4,
+                "unka8": light.unka8,
+                "unkac": light.unkac,
+                "unkb0": light.unkb0,
+                "unkb4": light.unkb4,
+                "unkb8": light.unkb8,
+                "unkbc": light.unkbc,
+                "far_plane": light.far_plane,
+                "half_fov": light.half_fov,
+                "unkc8": light.unkc8,
+                "unkcc": light.unkcc,
+                "unke8": light.unke8.to_string(),
+                "unkec": light.unkec.to_string(),
+                "unkf0": light.unkf0,
+                "unk104": light.unk104,
+                "scale": scale,
+            }),
+        }
+    }
+}
@@ -0,0 +1,274 @@
+use alkahest_data::{
+    map::{SLight, SShadowingLight},
+    technique::{STechnique, STechniqueShader},
+    texture::STextureHeader,
+};
+use alkahest_pm::package_manager;
+use destiny_pkg::TagHash;
+use glam::{Mat4, Vec3, Vec4};
+use serde::Serialize;
+use tiger_parse::PackageManagerExt;
+
+use super::technique::evaluate_cbuffer_cpu;
+use crate::{
+    ecs::render::light::LightShape,
+    tfx::externs::{DeferredLight, ExternStorage},
+};
+
+/// Light parameters recovered from a light struct and its shading technique
+#[derive(Debug, Clone, Serialize)]
+pub struct LightParameters {
+    pub shape: LightShape,
+    /// Linear color, normalized so the largest component is 1
+    pub color: [f32; 3],
+    pub intensity: f32,
+    pub color_source: LightColorSource,
+    /// Distance from the light origin to the furthest point of the light volume
+    pub range: f32,
+    /// Half angle in radians, of the light volume or the shadow frustum. Only set for spot lights.
+    /// Light structs don't have a known inner cone angle, the falloff is baked into the IES profile
+    pub outer_cone_angle: Option<f32>,
+    /// Light space direction of the cone axis. Only set for spot lights
+    pub direction: Option<[f32; 3]>,
+    pub textures: Vec<LightTexture>,
+    pub shadow: Option<LightShadow>,
+
+    /// Pixel stage constant buffer after evaluating the technique bytecode
+    pub cbuffer: Vec<[f32; 4]>,
+    /// Constant buffer elements written by the bytecode
+    pub cbuffer_written: Vec<usize>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub enum LightColorSource {
+    /// Written by the technique bytecode to the given constant buffer element
+    TfxOutput(usize),
+    /// Static constant buffer element
+    Constant(usize),
+    Unknown,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum LightTextureKind {
+    /// Attenuation lookup, usually a handful of rows tall
+    IesProfile,
+    /// Projected texture
+    Cookie,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct LightTexture {
+    pub slot: u32,
+    #[serde(serialize_with = "alkahest_data::tag::serialize_taghash")]
+    pub texture: TagHash,
+    pub kind: LightTextureKind,
+}
+
+/// Shadow frustum and the passes a shadowing light has shadowed variants of
+#[derive(Debug, Clone, Serialize)]
+pub struct LightShadow {
+    pub far_plane: f32,
+    /// Radians
+    pub half_fov: f32,
+    /// Set if [`SShadowingLight::technique_shading_shadowing`] is present
+    pub shadowed_shading: bool,
+    /// Set if [`SShadowingLight::technique_volumetrics_shadowing`] is present
+    pub shadowed_volumetrics: bool,
+    /// Set if [`SShadowingLight::technique_compute_lightprobe_shadowing`] is present
+    pub shadowed_lightprobe: bool,
+}
+
+impl LightShadow {
+    pub fn from_shadowing_light(light: &SShadowingLight) -> Self {
+        Self {
+            far_plane: light.far_plane,
+            half_fov: light.half_fov,
+            shadowed_shading: light.technique_shading_shadowing.is_some(),
+            shadowed_volumetrics: light.technique_volumetrics_shadowing.is_some(),
+            shadowed_lightprobe: light.technique_compute_lightprobe_shadowing.is_some(),
+        }
+    }
+}
+
+impl LightParameters {
+    pub fn from_light(light: &SLight) -> anyhow::Result<Self> {
+        Self::decode(
+            light.light_to_world,
+            light.unk50,
+            light.technique_shading,
+            None,
+        )
+    }
+
+    pub fn from_shadowing_light(light: &SShadowingLight) -> anyhow::Result<Self> {
+        let mut params = Self::decode(
+            light.light_to_world,
+            light.unk50,
+            light.technique_shading,
+            Some(LightShadow::from_shadowing_light(light)),
+        )?;
+
+        // The shadow frustum is more accurate than the one derived from the volume
+        if params.shape == LightShape::Spot {
+            params.range = light.far_plane;
+            params.outer_cone_angle = Some(light.half_fov);
+        }
+
+        Ok(params)
+    }
+
+    fn decode(
+        light_to_world: Mat4,
+        unk50: Vec4,
+        technique_shading: TagHash,
+        shadow: Option<LightShadow>,
+    ) -> anyhow::Result<Self> {
+        let shape = LightShape::from_volume_matrix(light_to_world);
+        let technique: STechnique = package_manager().read_tag_struct(technique_shading)?;
+        let (cbuffer, cbuffer_written) = evaluate_stage(&technique.shader_pixel, unk50)?;
+
+        let (raw_color, color_source) = find_color(&cbuffer, &cbuffer_written);
+        let intensity = raw_color.max_element();
+        let color = if intensity > 0.0 {
+            raw_color / intensity
+        } else {
+            Vec3::ONE
+        };
+
+        let (range, outer_cone_angle, direction) = match shape {
+            LightShape::Spot => {
+                let (range, angle, axis) = spot_frustum(light_to_world);
+                (range, Some(angle), Some(axis.to_array()))
+            }
+            LightShape::Omni | LightShape::Line => (
+                light_to_world
+                    .x_axis
+                    .truncate()
+                    .length()
+                    .max(light_to_world.y_axis.truncate().length())
+                    .max(light_to_world.z_axis.truncate().length()),
+                None,
+                None,
+            ),
+        };
+
+        Ok(Self {
+            shape,
+            color: color.to_array(),
+            intensity,
+            color_source,
+            range,
+            outer_cone_angle,
+            direction,
+            textures: classify_textures(&technique.shader_pixel),
+            shadow,
+            cbuffer: cbuffer.iter().map(|v| v.to_array()).collect(),
+            cbuffer_written,
+        })
+    }
+}
+
+/// Runs the bytecode of a technique stage on the CPU, returning the resulting constant buffer and the elements it wrote
+fn evaluate_stage(
+    shader: &STechniqueShader,
+    unk50: Vec4,
+) -> anyhow::Result<(Vec<Vec4>, Vec<usize>)> {
+    let externs = ExternStorage {
+        deferred_light: Some(DeferredLight {
+            unk100: unk50,
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+
+    evaluate_cbuffer_cpu(shader, &externs)
+}
+
+/// The light color is the first non-negative, non-black color written by the bytecode. Falls back to the first such static constant
+fn find_color(cbuffer: &[Vec4], written: &[usize]) -> (Vec3, LightColorSource) {
+    let is_color = |v: Vec4| {
+        let rgb = v.truncate();
+        rgb.is_finite() && rgb.min_element() >= 0.0 && rgb.max_element() > 0.0
+    };
+
+    if let Some(&element) = written.iter().find(|&&e| is_color(cbuffer[e])) {
+        return (
+            cbuffer[element].truncate(),
+            LightColorSource::TfxOutput(element),
+        );
+    }
+
+    if let Some((element, v)) = cbuffer
+        .iter()
+        .enumerate()
+        .find(|(i, v)| !written.contains(i) && is_color(**v))
+    {
+        return (v.truncate(), LightColorSource::Constant(element));
+    }
+
+    (Vec3::ZERO, LightColorSource::Unknown)
+}
+
+/// Range, outer half angle and axis of a spot light volume. The volume matrix maps the unit cube onto the light frustum
+fn spot_frustum(light_to_world: Mat4) -> (f32, f32, Vec3) {
+    let face = |z: f32| {
+        let center = light_to_world.project_point3(Vec3::new(0.0, 0.0, z));
+        let edges = [
+            Vec3::new(1.0, 0.0, z),
+            Vec3::new(-1.0, 0.0, z),
+            Vec3::new(0.0, 1.0, z),
+            Vec3::new(0.0, -1.0, z),
+        ]
+        .map(|p| light_to_world.project_point3(p));
+        (center, edges)
+    };
+
+    let (near, far) = (face(-1.0), face(1.0));
+    let (center, edges) = if near.0.length() > far.0.length() {
+        near
+    } else {
+        far
+    };
+
+    let range = [-1.0, 1.0]
+        .into_iter()
+        .flat_map(|x| [-1.0, 1.0].map(move |y| (x, y)))
+        .flat_map(|(x, y)| [-1.0, 1.0].map(move |z| Vec3::new(x, y, z)))
+        .map(|p| light_to_world.project_point3(p).length())
+        .fold(0.0, f32::max);
+
+    let axis = center.normalize_or_zero();
+    let angle = edges
+        .iter()
+        .map(|e| axis.angle_between(e.normalize_or_zero()))
+        .filter(|a| a.is_finite())
+        .fold(0.0, f32::max);
+
+    (range, angle, axis)
+}
+
+fn classify_textures(shader: &STechniqueShader) -> Vec<LightTexture> {
+    shader
+        .textures
+        .iter()
+        .filter_map(|assignment| {
+            let texture = assignment.texture.hash32_checked()?;
+            let kind = match package_manager().read_tag_struct::<STextureHeader>(texture) {
+                Ok(header) if header.height <= 4 && header.width > header.height => {
+                    LightTextureKind::IesProfile
+                }
+                Ok(_) => LightTextureKind::Cookie,
+                Err(e) => {
+                    warn!("Failed to read light texture header {texture}: {e}");
+                    LightTextureKind::Cookie
+                }
+            };
+
+            Some(LightTexture {
+                slot: assignment.slot,
+                texture,
+                kind,
+            })
+        })
+        .collect()
+}
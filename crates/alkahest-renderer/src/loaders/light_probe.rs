@@ -0,0 +1,192 @@
+//! Samples the baked indirect lighting of a map, see [`alkahest_data::lightprobe`] for the encoding
+//!
+//! Every cubemap volume with a voxel IBL texture holds a grid of probes spanning its influence box.
+//! When volumes overlap, the smallest volume containing a position is sampled, as it holds the most
+//! local lighting.
+
+use std::path::{Path, PathBuf};
+
+use alkahest_data::{
+    lightprobe::{ShL1, VoxelIblEncoding, VoxelProbeGrid},
+    text::StringContainer,
+};
+use anyhow::Context;
+use destiny_pkg::TagHash;
+use glam::{Quat, Vec3};
+use rustc_hash::FxHashMap;
+use serde_json::json;
+
+use crate::loaders::{cubemap_export::MapCubemaps, texture_decode::DecodedTexture};
+
+pub struct LightProbeVolume {
+    /// Data table and offset of the cubemap volume holding the probes
+    pub table: TagHash,
+    pub resource_offset: u64,
+    pub translation: Vec3,
+    pub rotation: Quat,
+    pub half_extents: Vec3,
+    pub texture: TagHash,
+    pub grid: VoxelProbeGrid,
+}
+
+impl LightProbeVolume {
+    /// Position of `world` in the 0-1 range of the probe grid, if it's inside the volume
+    pub fn world_to_uvw(&self, world: Vec3) -> Option<Vec3> {
+        let local = self.rotation.inverse() * (world - self.translation) / self.half_extents;
+        if local.abs().cmpgt(Vec3::ONE).any() || !local.is_finite() {
+            return None;
+        }
+
+        Some((local + 1.0) / 2.0)
+    }
+
+    pub fn uvw_to_world(&self, uvw: Vec3) -> Vec3 {
+        self.translation + self.rotation * ((uvw * 2.0 - 1.0) * self.half_extents)
+    }
+
+    pub fn sample(&self, world: Vec3) -> Option<ShL1> {
+        Some(self.grid.sample(self.world_to_uvw(world)?))
+    }
+
+    pub fn volume(&self) -> f32 {
+        self.half_extents.x * self.half_extents.y * self.half_extents.z * 8.0
+    }
+}
+
+#[derive(Default)]
+pub struct MapLightProbes {
+    pub map_hash: TagHash,
+    pub map_name: String,
+    pub volumes: Vec<LightProbeVolume>,
+    /// Whether the encoding of the voxel IBL textures was guessed, see [`VoxelIblEncoding::detect`]
+    pub encoding_guessed: bool,
+}
+
+impl MapLightProbes {
+    /// Loads the probe grids of every cubemap volume in a map. When `encoding` is `None`, the
+    /// encoding of each voxel IBL texture is guessed from its depth
+    pub fn gather(
+        map_hash: TagHash,
+        stringmap: &StringContainer,
+        encoding: Option<VoxelIblEncoding>,
+    ) -> anyhow::Result<Self> {
+        let cubemaps = MapCubemaps::gather(map_hash, stringmap)?;
+        let mut probes = MapLightProbes {
+            map_hash,
+            map_name: cubemaps.map_name,
+            encoding_guessed: encoding.is_none(),
+            ..Default::default()
+        };
+
+        // Volumes regularly share their voxel IBL texture with the volumes around them
+        let mut grids: FxHashMap<TagHash, Option<VoxelProbeGrid>> = FxHashMap::default();
+        for probe in cubemaps.probes {
+            let texture = probe.volume.voxel_ibl_texture;
+            if texture.is_none() {
+                continue;
+            }
+
+            let grid = grids.entry(texture).or_insert_with(|| {
+                load_probe_grid(texture, encoding)
+                    .map_err(|e| error!("Failed to load voxel IBL texture {texture}: {e:?}"))
+                    .ok()
+            });
+
+            if let Some(grid) = grid {
+                probes.volumes.push(LightProbeVolume {
+                    table: probe.table,
+                    resource_offset: probe.resource_offset,
+                    translation: probe.translation,
+                    rotation: probe.rotation,
+                    half_extents: probe.volume.cubemap_extents.truncate(),
+                    texture,
+                    grid: grid.clone(),
+                });
+            }
+        }
+
+        Ok(probes)
+    }
+
+    /// Samples the baked lighting at a world position, from the smallest volume containing it
+    pub fn sample(&self, world: Vec3) -> Option<ShL1> {
+        self.volumes
+            .iter()
+            .filter(|v| v.world_to_uvw(world).is_some())
+            .min_by(|a, b| a.volume().total_cmp(&b.volume()))?
+            .sample(world)
+    }
+
+    /// Writes every probe to `dir/lightprobes.json`, returning the path of the file
+    pub fn write(&self, dir: &Path) -> anyhow::Result<PathBuf> {
+        std::fs::create_dir_all(dir)
+            .with_context(|| format!("Failed to create {}", dir.display()))?;
+
+        let volumes: Vec<_> = self
+            .volumes
+            .iter()
+            .enumerate()
+            .map(|(i, v)| {
+                let [w, h, d] = v.grid.size;
+                let mut probes = Vec::with_capacity(w * h * d);
+                for z in 0..d {
+                    for y in 0..h {
+                        for x in 0..w {
+                            let sh = v.grid.probe(x, y, z);
+                            probes.push(json!({
+                                "position": v.uvw_to_world(v.grid.probe_uvw(x, y, z)).to_array(),
+                                "sh": sh.to_array(),
+                            }));
+                        }
+                    }
+                }
+
+                json!({
+                    "index": i,
+                    "table": v.table.to_string(),
+                    "resource_offset": v.resource_offset,
+                    "texture": v.texture.to_string(),
+                    "encoding": v.grid.encoding.name(),
+                    "center": v.translation.to_array(),
+                    "rotation": v.rotation.to_array(),
+                    "half_extents": v.half_extents.to_array(),
+                    "grid_size": v.grid.size,
+                    "probes": probes,
+                })
+            })
+            .collect();
+
+        let path = dir.join("lightprobes.json");
+        std::fs::write(
+            &path,
+            serde_json::to_string_pretty(&json!({
+                "map": self.map_hash.to_string(),
+                "map_name": self.map_name,
+                "encoding_guessed": self.encoding_guessed,
+                "coordinate_system": "z_up",
+                "sh_basis": "l1 radiance, coefficients Y00, Y1-1 (y), Y10 (z), Y11 (x), rgb each",
+                "probe_order": "x fastest, then y, then z",
+                "volumes": volumes,
+            }))?,
+        )
+        .with_context(|| format!("Failed to write {}", path.display()))?;
+
+        Ok(path)
+    }
+}
+
+fn load_probe_grid(
+    texture: TagHash,
+    encoding: Option<VoxelIblEncoding>,
+) -> anyhow::Result<VoxelProbeGrid> {
+    let decoded = DecodedTexture::load(texture)?;
+    let encoding = encoding.unwrap_or_else(|| VoxelIblEncoding::detect(decoded.layers));
+
+    VoxelProbeGrid::from_texels(
+        decoded.width,
+        decoded.height,
+        decoded.layers,
+        encoding,
+        &decoded.pixels,
+    )
+}
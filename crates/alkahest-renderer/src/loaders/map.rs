@@ -4,6 +4,7 @@ use alkahest_data::{
     activity::{SActivity, SEntityResource, SUnk8080460c, Unk80808cef, Unk80808e89, Unk808092d8},
     common::ResourceHash,
     decorator::SDecorator,
+    dye::GearDyeSlot,
     entity::{SEntity, Unk808072c5, Unk8080906b, Unk80809905},
     map::{
         SAudioClipCollection, SBubbleDefinition, SBubbleParent, SCubemapVolume, SLensFlare,
@@ -23,6 +24,7 @@ use binrw::BinReaderExt;
 use destiny_pkg::TagHash;
 use ecolor::Color32;
 use glam::{Mat4, Vec3, Vec4Swizzles};
+use indexmap::IndexMap;
 use itertools::{multizip, Itertools};
 use rustc_hash::{FxHashMap, FxHashSet};
 use tiger_parse::{Endian, FnvHash, PackageManagerExt, TigerReadable};
@@ -30,17 +32,21 @@ use tiger_parse::{Endian, FnvHash, PackageManagerExt, TigerReadable};
 use crate::{
     camera::CameraProjection,
     ecs::{
+        activity::{ActivityPhase, ActivityPhases},
+        animation::EntityAnimationSource,
         audio::AmbientAudio,
-        common::{Icon, Label, RenderCommonBundle, ResourceOrigin},
+        common::{ActivityGroup, Icon, Label, RenderCommonBundle, ResourceOrigin},
         hierarchy::{Children, Parent},
-        map::{CubemapVolume, MapAtmosphere, NodeMetadata},
+        map::{CubemapVolume, Decal, MapAtmosphere, NodeMetadata},
         render::{
             decorators::DecoratorRenderer,
             dynamic_geometry::DynamicModelComponent,
             havok::HavokShapeRenderer,
+            lens_flare::LensFlare,
             light::{LightRenderer, LightShape, ShadowMapRenderer},
             static_geometry::{StaticInstance, StaticInstances, StaticModel, StaticModelSingle},
             terrain::TerrainPatches,
+            water::WaterSurface,
         },
         tags::{insert_tag, EntityTag, NodeFilter},
         transform::{OriginalTransform, Transform, TransformFlags},
@@ -48,9 +54,14 @@ use crate::{
         Scene, SceneInfo,
     },
     icons::{
-        ICON_ACCOUNT_CONVERT, ICON_CUBE, ICON_CUBE_OUTLINE, ICON_FLARE, ICON_IMAGE_FILTER_HDR,
-        ICON_LABEL, ICON_LIGHTBULB_GROUP, ICON_SHAPE, ICON_SPEAKER, ICON_SPHERE,
-        ICON_SPOTLIGHT_BEAM, ICON_TREE, ICON_WAVES, ICON_WEATHER_FOG, ICON_WEATHER_PARTLY_CLOUDY,
+        ICON_ACCOUNT_CONVERT, ICON_CUBE, ICON_CUBE_OUTLINE, ICON_FLARE, ICON_FORMAT_PAINT,
+        ICON_IMAGE_FILTER_HDR, ICON_LABEL, ICON_LIGHTBULB_GROUP, ICON_SHAPE, ICON_SPEAKER,
+        ICON_SPHERE, ICON_SPOTLIGHT_BEAM, ICON_TREE, ICON_WAVES, ICON_WEATHER_FOG,
+        ICON_WEATHER_PARTLY_CLOUDY,
+    },
+    loaders::{
+        decal_export::{load_decal_collection, DecalMaterial},
+        water::WaterParameters,
     },
     renderer::{Renderer, RendererShared},
     util::{
@@ -189,15 +200,25 @@ pub async fn load_map(
     }
 
     let _unknown_res_types: FxHashSet<u32> = Default::default();
-    let mut phase_entities = FxHashMap::<ResourceHash, Entity>::default();
+    // Phases are kept in the order they're first referenced by the activity
+    let mut phase_entities = IndexMap::<ResourceHash, (Entity, ResourceOrigin)>::default();
     for (e, phase_name2, origin) in activity_entrefs {
-        let parent_entity = *phase_entities.entry(phase_name2).or_insert_with(|| {
-            scene
-                .spawn((Label::from(format!(
-                    "Activity Phase 0x{:08X}",
-                    phase_name2.0
-                )),))
-                .id()
+        let (parent_entity, _) = *phase_entities.entry(phase_name2).or_insert_with(|| {
+            let label = match stringmap.try_get(phase_name2.0) {
+                Some(name) => format!("Activity Phase '{name}' (0x{:08X})", phase_name2.0),
+                None => format!("Activity Phase 0x{:08X}", phase_name2.0),
+            };
+
+            let entity = scene
+                .spawn((
+                    Label::from(label),
+                    ActivityGroup(phase_name2.0),
+                    origin,
+                    VisibilityBundle::default(),
+                ))
+                .id();
+
+            (entity, origin)
         });
 
         for resource in &e.unk18.entity_resources {
@@ -214,12 +235,21 @@ pub async fn load_map(
                             TigerReadable::read_ds_endian(&mut cur, Endian::Little)?;
 
                         if tag.unk84.is_some() {
-                            let entity = scene.spawn((
-                                Label::from(format!("Activity Datatable {}", tag.unk84)),
-                                Transform::new(tag.translation.truncate(), tag.rotation, Vec3::ONE),
-                            ));
-
-                            data_tables.insert(tag.unk84, Some(entity.id()));
+                            let entity = spawn_data_entity(
+                                &mut scene,
+                                (
+                                    Label::from(format!("Activity Datatable {}", tag.unk84)),
+                                    Transform::new(
+                                        tag.translation.truncate(),
+                                        tag.rotation,
+                                        Vec3::ONE,
+                                    ),
+                                    origin,
+                                ),
+                                Some(parent_entity),
+                            );
+
+                            data_tables.insert(tag.unk84, Some(entity));
                         }
                     }
                     0x80808cef => {
@@ -335,7 +365,7 @@ pub async fn load_map(
                                 &mut scene,
                                 &renderer,
                                 origin,
-                                None,
+                                Some(parent_entity),
                                 transform,
                                 None,
                                 0,
@@ -350,6 +380,20 @@ pub async fn load_map(
         }
     }
 
+    scene.insert_resource(ActivityPhases {
+        phases: phase_entities
+            .into_iter()
+            .map(|(name_hash, (entity, origin))| ActivityPhase {
+                name_hash,
+                name: stringmap
+                    .try_get(name_hash.0)
+                    .unwrap_or_else(|| format!("0x{:08X}", name_hash.0)),
+                origin,
+                entity,
+            })
+            .collect(),
+    });
+
     // TODO(cohae): The persistent tag system is used exlusively for filtering, it's otherwise entirely redundant and should be replaced by components where possible
     let mut tags: Vec<(Entity, Vec<EntityTag>)> = vec![];
     for e in scene.iter_entities() {
@@ -667,7 +711,22 @@ fn load_datatable_into_scene<R: Read + Seek>(
                     TfxFeatureRenderer::Water,
                 )?;
                 if d.entity_model.is_some() {
-                    spawn_data_entity(
+                    let water = match WaterParameters::from_model(
+                        d.entity_model,
+                        &model.model.model,
+                        transform.local_to_world(),
+                    ) {
+                        Ok(parameters) => Some(WaterSurface::load(
+                            &mut renderer.data.lock().asset_manager,
+                            parameters,
+                        )),
+                        Err(e) => {
+                            warn!("Failed to read water surface {}: {e:?}", d.entity_model);
+                            None
+                        }
+                    };
+
+                    let entity = spawn_data_entity(
                         scene,
                         (
                             Icon::Unicode(ICON_WAVES),
@@ -681,6 +740,10 @@ fn load_datatable_into_scene<R: Read + Seek>(
                         ),
                         parent_entity,
                     );
+
+                    if let Some(water) = water {
+                        scene.entity_mut(entity).insert(water);
+                    }
                 } else {
                     warn!(
                         "Water entity model is None (table {}, offset 0x{:X})",
@@ -798,6 +861,7 @@ fn load_datatable_into_scene<R: Read + Seek>(
                     .unwrap();
 
                 let atmos: SMapAtmosphere = TigerReadable::read_ds(table_data)?;
+                let parameters = atmos.parameters();
                 scene.insert_resource(
                     MapAtmosphere::load(&renderer.gpu, atmos)
                         .context("Failed to load map atmosphere")?,
@@ -810,6 +874,7 @@ fn load_datatable_into_scene<R: Read + Seek>(
                         "Atmosphere Configuration (table {}@0x{:X})",
                         table_hash, data.data_resource.offset
                     )),
+                    parameters,
                     resource_origin,
                     metadata.clone(),
                 ));
@@ -881,13 +946,15 @@ fn load_datatable_into_scene<R: Read + Seek>(
                 }
 
                 let lens_flare: SLensFlare = package_manager().read_tag_struct(tag)?;
+                let lens_flare =
+                    LensFlare::load(&mut renderer.data.lock().asset_manager, tag, lens_flare);
 
                 spawn_data_entity(
                     scene,
                     (
                         NodeFilter::Light,
                         Icon::Unicode(ICON_FLARE),
-                        Label::from("Lens Flare"),
+                        Label::from(format!("Lens Flare {tag}")),
                         transform,
                         lens_flare,
                         resource_origin,
@@ -896,6 +963,77 @@ fn load_datatable_into_scene<R: Read + Seek>(
                     parent_entity,
                 );
             }
+            // Decal collection
+            0x80806e62 => {
+                table_data
+                    .seek(SeekFrom::Start(data.data_resource.offset + 16))
+                    .unwrap();
+                let tag: TagHash = table_data.read_le().unwrap();
+                if tag.is_none() {
+                    continue;
+                }
+
+                let instances = match load_decal_collection(tag) {
+                    Ok((_, instances)) => instances,
+                    Err(e) => {
+                        error!("Failed to load decal collection {tag}: {e:?}");
+                        continue;
+                    }
+                };
+
+                let collection_entity =
+                    spawn_data_entity(scene, (metadata.clone(),), parent_entity);
+                let mut materials: FxHashMap<TagHash, Vec<(u32, TagHash)>> = Default::default();
+                let mut children = vec![];
+                for instance in instances {
+                    let textures = materials
+                        .entry(instance.material)
+                        .or_insert_with(|| match DecalMaterial::load(instance.material) {
+                            Ok(material) => material.textures,
+                            Err(e) => {
+                                error!(
+                                    "Failed to load decal technique {}: {e:?}",
+                                    instance.material
+                                );
+                                vec![]
+                            }
+                        })
+                        .clone();
+
+                    let projector = instance.projector();
+                    children.push(
+                        scene
+                            .spawn((
+                                NodeFilter::Decal,
+                                Icon::Unicode(ICON_FORMAT_PAINT),
+                                Label::from(format!("Decal {tag}[{}]", instance.index)),
+                                Transform::from_translation(projector.center),
+                                Aabb::from_center_extents(Vec3::ZERO, projector.half_extents),
+                                Decal {
+                                    collection: tag,
+                                    technique: renderer
+                                        .data
+                                        .lock()
+                                        .asset_manager
+                                        .get_or_load_technique(instance.material),
+                                    textures,
+                                    instance,
+                                    projector,
+                                },
+                                resource_origin,
+                                Parent(collection_entity),
+                                RenderCommonBundle::default(),
+                            ))
+                            .id(),
+                    );
+                }
+
+                scene.entity_mut(collection_entity).insert((
+                    Icon::Unicode(ICON_FORMAT_PAINT),
+                    Label::from(format!("Decal Collection {tag}")),
+                    Children::from_slice(&children),
+                ));
+            }
             0x80808cb5 => {
                 table_data
                     .seek(SeekFrom::Start(data.data_resource.offset + 16))
@@ -1559,7 +1697,7 @@ fn load_entity_into_scene(
                 let materials: Vec<TagHash> =
                     TigerReadable::read_ds_endian(&mut cur, Endian::Little)?;
 
-                let model = DynamicModelComponent::load(
+                let mut model = DynamicModelComponent::load(
                     renderer,
                     &transform,
                     model_hash,
@@ -1567,6 +1705,17 @@ fn load_entity_into_scene(
                     materials,
                     TfxFeatureRenderer::DynamicObjects,
                 )?;
+
+                let has_dyed_parts = model.model.model.meshes.iter().any(|m| {
+                    m.parts.iter().any(|p| {
+                        GearDyeSlot::from_change_color_index(p.gear_dye_change_color_index)
+                            .is_some()
+                    })
+                });
+                if has_dyed_parts {
+                    model.dye_source = Some(entity_hash);
+                }
+
                 scene.entity_mut(scene_entity).insert((
                     model.model.occlusion_bounds(),
                     model,
@@ -1613,6 +1762,10 @@ fn load_entity_into_scene(
         }
     }
 
+    scene
+        .entity_mut(scene_entity)
+        .insert(EntityAnimationSource(entity_hash));
+
     Ok(scene_entity)
 }
 const FNV1_BASE: u32 = 0x811c9dc5;
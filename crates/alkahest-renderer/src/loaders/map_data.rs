@@ -0,0 +1,102 @@
+//! Walks the data tables of a map, which the map exporters pick their resources from
+
+use std::{
+    io::{Cursor, Seek, SeekFrom},
+    sync::Arc,
+};
+
+use alkahest_data::{
+    common::ResourceHash,
+    map::{SBubbleDefinition, SBubbleParent, SMapDataTable, SUnk80809885},
+};
+use alkahest_pm::package_manager;
+use anyhow::Context;
+use destiny_pkg::TagHash;
+use rustc_hash::FxHashSet;
+use tiger_parse::{PackageManagerExt, TigerReadable};
+
+/// Data table entry along with a cursor over its table, positioned at the start of the entry's
+/// data resource
+pub type MapDataEntry = (TagHash, SUnk80809885, Cursor<Arc<[u8]>>);
+
+pub struct MapDataTables {
+    pub map_hash: TagHash,
+    pub map_name: ResourceHash,
+    /// Unique data tables of every map container, sorted by hash to keep exports stable
+    pub tables: Vec<TagHash>,
+}
+
+impl MapDataTables {
+    pub fn load(map_hash: TagHash) -> anyhow::Result<Self> {
+        let bubble_parent = package_manager()
+            .read_tag_struct::<SBubbleParent>(map_hash)
+            .context("Failed to read SBubbleParent")?;
+
+        let mut tables = MapDataTables {
+            map_hash,
+            map_name: bubble_parent.map_name,
+            tables: vec![],
+        };
+
+        if bubble_parent.child_map.is_none() {
+            warn!("Map {map_hash} is missing a bubble definition!");
+            return Ok(tables);
+        }
+
+        let bubble_definition = package_manager()
+            .read_tag_struct::<SBubbleDefinition>(bubble_parent.child_map)
+            .context("Failed to read bubble definition")?;
+
+        tables.tables = bubble_definition
+            .map_resources
+            .iter()
+            .flat_map(|c| c.data_tables.iter().copied())
+            .collect::<FxHashSet<_>>()
+            .into_iter()
+            .collect();
+        tables.tables.sort_by_key(|t| t.0);
+
+        Ok(tables)
+    }
+
+    /// Every entry of every data table. A table that fails to read yields a single error, which
+    /// callers log before moving on to the other tables
+    pub fn entries(&self) -> impl Iterator<Item = anyhow::Result<MapDataEntry>> + '_ {
+        self.tables.iter().flat_map(|&table_hash| {
+            let entries: Vec<anyhow::Result<MapDataEntry>> = match read_table(table_hash) {
+                Ok((table, data)) => table
+                    .data_entries
+                    .into_iter()
+                    .map(|entry| {
+                        let mut cur = Cursor::new(data.clone());
+                        cur.seek(SeekFrom::Start(entry.data_resource.offset))?;
+                        Ok((table_hash, entry, cur))
+                    })
+                    .collect(),
+                Err(e) => vec![Err(
+                    e.context(format!("Failed to read data table {table_hash}"))
+                )],
+            };
+
+            entries
+        })
+    }
+
+    /// Entries whose data resource is of the given type
+    pub fn resources(
+        &self,
+        resource_type: u32,
+    ) -> impl Iterator<Item = anyhow::Result<MapDataEntry>> + '_ {
+        self.entries().filter(move |e| match e {
+            Ok((_, entry, _)) => entry.data_resource.resource_type == resource_type,
+            Err(_) => true,
+        })
+    }
+}
+
+fn read_table(hash: TagHash) -> anyhow::Result<(SMapDataTable, Arc<[u8]>)> {
+    let data: Arc<[u8]> = package_manager().read_tag(hash)?.into();
+    let table = TigerReadable::read_ds(&mut Cursor::new(&data[..]))?;
+
+    Ok((table, data))
+}
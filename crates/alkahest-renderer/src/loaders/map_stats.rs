@@ -0,0 +1,397 @@
+//! Map complexity statistics, gathered straight from a map's data tables without loading anything onto the GPU
+
+use std::{
+    collections::BTreeMap,
+    fmt::Write,
+    io::{Read, Seek, SeekFrom},
+};
+
+use alkahest_data::{
+    decal::SMapDecals,
+    decorator::SDecorator,
+    geometry::{ELodCategory, EPrimitiveType},
+    map::{
+        SCubemapVolume, SLightCollection, SUnk80806aa7, SUnk80806ef4, SUnk80808cb7, SUnk8080917b,
+        SUnk80809885,
+    },
+    statics::SStaticMesh,
+    technique::STechnique,
+    text::StringContainer,
+    texture::STextureHeader,
+};
+use alkahest_pm::package_manager;
+use binrw::BinReaderExt;
+use destiny_pkg::TagHash;
+use rustc_hash::{FxHashMap, FxHashSet};
+use strum::IntoEnumIterator;
+use tiger_parse::{PackageManagerExt, TigerReadable};
+
+use crate::{
+    ecs::{render::light::LightShape, tags::NodeFilter},
+    loaders::map_data::MapDataTables,
+};
+
+#[derive(Default)]
+pub struct MapStats {
+    pub map_hash: TagHash,
+    pub map_name: String,
+
+    pub nodes: FxHashMap<NodeFilter, usize>,
+    pub unique_statics: usize,
+    pub static_instances: usize,
+    /// Triangle count of all static instances, per LOD category
+    pub triangles: BTreeMap<ELodCategory, u64>,
+    pub lights: FxHashMap<LightShape, usize>,
+    pub shadowing_lights: usize,
+    pub decorators: usize,
+    pub decorator_instances: usize,
+    pub unique_textures: usize,
+    /// Estimated from the data size in the texture headers
+    pub texture_memory: u64,
+}
+
+#[derive(strum::EnumString, strum::Display, Debug, Copy, Clone, PartialEq, Eq, Default)]
+#[strum(ascii_case_insensitive)]
+pub enum MapStatSort {
+    #[default]
+    Category,
+    Name,
+    Value,
+}
+
+pub struct MapStatRow {
+    pub category: &'static str,
+    pub name: String,
+    pub value: u64,
+}
+
+impl MapStats {
+    pub fn gather(map_hash: TagHash, stringmap: &StringContainer) -> anyhow::Result<Self> {
+        let tables = MapDataTables::load(map_hash)?;
+        let mut stats = MapStats {
+            map_hash,
+            map_name: stringmap.get(tables.map_name),
+            ..Default::default()
+        };
+
+        let mut statics: FxHashMap<TagHash, usize> = FxHashMap::default();
+        let mut techniques: FxHashSet<TagHash> = FxHashSet::default();
+        let mut textures: FxHashSet<TagHash> = FxHashSet::default();
+        for entry in tables.entries() {
+            let (table_hash, entry, mut cur) = match entry {
+                Ok(entry) => entry,
+                Err(e) => {
+                    error!("{e:?}");
+                    continue;
+                }
+            };
+            if let Err(e) = stats.gather_entry(
+                &entry,
+                &mut cur,
+                &mut statics,
+                &mut techniques,
+                &mut textures,
+            ) {
+                error!("Failed to gather stats for data table {table_hash}: {e}");
+            }
+        }
+
+        stats.unique_statics = statics.len();
+        for (mesh_hash, instance_count) in statics {
+            let mesh: SStaticMesh = match package_manager().read_tag_struct(mesh_hash) {
+                Ok(m) => m,
+                Err(e) => {
+                    error!("Failed to read static mesh {mesh_hash}: {e}");
+                    continue;
+                }
+            };
+
+            for part in &mesh.opaque_meshes.parts {
+                *stats.triangles.entry(part.lod_category).or_default() +=
+                    triangle_count(part.primitive_type, part.index_count) * instance_count as u64;
+            }
+
+            for special in &mesh.special_meshes {
+                *stats.triangles.entry(special.lod).or_default() +=
+                    triangle_count(special.primitive_type, special.index_count)
+                        * instance_count as u64;
+            }
+
+            techniques.extend(mesh.techniques.iter().copied());
+            techniques.extend(mesh.special_meshes.iter().map(|m| m.technique));
+        }
+
+        for technique_hash in techniques.into_iter().filter(|t| t.is_some()) {
+            let Ok(technique) = package_manager().read_tag_struct::<STechnique>(technique_hash)
+            else {
+                continue;
+            };
+
+            for (_, shader) in technique.all_valid_shaders() {
+                textures.extend(shader.textures.iter().map(|t| t.texture.hash32()));
+            }
+        }
+
+        for texture in textures.into_iter().filter(|t| t.is_some()) {
+            if let Ok(header) = package_manager().read_tag_struct::<STextureHeader>(texture) {
+                stats.unique_textures += 1;
+                stats.texture_memory += header.data_size as u64;
+            }
+        }
+
+        Ok(stats)
+    }
+
+    fn gather_entry<R: Read + Seek>(
+        &mut self,
+        data: &SUnk80809885,
+        table_data: &mut R,
+        statics: &mut FxHashMap<TagHash, usize>,
+        techniques: &mut FxHashSet<TagHash>,
+        textures: &mut FxHashSet<TagHash>,
+    ) -> anyhow::Result<()> {
+        match data.data_resource.resource_type {
+            // Static placements
+            0x80806cc9 => {
+                table_data.seek(SeekFrom::Current(16))?;
+                let preheader_tag: TagHash = table_data.read_le()?;
+                let preheader: SUnk80806ef4 = package_manager().read_tag_struct(preheader_tag)?;
+
+                for group in &preheader.instances.instance_groups {
+                    let Some(&mesh) = preheader.instances.statics.get(group.static_index as usize)
+                    else {
+                        warn!(
+                            "Instance group in {preheader_tag} references static {} out of {}",
+                            group.static_index,
+                            preheader.instances.statics.len()
+                        );
+                        continue;
+                    };
+                    *statics.entry(mesh).or_default() += group.instance_count as usize;
+                    self.static_instances += group.instance_count as usize;
+                    self.add_nodes(NodeFilter::Static, group.instance_count as usize);
+                }
+            }
+            // Ambient sound source
+            0x8080666f => self.add_nodes(NodeFilter::Sound, 1),
+            // Sky objects
+            0x80806aa3 => {
+                table_data.seek(SeekFrom::Current(16))?;
+                let tag: TagHash = table_data.read_le()?;
+                if tag.is_none() {
+                    return Ok(());
+                }
+
+                let header: SUnk80806aa7 = package_manager().read_tag_struct(tag)?;
+                let count = header.unk8.iter().filter(|o| o.unk70 != 5).count();
+                self.add_nodes(NodeFilter::SkyObject, count);
+            }
+            // Light collection
+            0x80806a63 => {
+                table_data.seek(SeekFrom::Current(16))?;
+                let tag: TagHash = table_data.read_le()?;
+                if tag.is_none() {
+                    return Ok(());
+                }
+
+                let collection: SLightCollection = package_manager().read_tag_struct(tag)?;
+                for light in &collection.unk30 {
+                    let shape = LightShape::from_volume_matrix(light.light_to_world);
+                    *self.lights.entry(shape).or_default() += 1;
+                    techniques.insert(light.technique_shading);
+                }
+                self.add_nodes(NodeFilter::Light, collection.unk30.len());
+            }
+            // Shadowing spotlight
+            0x80806c5e => {
+                self.shadowing_lights += 1;
+                *self.lights.entry(LightShape::Spot).or_default() += 1;
+                self.add_nodes(NodeFilter::Light, 1);
+            }
+            // Lens flare
+            0x808067b5 => self.add_nodes(NodeFilter::Light, 1),
+            // Cubemap volume
+            0x80806695 => {
+                let cubemap_volume = SCubemapVolume::read_ds(table_data)?;
+                textures.insert(cubemap_volume.cubemap_texture);
+                textures.insert(cubemap_volume.voxel_ibl_texture);
+                self.add_nodes(NodeFilter::Cubemap, 1);
+            }
+            // Respawn points
+            0x80808cb5 => {
+                table_data.seek(SeekFrom::Current(16))?;
+                let tag: TagHash = table_data.read_le()?;
+                if tag.is_none() {
+                    return Ok(());
+                }
+
+                let header: SUnk80808cb7 = package_manager().read_tag_struct(tag)?;
+                self.add_nodes(NodeFilter::RespawnPoint, header.unk8.len());
+            }
+            // Decorator
+            0x80806cc3 => {
+                table_data.seek(SeekFrom::Current(16))?;
+                let header_tag: TagHash = table_data.read_le()?;
+                let header: SDecorator = package_manager().read_tag_struct(header_tag)?;
+
+                self.decorators += 1;
+                self.decorator_instances += header.unk48.instance_data.data.len();
+                self.add_nodes(NodeFilter::Decorator, 1);
+            }
+            // Decal collection
+            0x80806e62 => {
+                table_data.seek(SeekFrom::Current(16))?;
+                let tag: TagHash = table_data.read_le()?;
+                if tag.is_none() {
+                    return Ok(());
+                }
+
+                let decals: SMapDecals = package_manager().read_tag_struct(tag)?;
+                for group in &decals.groups {
+                    techniques.insert(group.material);
+                    self.add_nodes(NodeFilter::Decal, group.count as usize);
+                }
+            }
+            0x80809178 => self.add_nodes(NodeFilter::NamedArea, 1),
+            0x8080917b => {
+                let d: SUnk8080917b = TigerReadable::read_ds(table_data)?;
+                let filter = match d.kind {
+                    1 => NodeFilter::TurnbackBarrier,
+                    _ => NodeFilter::InstakillBarrier,
+                };
+                self.add_nodes(filter, 1);
+            }
+            0x80808604 => self.add_nodes(NodeFilter::PlayerContainmentVolume, 1),
+            0x80809121 => self.add_nodes(NodeFilter::SlipSurfaceVolume, 1),
+            // Terrain, water and atmosphere don't have a node filter
+            0x80806c7d | 0x808068d4 | 0x80806BC1 => {}
+            u => {
+                if data.entity.hash32().is_none() {
+                    return Ok(());
+                }
+
+                self.add_nodes(
+                    if u == u32::MAX {
+                        NodeFilter::Entity
+                    } else {
+                        NodeFilter::Unknown
+                    },
+                    1,
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    fn add_nodes(&mut self, filter: NodeFilter, count: usize) {
+        *self.nodes.entry(filter).or_default() += count;
+    }
+
+    pub fn rows(&self, sort: MapStatSort) -> Vec<MapStatRow> {
+        let mut rows = vec![];
+        let mut push = |category: &'static str, name: String, value: u64| {
+            rows.push(MapStatRow {
+                category,
+                name,
+                value,
+            })
+        };
+
+        for filter in NodeFilter::iter() {
+            if let Some(&count) = self.nodes.get(&filter) {
+                push("nodes", filter.to_string(), count as u64);
+            }
+        }
+
+        push("statics", "Unique".to_string(), self.unique_statics as u64);
+        push(
+            "statics",
+            "Instances".to_string(),
+            self.static_instances as u64,
+        );
+
+        for (lod, triangles) in &self.triangles {
+            push("triangles", format!("{lod:?}"), *triangles);
+        }
+
+        for shape in LightShape::iter() {
+            if let Some(&count) = self.lights.get(&shape) {
+                push("lights", shape.name().to_string(), count as u64);
+            }
+        }
+        push(
+            "lights",
+            "Shadowing".to_string(),
+            self.shadowing_lights as u64,
+        );
+
+        push(
+            "decorators",
+            "Decorators".to_string(),
+            self.decorators as u64,
+        );
+        push(
+            "decorators",
+            "Instances".to_string(),
+            self.decorator_instances as u64,
+        );
+
+        push(
+            "textures",
+            "Unique".to_string(),
+            self.unique_textures as u64,
+        );
+        push(
+            "textures",
+            "Memory (bytes)".to_string(),
+            self.texture_memory,
+        );
+
+        match sort {
+            // Rows are already grouped by category
+            MapStatSort::Category => {}
+            MapStatSort::Name => rows.sort_by(|a, b| a.name.cmp(&b.name)),
+            MapStatSort::Value => rows.sort_by(|a, b| b.value.cmp(&a.value)),
+        }
+
+        rows
+    }
+
+    /// Writes the stats of one or more maps as CSV, with one row per statistic
+    pub fn to_csv(stats: &[MapStats], sort: MapStatSort) -> String {
+        let mut out = String::from("map,map_name,category,name,value\n");
+        for s in stats {
+            for row in s.rows(sort) {
+                writeln!(
+                    out,
+                    "{},{},{},{},{}",
+                    s.map_hash,
+                    csv_escape(&s.map_name),
+                    row.category,
+                    csv_escape(&row.name),
+                    row.value
+                )
+                .unwrap();
+            }
+        }
+
+        out
+    }
+}
+
+fn triangle_count(primitive_type: EPrimitiveType, index_count: u32) -> u64 {
+    match primitive_type {
+        EPrimitiveType::Triangles => index_count as u64 / 3,
+        EPrimitiveType::TriangleStrip => index_count.saturating_sub(2) as u64,
+        _ => 0,
+    }
+}
+
+fn csv_escape(s: &str) -> String {
+    if s.contains([',', '"', '\n']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
@@ -0,0 +1,666 @@
+//! Converts techniques to glTF metallic-roughness materials
+//!
+//! Textures are classified from the pixel stage texture assignments, and the gstack texture is
+//! unpacked into separate maps. The gstack layout below has not been checked against the game's
+//! shaders, so the gstack is also written as is for anyone that needs to remap it:
+//! - R: metalness
+//! - G: texture ambient occlusion
+//! - B: transmission below [`GSTACK_EMISSION_THRESHOLD`], emission above it
+//! - A: smoothness
+//!
+//! Techniques whose pixel shader contains a `discard` are exported with the `MASK` alpha mode. The
+//! cutoff the shader compares against is not known, so glTF's default cutoff is used.
+
+use std::path::{Path, PathBuf};
+
+use alkahest_data::{
+    dxgi::DxgiFormat,
+    dye::GearDyeSlot,
+    technique::{STechnique, STechniqueShader},
+    texture::STextureHeader,
+};
+use alkahest_pm::package_manager;
+use anyhow::Context;
+use destiny_pkg::TagHash;
+use glam::{Vec2, Vec4};
+use render_states::PipelineStateDesc;
+use serde::Serialize;
+use serde_json::{json, Value};
+use tiger_parse::PackageManagerExt;
+
+use crate::{
+    loaders::{
+        gear_dye::GearDye,
+        gltf::{GltfBuilder, GLTF_FLOAT, GLTF_UNSIGNED_SHORT},
+        technique::evaluate_cbuffer_cpu,
+        texture_decode::{encode_png, DecodedTexture},
+    },
+    tfx::externs::ExternStorage,
+};
+
+/// Gstack blue values up to this point are transmission, values above it are emission
+pub const GSTACK_EMISSION_THRESHOLD: f32 = 40.0 / 255.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MaterialTextureRole {
+    Albedo,
+    Normal,
+    Gstack,
+    Other,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct MaterialTexture {
+    pub slot: u32,
+    #[serde(serialize_with = "alkahest_data::tag::serialize_taghash")]
+    pub texture: TagHash,
+    pub format: String,
+    pub width: u16,
+    pub height: u16,
+    pub role: MaterialTextureRole,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct MaterialParameters {
+    #[serde(serialize_with = "alkahest_data::tag::serialize_taghash")]
+    pub technique: TagHash,
+    pub textures: Vec<MaterialTexture>,
+    pub alpha_blended: bool,
+    /// The pixel shader discards pixels, usually to cut out alpha tested surfaces
+    pub alpha_tested: bool,
+    pub two_sided: bool,
+    pub pipeline: PipelineStateDesc,
+    /// Pixel stage constant buffer after evaluating the technique bytecode with default externs
+    pub cbuffer: Vec<[f32; 4]>,
+    /// Constant buffer elements written by the bytecode
+    pub cbuffer_written: Vec<usize>,
+}
+
+impl MaterialParameters {
+    pub fn from_technique(hash: TagHash) -> anyhow::Result<Self> {
+        let technique: STechnique = package_manager().read_tag_struct(hash)?;
+        let pipeline = PipelineStateDesc::from_selection(technique.states);
+        let (cbuffer, cbuffer_written) =
+            evaluate_cbuffer_cpu(&technique.shader_pixel, &ExternStorage::default())?;
+
+        let alpha_tested = match shader_discards(technique.shader_pixel.shader) {
+            Ok(discards) => discards,
+            Err(e) => {
+                warn!("Failed to read pixel shader of technique {hash}: {e:?}");
+                false
+            }
+        };
+
+        Ok(Self {
+            technique: hash,
+            textures: classify_textures(&technique.shader_pixel),
+            alpha_blended: pipeline.is_alpha_blended(),
+            alpha_tested,
+            two_sided: pipeline.is_two_sided(),
+            pipeline,
+            cbuffer: cbuffer.iter().map(|v| v.to_array()).collect(),
+            cbuffer_written,
+        })
+    }
+
+    pub fn texture(&self, role: MaterialTextureRole) -> Option<&MaterialTexture> {
+        self.textures.iter().find(|t| t.role == role)
+    }
+
+    /// glTF alpha mode
+    pub fn alpha_mode(&self) -> &'static str {
+        if self.alpha_blended {
+            "BLEND"
+        } else if self.alpha_tested {
+            "MASK"
+        } else {
+            "OPAQUE"
+        }
+    }
+}
+
+/// Whether the shader bytecode contains a `discard` instruction
+fn shader_discards(shader: TagHash) -> anyhow::Result<bool> {
+    if shader.is_none() {
+        return Ok(false);
+    }
+
+    let entry = package_manager()
+        .get_entry(shader)
+        .context("Entry not found")?;
+    let data = package_manager()
+        .read_tag(entry.reference)
+        .context("Failed to read shader data")?;
+    let Some(program) = dxbc::Dxbc::parse(&data)?.program()? else {
+        return Ok(false);
+    };
+
+    Ok(program.instructions.iter().any(|i| i.name() == "discard"))
+}
+
+/// Sorts the pixel stage textures into albedo, normal and gstack maps
+///
+/// Albedo is the first sRGB texture, normals are the first two channel texture, and the gstack is
+/// the first remaining four channel texture. Slot order decides between candidates
+fn classify_textures(shader: &STechniqueShader) -> Vec<MaterialTexture> {
+    let mut textures: Vec<MaterialTexture> = shader
+        .textures
+        .iter()
+        .filter_map(|assignment| {
+            let texture = assignment.texture.hash32_checked()?;
+            let header = match package_manager().read_tag_struct::<STextureHeader>(texture) {
+                Ok(header) => header,
+                Err(e) => {
+                    warn!("Failed to read material texture header {texture}: {e}");
+                    return None;
+                }
+            };
+
+            Some((assignment.slot, texture, header))
+        })
+        .map(|(slot, texture, header)| MaterialTexture {
+            slot,
+            texture,
+            format: format!("{:?}", header.format),
+            width: header.width,
+            height: header.height,
+            role: if header.depth > 1 || header.array_size > 1 {
+                MaterialTextureRole::Other
+            } else if header.format.is_srgb() {
+                MaterialTextureRole::Albedo
+            } else if matches!(
+                header.format,
+                DxgiFormat::BC5_UNORM | DxgiFormat::BC5_SNORM | DxgiFormat::R8G8_UNORM
+            ) {
+                MaterialTextureRole::Normal
+            } else if matches!(
+                header.format,
+                DxgiFormat::BC7_UNORM
+                    | DxgiFormat::BC3_UNORM
+                    | DxgiFormat::R8G8B8A8_UNORM
+                    | DxgiFormat::B8G8R8A8_UNORM
+            ) {
+                MaterialTextureRole::Gstack
+            } else {
+                MaterialTextureRole::Other
+            },
+        })
+        .collect();
+
+    textures.sort_by_key(|t| t.slot);
+
+    // Only the first texture of every role is used, any others are kept for reference
+    for role in [
+        MaterialTextureRole::Albedo,
+        MaterialTextureRole::Normal,
+        MaterialTextureRole::Gstack,
+    ] {
+        for t in textures.iter_mut().filter(|t| t.role == role).skip(1) {
+            t.role = MaterialTextureRole::Other;
+        }
+    }
+
+    textures
+}
+
+/// Image files written for a material, relative to the output directory
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct MaterialImages {
+    pub albedo: Option<String>,
+    pub normal: Option<String>,
+    /// Occlusion, roughness and metalness packed as glTF expects them
+    pub occlusion_roughness_metallic: Option<String>,
+    pub emissive: Option<String>,
+    /// The gstack texture as stored
+    pub gstack: Option<String>,
+    pub metalness: Option<String>,
+    pub roughness: Option<String>,
+    pub ambient_occlusion: Option<String>,
+    pub transmission: Option<String>,
+    pub emission: Option<String>,
+}
+
+impl MaterialParameters {
+    /// Decodes the material textures and writes them as PNGs to `dir`
+    pub fn write_images(&self, dir: &Path) -> anyhow::Result<MaterialImages> {
+        let mut images = MaterialImages::default();
+        let name = |suffix: &str| format!("{}_{suffix}.png", self.technique);
+        let write = |file: String, data: Vec<u8>| -> anyhow::Result<Option<String>> {
+            let path = dir.join(&file);
+            std::fs::write(&path, data)
+                .with_context(|| format!("Failed to write {}", path.display()))?;
+            Ok(Some(file))
+        };
+
+        let albedo = self
+            .texture(MaterialTextureRole::Albedo)
+            .and_then(|t| decode_logged(t.texture));
+        if let Some(albedo) = &albedo {
+            images.albedo = write(name("albedo"), albedo.to_png(0)?)?;
+        }
+
+        if let Some(normal) = self
+            .texture(MaterialTextureRole::Normal)
+            .and_then(|t| decode_logged(t.texture))
+        {
+            images.normal = write(name("normal"), reconstruct_normal_png(&normal)?)?;
+        }
+
+        let Some(gstack) = self
+            .texture(MaterialTextureRole::Gstack)
+            .and_then(|t| decode_logged(t.texture))
+        else {
+            return Ok(images);
+        };
+
+        images.gstack = write(name("gstack"), gstack.to_png(0)?)?;
+
+        let (width, height) = (gstack.width, gstack.height);
+        let texels: Vec<GstackTexel> = gstack
+            .layer(0)
+            .iter()
+            .map(|p| GstackTexel::unpack(*p))
+            .collect();
+        let channel = |f: &dyn Fn(&GstackTexel) -> f32| -> Vec<u8> {
+            texels
+                .iter()
+                .map(|t| (f(t).clamp(0.0, 1.0) * 255.0).round() as u8)
+                .collect()
+        };
+        let gray = |data: Vec<u8>| {
+            encode_png(
+                &data,
+                width,
+                height,
+                png::ColorType::Grayscale,
+                png::BitDepth::Eight,
+            )
+        };
+
+        let metalness = channel(&|t| t.metalness);
+        let roughness = channel(&|t| t.roughness);
+        let occlusion = channel(&|t| t.occlusion);
+        let transmission = channel(&|t| t.transmission);
+        let emission = channel(&|t| t.emission);
+
+        let orm: Vec<u8> = occlusion
+            .iter()
+            .zip(&roughness)
+            .zip(&metalness)
+            .flat_map(|((o, r), m)| [*o, *r, *m])
+            .collect();
+        images.occlusion_roughness_metallic = write(
+            name("orm"),
+            encode_png(
+                &orm,
+                width,
+                height,
+                png::ColorType::Rgb,
+                png::BitDepth::Eight,
+            )?,
+        )?;
+
+        // Emissive surfaces glow in their albedo color
+        if emission.iter().any(|&e| e > 0) {
+            let emissive: Vec<Vec4> = emission
+                .iter()
+                .enumerate()
+                .map(|(i, &e)| {
+                    let uv = Vec2::new(
+                        ((i % width) as f32 + 0.5) / width as f32,
+                        ((i / width) as f32 + 0.5) / height as f32,
+                    );
+                    let color = albedo
+                        .as_ref()
+                        .map(|a| a.sample(0, uv))
+                        .unwrap_or(Vec4::ONE);
+                    (color * (e as f32 / 255.0)).truncate().extend(1.0)
+                })
+                .collect();
+
+            let emissive = DecodedTexture {
+                width,
+                height,
+                layers: 1,
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                pixels: emissive,
+            };
+            images.emissive = write(name("emissive"), emissive.to_png(0)?)?;
+        }
+
+        images.metalness = write(name("metalness"), gray(metalness)?)?;
+        images.roughness = write(name("roughness"), gray(roughness)?)?;
+        images.ambient_occlusion = write(name("ao"), gray(occlusion)?)?;
+        images.transmission = write(name("transmission"), gray(transmission)?)?;
+        images.emission = write(name("emission"), gray(emission)?)?;
+
+        Ok(images)
+    }
+}
+
+fn decode_logged(texture: TagHash) -> Option<DecodedTexture> {
+    match DecodedTexture::load(texture) {
+        Ok(t) => Some(t),
+        Err(e) => {
+            warn!("Failed to decode material texture {texture}: {e:?}");
+            None
+        }
+    }
+}
+
+/// Material channels of a single gstack texel, following the layout in the module docs
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct GstackTexel {
+    metalness: f32,
+    roughness: f32,
+    occlusion: f32,
+    transmission: f32,
+    emission: f32,
+}
+
+impl GstackTexel {
+    fn unpack(p: Vec4) -> Self {
+        Self {
+            metalness: p.x,
+            roughness: 1.0 - p.w,
+            occlusion: p.y,
+            transmission: (p.z / GSTACK_EMISSION_THRESHOLD).min(1.0),
+            emission: ((p.z - GSTACK_EMISSION_THRESHOLD) / (1.0 - GSTACK_EMISSION_THRESHOLD))
+                .max(0.0),
+        }
+    }
+}
+
+/// Normal maps only store X and Y, Z is reconstructed for viewers that expect all three
+fn reconstruct_normal_png(normal: &DecodedTexture) -> anyhow::Result<Vec<u8>> {
+    let data: Vec<u8> = normal
+        .layer(0)
+        .iter()
+        .flat_map(|p| reconstruct_normal(normal.format, *p))
+        .collect();
+
+    encode_png(
+        &data,
+        normal.width,
+        normal.height,
+        png::ColorType::Rgb,
+        png::BitDepth::Eight,
+    )
+}
+
+/// Tangent space normal packed into 0..255. Signed formats already decode to -1..1, unsigned ones
+/// are remapped from 0..1
+fn reconstruct_normal(format: DxgiFormat, p: Vec4) -> [u8; 3] {
+    let (x, y) = if matches!(format, DxgiFormat::BC5_SNORM | DxgiFormat::R8G8_SNORM) {
+        (p.x, p.y)
+    } else {
+        (p.x * 2.0 - 1.0, p.y * 2.0 - 1.0)
+    };
+    let z = (1.0 - x * x - y * y).max(0.0).sqrt();
+    [x, y, z].map(|v| ((v * 0.5 + 0.5).clamp(0.0, 1.0) * 255.0).round() as u8)
+}
+
+/// Collects materials, textures and images for a glTF document
+#[derive(Default)]
+pub struct GltfMaterialLibrary {
+    pub images: Vec<Value>,
+    pub textures: Vec<Value>,
+    pub materials: Vec<Value>,
+}
+
+impl GltfMaterialLibrary {
+    fn texture(&mut self, uri: &Option<String>) -> Option<usize> {
+        let uri = uri.as_ref()?;
+        self.images.push(json!({ "uri": uri }));
+        self.textures.push(json!({
+            "sampler": 0,
+            "source": self.images.len() - 1,
+        }));
+        Some(self.textures.len() - 1)
+    }
+
+    /// Adds a material and returns its index
+    pub fn add(&mut self, params: &MaterialParameters, images: &MaterialImages) -> usize {
+        let mut pbr = json!({
+            "baseColorFactor": [1.0, 1.0, 1.0, 1.0],
+            "metallicFactor": 1.0,
+            "roughnessFactor": 1.0,
+        });
+        let mut material = json!({
+            "name": params.technique.to_string(),
+            "alphaMode": params.alpha_mode(),
+            "doubleSided": params.two_sided,
+        });
+
+        if let Some(index) = self.texture(&images.albedo) {
+            pbr["baseColorTexture"] = json!({ "index": index });
+        }
+        if let Some(index) = self.texture(&images.occlusion_roughness_metallic) {
+            pbr["metallicRoughnessTexture"] = json!({ "index": index });
+            material["occlusionTexture"] = json!({ "index": index });
+        } else {
+            // Without a gstack there is nothing to go on, so fall back to a rough dielectric
+            pbr["metallicFactor"] = json!(0.0);
+        }
+        if let Some(index) = self.texture(&images.normal) {
+            material["normalTexture"] = json!({ "index": index });
+        }
+        if let Some(index) = self.texture(&images.emissive) {
+            material["emissiveTexture"] = json!({ "index": index });
+            material["emissiveFactor"] = json!([1.0, 1.0, 1.0]);
+        }
+
+        material["pbrMetallicRoughness"] = pbr;
+        self.materials.push(material);
+        self.materials.len() - 1
+    }
+
+    /// Adds a material tinted with the albedo of a gear dye slot, keeping the dye parameters in the
+    /// material extras
+    pub fn add_dyed(
+        &mut self,
+        params: &MaterialParameters,
+        images: &MaterialImages,
+        slot: GearDyeSlot,
+        dye: &GearDye,
+    ) -> usize {
+        let index = self.add(params, images);
+        let channel = dye.parameters.channel(slot);
+        let [r, g, b, _] = channel.albedo_tint;
+
+        let material = &mut self.materials[index];
+        material["name"] = json!(format!("{} {}", params.technique, slot.name()));
+        material["pbrMetallicRoughness"]["baseColorFactor"] = json!([r, g, b, 1.0]);
+        material["extras"] = json!({
+            "gear_dye": {
+                "dye": dye.hash.to_string(),
+                "slot": slot,
+                "parameters": channel,
+            },
+        });
+
+        index
+    }
+
+    /// Adds the materials, textures, images and a shared sampler to `document`
+    pub fn apply(self, document: &mut Value) {
+        document["materials"] = json!(self.materials);
+        if !self.textures.is_empty() {
+            document["textures"] = json!(self.textures);
+            document["images"] = json!(self.images);
+            // Linear filtering with mipmaps, repeating
+            document["samplers"] = json!([{
+                "magFilter": 9729,
+                "minFilter": 9987,
+                "wrapS": 10497,
+                "wrapT": 10497,
+            }]);
+        }
+    }
+}
+
+/// Exports techniques as materials applied to a row of preview quads, with a JSON sidecar holding
+/// the raw parameters. Returns the path of the sidecar
+pub fn export_materials(techniques: &[TagHash], path: &Path) -> anyhow::Result<PathBuf> {
+    let dir = path.parent().unwrap_or(Path::new("."));
+    let mut library = GltfMaterialLibrary::default();
+    let mut sidecar = vec![];
+    for &technique in techniques {
+        let result = MaterialParameters::from_technique(technique).and_then(|params| {
+            let images = params.write_images(dir)?;
+            Ok((params, images))
+        });
+
+        match result {
+            Ok((params, images)) => {
+                library.add(&params, &images);
+                sidecar.push(json!({
+                    "parameters": params,
+                    "images": images,
+                }));
+            }
+            Err(e) => error!("Failed to export material {technique}: {e:?}"),
+        }
+    }
+
+    // Unit quad facing +Z in glTF space
+    let mut gltf = GltfBuilder::default();
+    let positions: [[f32; 3]; 4] = [[0., 0., 0.], [1., 0., 0.], [1., 1., 0.], [0., 1., 0.]];
+    let position = gltf.accessor(
+        bytemuck::cast_slice(&positions),
+        4,
+        GLTF_FLOAT,
+        "VEC3",
+        false,
+        Some(([0., 0., 0.], [1., 1., 0.])),
+    );
+    let normal = gltf.accessor(
+        bytemuck::cast_slice(&[[0f32, 0., 1.]; 4]),
+        4,
+        GLTF_FLOAT,
+        "VEC3",
+        false,
+        None,
+    );
+    let texcoords: [[f32; 2]; 4] = [[0., 1.], [1., 1.], [1., 0.], [0., 0.]];
+    let texcoord = gltf.accessor(
+        bytemuck::cast_slice(&texcoords),
+        4,
+        GLTF_FLOAT,
+        "VEC2",
+        false,
+        None,
+    );
+    let indices = gltf.accessor(
+        bytemuck::cast_slice(&[0u16, 1, 2, 0, 2, 3]),
+        6,
+        GLTF_UNSIGNED_SHORT,
+        "SCALAR",
+        false,
+        None,
+    );
+
+    let (meshes, nodes): (Vec<Value>, Vec<Value>) = (0..library.materials.len())
+        .map(|i| {
+            (
+                json!({
+                    "primitives": [{
+                        "attributes": {
+                            "POSITION": position,
+                            "NORMAL": normal,
+                            "TEXCOORD_0": texcoord,
+                        },
+                        "indices": indices,
+                        "material": i,
+                    }],
+                }),
+                json!({
+                    "name": library.materials[i]["name"],
+                    "mesh": i,
+                    "translation": [i as f32 * 1.25, 0.0, 0.0],
+                }),
+            )
+        })
+        .unzip();
+
+    let roots: Vec<usize> = (0..nodes.len()).collect();
+    let mut document = json!({
+        "asset": {
+            "version": "2.0",
+            "generator": concat!("alkahest ", env!("CARGO_PKG_VERSION")),
+        },
+        "scene": 0,
+        "scenes": [{ "nodes": roots }],
+        "nodes": nodes,
+        "meshes": meshes,
+    });
+    library.apply(&mut document);
+
+    let bin_path = path.with_extension("bin");
+    let (document, buffer) = gltf.finish(
+        document,
+        &bin_path
+            .file_name()
+            .context("Invalid output path")?
+            .to_string_lossy(),
+    );
+
+    std::fs::write(path, serde_json::to_string_pretty(&document)?)
+        .with_context(|| format!("Failed to write {}", path.display()))?;
+    std::fs::write(&bin_path, buffer)
+        .with_context(|| format!("Failed to write {}", bin_path.display()))?;
+
+    let json_path = path.with_extension("materials.json");
+    std::fs::write(&json_path, serde_json::to_string_pretty(&sidecar)?)
+        .with_context(|| format!("Failed to write {}", json_path.display()))?;
+
+    Ok(json_path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normal_reconstruction() {
+        // Straight up normal in both encodings
+        assert_eq!(
+            reconstruct_normal(DxgiFormat::BC5_UNORM, Vec4::new(0.5, 0.5, 0.0, 1.0)),
+            [128, 128, 255]
+        );
+        assert_eq!(
+            reconstruct_normal(DxgiFormat::BC5_SNORM, Vec4::new(0.0, 0.0, 0.0, 1.0)),
+            [128, 128, 255]
+        );
+
+        // Fully tilted along +X, which signed data must not remap a second time
+        assert_eq!(
+            reconstruct_normal(DxgiFormat::BC5_UNORM, Vec4::new(1.0, 0.5, 0.0, 1.0)),
+            [255, 128, 128]
+        );
+        assert_eq!(
+            reconstruct_normal(DxgiFormat::BC5_SNORM, Vec4::new(1.0, 0.0, 0.0, 1.0)),
+            [255, 128, 128]
+        );
+        assert_eq!(
+            reconstruct_normal(DxgiFormat::BC5_SNORM, Vec4::new(-1.0, 0.0, 0.0, 1.0)),
+            [0, 128, 128]
+        );
+    }
+
+    #[test]
+    fn gstack_channels() {
+        let t = GstackTexel::unpack(Vec4::new(0.25, 0.5, 0.0, 0.75));
+        assert_eq!(t.metalness, 0.25);
+        assert_eq!(t.occlusion, 0.5);
+        assert_eq!(t.roughness, 0.25);
+        assert_eq!((t.transmission, t.emission), (0.0, 0.0));
+
+        let t = GstackTexel::unpack(Vec4::new(0.0, 0.0, GSTACK_EMISSION_THRESHOLD, 1.0));
+        assert_eq!((t.transmission, t.emission), (1.0, 0.0));
+
+        let t = GstackTexel::unpack(Vec4::new(0.0, 0.0, 1.0, 1.0));
+        assert_eq!((t.transmission, t.emission), (1.0, 1.0));
+    }
+}
@@ -11,11 +11,31 @@ use crate::{
     util::{d3d::ErrorExt, packages::TagHashExt},
 };
 
+pub mod atmosphere_export;
+pub mod cubemap_export;
+pub mod decal_export;
+pub mod decorator_export;
+pub mod exr;
+pub mod gear_dye;
+pub mod gltf;
 pub mod index_buffer;
+pub mod ktx2;
+pub mod light_export;
+pub mod light_params;
+pub mod light_probe;
 pub mod map;
+pub mod map_data;
+pub mod map_stats;
+pub mod material_export;
+pub mod skinned_export;
 pub mod technique;
+pub mod terrain_export;
 pub mod texture;
+pub mod texture_decode;
+pub mod topdown_map;
 pub mod vertex_buffer;
+pub mod volume_export;
+pub mod water;
 
 pub struct AssetManager {
     gctx: SharedGpuContext,
@@ -0,0 +1,971 @@
+//! Skinned entity models: skeletons, per-vertex joint weights, glTF skins and animations
+//!
+//! Dynamic mesh vertices are laid out as follows:
+//! - vertex buffer 0: SNORM16x4 position, followed by SNORM16x2 texcoords when the stride allows
+//!   for it. Positions are `position.xyz * model_scale + model_offset`, texcoords are
+//!   `texcoord * texcoord_scale + texcoord_offset`
+//! - vertex buffer 1: SNORM16x4 normal
+//!
+//! The W component of the position selects how a vertex is skinned:
+//! - `0..0x800`: the vertex is rigidly bound to the skeleton node with that index
+//! - anything else: the low 11 bits index an 8 byte entry in the skinning buffer, holding 4 node
+//!   indices followed by 4 UNORM8 weights
+//!
+//! Skeleton transforms are stored in object space, local transforms are derived from the object
+//! space transform of the parent node.
+
+use std::{
+    io::{Cursor, Seek, SeekFrom},
+    path::{Path, PathBuf},
+};
+
+use alkahest_data::{
+    animation::{find_entity_clips, AnimationClip, Interpolation, Keyframes},
+    dye::GearDyeSlot,
+    entity::{
+        variant_technique_index, IdentifierMask, SDynamicMesh, SDynamicModel, SEntity, SSkeleton,
+        Unk808072c5,
+    },
+    geometry::EPrimitiveType,
+    tfx::TfxRenderStage,
+};
+use alkahest_pm::package_manager;
+use anyhow::Context;
+use destiny_pkg::TagHash;
+use glam::{Mat4, Quat, Vec2, Vec3};
+use itertools::Itertools;
+use rustc_hash::{FxHashMap, FxHashSet};
+use serde_json::json;
+use tiger_parse::{Endian, PackageManagerExt, TigerReadable};
+
+use crate::loaders::{
+    gear_dye::EntityDyes,
+    gltf::{to_gltf_space, GltfBuilder, GLTF_FLOAT, GLTF_UNSIGNED_INT, GLTF_UNSIGNED_SHORT},
+    index_buffer::{read_index_buffer, unroll_triangle_strip},
+    map_data::MapDataTables,
+    material_export::{GltfMaterialLibrary, MaterialParameters},
+    vertex_buffer::read_vertex_buffer,
+};
+
+/// Position W values below this bind the vertex to a single node
+const RIGID_NODE_LIMIT: i16 = 0x800;
+
+#[derive(Debug, Clone)]
+pub struct SkeletonJoint {
+    pub name_hash: u32,
+    pub parent: Option<usize>,
+    pub object_space: Mat4,
+    pub inverse_bind: Mat4,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Skeleton {
+    pub joints: Vec<SkeletonJoint>,
+}
+
+impl Skeleton {
+    pub fn from_data(skeleton: &SSkeleton) -> Self {
+        let joints = skeleton
+            .nodes
+            .iter()
+            .enumerate()
+            .map(|(i, node)| {
+                let object_space = skeleton
+                    .default_object_space_transforms
+                    .get(i)
+                    .map(|t| t.to_mat4())
+                    .unwrap_or(Mat4::IDENTITY);
+                let inverse_bind = skeleton
+                    .default_inverse_object_space_transforms
+                    .get(i)
+                    .map(|t| t.to_mat4())
+                    .unwrap_or_else(|| object_space.inverse());
+
+                SkeletonJoint {
+                    name_hash: node.name_hash.0,
+                    parent: usize::try_from(node.parent_node_index)
+                        .ok()
+                        .filter(|&p| p < skeleton.nodes.len() && p != i),
+                    object_space,
+                    inverse_bind,
+                }
+            })
+            .collect();
+
+        Self { joints }
+    }
+
+    /// Transform of a joint relative to its parent
+    pub fn local_transform(&self, index: usize) -> (Vec3, Quat, Vec3) {
+        let joint = &self.joints[index];
+        let local = match joint.parent {
+            Some(parent) => self.joints[parent].object_space.inverse() * joint.object_space,
+            None => joint.object_space,
+        };
+
+        let (scale, rotation, translation) = local.to_scale_rotation_translation();
+        (translation, rotation.normalize(), scale)
+    }
+
+    /// Joints without a parent, or the children of `parent`
+    pub fn children(&self, parent: Option<usize>) -> Vec<usize> {
+        self.joints
+            .iter()
+            .positions(|j| j.parent == parent)
+            .collect()
+    }
+}
+
+/// A part of a dynamic mesh, unrolled into a triangle list
+pub struct SkinnedPart {
+    pub technique: TagHash,
+    pub dye_slot: Option<GearDyeSlot>,
+    pub indices: Vec<u32>,
+}
+
+pub struct SkinnedMesh {
+    pub positions: Vec<Vec3>,
+    pub normals: Vec<Vec3>,
+    pub texcoords: Vec<Vec2>,
+    pub joints: Vec<[u16; 4]>,
+    pub weights: Vec<[f32; 4]>,
+    pub parts: Vec<SkinnedPart>,
+}
+
+/// The dynamic model and skeleton of an entity
+pub struct SkinnedModel {
+    pub entity: TagHash,
+    pub model: TagHash,
+    pub skeleton: Option<Skeleton>,
+    pub meshes: Vec<SkinnedMesh>,
+    pub animations: Vec<AnimationClip>,
+    /// Material variant the part techniques were resolved with
+    pub variant: usize,
+    pub identifiers: IdentifierMask,
+    pub dyes: EntityDyes,
+}
+
+impl SkinnedModel {
+    /// Loads every part of the entity model, with the techniques of its first material variant
+    pub fn load_entity(entity: TagHash) -> anyhow::Result<Self> {
+        Self::load_entity_variant(entity, 0, IdentifierMask::All)
+    }
+
+    /// Loads the parts of the entity model matching `identifiers`, with the techniques of the given
+    /// material variant
+    pub fn load_entity_variant(
+        entity: TagHash,
+        variant: usize,
+        identifiers: IdentifierMask,
+    ) -> anyhow::Result<Self> {
+        let header: SEntity = package_manager()
+            .read_tag_struct(entity)
+            .context("Failed to read SEntity")?;
+
+        let mut model = None;
+        let mut skeleton = None;
+        for e in &header.entity_resources {
+            let entres = &e.unk0;
+            match entres.unk10.resource_type {
+                // Dynamic model, see `load_entity_into_scene`
+                0x80806d8a => {
+                    let mut cur = Cursor::new(package_manager().read_tag(entres.taghash())?);
+                    cur.seek(SeekFrom::Start(entres.unk18.offset + 0x224))?;
+                    let model_hash: TagHash =
+                        TigerReadable::read_ds_endian(&mut cur, Endian::Little)?;
+
+                    cur.seek(SeekFrom::Start(entres.unk18.offset + 0x3c0))?;
+                    let technique_map: Vec<Unk808072c5> =
+                        TigerReadable::read_ds_endian(&mut cur, Endian::Little)?;
+
+                    cur.seek(SeekFrom::Start(entres.unk18.offset + 0x400))?;
+                    let techniques: Vec<TagHash> =
+                        TigerReadable::read_ds_endian(&mut cur, Endian::Little)?;
+
+                    model.get_or_insert((model_hash, technique_map, techniques));
+                }
+                // Skeleton
+                0x808081dd => {
+                    let mut cur = Cursor::new(package_manager().read_tag(entres.taghash())?);
+                    cur.seek(SeekFrom::Start(entres.unk18.offset))?;
+                    let data: SSkeleton = TigerReadable::read_ds_endian(&mut cur, Endian::Little)
+                        .context("Failed to read skeleton")?;
+                    skeleton.get_or_insert(Skeleton::from_data(&data));
+                }
+                _ => {}
+            }
+        }
+
+        let Some((model_hash, technique_map, techniques)) = model else {
+            anyhow::bail!("Entity {entity} does not have a dynamic model");
+        };
+
+        let model: SDynamicModel = package_manager()
+            .read_tag_struct(model_hash)
+            .context("Failed to read SDynamicModel")?;
+
+        let has_dyed_parts = model.meshes.iter().any(|m| {
+            m.parts.iter().any(|p| {
+                GearDyeSlot::from_change_color_index(p.gear_dye_change_color_index).is_some()
+            })
+        });
+        let dyes = if has_dyed_parts {
+            EntityDyes::load(&header)
+        } else {
+            EntityDyes::default()
+        };
+
+        let mut meshes = vec![];
+        for (i, mesh) in model.meshes.iter().enumerate() {
+            match Self::load_mesh(
+                &model,
+                mesh,
+                &technique_map,
+                &techniques,
+                variant,
+                identifiers,
+            ) {
+                Ok(mesh) => meshes.push(mesh),
+                Err(e) => error!("Failed to load mesh {i} of model {model_hash}: {e:?}"),
+            }
+        }
+
+        if let Some(skeleton) = &skeleton {
+            let joint_count = skeleton.joints.len() as u16;
+            let mut invalid = 0;
+            for mesh in &mut meshes {
+                for (joints, weights) in mesh.joints.iter_mut().zip(mesh.weights.iter_mut()) {
+                    for (joint, weight) in joints.iter_mut().zip(weights.iter_mut()) {
+                        if *joint >= joint_count {
+                            invalid += 1;
+                            *joint = 0;
+                            *weight = 0.0;
+                        }
+                    }
+
+                    if weights.iter().all(|&w| w == 0.0) {
+                        weights[0] = 1.0;
+                    }
+                }
+            }
+
+            if invalid > 0 {
+                warn!(
+                    "Model {model_hash} has {invalid} joint influences outside of its {joint_count} node skeleton"
+                );
+            }
+        }
+
+        Ok(Self {
+            entity,
+            model: model_hash,
+            skeleton,
+            meshes,
+            animations: vec![],
+            variant,
+            identifiers,
+            dyes,
+        })
+    }
+
+    /// Loads the animation clips referenced by the resources of the entity
+    pub fn load_animations(&mut self) -> anyhow::Result<()> {
+        let header: SEntity = package_manager().read_tag_struct(self.entity)?;
+        for clip in find_entity_clips(&header) {
+            match AnimationClip::load(clip) {
+                Ok(clip) => self.animations.push(clip),
+                Err(e) => error!("Failed to load animation clip {clip}: {e:?}"),
+            }
+        }
+
+        Ok(())
+    }
+
+    fn load_mesh(
+        model: &SDynamicModel,
+        mesh: &SDynamicMesh,
+        technique_map: &[Unk808072c5],
+        techniques: &[TagHash],
+        variant: usize,
+        identifiers: IdentifierMask,
+    ) -> anyhow::Result<SkinnedMesh> {
+        let (vertex0, stride0) =
+            read_vertex_buffer(mesh.vertex0_buffer).context("Failed to read vertex buffer 0")?;
+        anyhow::ensure!(stride0 >= 8, "Unexpected vertex buffer 0 stride {stride0}");
+
+        let vertex1 = if mesh.vertex1_buffer.is_some() {
+            Some(
+                read_vertex_buffer(mesh.vertex1_buffer)
+                    .context("Failed to read vertex buffer 1")?,
+            )
+        } else {
+            None
+        };
+
+        let skinning_buffer = if mesh.skinning_buffer.is_some() {
+            read_vertex_buffer(mesh.skinning_buffer)
+                .context("Failed to read skinning buffer")?
+                .0
+        } else {
+            vec![]
+        };
+
+        let i16_at =
+            |data: &[u8], offset: usize| i16::from_le_bytes([data[offset], data[offset + 1]]);
+        let snorm = |v: i16| v as f32 / i16::MAX as f32;
+
+        let vertex_count = vertex0.len() / stride0;
+        let mut skinned = SkinnedMesh {
+            positions: Vec::with_capacity(vertex_count),
+            normals: Vec::with_capacity(vertex_count),
+            texcoords: Vec::with_capacity(vertex_count),
+            joints: Vec::with_capacity(vertex_count),
+            weights: Vec::with_capacity(vertex_count),
+            parts: vec![],
+        };
+
+        for v in vertex0.chunks_exact(stride0) {
+            let position = Vec3::new(
+                snorm(i16_at(v, 0)),
+                snorm(i16_at(v, 2)),
+                snorm(i16_at(v, 4)),
+            );
+            skinned
+                .positions
+                .push(position * model.model_scale.truncate() + model.model_offset.truncate());
+
+            let texcoord = if stride0 >= 12 {
+                Vec2::new(snorm(i16_at(v, 8)), snorm(i16_at(v, 10)))
+            } else {
+                Vec2::ZERO
+            };
+            skinned
+                .texcoords
+                .push(texcoord * model.texcoord_scale + model.texcoord_offset);
+
+            let (joints, weights) = decode_skin_weights(i16_at(v, 6), &skinning_buffer);
+            skinned.joints.push(joints);
+            skinned.weights.push(weights);
+        }
+
+        match &vertex1 {
+            Some((vertex1, stride1)) if *stride1 >= 8 => {
+                skinned
+                    .normals
+                    .extend(vertex1.chunks_exact(*stride1).map(|v| {
+                        Vec3::new(
+                            snorm(i16_at(v, 0)),
+                            snorm(i16_at(v, 2)),
+                            snorm(i16_at(v, 4)),
+                        )
+                        .normalize_or_zero()
+                    }));
+            }
+            _ => {}
+        }
+        skinned.normals.resize(vertex_count, Vec3::Z);
+
+        let indices = read_index_buffer(mesh.index_buffer).context("Failed to read indices")?;
+        let mut seen_ranges = FxHashSet::default();
+        for part in &mesh.parts[mesh.get_range_for_stage(TfxRenderStage::GenerateGbuffer)] {
+            if !part.lod_category.is_highest_detail()
+                || !identifiers.contains(part.external_identifier)
+                || !seen_ranges.insert((part.index_start, part.index_count))
+            {
+                continue;
+            }
+
+            let start = part.index_start as usize;
+            let end = (start + part.index_count as usize).min(indices.len());
+            let Some(part_indices) = indices.get(start..end) else {
+                continue;
+            };
+
+            let triangles = match part.primitive_type {
+                EPrimitiveType::TriangleStrip => unroll_triangle_strip(part_indices),
+                EPrimitiveType::Triangles => part_indices
+                    .chunks_exact(3)
+                    .map(|t| [t[0], t[1], t[2]])
+                    .collect(),
+                _ => continue,
+            };
+
+            // Variant techniques override the technique of the part, like they do in the renderer
+            let technique =
+                variant_technique_index(technique_map, part.variant_shader_index, variant)
+                    .and_then(|i| techniques.get(i).copied())
+                    .unwrap_or(part.technique);
+
+            skinned.parts.push(SkinnedPart {
+                technique,
+                dye_slot: GearDyeSlot::from_change_color_index(part.gear_dye_change_color_index),
+                indices: triangles
+                    .into_iter()
+                    .filter(|t| t.iter().all(|&v| (v as usize) < vertex_count))
+                    .flatten()
+                    .collect(),
+            });
+        }
+
+        Ok(skinned)
+    }
+}
+
+/// Decodes the joints and normalized weights of a vertex from the W component of its position
+pub fn decode_skin_weights(position_w: i16, skinning_buffer: &[u8]) -> ([u16; 4], [f32; 4]) {
+    if (0..RIGID_NODE_LIMIT).contains(&position_w) {
+        return ([position_w as u16, 0, 0, 0], [1.0, 0.0, 0.0, 0.0]);
+    }
+
+    let offset = (position_w as u16 & 0x7ff) as usize * 8;
+    let Some(entry) = skinning_buffer.get(offset..offset + 8) else {
+        return ([0; 4], [1.0, 0.0, 0.0, 0.0]);
+    };
+
+    let joints: [u16; 4] = std::array::from_fn(|i| entry[i] as u16);
+    let weights: [f32; 4] = std::array::from_fn(|i| entry[4 + i] as f32 / 255.0);
+
+    let total: f32 = weights.iter().sum();
+    if total <= f32::EPSILON {
+        return ([joints[0], 0, 0, 0], [1.0, 0.0, 0.0, 0.0]);
+    }
+
+    (joints, weights.map(|w| w / total))
+}
+
+/// Finds every entity placed in a map that has both a dynamic model and a skeleton
+pub fn find_map_skinned_entities(map_hash: TagHash) -> anyhow::Result<Vec<(TagHash, Mat4)>> {
+    let mut is_skinned: FxHashMap<TagHash, bool> = FxHashMap::default();
+    let mut entities = vec![];
+    for entry in MapDataTables::load(map_hash)?.entries() {
+        let (_, entry, _) = match entry {
+            Ok(entry) => entry,
+            Err(e) => {
+                error!("{e:?}");
+                continue;
+            }
+        };
+        let entity = entry.entity.hash32();
+        if entity.is_none() {
+            continue;
+        }
+
+        let skinned = *is_skinned
+            .entry(entity)
+            .or_insert_with(|| has_skeleton(entity).unwrap_or(false));
+        if skinned {
+            entities.push((
+                entity,
+                Mat4::from_scale_rotation_translation(
+                    Vec3::splat(entry.translation.w),
+                    entry.rotation,
+                    entry.translation.truncate(),
+                ),
+            ));
+        }
+    }
+
+    Ok(entities)
+}
+
+fn has_skeleton(entity: TagHash) -> anyhow::Result<bool> {
+    if package_manager()
+        .get_entry(entity)
+        .map_or(true, |v| Some(v.reference) != SEntity::ID)
+    {
+        return Ok(false);
+    }
+
+    let header: SEntity = package_manager().read_tag_struct(entity)?;
+    let resource_types = header
+        .entity_resources
+        .iter()
+        .map(|e| e.unk0.unk10.resource_type)
+        .collect_vec();
+
+    Ok(resource_types.contains(&0x80806d8a) && resource_types.contains(&0x808081dd))
+}
+
+/// A glTF animation sampler for a single node channel
+struct ClipChannel {
+    node: usize,
+    path: &'static str,
+    interpolation: Interpolation,
+    input: usize,
+    output: usize,
+}
+
+/// Writes the keyframes of every track in `clip` to the buffer
+fn write_clip_channels(gltf: &mut GltfBuilder, clip: &AnimationClip) -> Vec<ClipChannel> {
+    fn channel<T>(
+        gltf: &mut GltfBuilder,
+        node: usize,
+        path: &'static str,
+        keys: &Keyframes<T>,
+        kind: &str,
+        values: &[f32],
+    ) -> Option<ClipChannel> {
+        let count = keys.times.len().min(keys.values.len());
+        if count == 0 {
+            return None;
+        }
+
+        let times = &keys.times[..count];
+        let input = gltf.accessor(
+            bytemuck::cast_slice(times),
+            count,
+            GLTF_FLOAT,
+            "SCALAR",
+            false,
+            None,
+        );
+        // Animation inputs require bounds
+        gltf.accessors[input]["min"] = json!([times[0]]);
+        gltf.accessors[input]["max"] = json!([times[count - 1]]);
+
+        let components = values.len() / keys.values.len();
+        let output = gltf.accessor(
+            bytemuck::cast_slice(&values[..count * components]),
+            count,
+            GLTF_FLOAT,
+            kind,
+            false,
+            None,
+        );
+
+        Some(ClipChannel {
+            node,
+            path,
+            interpolation: keys.interpolation,
+            input,
+            output,
+        })
+    }
+
+    let mut channels = vec![];
+    for track in &clip.tracks {
+        if let Some(keys) = &track.translation {
+            let values = keys.values.iter().flat_map(|v| v.to_array()).collect_vec();
+            channels.extend(channel(
+                gltf,
+                track.node,
+                "translation",
+                keys,
+                "VEC3",
+                &values,
+            ));
+        }
+        if let Some(keys) = &track.rotation {
+            let values = keys.values.iter().flat_map(|q| q.to_array()).collect_vec();
+            channels.extend(channel(gltf, track.node, "rotation", keys, "VEC4", &values));
+        }
+        if let Some(keys) = &track.scale {
+            let values = keys.values.iter().flat_map(|v| v.to_array()).collect_vec();
+            channels.extend(channel(gltf, track.node, "scale", keys, "VEC3", &values));
+        }
+    }
+
+    channels
+}
+
+/// Writes placed models as a glTF file with a skin per placement, along with a `.rigs.json` sidecar
+/// listing the joints of every model. Meshes are shared between placements of the same model.
+/// When `materials` is set, the techniques of every part are exported next to the glTF file with
+/// [`GltfMaterialLibrary`]. Loaded animation clips are written as a glTF animation per placement,
+/// targeting the skeleton joints, or the placement itself for the root track of models without a
+/// skeleton. Returns the path of the sidecar
+pub fn export_skinned_models(
+    models: &[SkinnedModel],
+    placements: &[(usize, Mat4)],
+    path: &Path,
+    materials: bool,
+) -> anyhow::Result<PathBuf> {
+    let dir = path.parent().unwrap_or(Path::new("."));
+    let mut gltf = GltfBuilder::default();
+    let mut library = GltfMaterialLibrary::default();
+    // Keyed by technique, and the dye and slot tinting it
+    let mut material_indices: FxHashMap<(TagHash, Option<(TagHash, GearDyeSlot)>), Option<usize>> =
+        FxHashMap::default();
+
+    let mut meshes = vec![];
+    // (glTF mesh indices, inverse bind matrix accessor, animation channels) per model
+    let mut model_data = vec![];
+    for model in models {
+        let clips = model
+            .animations
+            .iter()
+            .map(|clip| (clip.hash, write_clip_channels(&mut gltf, clip)))
+            .collect_vec();
+
+        let inverse_bind_matrices = model.skeleton.as_ref().map(|skeleton| {
+            let inverse_binds = skeleton
+                .joints
+                .iter()
+                .map(|j| j.inverse_bind.to_cols_array())
+                .collect_vec();
+            gltf.accessor(
+                bytemuck::cast_slice(&inverse_binds),
+                inverse_binds.len(),
+                GLTF_FLOAT,
+                "MAT4",
+                false,
+                None,
+            )
+        });
+
+        let mut mesh_indices = vec![];
+        for (mesh_index, mesh) in model.meshes.iter().enumerate() {
+            if mesh.parts.iter().all(|p| p.indices.is_empty()) {
+                continue;
+            }
+
+            let count = mesh.positions.len();
+            let min = mesh
+                .positions
+                .iter()
+                .fold(Vec3::INFINITY, |acc, p| acc.min(*p));
+            let max = mesh
+                .positions
+                .iter()
+                .fold(Vec3::NEG_INFINITY, |acc, p| acc.max(*p));
+            let positions = mesh.positions.iter().map(|p| p.to_array()).collect_vec();
+            let normals = mesh.normals.iter().map(|n| n.to_array()).collect_vec();
+            let texcoords = mesh.texcoords.iter().map(|t| t.to_array()).collect_vec();
+
+            let mut attributes = json!({
+                "POSITION": gltf.accessor(
+                    bytemuck::cast_slice(&positions),
+                    count,
+                    GLTF_FLOAT,
+                    "VEC3",
+                    false,
+                    Some((min.to_array(), max.to_array())),
+                ),
+                "NORMAL": gltf.accessor(
+                    bytemuck::cast_slice(&normals),
+                    count,
+                    GLTF_FLOAT,
+                    "VEC3",
+                    false,
+                    None,
+                ),
+                "TEXCOORD_0": gltf.accessor(
+                    bytemuck::cast_slice(&texcoords),
+                    count,
+                    GLTF_FLOAT,
+                    "VEC2",
+                    false,
+                    None,
+                ),
+            });
+            if model.skeleton.is_some() {
+                attributes["JOINTS_0"] = json!(gltf.accessor(
+                    bytemuck::cast_slice(&mesh.joints),
+                    count,
+                    GLTF_UNSIGNED_SHORT,
+                    "VEC4",
+                    false,
+                    None,
+                ));
+                attributes["WEIGHTS_0"] = json!(gltf.accessor(
+                    bytemuck::cast_slice(&mesh.weights),
+                    count,
+                    GLTF_FLOAT,
+                    "VEC4",
+                    false,
+                    None,
+                ));
+            }
+
+            let mut primitives = vec![];
+            for part in mesh.parts.iter().filter(|p| !p.indices.is_empty()) {
+                let mut primitive = json!({
+                    "attributes": attributes,
+                    "indices": gltf.accessor(
+                        bytemuck::cast_slice(&part.indices),
+                        part.indices.len(),
+                        GLTF_UNSIGNED_INT,
+                        "SCALAR",
+                        false,
+                        None,
+                    ),
+                });
+
+                if materials && part.technique.is_some() {
+                    let dye = part
+                        .dye_slot
+                        .and_then(|slot| Some((slot, model.dyes.dye(slot)?)));
+                    let key = (part.technique, dye.map(|(slot, dye)| (dye.hash, slot)));
+                    let material = *material_indices.entry(key).or_insert_with(|| {
+                        MaterialParameters::from_technique(part.technique)
+                            .and_then(|params| {
+                                let images = params.write_images(dir)?;
+                                Ok(match dye {
+                                    Some((slot, dye)) => {
+                                        library.add_dyed(&params, &images, slot, dye)
+                                    }
+                                    None => library.add(&params, &images),
+                                })
+                            })
+                            .map_err(|e| {
+                                error!("Failed to export material {}: {e:?}", part.technique)
+                            })
+                            .ok()
+                    });
+
+                    if let Some(material) = material {
+                        primitive["material"] = json!(material);
+                    }
+                }
+
+                primitives.push(primitive);
+            }
+
+            mesh_indices.push(meshes.len());
+            meshes.push(json!({
+                "name": format!("{} mesh{mesh_index}", model.model),
+                "primitives": primitives,
+            }));
+        }
+
+        model_data.push((mesh_indices, inverse_bind_matrices, clips));
+    }
+
+    // Node 0 converts from Destiny's Z-up space, everything below it is left as-is
+    let mut nodes = vec![json!({
+        "name": "root",
+        "rotation": to_gltf_space(Vec3::ZERO, Quat::IDENTITY).1.to_array(),
+    })];
+    let mut root_children = vec![];
+    let mut skins = vec![];
+    let mut animations = vec![];
+    let mut sidecar = vec![];
+
+    for &(model_index, transform) in placements {
+        let (model, (mesh_indices, inverse_bind_matrices, clips)) =
+            (&models[model_index], &model_data[model_index]);
+
+        let model_node = nodes.len();
+        root_children.push(model_node);
+        let (scale, rotation, translation) = transform.to_scale_rotation_translation();
+        nodes.push(json!({
+            "name": model.entity.to_string(),
+            "translation": translation.to_array(),
+            "rotation": rotation.to_array(),
+            "scale": scale.to_array(),
+            "extras": {
+                "entity": model.entity.to_string(),
+                "model": model.model.to_string(),
+            },
+        }));
+        let mut model_children = vec![];
+
+        let first_joint = nodes.len();
+        let skin = model.skeleton.as_ref().map(|skeleton| {
+            for (i, joint) in skeleton.joints.iter().enumerate() {
+                let (translation, rotation, scale) = skeleton.local_transform(i);
+                let mut node = json!({
+                    "name": format!("{:08X}", joint.name_hash),
+                    "translation": translation.to_array(),
+                    "rotation": rotation.to_array(),
+                    "scale": scale.to_array(),
+                });
+
+                let children = skeleton.children(Some(i));
+                if !children.is_empty() {
+                    node["children"] =
+                        json!(children.into_iter().map(|c| first_joint + c).collect_vec());
+                }
+                nodes.push(node);
+            }
+
+            model_children.extend(
+                skeleton
+                    .children(None)
+                    .into_iter()
+                    .map(|root| first_joint + root),
+            );
+
+            let mut skin = json!({
+                "name": model.entity.to_string(),
+                "joints": (first_joint..first_joint + skeleton.joints.len()).collect_vec(),
+            });
+            if let Some(accessor) = inverse_bind_matrices {
+                skin["inverseBindMatrices"] = json!(accessor);
+            }
+            skins.push(skin);
+            skins.len() - 1
+        });
+
+        // Rigid models are animated through a node between the placement and the meshes
+        let animated_node = (model.skeleton.is_none() && !clips.is_empty()).then(|| {
+            nodes.push(json!({ "name": format!("{} animated", model.entity) }));
+            model_children.push(nodes.len() - 1);
+            nodes.len() - 1
+        });
+
+        let mut mesh_nodes = vec![];
+        for &mesh in mesh_indices {
+            let mut node = json!({
+                "name": meshes[mesh]["name"],
+                "mesh": mesh,
+            });
+            if let Some(skin) = skin {
+                node["skin"] = json!(skin);
+            }
+            mesh_nodes.push(nodes.len());
+            nodes.push(node);
+        }
+
+        match animated_node {
+            Some(animated_node) if !mesh_nodes.is_empty() => {
+                nodes[animated_node]["children"] = json!(mesh_nodes);
+            }
+            _ => model_children.extend(mesh_nodes),
+        }
+
+        if !model_children.is_empty() {
+            nodes[model_node]["children"] = json!(model_children);
+        }
+
+        let target_node = |node: usize| match &model.skeleton {
+            Some(skeleton) => (node < skeleton.joints.len()).then_some(first_joint + node),
+            None => animated_node.filter(|_| node == 0),
+        };
+        for (clip, channels) in clips {
+            let mut samplers = vec![];
+            let mut targets = vec![];
+            for channel in channels {
+                let Some(target) = target_node(channel.node) else {
+                    continue;
+                };
+
+                targets.push(json!({
+                    "sampler": samplers.len(),
+                    "target": { "node": target, "path": channel.path },
+                }));
+                samplers.push(json!({
+                    "input": channel.input,
+                    "output": channel.output,
+                    "interpolation": match channel.interpolation {
+                        Interpolation::Step => "STEP",
+                        Interpolation::Linear => "LINEAR",
+                    },
+                }));
+            }
+
+            if !targets.is_empty() {
+                animations.push(json!({
+                    "name": format!("{} {clip}", model.entity),
+                    "channels": targets,
+                    "samplers": samplers,
+                }));
+            }
+        }
+
+        sidecar.push(json!({
+            "entity": model.entity.to_string(),
+            "model": model.model.to_string(),
+            "variant": model.variant,
+            "identifiers": model.identifiers.to_string(),
+            "dyes": model.dyes.dyes,
+            "transform": transform.to_cols_array(),
+            "animations": model.animations.iter().map(|c| json!({
+                "clip": c.hash.to_string(),
+                "duration": c.duration,
+                "tracks": c.tracks.len(),
+            })).collect_vec(),
+            "joints": model.skeleton.as_ref().map(|s| s.joints.iter().map(|j| json!({
+                "name_hash": format!("{:08X}", j.name_hash),
+                "parent": j.parent,
+            })).collect_vec()),
+        }));
+    }
+
+    if !root_children.is_empty() {
+        nodes[0]["children"] = json!(root_children);
+    }
+
+    let mut document = json!({
+        "asset": {
+            "version": "2.0",
+            "generator": concat!("alkahest ", env!("CARGO_PKG_VERSION")),
+        },
+        "scene": 0,
+        "scenes": [{ "nodes": [0] }],
+        "nodes": nodes,
+        "meshes": meshes,
+    });
+    if !skins.is_empty() {
+        document["skins"] = json!(skins);
+    }
+    if !animations.is_empty() {
+        document["animations"] = json!(animations);
+    }
+    if materials {
+        library.apply(&mut document);
+    }
+
+    let bin_path = path.with_extension("bin");
+    let (document, buffer) = gltf.finish(
+        document,
+        &bin_path
+            .file_name()
+            .context("Invalid output path")?
+            .to_string_lossy(),
+    );
+
+    std::fs::write(path, serde_json::to_string_pretty(&document)?)
+        .with_context(|| format!("Failed to write {}", path.display()))?;
+    std::fs::write(&bin_path, buffer)
+        .with_context(|| format!("Failed to write {}", bin_path.display()))?;
+
+    let json_path = path.with_extension("rigs.json");
+    std::fs::write(&json_path, serde_json::to_string_pretty(&sidecar)?)
+        .with_context(|| format!("Failed to write {}", json_path.display()))?;
+
+    Ok(json_path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn skin_weights() {
+        let mut buffer = vec![0u8; 8 * 3];
+        // Entry 1 blends 3 joints, entry 2 has no weights at all
+        buffer[8..16].copy_from_slice(&[3, 7, 9, 0, 100, 100, 50, 0]);
+        buffer[16..24].copy_from_slice(&[4, 5, 6, 0, 0, 0, 0, 0]);
+
+        // Anything below the rigid limit is a single joint index
+        assert_eq!(
+            decode_skin_weights(5, &buffer),
+            ([5, 0, 0, 0], [1.0, 0.0, 0.0, 0.0])
+        );
+        assert_eq!(
+            decode_skin_weights(RIGID_NODE_LIMIT - 1, &buffer),
+            ([0x7ff, 0, 0, 0], [1.0, 0.0, 0.0, 0.0])
+        );
+
+        // The low 11 bits index 8 byte entries, weights are normalized
+        let (joints, weights) = decode_skin_weights(RIGID_NODE_LIMIT | 1, &buffer);
+        assert_eq!(joints, [3, 7, 9, 0]);
+        for (w, expected) in weights.iter().zip([0.4, 0.4, 0.2, 0.0]) {
+            assert!((w - expected).abs() < 1e-6, "{weights:?}");
+        }
+        assert_eq!(decode_skin_weights(i16::MIN | 1, &buffer).0, [3, 7, 9, 0]);
+
+        // Entries past the end of the buffer fall back to the root joint
+        assert_eq!(
+            decode_skin_weights(RIGID_NODE_LIMIT | 3, &buffer),
+            ([0, 0, 0, 0], [1.0, 0.0, 0.0, 0.0])
+        );
+
+        // Entries without any weight keep their first joint
+        assert_eq!(
+            decode_skin_weights(RIGID_NODE_LIMIT | 2, &buffer),
+            ([4, 0, 0, 0], [1.0, 0.0, 0.0, 0.0])
+        );
+    }
+}
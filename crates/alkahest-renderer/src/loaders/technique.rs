@@ -5,6 +5,7 @@ use alkahest_data::{
 use alkahest_pm::package_manager;
 use anyhow::{ensure, Context};
 use destiny_pkg::TagHash;
+use glam::Vec4;
 use tiger_parse::PackageManagerExt;
 use windows::Win32::Graphics::Direct3D11::ID3D11SamplerState;
 
@@ -12,6 +13,7 @@ use crate::{
     gpu::{buffer::ConstantBufferCached, GpuContext, SharedGpuContext},
     tfx::{
         bytecode::{interpreter::TfxBytecodeInterpreter, opcodes::TfxBytecodeOp},
+        externs::ExternStorage,
         technique::{ShaderModule, Technique, TechniqueStage},
     },
 };
@@ -49,6 +51,57 @@ pub fn load_technique(gctx: SharedGpuContext, hash: TagHash) -> anyhow::Result<T
     })
 }
 
+/// Reads the initial constant buffer contents of a technique stage, if it has any
+pub fn load_cbuffer_data(shader: &STechniqueShader) -> anyhow::Result<Option<Vec<Vec4>>> {
+    if shader.constants.constant_buffer.is_some() {
+        let buffer_header_ref = package_manager()
+            .get_entry(shader.constants.constant_buffer)
+            .context("Constant buffer entry not found")?
+            .reference;
+
+        let data_raw = package_manager()
+            .read_tag(buffer_header_ref)
+            .context("Failed to read constant buffer data")?;
+
+        Ok(Some(bytemuck::cast_slice(&data_raw).to_vec()))
+    } else if !shader.constants.unk38.is_empty() {
+        Ok(Some(shader.constants.unk38.clone()))
+    } else {
+        Ok(None)
+    }
+}
+
+/// Evaluates the bytecode of a technique stage on the CPU against the given externs
+///
+/// Returns the resulting constant buffer along with the elements written by the bytecode
+pub fn evaluate_cbuffer_cpu(
+    shader: &STechniqueShader,
+    externs: &ExternStorage,
+) -> anyhow::Result<(Vec<Vec4>, Vec<usize>)> {
+    let mut cbuffer = load_cbuffer_data(shader)?.unwrap_or_default();
+    if shader.constants.bytecode.is_empty() {
+        return Ok((cbuffer, vec![]));
+    }
+
+    let opcodes = TfxBytecodeOp::parse_all(&shader.constants.bytecode, binrw::Endian::Little)?;
+    let interpreter = TfxBytecodeInterpreter::new(opcodes);
+
+    let written: Vec<usize> = interpreter
+        .output_elements()
+        .into_iter()
+        .flat_map(|(element, mat4)| element..element + if mat4 { 4 } else { 1 })
+        .collect();
+    if let Some(&max) = written.iter().max() {
+        if cbuffer.len() <= max {
+            cbuffer.resize(max + 1, Vec4::ZERO);
+        }
+    }
+
+    interpreter.evaluate_cpu(externs, &mut cbuffer, &shader.constants.bytecode_constants)?;
+
+    Ok((cbuffer, written))
+}
+
 fn load_technique_stage(
     gctx: SharedGpuContext,
     shader: &STechniqueShader,
@@ -59,28 +112,10 @@ fn load_technique_stage(
         return Ok(None);
     }
 
-    let cbuffer = if shader.constants.constant_buffer.is_some() {
-        let buffer_header_ref = package_manager()
-            .get_entry(shader.constants.constant_buffer)
-            .context("Constant buffer entry not found")?
-            .reference;
-
-        let data_raw = package_manager()
-            .read_tag(buffer_header_ref)
-            .context("Failed to read constant buffer data")?;
-
-        let data = bytemuck::cast_slice(&data_raw);
-        let buf = ConstantBufferCached::create_array_init(gctx.clone(), data)
+    let cbuffer = if let Some(data) = load_cbuffer_data(shader)? {
+        let buf = ConstantBufferCached::create_array_init(gctx.clone(), &data)
             .context("Failed to create constant buffer from data")?;
 
-        Some(buf)
-    } else if !shader.constants.unk38.is_empty() {
-        let buf = ConstantBufferCached::create_array_init(
-            gctx.clone(),
-            bytemuck::cast_slice(&shader.constants.unk38),
-        )
-        .context("Failed to create constant buffer from data")?;
-
         Some(buf)
     } else {
         None
@@ -0,0 +1,592 @@
+//! Terrain extraction: a unified mesh per detail level, a heightmap resampled over the terrain
+//! bounds and the per-group dyemaps stitched into a single splat texture
+//!
+//! Terrain vertices are stored as 16-bit integers. World positions are `position * unk30.w + unk30.xyz`,
+//! and dyemaps are sampled with the texcoords transformed by the group's `unk20` (`uv * xy + zw`),
+//! mirroring what `TerrainPatches` hands to the terrain shaders.
+//!
+//! Images are laid out with +X to the right and +Y up, so row 0 is the northern (max Y) edge of the
+//! bounds.
+
+use std::path::Path;
+
+use alkahest_data::{
+    map::{STerrain, SUnk8080714b, SUnk80807152},
+    occlusion::Aabb,
+};
+use alkahest_pm::package_manager;
+use anyhow::Context;
+use destiny_pkg::TagHash;
+use glam::{Quat, Vec2, Vec3, Vec4};
+use itertools::Itertools;
+use serde_json::{json, Value};
+use tiger_parse::{PackageManagerExt, TigerReadable};
+
+use crate::loaders::{
+    exr::encode_exr,
+    gltf::{to_gltf_space, GltfBuilder, GLTF_FLOAT, GLTF_UNSIGNED_INT},
+    index_buffer::{read_index_buffer, unroll_triangle_strip},
+    map_data::MapDataTables,
+    texture_decode::{encode_png, f16_to_f32, DecodedTexture},
+    vertex_buffer::read_vertex_buffer,
+};
+
+/// Largest heightmap/splat texture dimension
+const MAX_RASTER_SIZE: usize = 8192;
+
+/// Terrain vertex and index data decoded to world space
+pub struct TerrainData {
+    pub hash: TagHash,
+    pub terrain: STerrain,
+    pub positions: Vec<Vec3>,
+    pub normals: Vec<Vec3>,
+    pub texcoords: Vec<Vec2>,
+    /// Triangle strip indices, with strip cuts as `u32::MAX`
+    pub indices: Vec<u32>,
+}
+
+/// Every part of a detail level merged into one indexed triangle list
+pub struct TerrainMesh {
+    pub detail_level: u8,
+    pub positions: Vec<Vec3>,
+    pub normals: Vec<Vec3>,
+    pub texcoords: Vec<Vec2>,
+    /// Position within the terrain bounds, matching the heightmap and splat texture
+    pub splat_texcoords: Vec<Vec2>,
+    pub indices: Vec<u32>,
+}
+
+/// Heights and dyemap colors rasterized from the most detailed terrain level
+pub struct TerrainRaster {
+    pub width: usize,
+    pub height: usize,
+    pub bounds: Aabb,
+    pub meters_per_pixel: f32,
+    /// World space height per pixel, NaN where there is no terrain
+    pub heights: Vec<f32>,
+    pub splat: Vec<Vec4>,
+}
+
+impl TerrainData {
+    pub fn load(hash: TagHash) -> anyhow::Result<Self> {
+        let terrain: STerrain = package_manager().read_tag_struct(hash)?;
+
+        let (vertex0, stride0) = read_vertex_buffer(terrain.vertex0_buffer)
+            .context("Failed to read terrain vertex buffer 0")?;
+        let (vertex1, stride1) = read_vertex_buffer(terrain.vertex1_buffer)
+            .context("Failed to read terrain vertex buffer 1")?;
+        let indices =
+            read_index_buffer(terrain.index_buffer).context("Failed to read terrain indices")?;
+
+        anyhow::ensure!(
+            stride0 >= 8 && stride1 >= 4,
+            "Unexpected terrain vertex strides {stride0}/{stride1}"
+        );
+
+        let i16_at =
+            |data: &[u8], offset: usize| i16::from_le_bytes([data[offset], data[offset + 1]]);
+        let offset = terrain.unk30.truncate();
+        let scale = terrain.unk30.w;
+
+        let mut positions = vec![];
+        let mut normals = vec![];
+        for v in vertex0.chunks_exact(stride0) {
+            let p = Vec3::new(
+                i16_at(v, 0) as f32,
+                i16_at(v, 2) as f32,
+                i16_at(v, 4) as f32,
+            );
+            positions.push(p * scale + offset);
+
+            if stride0 >= 16 {
+                let n = Vec3::new(
+                    i16_at(v, 8) as f32,
+                    i16_at(v, 10) as f32,
+                    i16_at(v, 12) as f32,
+                ) / i16::MAX as f32;
+                normals.push(n.normalize_or_zero());
+            } else {
+                normals.push(Vec3::Z);
+            }
+        }
+
+        let texcoords = vertex1
+            .chunks_exact(stride1)
+            .map(|v| {
+                Vec2::new(
+                    f16_to_f32(u16::from_le_bytes([v[0], v[1]])),
+                    f16_to_f32(u16::from_le_bytes([v[2], v[3]])),
+                )
+            })
+            .collect_vec();
+
+        if texcoords.len() != positions.len() {
+            warn!(
+                "Terrain {hash} has {} positions but {} texcoords",
+                positions.len(),
+                texcoords.len()
+            );
+        }
+
+        Ok(Self {
+            hash,
+            terrain,
+            positions,
+            normals,
+            texcoords,
+            indices,
+        })
+    }
+
+    pub fn detail_levels(&self) -> Vec<u8> {
+        self.terrain
+            .mesh_parts
+            .iter()
+            .map(|p| p.detail_level)
+            .sorted()
+            .dedup()
+            .collect()
+    }
+
+    /// Unrolls the triangle strip of a part into a triangle list
+    pub fn part_triangles(&self, part: &SUnk80807152) -> Vec<[u32; 3]> {
+        let start = part.index_start as usize;
+        let end = (start + part.index_count as usize).min(self.indices.len());
+        let Some(strip) = self.indices.get(start..end) else {
+            return vec![];
+        };
+
+        let vertex_count = self.positions.len().min(self.texcoords.len()) as u32;
+        unroll_triangle_strip(strip)
+            .into_iter()
+            .filter(|t| t.iter().all(|&v| v < vertex_count))
+            .collect()
+    }
+
+    fn group_texcoord(&self, group_index: u8, vertex: u32) -> Vec2 {
+        let transform = self
+            .terrain
+            .mesh_groups
+            .get(group_index as usize)
+            .map(|g| g.unk20)
+            .unwrap_or(Vec4::new(1.0, 1.0, 0.0, 0.0));
+
+        self.texcoords[vertex as usize] * Vec2::new(transform.x, transform.y)
+            + Vec2::new(transform.z, transform.w)
+    }
+
+    fn splat_texcoord(&self, position: Vec3) -> Vec2 {
+        let bounds = &self.terrain.bounds;
+        let size = (bounds.max - bounds.min)
+            .truncate()
+            .max(Vec2::splat(f32::EPSILON));
+        Vec2::new(
+            (position.x - bounds.min.x) / size.x,
+            (bounds.max.y - position.y) / size.y,
+        )
+    }
+
+    /// Merges every part of a detail level into a single mesh, keeping only the vertices it uses
+    pub fn mesh(&self, detail_level: u8) -> TerrainMesh {
+        let mut mesh = TerrainMesh {
+            detail_level,
+            positions: vec![],
+            normals: vec![],
+            texcoords: vec![],
+            splat_texcoords: vec![],
+            indices: vec![],
+        };
+
+        let mut remap = vec![u32::MAX; self.positions.len()];
+        for part in self
+            .terrain
+            .mesh_parts
+            .iter()
+            .filter(|p| p.detail_level == detail_level)
+        {
+            for triangle in self.part_triangles(part) {
+                for v in triangle {
+                    if remap[v as usize] == u32::MAX {
+                        remap[v as usize] = mesh.positions.len() as u32;
+                        let position = self.positions[v as usize];
+                        mesh.positions.push(position);
+                        mesh.normals.push(self.normals[v as usize]);
+                        mesh.texcoords
+                            .push(self.group_texcoord(part.group_index, v));
+                        mesh.splat_texcoords.push(self.splat_texcoord(position));
+                    }
+
+                    mesh.indices.push(remap[v as usize]);
+                }
+            }
+        }
+
+        mesh
+    }
+
+    /// Rasterizes the most detailed level into a heightmap, sampling the group dyemaps along the way
+    pub fn rasterize(&self, meters_per_pixel: f32) -> anyhow::Result<TerrainRaster> {
+        let bounds = self.terrain.bounds;
+        let size = (bounds.max - bounds.min).truncate();
+        anyhow::ensure!(size.min_element() > 0.0, "Terrain bounds are empty");
+
+        let meters_per_pixel = meters_per_pixel
+            .max(size.max_element() / MAX_RASTER_SIZE as f32)
+            .max(f32::EPSILON);
+        let width = ((size.x / meters_per_pixel).ceil() as usize).max(1);
+        let height = ((size.y / meters_per_pixel).ceil() as usize).max(1);
+
+        let dyemaps: Vec<Option<DecodedTexture>> = self
+            .terrain
+            .mesh_groups
+            .iter()
+            .map(|g| {
+                let hash = g.dyemap;
+                if hash.is_none() {
+                    return None;
+                }
+
+                DecodedTexture::load(hash)
+                    .map_err(|e| warn!("Failed to decode terrain dyemap {hash}: {e:?}"))
+                    .ok()
+            })
+            .collect();
+
+        let mut raster = TerrainRaster {
+            width,
+            height,
+            bounds,
+            meters_per_pixel,
+            heights: vec![f32::NAN; width * height],
+            splat: vec![Vec4::ZERO; width * height],
+        };
+
+        let Some(&detail_level) = self.detail_levels().first() else {
+            return Ok(raster);
+        };
+
+        let to_pixel = |p: Vec3| {
+            Vec2::new(
+                (p.x - bounds.min.x) / meters_per_pixel,
+                (bounds.max.y - p.y) / meters_per_pixel,
+            )
+        };
+
+        for part in self
+            .terrain
+            .mesh_parts
+            .iter()
+            .filter(|p| p.detail_level == detail_level)
+        {
+            let dyemap = dyemaps
+                .get(part.group_index as usize)
+                .and_then(|d| d.as_ref());
+
+            for triangle in self.part_triangles(part) {
+                let p = triangle.map(|v| self.positions[v as usize]);
+                let uv = triangle.map(|v| self.group_texcoord(part.group_index, v));
+                let [a, b, c] = p.map(to_pixel);
+
+                let area = (b - a).perp_dot(c - a);
+                if area.abs() <= f32::EPSILON {
+                    continue;
+                }
+
+                let min = a.min(b).min(c).floor().max(Vec2::ZERO);
+                let max = a
+                    .max(b)
+                    .max(c)
+                    .ceil()
+                    .min(Vec2::new(width as f32, height as f32));
+
+                for y in min.y as usize..max.y as usize {
+                    for x in min.x as usize..max.x as usize {
+                        let center = Vec2::new(x as f32 + 0.5, y as f32 + 0.5);
+                        let w0 = (c - b).perp_dot(center - b) / area;
+                        let w1 = (a - c).perp_dot(center - c) / area;
+                        let w2 = 1.0 - w0 - w1;
+                        if w0 < -1e-4 || w1 < -1e-4 || w2 < -1e-4 {
+                            continue;
+                        }
+
+                        let z = p[0].z * w0 + p[1].z * w1 + p[2].z * w2;
+                        let i = y * width + x;
+                        if raster.heights[i].is_nan() || z > raster.heights[i] {
+                            raster.heights[i] = z;
+                            if let Some(dyemap) = dyemap {
+                                raster.splat[i] =
+                                    dyemap.sample(0, uv[0] * w0 + uv[1] * w1 + uv[2] * w2);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(raster)
+    }
+
+    /// Builds a glTF document with one node per detail level. Positions are converted to glTF's Y-up space
+    pub fn to_gltf(&self, buffer_uri: &str) -> (Value, Vec<u8>) {
+        let basis = to_gltf_space(Vec3::ZERO, Quat::IDENTITY).1;
+        let mut gltf = GltfBuilder::default();
+        let mut meshes = vec![];
+        let mut nodes = vec![];
+
+        for detail_level in self.detail_levels() {
+            let mesh = self.mesh(detail_level);
+            if mesh.indices.is_empty() {
+                continue;
+            }
+
+            let positions = mesh
+                .positions
+                .iter()
+                .map(|p| to_gltf_space(*p, Quat::IDENTITY).0)
+                .collect_vec();
+            let normals = mesh
+                .normals
+                .iter()
+                .map(|n| (basis * *n).to_array())
+                .collect_vec();
+            let min = positions.iter().fold(Vec3::INFINITY, |acc, p| acc.min(*p));
+            let max = positions
+                .iter()
+                .fold(Vec3::NEG_INFINITY, |acc, p| acc.max(*p));
+            let positions = positions.iter().map(|p| p.to_array()).collect_vec();
+            let texcoords = mesh.texcoords.iter().map(|t| t.to_array()).collect_vec();
+            let splat_texcoords = mesh
+                .splat_texcoords
+                .iter()
+                .map(|t| t.to_array())
+                .collect_vec();
+
+            let count = positions.len();
+            let position = gltf.accessor(
+                bytemuck::cast_slice(&positions),
+                count,
+                GLTF_FLOAT,
+                "VEC3",
+                false,
+                Some((min.to_array(), max.to_array())),
+            );
+            let normal = gltf.accessor(
+                bytemuck::cast_slice(&normals),
+                count,
+                GLTF_FLOAT,
+                "VEC3",
+                false,
+                None,
+            );
+            let texcoord0 = gltf.accessor(
+                bytemuck::cast_slice(&texcoords),
+                count,
+                GLTF_FLOAT,
+                "VEC2",
+                false,
+                None,
+            );
+            let texcoord1 = gltf.accessor(
+                bytemuck::cast_slice(&splat_texcoords),
+                count,
+                GLTF_FLOAT,
+                "VEC2",
+                false,
+                None,
+            );
+            let indices = gltf.accessor(
+                bytemuck::cast_slice(&mesh.indices),
+                mesh.indices.len(),
+                GLTF_UNSIGNED_INT,
+                "SCALAR",
+                false,
+                None,
+            );
+
+            nodes.push(json!({
+                "name": format!("{} lod{detail_level}", self.hash),
+                "mesh": meshes.len(),
+                "extras": { "detail_level": detail_level },
+            }));
+            meshes.push(json!({
+                "name": format!("terrain_lod{detail_level}"),
+                "primitives": [{
+                    "attributes": {
+                        "POSITION": position,
+                        "NORMAL": normal,
+                        "TEXCOORD_0": texcoord0,
+                        "TEXCOORD_1": texcoord1,
+                    },
+                    "indices": indices,
+                }],
+            }));
+        }
+
+        let document = json!({
+            "asset": {
+                "version": "2.0",
+                "generator": concat!("alkahest ", env!("CARGO_PKG_VERSION")),
+            },
+            "scene": 0,
+            "scenes": [{
+                "name": self.hash.to_string(),
+                "nodes": (0..nodes.len()).collect_vec(),
+            }],
+            "nodes": nodes,
+            "meshes": meshes,
+        });
+
+        gltf.finish(document, buffer_uri)
+    }
+
+    /// Writes `terrain.gltf`/`terrain.bin`, `heightmap.png`, `heightmap.exr`, `splat.png` and a
+    /// `terrain.json` manifest to `output`
+    pub fn export(&self, output: &Path, meters_per_pixel: f32) -> anyhow::Result<()> {
+        std::fs::create_dir_all(output)
+            .with_context(|| format!("Failed to create {}", output.display()))?;
+        let write = |name: &str, data: &[u8]| {
+            let path = output.join(name);
+            std::fs::write(&path, data)
+                .with_context(|| format!("Failed to write {}", path.display()))
+        };
+
+        let (gltf, buffer) = self.to_gltf("terrain.bin");
+        write(
+            "terrain.gltf",
+            serde_json::to_string_pretty(&gltf)?.as_bytes(),
+        )?;
+        write("terrain.bin", &buffer)?;
+
+        let raster = self.rasterize(meters_per_pixel)?;
+        let (height_min, height_max) = raster.height_range();
+        write("heightmap.png", &raster.heightmap_png16()?)?;
+        write("heightmap.exr", &raster.heightmap_exr()?)?;
+        write("splat.png", &raster.splat_png()?)?;
+
+        let manifest = json!({
+            "terrain": self.hash.to_string(),
+            "bounds": {
+                "min": self.terrain.bounds.min.to_array(),
+                "max": self.terrain.bounds.max.to_array(),
+            },
+            "vertex_transform": self.terrain.unk30.to_array(),
+            "detail_levels": self.detail_levels().iter().map(|&level| json!({
+                "detail_level": level,
+                "parts": self.terrain.mesh_parts.iter().filter(|p| p.detail_level == level).count(),
+            })).collect_vec(),
+            "heightmap": {
+                "width": raster.width,
+                "height": raster.height,
+                "meters_per_pixel": raster.meters_per_pixel,
+                // heightmap.png maps 0-65535 to this range, heightmap.exr stores world heights directly
+                "height_min": height_min,
+                "height_max": height_max,
+            },
+            "groups": self.terrain.mesh_groups.iter().enumerate().map(|(i, g)| json!({
+                "index": i,
+                "dyemap": g.dyemap.to_string(),
+                "texcoord_transform": g.unk20.to_array(),
+            })).collect_vec(),
+        });
+        write(
+            "terrain.json",
+            serde_json::to_string_pretty(&manifest)?.as_bytes(),
+        )?;
+
+        Ok(())
+    }
+}
+
+impl TerrainRaster {
+    pub fn height_range(&self) -> (f32, f32) {
+        let (min, max) = self
+            .heights
+            .iter()
+            .filter(|h| h.is_finite())
+            .fold((f32::INFINITY, f32::NEG_INFINITY), |(min, max), &h| {
+                (min.min(h), max.max(h))
+            });
+
+        if min > max {
+            (self.bounds.min.z, self.bounds.max.z)
+        } else {
+            (min, max)
+        }
+    }
+
+    /// 16-bit grayscale heightmap normalized to [`Self::height_range`]. Holes are black
+    pub fn heightmap_png16(&self) -> anyhow::Result<Vec<u8>> {
+        let (min, max) = self.height_range();
+        let range = (max - min).max(f32::EPSILON);
+        let data: Vec<u8> = self
+            .heights
+            .iter()
+            .map(|&h| {
+                if h.is_finite() {
+                    (((h - min) / range).clamp(0.0, 1.0) * 65535.0).round() as u16
+                } else {
+                    0
+                }
+            })
+            .flat_map(|v| v.to_be_bytes())
+            .collect();
+
+        encode_png(
+            &data,
+            self.width,
+            self.height,
+            png::ColorType::Grayscale,
+            png::BitDepth::Sixteen,
+        )
+    }
+
+    /// Single channel float heightmap with world space heights. Holes are set to the lowest height
+    pub fn heightmap_exr(&self) -> anyhow::Result<Vec<u8>> {
+        let (min, _) = self.height_range();
+        let heights = self
+            .heights
+            .iter()
+            .map(|&h| if h.is_finite() { h } else { min })
+            .collect_vec();
+
+        encode_exr(self.width, self.height, &[("Y", &heights)])
+    }
+
+    /// Linear 8-bit RGBA splat texture
+    pub fn splat_png(&self) -> anyhow::Result<Vec<u8>> {
+        let data: Vec<u8> = self
+            .splat
+            .iter()
+            .flat_map(|p| p.to_array())
+            .map(|v| (v.clamp(0.0, 1.0) * 255.0).round() as u8)
+            .collect();
+
+        encode_png(
+            &data,
+            self.width,
+            self.height,
+            png::ColorType::Rgba,
+            png::BitDepth::Eight,
+        )
+    }
+}
+
+/// Finds the terrain tags referenced by a map's data tables
+pub fn find_map_terrains(map_hash: TagHash) -> anyhow::Result<Vec<TagHash>> {
+    let mut terrains = vec![];
+    for entry in MapDataTables::load(map_hash)?.resources(0x80806c7d) {
+        let (_, _, mut cur) = match entry {
+            Ok(entry) => entry,
+            Err(e) => {
+                error!("{e:?}");
+                continue;
+            }
+        };
+        let resource: SUnk8080714b = TigerReadable::read_ds(&mut cur)?;
+        if resource.terrain.is_some() && !terrains.contains(&resource.terrain) {
+            terrains.push(resource.terrain);
+        }
+    }
+
+    Ok(terrains)
+}
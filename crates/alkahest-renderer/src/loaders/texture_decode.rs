@@ -0,0 +1,618 @@
+//! CPU texture decoding, for exporting textures without going through the GPU
+
+use alkahest_data::{dxgi::DxgiFormat, texture::STextureHeader, WideHash};
+use anyhow::Context;
+use destiny_pkg::TagHash;
+use glam::{Vec2, Vec4};
+
+use crate::gpu::texture::Texture;
+
+/// The top mip of every layer of a texture, as linear RGBA
+pub struct DecodedTexture {
+    pub width: usize,
+    pub height: usize,
+    /// Array slices, cube faces or depth slices
+    pub layers: usize,
+    pub format: DxgiFormat,
+    pub pixels: Vec<Vec4>,
+}
+
+impl DecodedTexture {
+    pub fn load(hash: TagHash) -> anyhow::Result<Self> {
+        let (header, data) = Texture::load_data(WideHash::Hash32(hash), true)?;
+        Self::decode(&header, &data).with_context(|| format!("Failed to decode texture {hash}"))
+    }
+
+    pub fn decode(header: &STextureHeader, data: &[u8]) -> anyhow::Result<Self> {
+        let width = header.width as usize;
+        let height = header.height as usize;
+        let layers = header.depth.max(header.array_size).max(1) as usize;
+        let (_, slice_pitch) = header.format.calculate_pitch(width, height);
+
+        let mut pixels = Vec::with_capacity(width * height * layers);
+        for layer in 0..layers {
+            // Array slices are stored mip-major, so the top mips of every layer are contiguous
+            let start = layer * slice_pitch;
+            let slice = data.get(start..start + slice_pitch).with_context(|| {
+                format!(
+                    "Texture data is too small for layer {layer} ({} < {})",
+                    data.len(),
+                    start + slice_pitch
+                )
+            })?;
+
+            pixels.extend(decode_slice(header.format, slice, width, height)?);
+        }
+
+        let mut decoded = Self {
+            width,
+            height,
+            layers,
+            format: header.format,
+            pixels,
+        };
+
+        if header.format.is_srgb() {
+            for p in &mut decoded.pixels {
+                *p = Vec4::new(
+                    srgb_to_linear(p.x),
+                    srgb_to_linear(p.y),
+                    srgb_to_linear(p.z),
+                    p.w,
+                );
+            }
+        }
+
+        Ok(decoded)
+    }
+
+    pub fn layer(&self, layer: usize) -> &[Vec4] {
+        let size = self.width * self.height;
+        &self.pixels[layer * size..(layer + 1) * size]
+    }
+
+    /// Bilinearly samples a layer with clamped UV coordinates
+    pub fn sample(&self, layer: usize, uv: Vec2) -> Vec4 {
+        let pixels = self.layer(layer);
+        let x = (uv.x.clamp(0.0, 1.0) * self.width as f32 - 0.5).max(0.0);
+        let y = (uv.y.clamp(0.0, 1.0) * self.height as f32 - 0.5).max(0.0);
+
+        let (x0, y0) = (x as usize, y as usize);
+        let (x1, y1) = ((x0 + 1).min(self.width - 1), (y0 + 1).min(self.height - 1));
+        let (x0, y0) = (x0.min(self.width - 1), y0.min(self.height - 1));
+        let (fx, fy) = (x.fract(), y.fract());
+
+        let top = pixels[y0 * self.width + x0].lerp(pixels[y0 * self.width + x1], fx);
+        let bottom = pixels[y1 * self.width + x0].lerp(pixels[y1 * self.width + x1], fx);
+        top.lerp(bottom, fy)
+    }
+
+    /// Largest color component across all layers, ignoring non-finite values
+    pub fn max_value(&self) -> f32 {
+        self.pixels
+            .iter()
+            .flat_map(|p| p.to_array())
+            .filter(|v| v.is_finite())
+            .fold(0.0, f32::max)
+    }
+
+    /// Encodes a layer as a 16-bit RGBA PNG. Values are divided by `scale` and clamped to 0-1
+    pub fn to_png16(&self, layer: usize, scale: f32) -> anyhow::Result<Vec<u8>> {
+        let data: Vec<u8> = self
+            .layer(layer)
+            .iter()
+            .flat_map(|p| p.to_array())
+            .flat_map(|v| (((v / scale).clamp(0.0, 1.0) * 65535.0).round() as u16).to_be_bytes())
+            .collect();
+
+        encode_png(
+            &data,
+            self.width,
+            self.height,
+            png::ColorType::Rgba,
+            png::BitDepth::Sixteen,
+        )
+    }
+
+    /// Encodes a layer as an 8-bit sRGB PNG
+    pub fn to_png(&self, layer: usize) -> anyhow::Result<Vec<u8>> {
+        let data: Vec<u8> = self
+            .layer(layer)
+            .iter()
+            .flat_map(|p| {
+                [
+                    linear_to_srgb(p.x),
+                    linear_to_srgb(p.y),
+                    linear_to_srgb(p.z),
+                    p.w,
+                ]
+            })
+            .map(|v| (v.clamp(0.0, 1.0) * 255.0).round() as u8)
+            .collect();
+
+        encode_png(
+            &data,
+            self.width,
+            self.height,
+            png::ColorType::Rgba,
+            png::BitDepth::Eight,
+        )
+    }
+}
+
+pub(crate) fn encode_png(
+    data: &[u8],
+    width: usize,
+    height: usize,
+    color: png::ColorType,
+    depth: png::BitDepth,
+) -> anyhow::Result<Vec<u8>> {
+    let mut result = vec![];
+    let mut encoder = png::Encoder::new(&mut result, width as u32, height as u32);
+    encoder.set_color(color);
+    encoder.set_depth(depth);
+    let mut writer = encoder.write_header()?;
+    writer.write_image_data(data)?;
+    writer.finish()?;
+    Ok(result)
+}
+
+fn decode_slice(
+    format: DxgiFormat,
+    data: &[u8],
+    width: usize,
+    height: usize,
+) -> anyhow::Result<Vec<Vec4>> {
+    if format.is_compressed() {
+        return decode_blocks(format, data, width, height);
+    }
+
+    let bytes_per_pixel = format.bpp() / 8;
+    let pixels = data
+        .chunks_exact(bytes_per_pixel)
+        .take(width * height)
+        .map(|p| decode_pixel(format, p))
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
+    anyhow::ensure!(
+        pixels.len() == width * height,
+        "Expected {} pixels, got {}",
+        width * height,
+        pixels.len()
+    );
+
+    Ok(pixels)
+}
+
+fn decode_pixel(format: DxgiFormat, p: &[u8]) -> anyhow::Result<Vec4> {
+    let unorm8 = |i: usize| p[i] as f32 / 255.0;
+    let u16_at = |i: usize| u16::from_le_bytes([p[i * 2], p[i * 2 + 1]]);
+    let f32_at = |i: usize| f32::from_le_bytes(p[i * 4..i * 4 + 4].try_into().unwrap());
+    let u32_at = |i: usize| u32::from_le_bytes(p[i * 4..i * 4 + 4].try_into().unwrap());
+
+    Ok(match format {
+        DxgiFormat::R8G8B8A8_UNORM
+        | DxgiFormat::R8G8B8A8_UNORM_SRGB
+        | DxgiFormat::R8G8B8A8_TYPELESS => Vec4::new(unorm8(0), unorm8(1), unorm8(2), unorm8(3)),
+        DxgiFormat::B8G8R8A8_UNORM
+        | DxgiFormat::B8G8R8A8_UNORM_SRGB
+        | DxgiFormat::B8G8R8A8_TYPELESS => Vec4::new(unorm8(2), unorm8(1), unorm8(0), unorm8(3)),
+        DxgiFormat::B8G8R8X8_UNORM | DxgiFormat::B8G8R8X8_UNORM_SRGB => {
+            Vec4::new(unorm8(2), unorm8(1), unorm8(0), 1.0)
+        }
+        DxgiFormat::R8G8_UNORM => Vec4::new(unorm8(0), unorm8(1), 0.0, 1.0),
+        DxgiFormat::R8_UNORM => Vec4::new(unorm8(0), 0.0, 0.0, 1.0),
+        DxgiFormat::A8_UNORM => Vec4::new(0.0, 0.0, 0.0, unorm8(0)),
+        DxgiFormat::R16_UNORM => Vec4::new(u16_at(0) as f32 / 65535.0, 0.0, 0.0, 1.0),
+        DxgiFormat::R16G16_UNORM => Vec4::new(
+            u16_at(0) as f32 / 65535.0,
+            u16_at(1) as f32 / 65535.0,
+            0.0,
+            1.0,
+        ),
+        DxgiFormat::R16G16B16A16_UNORM => Vec4::new(
+            u16_at(0) as f32 / 65535.0,
+            u16_at(1) as f32 / 65535.0,
+            u16_at(2) as f32 / 65535.0,
+            u16_at(3) as f32 / 65535.0,
+        ),
+        DxgiFormat::R16_FLOAT => Vec4::new(f16_to_f32(u16_at(0)), 0.0, 0.0, 1.0),
+        DxgiFormat::R16G16_FLOAT => {
+            Vec4::new(f16_to_f32(u16_at(0)), f16_to_f32(u16_at(1)), 0.0, 1.0)
+        }
+        DxgiFormat::R16G16B16A16_FLOAT => Vec4::new(
+            f16_to_f32(u16_at(0)),
+            f16_to_f32(u16_at(1)),
+            f16_to_f32(u16_at(2)),
+            f16_to_f32(u16_at(3)),
+        ),
+        DxgiFormat::R32_FLOAT => Vec4::new(f32_at(0), 0.0, 0.0, 1.0),
+        DxgiFormat::R32G32_FLOAT => Vec4::new(f32_at(0), f32_at(1), 0.0, 1.0),
+        DxgiFormat::R32G32B32_FLOAT => Vec4::new(f32_at(0), f32_at(1), f32_at(2), 1.0),
+        DxgiFormat::R32G32B32A32_FLOAT => Vec4::new(f32_at(0), f32_at(1), f32_at(2), f32_at(3)),
+        DxgiFormat::R11G11B10_FLOAT => {
+            let v = u32_at(0);
+            Vec4::new(
+                small_float_to_f32(v & 0x7ff, 6),
+                small_float_to_f32((v >> 11) & 0x7ff, 6),
+                small_float_to_f32(v >> 22, 5),
+                1.0,
+            )
+        }
+        DxgiFormat::R10G10B10A2_UNORM => {
+            let v = u32_at(0);
+            Vec4::new(
+                (v & 0x3ff) as f32 / 1023.0,
+                ((v >> 10) & 0x3ff) as f32 / 1023.0,
+                ((v >> 20) & 0x3ff) as f32 / 1023.0,
+                (v >> 30) as f32 / 3.0,
+            )
+        }
+        u => anyhow::bail!("Unsupported texture format {u:?}"),
+    })
+}
+
+fn decode_blocks(
+    format: DxgiFormat,
+    data: &[u8],
+    width: usize,
+    height: usize,
+) -> anyhow::Result<Vec<Vec4>> {
+    let block_size = match format {
+        DxgiFormat::BC1_UNORM
+        | DxgiFormat::BC1_UNORM_SRGB
+        | DxgiFormat::BC4_UNORM
+        | DxgiFormat::BC4_SNORM => 8,
+        DxgiFormat::BC2_UNORM
+        | DxgiFormat::BC2_UNORM_SRGB
+        | DxgiFormat::BC3_UNORM
+        | DxgiFormat::BC3_UNORM_SRGB
+        | DxgiFormat::BC5_UNORM
+        | DxgiFormat::BC5_SNORM
+        | DxgiFormat::BC7_UNORM
+        | DxgiFormat::BC7_UNORM_SRGB => 16,
+        u => anyhow::bail!("Unsupported compressed texture format {u:?}"),
+    };
+
+    let blocks_x = width.div_ceil(4).max(1);
+    let blocks_y = height.div_ceil(4).max(1);
+    anyhow::ensure!(
+        data.len() >= blocks_x * blocks_y * block_size,
+        "Expected {} bytes of block data, got {}",
+        blocks_x * blocks_y * block_size,
+        data.len()
+    );
+
+    let mut pixels = vec![Vec4::ZERO; width * height];
+    for by in 0..blocks_y {
+        for bx in 0..blocks_x {
+            let offset = (by * blocks_x + bx) * block_size;
+            let block = &data[offset..offset + block_size];
+
+            let texels: [Vec4; 16] = match format {
+                DxgiFormat::BC1_UNORM | DxgiFormat::BC1_UNORM_SRGB => decode_bc1(block, true),
+                DxgiFormat::BC2_UNORM | DxgiFormat::BC2_UNORM_SRGB => {
+                    let mut texels = decode_bc1(&block[8..], false);
+                    for (i, t) in texels.iter_mut().enumerate() {
+                        t.w = ((block[i / 2] >> ((i % 2) * 4)) & 0xf) as f32 / 15.0;
+                    }
+                    texels
+                }
+                DxgiFormat::BC3_UNORM | DxgiFormat::BC3_UNORM_SRGB => {
+                    let alpha = decode_bc4(&block[..8], false);
+                    let mut texels = decode_bc1(&block[8..], false);
+                    for (t, a) in texels.iter_mut().zip(alpha) {
+                        t.w = a;
+                    }
+                    texels
+                }
+                DxgiFormat::BC4_UNORM | DxgiFormat::BC4_SNORM => {
+                    decode_bc4(block, format == DxgiFormat::BC4_SNORM)
+                        .map(|r| Vec4::new(r, r, r, 1.0))
+                }
+                DxgiFormat::BC5_UNORM | DxgiFormat::BC5_SNORM => {
+                    let snorm = format == DxgiFormat::BC5_SNORM;
+                    let r = decode_bc4(&block[..8], snorm);
+                    let g = decode_bc4(&block[8..], snorm);
+                    std::array::from_fn(|i| Vec4::new(r[i], g[i], 0.0, 1.0))
+                }
+                DxgiFormat::BC7_UNORM | DxgiFormat::BC7_UNORM_SRGB => decode_bc7(block),
+                _ => unreachable!(),
+            };
+
+            for (i, texel) in texels.into_iter().enumerate() {
+                let x = bx * 4 + i % 4;
+                let y = by * 4 + i / 4;
+                if x < width && y < height {
+                    pixels[y * width + x] = texel;
+                }
+            }
+        }
+    }
+
+    Ok(pixels)
+}
+
+fn decode_bc1(block: &[u8], allow_alpha: bool) -> [Vec4; 16] {
+    let c0 = u16::from_le_bytes([block[0], block[1]]);
+    let c1 = u16::from_le_bytes([block[2], block[3]]);
+    let rgb565 = |c: u16| {
+        Vec4::new(
+            ((c >> 11) & 0x1f) as f32 / 31.0,
+            ((c >> 5) & 0x3f) as f32 / 63.0,
+            (c & 0x1f) as f32 / 31.0,
+            1.0,
+        )
+    };
+
+    let (e0, e1) = (rgb565(c0), rgb565(c1));
+    let palette = if c0 > c1 || !allow_alpha {
+        [e0, e1, e0.lerp(e1, 1.0 / 3.0), e0.lerp(e1, 2.0 / 3.0)]
+    } else {
+        [e0, e1, e0.lerp(e1, 0.5), Vec4::ZERO]
+    };
+
+    let indices = u32::from_le_bytes([block[4], block[5], block[6], block[7]]);
+    std::array::from_fn(|i| palette[((indices >> (i * 2)) & 3) as usize])
+}
+
+fn decode_bc4(block: &[u8], snorm: bool) -> [f32; 16] {
+    let (e0, e1) = if snorm {
+        (
+            (block[0] as i8 as f32 / 127.0).max(-1.0),
+            (block[1] as i8 as f32 / 127.0).max(-1.0),
+        )
+    } else {
+        (block[0] as f32 / 255.0, block[1] as f32 / 255.0)
+    };
+
+    let mut palette = [0.0; 8];
+    palette[0] = e0;
+    palette[1] = e1;
+    if e0 > e1 {
+        for i in 1..7 {
+            palette[i + 1] = (e0 * (7 - i) as f32 + e1 * i as f32) / 7.0;
+        }
+    } else {
+        for i in 1..5 {
+            palette[i + 1] = (e0 * (5 - i) as f32 + e1 * i as f32) / 5.0;
+        }
+        palette[6] = if snorm { -1.0 } else { 0.0 };
+        palette[7] = 1.0;
+    }
+
+    let mut bits = [0u8; 8];
+    bits[..6].copy_from_slice(&block[2..8]);
+    let indices = u64::from_le_bytes(bits);
+    std::array::from_fn(|i| palette[((indices >> (i * 3)) & 7) as usize])
+}
+
+/// Subsets, partition bits, rotation bits, index selection bits, color bits, alpha bits, endpoint p-bits, shared p-bits, index bits and secondary index bits of each BC7 mode
+const BC7_MODES: [[u32; 10]; 8] = [
+    [3, 4, 0, 0, 4, 0, 1, 0, 3, 0],
+    [2, 6, 0, 0, 6, 0, 0, 1, 3, 0],
+    [3, 6, 0, 0, 5, 0, 0, 0, 2, 0],
+    [2, 6, 0, 0, 7, 0, 1, 0, 2, 0],
+    [1, 0, 2, 1, 5, 6, 0, 0, 2, 3],
+    [1, 0, 2, 0, 7, 8, 0, 0, 2, 2],
+    [1, 0, 0, 0, 7, 7, 1, 0, 4, 0],
+    [2, 6, 0, 0, 5, 5, 1, 0, 2, 0],
+];
+
+/// Subset of every texel for the 2 subset partitions, one bit per texel
+const BC7_PARTITIONS_2: [u16; 64] = [
+    0xCCCC, 0x8888, 0xEEEE, 0xECC8, 0xC880, 0xFEEC, 0xFEC8, 0xEC80, 0xC800, 0xFFEC, 0xFE80, 0xE800,
+    0xFFE8, 0xFF00, 0xFFF0, 0xF000, 0xF710, 0x008E, 0x7100, 0x08CE, 0x008C, 0x7310, 0x3100, 0x8CCE,
+    0x088C, 0x3110, 0x6666, 0x366C, 0x17E8, 0x0FF0, 0x718E, 0x399C, 0xAAAA, 0xF0F0, 0x5A5A, 0x33CC,
+    0x3C3C, 0x55AA, 0x9696, 0xA55A, 0x73CE, 0x13C8, 0x324C, 0x3BDC, 0x6996, 0xC33C, 0x9966, 0x0660,
+    0x0272, 0x04E4, 0x4E40, 0x2720, 0xC936, 0x936C, 0x39C6, 0x639C, 0x9336, 0x9CC6, 0x817E, 0xE718,
+    0xCCF0, 0x0FCC, 0x7744, 0xEE22,
+];
+
+/// Subset of every texel for the 3 subset partitions, two bits per texel
+const BC7_PARTITIONS_3: [u32; 64] = [
+    0xaa685050, 0x6a5a5040, 0x5a5a4200, 0x5450a0a8, 0xa5a50000, 0xa0a05050, 0x5555a0a0, 0x5a5a5050,
+    0xaa550000, 0xaa555500, 0xaaaa5500, 0x90909090, 0x94949494, 0xa4a4a4a4, 0xa9a59450, 0x2a0a4250,
+    0xa5945040, 0x0a425054, 0xa5a5a500, 0x55a0a0a0, 0xa8a85454, 0x6a6a4040, 0xa4a45000, 0x1a1a0500,
+    0x0050a4a4, 0xaaa59090, 0x14696914, 0x69691400, 0xa08585a0, 0xaa821414, 0x50a4a450, 0x6a5a0200,
+    0xa9a58000, 0x5090a0a8, 0xa8a09050, 0x24242424, 0x00aa5500, 0x24924924, 0x24499224, 0x50a50a50,
+    0x500aa550, 0xaaaa4444, 0x66660000, 0xa5a0a5a0, 0x50a050a0, 0x69286928, 0x44aaaa44, 0x66666600,
+    0xaa444444, 0x54a854a8, 0x95809580, 0x96969600, 0xa85454a8, 0x80959580, 0xaa141414, 0x96960000,
+    0xaaaa1414, 0xa05050a0, 0xa0a5a5a0, 0x96000000, 0x40804080, 0xa9a8a9a8, 0xaaaaaa44, 0x2a4a5254,
+];
+
+/// Anchor texel of the second subset of the 2 subset partitions
+const BC7_ANCHORS_2: [usize; 64] = [
+    15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 2, 8, 2, 2, 8, 8, 15, 2, 8,
+    2, 2, 8, 8, 2, 2, 15, 15, 6, 8, 2, 8, 15, 15, 2, 8, 2, 2, 2, 15, 15, 6, 6, 2, 6, 8, 15, 15, 2,
+    2, 15, 15, 15, 15, 15, 2, 2, 15,
+];
+
+/// Anchor texels of the second and third subsets of the 3 subset partitions
+const BC7_ANCHORS_3: [[usize; 64]; 2] = [
+    [
+        3, 3, 15, 15, 8, 3, 15, 15, 8, 8, 6, 6, 6, 5, 3, 3, 3, 3, 8, 15, 3, 3, 6, 10, 5, 8, 8, 6,
+        8, 5, 15, 15, 8, 15, 3, 5, 6, 10, 8, 15, 15, 3, 15, 5, 15, 15, 15, 15, 3, 15, 5, 5, 5, 8,
+        5, 10, 5, 10, 8, 13, 15, 12, 3, 3,
+    ],
+    [
+        15, 8, 8, 3, 15, 15, 3, 8, 15, 15, 15, 15, 15, 15, 15, 8, 15, 8, 15, 3, 15, 8, 15, 8, 3,
+        15, 6, 10, 15, 15, 10, 8, 15, 3, 15, 10, 10, 8, 9, 10, 6, 15, 8, 15, 3, 6, 6, 8, 15, 3, 15,
+        15, 15, 15, 15, 15, 15, 15, 15, 15, 3, 15, 15, 8,
+    ],
+];
+
+fn bc7_weights(bits: u32) -> &'static [u32] {
+    match bits {
+        2 => &[0, 21, 43, 64],
+        3 => &[0, 9, 18, 27, 37, 46, 55, 64],
+        _ => &[0, 4, 9, 13, 17, 21, 26, 30, 34, 38, 43, 47, 51, 55, 60, 64],
+    }
+}
+
+fn decode_bc7(block: &[u8]) -> [Vec4; 16] {
+    let mut bits = u128::from_le_bytes(block.try_into().unwrap());
+    let mut read = |count: u32| {
+        let value = (bits & ((1u128 << count) - 1)) as u32;
+        bits >>= count;
+        value
+    };
+
+    let Some(mode) = (0..8).find(|&m| block[0] & (1 << m) != 0) else {
+        // Reserved mode, decoded as transparent black
+        return [Vec4::ZERO; 16];
+    };
+    read(mode as u32 + 1);
+
+    let [subsets, partition_bits, rotation_bits, selection_bits, color_bits, alpha_bits, endpoint_pbits, shared_pbits, index_bits, index_bits2] =
+        BC7_MODES[mode];
+    let subsets = subsets as usize;
+    let partition = read(partition_bits) as usize;
+    let rotation = read(rotation_bits);
+    let index_selection = read(selection_bits);
+
+    // Endpoints are stored channel-major, [subset * 2 + endpoint][channel]
+    let mut endpoints = [[0u32; 4]; 6];
+    for channel in 0..4 {
+        let channel_bits = if channel < 3 { color_bits } else { alpha_bits };
+        for endpoint in endpoints.iter_mut().take(subsets * 2) {
+            endpoint[channel] = if channel_bits > 0 {
+                read(channel_bits)
+            } else {
+                255
+            };
+        }
+    }
+
+    let mut pbits = [0u32; 6];
+    if endpoint_pbits > 0 {
+        for p in pbits.iter_mut().take(subsets * 2) {
+            *p = read(1);
+        }
+    } else if shared_pbits > 0 {
+        for subset in 0..subsets {
+            let p = read(1);
+            pbits[subset * 2] = p;
+            pbits[subset * 2 + 1] = p;
+        }
+    }
+
+    let has_pbits = endpoint_pbits + shared_pbits > 0;
+    for (endpoint, p) in endpoints.iter_mut().zip(pbits).take(subsets * 2) {
+        for channel in 0..4 {
+            let mut channel_bits = if channel < 3 { color_bits } else { alpha_bits };
+            if channel_bits == 0 {
+                continue;
+            }
+
+            let mut v = endpoint[channel];
+            if has_pbits {
+                v = (v << 1) | p;
+                channel_bits += 1;
+            }
+            v <<= 8 - channel_bits;
+            endpoint[channel] = v | (v >> channel_bits);
+        }
+    }
+
+    let subset_of = |texel: usize| match subsets {
+        2 => ((BC7_PARTITIONS_2[partition] >> texel) & 1) as usize,
+        3 => ((BC7_PARTITIONS_3[partition] >> (texel * 2)) & 3) as usize,
+        _ => 0,
+    };
+    let is_anchor = |texel: usize| {
+        texel == 0
+            || match subsets {
+                2 => texel == BC7_ANCHORS_2[partition],
+                3 => texel == BC7_ANCHORS_3[0][partition] || texel == BC7_ANCHORS_3[1][partition],
+                _ => false,
+            }
+    };
+
+    let indices: [u32; 16] =
+        std::array::from_fn(|texel| read(index_bits - is_anchor(texel) as u32));
+    let indices2: [u32; 16] = if index_bits2 > 0 {
+        std::array::from_fn(|texel| read(index_bits2 - (texel == 0) as u32))
+    } else {
+        [0; 16]
+    };
+
+    std::array::from_fn(|texel| {
+        let subset = subset_of(texel);
+        let (e0, e1) = (endpoints[subset * 2], endpoints[subset * 2 + 1]);
+        let interpolate = |channel: usize, index: u32, bits: u32| {
+            let w = bc7_weights(bits)[index as usize];
+            ((64 - w) * e0[channel] + w * e1[channel] + 32) >> 6
+        };
+
+        let (color_index, color_index_bits, alpha_index, alpha_index_bits) = if index_bits2 == 0 {
+            (indices[texel], index_bits, indices[texel], index_bits)
+        } else if index_selection == 0 {
+            (indices[texel], index_bits, indices2[texel], index_bits2)
+        } else {
+            (indices2[texel], index_bits2, indices[texel], index_bits)
+        };
+
+        let mut rgba = [
+            interpolate(0, color_index, color_index_bits),
+            interpolate(1, color_index, color_index_bits),
+            interpolate(2, color_index, color_index_bits),
+            interpolate(3, alpha_index, alpha_index_bits),
+        ];
+        if rotation > 0 {
+            rgba.swap(3, rotation as usize - 1);
+        }
+
+        Vec4::from_array(rgba.map(|v| v as f32 / 255.0))
+    })
+}
+
+pub fn f16_to_f32(h: u16) -> f32 {
+    let sign = ((h >> 15) as u32) << 31;
+    let exponent = ((h >> 10) & 0x1f) as u32;
+    let mantissa = (h & 0x3ff) as u32;
+
+    let bits = match (exponent, mantissa) {
+        (0, 0) => sign,
+        (0, mut m) => {
+            // Subnormal, normalize it
+            let mut e = 127 - 15 + 1;
+            while m & 0x400 == 0 {
+                m <<= 1;
+                e -= 1;
+            }
+            sign | (e << 23) | ((m & 0x3ff) << 13)
+        }
+        (0x1f, m) => sign | 0x7f80_0000 | (m << 13),
+        (e, m) => sign | ((e + 127 - 15) << 23) | (m << 13),
+    };
+
+    f32::from_bits(bits)
+}
+
+/// Unsigned floats with a 5-bit exponent, as used by R11G11B10_FLOAT
+fn small_float_to_f32(v: u32, mantissa_bits: u32) -> f32 {
+    let exponent = v >> mantissa_bits;
+    let mantissa = (v & ((1 << mantissa_bits) - 1)) as f32 / (1 << mantissa_bits) as f32;
+    match exponent {
+        0 => mantissa * 2f32.powi(-14),
+        31 => f32::INFINITY,
+        e => 2f32.powi(e as i32 - 15) * (1.0 + mantissa),
+    }
+}
+
+fn srgb_to_linear(v: f32) -> f32 {
+    if v <= 0.04045 {
+        v / 12.92
+    } else {
+        ((v + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+fn linear_to_srgb(v: f32) -> f32 {
+    if v <= 0.0031308 {
+        v * 12.92
+    } else {
+        1.055 * v.powf(1.0 / 2.4) - 0.055
+    }
+}
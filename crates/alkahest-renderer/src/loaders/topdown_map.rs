@@ -0,0 +1,947 @@
+//! CPU top-down map renderer for callout maps
+//!
+//! Static geometry and terrain are rasterized into an orthographic height buffer, which is shaded
+//! by height and slope into `base.png`. Respawn points, named areas, barriers and routes are drawn
+//! on top as separate transparent PNG layers of the same size, and as vector layers in `map.svg`.
+//! Only the SVG carries text labels.
+//!
+//! Images are laid out with north (+Y) up: `px = (x - min.x) / meters_per_pixel` and
+//! `py = (max.y - y) / meters_per_pixel`. Volumes are drawn as the convex hull of their shape's
+//! footprint.
+
+use std::{
+    fmt::Write,
+    io::{Seek, SeekFrom},
+    path::Path,
+    sync::Arc,
+};
+
+use alkahest_data::{
+    geometry::EPrimitiveType,
+    map::{SRespawnPoint, SUnk80806ef4, SUnk8080714b, SUnk80808cb7},
+    occlusion::Aabb,
+    statics::SStaticMesh,
+    text::StringContainer,
+    tfx::TfxRenderStage,
+};
+use alkahest_pm::package_manager;
+use anyhow::Context;
+use binrw::BinReaderExt;
+use destiny_pkg::TagHash;
+use glam::{Mat4, Quat, Vec2, Vec3, Vec4};
+use rustc_hash::FxHashMap;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use tiger_parse::{PackageManagerExt, TigerReadable};
+
+use crate::{
+    ecs::tags::NodeFilter,
+    loaders::{
+        index_buffer::{read_index_buffer, unroll_triangle_strip},
+        map_data::MapDataTables,
+        terrain_export::TerrainData,
+        texture_decode::encode_png,
+        vertex_buffer::read_vertex_buffer,
+        volume_export::{GameplayVolume, MapVolumes},
+    },
+};
+
+/// Largest image dimension
+const MAX_IMAGE_SIZE: usize = 8192;
+
+#[derive(Clone, Copy)]
+pub struct TopdownOptions {
+    pub meters_per_pixel: f32,
+    /// Geometry and markers outside of this height range are left out, for slicing multi-level
+    /// interiors
+    pub height_range: Option<(f32, f32)>,
+    pub include_statics: bool,
+    pub include_terrain: bool,
+}
+
+impl Default for TopdownOptions {
+    fn default() -> Self {
+        Self {
+            meters_per_pixel: 0.5,
+            height_range: None,
+            include_statics: true,
+            include_terrain: true,
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum TopdownLayer {
+    Barriers,
+    NamedAreas,
+    Routes,
+    RespawnPoints,
+}
+
+impl TopdownLayer {
+    /// Layers from bottom to top
+    pub const ALL: [TopdownLayer; 4] = [
+        TopdownLayer::Barriers,
+        TopdownLayer::NamedAreas,
+        TopdownLayer::Routes,
+        TopdownLayer::RespawnPoints,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            TopdownLayer::Barriers => "barriers",
+            TopdownLayer::NamedAreas => "named_areas",
+            TopdownLayer::Routes => "routes",
+            TopdownLayer::RespawnPoints => "respawn_points",
+        }
+    }
+}
+
+/// Route polyline in world space. Routes only exist in the viewer, so headless exports read them
+/// from a JSON file
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct MapRoute {
+    #[serde(default)]
+    pub name: String,
+    /// sRGB color with straight alpha
+    #[serde(default = "MapRoute::default_color")]
+    pub color: [u8; 4],
+    pub points: Vec<[f32; 3]>,
+}
+
+impl MapRoute {
+    fn default_color() -> [u8; 4] {
+        [255, 220, 0, 255]
+    }
+}
+
+pub struct TopdownRespawnPoint {
+    pub id: u32,
+    pub translation: Vec3,
+    pub rotation: Quat,
+}
+
+impl TopdownRespawnPoint {
+    /// Facing direction on the XY plane, in radians counter-clockwise from +X
+    pub fn yaw(&self) -> f32 {
+        let forward = self.rotation * Vec3::X;
+        forward.y.atan2(forward.x)
+    }
+}
+
+pub struct TopdownMap {
+    pub map_hash: TagHash,
+    pub map_name: String,
+    /// XY extents of the image, with the Z range of the rasterized geometry
+    pub bounds: Aabb,
+    pub meters_per_pixel: f32,
+    pub width: usize,
+    pub height: usize,
+    /// Highest geometry per pixel, NaN where there is none
+    pub heights: Vec<f32>,
+
+    pub respawn_points: Vec<TopdownRespawnPoint>,
+    pub volumes: Vec<GameplayVolume>,
+    pub routes: Vec<MapRoute>,
+}
+
+/// Triangles of a mesh in its local space, shared by every instance of it
+struct TopdownMesh {
+    triangles: Vec<[Vec3; 3]>,
+    bounds: Aabb,
+}
+
+impl TopdownMesh {
+    fn new(triangles: Vec<[Vec3; 3]>) -> Option<Arc<Self>> {
+        let bounds = overlay_bounds(triangles.iter().flatten().copied())?;
+        Some(Arc::new(Self { triangles, bounds }))
+    }
+}
+
+impl TopdownMap {
+    pub fn render(
+        map_hash: TagHash,
+        stringmap: &StringContainer,
+        options: TopdownOptions,
+        routes: Vec<MapRoute>,
+    ) -> anyhow::Result<Self> {
+        let tables = MapDataTables::load(map_hash)?;
+        let map_name = stringmap.get(tables.map_name);
+
+        let mut instances: Vec<(Arc<TopdownMesh>, Mat4)> = vec![];
+        let mut respawn_points = vec![];
+        let mut statics: FxHashMap<TagHash, Option<Arc<TopdownMesh>>> = FxHashMap::default();
+        for entry in tables.entries() {
+            let (_, entry, mut cur) = match entry {
+                Ok(entry) => entry,
+                Err(e) => {
+                    error!("{e:?}");
+                    continue;
+                }
+            };
+            match entry.data_resource.resource_type {
+                // Static placement
+                0x80806cc9 if options.include_statics => {
+                    cur.seek(SeekFrom::Current(16))?;
+                    let tag: TagHash = cur.read_le()?;
+                    let preheader: SUnk80806ef4 = match package_manager().read_tag_struct(tag) {
+                        Ok(p) => p,
+                        Err(e) => {
+                            error!("Failed to read static placement {tag}: {e}");
+                            continue;
+                        }
+                    };
+
+                    let placements = &preheader.instances;
+                    for group in &placements.instance_groups {
+                        let Some(&mesh_hash) = placements.statics.get(group.static_index as usize)
+                        else {
+                            continue;
+                        };
+
+                        let triangles = statics.entry(mesh_hash).or_insert_with(|| {
+                            load_static_triangles(mesh_hash)
+                                .map_err(|e| {
+                                    warn!("Failed to decode static mesh {mesh_hash}: {e:?}")
+                                })
+                                .ok()
+                                .and_then(TopdownMesh::new)
+                        });
+                        let Some(triangles) = triangles else {
+                            continue;
+                        };
+
+                        let start = group.instance_start as usize;
+                        let end = start + group.instance_count as usize;
+                        for t in placements.transforms.get(start..end).unwrap_or(&[]) {
+                            instances.push((
+                                triangles.clone(),
+                                Mat4::from_scale_rotation_translation(
+                                    Vec3::splat(t.scale.x),
+                                    t.rotation,
+                                    t.translation,
+                                ),
+                            ));
+                        }
+                    }
+                }
+                // Terrain
+                0x80806c7d if options.include_terrain => {
+                    let resource: SUnk8080714b = TigerReadable::read_ds(&mut cur)?;
+                    if resource.terrain.is_none() {
+                        continue;
+                    }
+
+                    match load_terrain_triangles(resource.terrain) {
+                        Ok(triangles) => instances
+                            .extend(TopdownMesh::new(triangles).map(|m| (m, Mat4::IDENTITY))),
+                        Err(e) => {
+                            warn!("Failed to decode terrain {}: {e:?}", resource.terrain)
+                        }
+                    }
+                }
+                // Respawn points
+                0x80808cb5 => {
+                    cur.seek(SeekFrom::Current(16))?;
+                    let tag: TagHash = cur.read_le()?;
+                    if tag.is_none() {
+                        continue;
+                    }
+
+                    let header: SUnk80808cb7 = package_manager().read_tag_struct(tag)?;
+                    respawn_points.extend(header.unk8.iter().map(|p: &SRespawnPoint| {
+                        TopdownRespawnPoint {
+                            id: p.unk20,
+                            translation: p.translation.truncate(),
+                            rotation: p.rotation,
+                        }
+                    }));
+                }
+                _ => {}
+            }
+        }
+
+        let mut volumes = MapVolumes::gather(map_hash, stringmap)?.volumes;
+
+        if let Some((min, max)) = options.height_range {
+            let in_range = |z: f32| z >= min && z <= max;
+            respawn_points.retain(|p| in_range(p.translation.z));
+            volumes.retain(|v| {
+                let bounds = v.bounds();
+                bounds.max.z >= min && bounds.min.z <= max
+            });
+        }
+
+        let bounds = geometry_bounds(&instances, options.height_range)
+            .or_else(|| {
+                overlay_bounds(
+                    respawn_points
+                        .iter()
+                        .map(|p| p.translation)
+                        .chain(volumes.iter().flat_map(|v| v.vertices.iter().copied()))
+                        .chain(
+                            routes
+                                .iter()
+                                .flat_map(|r| r.points.iter().map(|&p| p.into())),
+                        ),
+                )
+            })
+            .context("Map has no geometry or markers to render")?;
+
+        let size = (bounds.max - bounds.min).truncate().max(Vec2::ONE);
+        let meters_per_pixel = options
+            .meters_per_pixel
+            .max(size.max_element() / MAX_IMAGE_SIZE as f32)
+            .max(f32::EPSILON);
+        let width = ((size.x / meters_per_pixel).ceil() as usize).max(1);
+        let height = ((size.y / meters_per_pixel).ceil() as usize).max(1);
+
+        let mut map = Self {
+            map_hash,
+            map_name,
+            bounds,
+            meters_per_pixel,
+            width,
+            height,
+            heights: vec![f32::NAN; width * height],
+            respawn_points,
+            volumes,
+            routes,
+        };
+
+        for (mesh, transform) in &instances {
+            map.rasterize(&mesh.triangles, *transform, options.height_range);
+        }
+
+        Ok(map)
+    }
+
+    pub fn world_to_pixel(&self, p: Vec3) -> Vec2 {
+        Vec2::new(
+            (p.x - self.bounds.min.x) / self.meters_per_pixel,
+            (self.bounds.max.y - p.y) / self.meters_per_pixel,
+        )
+    }
+
+    fn rasterize(&mut self, triangles: &[[Vec3; 3]], transform: Mat4, range: Option<(f32, f32)>) {
+        let (range_min, range_max) = range.unwrap_or((f32::NEG_INFINITY, f32::INFINITY));
+        let size = Vec2::new(self.width as f32, self.height as f32);
+
+        for triangle in triangles {
+            let p = triangle.map(|v| transform.transform_point3(v));
+            if p.iter().all(|v| v.z > range_max) || p.iter().all(|v| v.z < range_min) {
+                continue;
+            }
+
+            let [a, b, c] = p.map(|v| self.world_to_pixel(v));
+            let area = (b - a).perp_dot(c - a);
+            if area.abs() <= f32::EPSILON {
+                continue;
+            }
+
+            let min = a.min(b).min(c).floor().max(Vec2::ZERO);
+            let max = a.max(b).max(c).ceil().min(size);
+            for y in min.y as usize..max.y as usize {
+                for x in min.x as usize..max.x as usize {
+                    let center = Vec2::new(x as f32 + 0.5, y as f32 + 0.5);
+                    let w0 = (c - b).perp_dot(center - b) / area;
+                    let w1 = (a - c).perp_dot(center - c) / area;
+                    let w2 = 1.0 - w0 - w1;
+                    if w0 < 0.0 || w1 < 0.0 || w2 < 0.0 {
+                        continue;
+                    }
+
+                    let z = p[0].z * w0 + p[1].z * w1 + p[2].z * w2;
+                    if z < range_min || z > range_max {
+                        continue;
+                    }
+
+                    let height = &mut self.heights[y * self.width + x];
+                    if height.is_nan() || z > *height {
+                        *height = z;
+                    }
+                }
+            }
+        }
+    }
+
+    pub fn height_range(&self) -> (f32, f32) {
+        let (min, max) = self
+            .heights
+            .iter()
+            .filter(|h| h.is_finite())
+            .fold((f32::INFINITY, f32::NEG_INFINITY), |(min, max), &h| {
+                (min.min(h), max.max(h))
+            });
+
+        if min > max {
+            (0.0, 0.0)
+        } else {
+            (min, max)
+        }
+    }
+
+    /// Geometry shaded by height, with a hillshade lit from the northwest. Empty pixels are transparent
+    pub fn base_png(&self) -> anyhow::Result<Vec<u8>> {
+        let (min, max) = self.height_range();
+        let range = (max - min).max(f32::EPSILON);
+        let light = Vec3::new(-1.0, 1.0, 1.5).normalize();
+        let low = Vec3::new(0.16, 0.18, 0.22);
+        let high = Vec3::new(0.93, 0.91, 0.86);
+
+        let height_at = |x: usize, y: usize, fallback: f32| {
+            let h = self.heights[y * self.width + x];
+            if h.is_finite() {
+                h
+            } else {
+                fallback
+            }
+        };
+
+        let mut data = Vec::with_capacity(self.width * self.height * 4);
+        for y in 0..self.height {
+            for x in 0..self.width {
+                let h = self.heights[y * self.width + x];
+                if !h.is_finite() {
+                    data.extend_from_slice(&[0; 4]);
+                    continue;
+                }
+
+                let left = height_at(x.saturating_sub(1), y, h);
+                let right = height_at((x + 1).min(self.width - 1), y, h);
+                let up = height_at(x, y.saturating_sub(1), h);
+                let down = height_at(x, (y + 1).min(self.height - 1), h);
+
+                // Rows go south, so the Y slope is flipped
+                let scale = 2.0 * self.meters_per_pixel;
+                let normal =
+                    Vec3::new(-(right - left) / scale, (down - up) / scale, 1.0).normalize();
+                let shade = 0.4 + 0.6 * normal.dot(light).max(0.0);
+
+                let t = (h - min) / range;
+                let color = low.lerp(high, t) * shade;
+                data.extend(
+                    color
+                        .to_array()
+                        .map(|v| (v.clamp(0.0, 1.0) * 255.0).round() as u8),
+                );
+                data.push(255);
+            }
+        }
+
+        encode_png(
+            &data,
+            self.width,
+            self.height,
+            png::ColorType::Rgba,
+            png::BitDepth::Eight,
+        )
+    }
+
+    /// Transparent layer image matching `base.png`, without labels
+    pub fn layer_png(&self, layer: TopdownLayer) -> anyhow::Result<Vec<u8>> {
+        let mut canvas = Canvas::new(self.width, self.height);
+        let marker_radius = 6.0;
+
+        match layer {
+            TopdownLayer::Barriers | TopdownLayer::NamedAreas => {
+                for volume in self.layer_volumes(layer) {
+                    let hull = self.volume_footprint(volume);
+                    let color = node_filter_color(volume.kind);
+                    canvas.fill_polygon(&hull, with_alpha(color, 0.35));
+                    canvas.stroke_polyline(&hull, true, 2.0, color);
+                }
+            }
+            TopdownLayer::Routes => {
+                for route in &self.routes {
+                    let points: Vec<Vec2> = route
+                        .points
+                        .iter()
+                        .map(|&p| self.world_to_pixel(p.into()))
+                        .collect();
+                    let color = Vec4::from(route.color.map(|v| v as f32 / 255.0));
+                    canvas.stroke_polyline(&points, false, 3.0, color);
+                    for p in &points {
+                        canvas.fill_circle(*p, 3.0, color);
+                    }
+                }
+            }
+            TopdownLayer::RespawnPoints => {
+                let color = node_filter_color(NodeFilter::RespawnPoint);
+                for point in &self.respawn_points {
+                    let center = self.world_to_pixel(point.translation);
+                    let yaw = point.yaw();
+                    let heading = center + Vec2::new(yaw.cos(), -yaw.sin()) * marker_radius * 2.0;
+                    canvas.stroke_polyline(&[center, heading], false, 2.0, color);
+                    canvas.fill_circle(center, marker_radius, color);
+                }
+            }
+        }
+
+        canvas.to_png()
+    }
+
+    /// Vector version of every layer, on top of `base_href`
+    pub fn to_svg(&self, base_href: &str) -> String {
+        let mut svg = String::new();
+        writeln!(
+            svg,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" xmlns:xlink="http://www.w3.org/1999/xlink" width="{w}" height="{h}" viewBox="0 0 {w} {h}">"#,
+            w = self.width,
+            h = self.height
+        )
+        .unwrap();
+        writeln!(svg, "<title>{}</title>", xml_escape(&self.map_name)).unwrap();
+        writeln!(
+            svg,
+            r#"<image id="base" href="{href}" xlink:href="{href}" width="{}" height="{}"/>"#,
+            self.width,
+            self.height,
+            href = xml_escape(base_href)
+        )
+        .unwrap();
+
+        let font_size = (self.width.max(self.height) as f32 / 120.0).clamp(10.0, 48.0);
+        for layer in TopdownLayer::ALL {
+            writeln!(svg, r#"<g id="{}">"#, layer.name()).unwrap();
+            match layer {
+                TopdownLayer::Barriers | TopdownLayer::NamedAreas => {
+                    for volume in self.layer_volumes(layer) {
+                        let hull = self.volume_footprint(volume);
+                        if hull.is_empty() {
+                            continue;
+                        }
+
+                        let color = svg_color(node_filter_color(volume.kind));
+                        writeln!(
+                            svg,
+                            r#"<polygon points="{}" fill="{color}" fill-opacity="0.35" stroke="{color}" stroke-width="2"><title>{}</title></polygon>"#,
+                            svg_points(&hull),
+                            xml_escape(&volume.label())
+                        )
+                        .unwrap();
+
+                        if let Some(name) = &volume.name {
+                            let centroid = hull.iter().copied().sum::<Vec2>() / hull.len() as f32;
+                            writeln!(
+                                svg,
+                                r#"<text x="{:.1}" y="{:.1}" font-family="sans-serif" font-size="{font_size:.0}" text-anchor="middle" fill="white" stroke="black" stroke-width="0.5">{}</text>"#,
+                                centroid.x,
+                                centroid.y,
+                                xml_escape(name)
+                            )
+                            .unwrap();
+                        }
+                    }
+                }
+                TopdownLayer::Routes => {
+                    for route in &self.routes {
+                        let points: Vec<Vec2> = route
+                            .points
+                            .iter()
+                            .map(|&p| self.world_to_pixel(p.into()))
+                            .collect();
+                        let [r, g, b, a] = route.color;
+                        writeln!(
+                            svg,
+                            r#"<polyline points="{}" fill="none" stroke="rgb({r},{g},{b})" stroke-opacity="{:.3}" stroke-width="3" stroke-linejoin="round"><title>{}</title></polyline>"#,
+                            svg_points(&points),
+                            a as f32 / 255.0,
+                            xml_escape(&route.name)
+                        )
+                        .unwrap();
+                    }
+                }
+                TopdownLayer::RespawnPoints => {
+                    let color = svg_color(node_filter_color(NodeFilter::RespawnPoint));
+                    for point in &self.respawn_points {
+                        let center = self.world_to_pixel(point.translation);
+                        let yaw = point.yaw();
+                        let heading = center + Vec2::new(yaw.cos(), -yaw.sin()) * 12.0;
+                        writeln!(
+                            svg,
+                            r#"<g><title>Respawn point 0x{:X}</title><line x1="{:.1}" y1="{:.1}" x2="{:.1}" y2="{:.1}" stroke="{color}" stroke-width="2"/><circle cx="{:.1}" cy="{:.1}" r="6" fill="{color}" stroke="black" stroke-width="1"/></g>"#,
+                            point.id,
+                            center.x,
+                            center.y,
+                            heading.x,
+                            heading.y,
+                            center.x,
+                            center.y
+                        )
+                        .unwrap();
+                    }
+                }
+            }
+            writeln!(svg, "</g>").unwrap();
+        }
+
+        writeln!(svg, "</svg>").unwrap();
+        svg
+    }
+
+    /// Image placement and marker positions, for georeferencing the images
+    pub fn to_json(&self) -> Value {
+        let (height_min, height_max) = self.height_range();
+        json!({
+            "map": self.map_hash.to_string(),
+            "map_name": self.map_name,
+            "width": self.width,
+            "height": self.height,
+            "meters_per_pixel": self.meters_per_pixel,
+            "bounds": {
+                "min": self.bounds.min.to_array(),
+                "max": self.bounds.max.to_array(),
+            },
+            "height_min": height_min,
+            "height_max": height_max,
+            "pixel_mapping": "px = (x - bounds.min.x) / meters_per_pixel, py = (bounds.max.y - y) / meters_per_pixel",
+            "layers": TopdownLayer::ALL.iter().map(|l| format!("{}.png", l.name())).collect::<Vec<_>>(),
+            "respawn_points": self.respawn_points.iter().map(|p| json!({
+                "id": format!("0x{:X}", p.id),
+                "translation": p.translation.to_array(),
+                "yaw": p.yaw().to_degrees(),
+                "pixel": self.world_to_pixel(p.translation).to_array(),
+            })).collect::<Vec<_>>(),
+            "volumes": self.volumes.iter().map(|v| json!({
+                "kind": v.kind.to_string(),
+                "name": v.name,
+                "world_id": format!("{:016X}", v.world_id),
+                "footprint": self.volume_footprint(v).iter().map(|p| p.to_array()).collect::<Vec<_>>(),
+            })).collect::<Vec<_>>(),
+            "routes": self.routes,
+        })
+    }
+
+    /// Writes `base.png`, a PNG per layer, `map.svg` and `map.json` to `output`
+    pub fn export(&self, output: &Path) -> anyhow::Result<()> {
+        std::fs::create_dir_all(output)
+            .with_context(|| format!("Failed to create {}", output.display()))?;
+        let write = |name: &str, data: &[u8]| {
+            let path = output.join(name);
+            std::fs::write(&path, data)
+                .with_context(|| format!("Failed to write {}", path.display()))
+        };
+
+        write("base.png", &self.base_png()?)?;
+        for layer in TopdownLayer::ALL {
+            write(&format!("{}.png", layer.name()), &self.layer_png(layer)?)?;
+        }
+        write("map.svg", self.to_svg("base.png").as_bytes())?;
+        write(
+            "map.json",
+            serde_json::to_string_pretty(&self.to_json())?.as_bytes(),
+        )?;
+
+        Ok(())
+    }
+
+    fn layer_volumes(&self, layer: TopdownLayer) -> impl Iterator<Item = &GameplayVolume> {
+        self.volumes.iter().filter(move |v| {
+            (v.kind == NodeFilter::NamedArea) == (layer == TopdownLayer::NamedAreas)
+        })
+    }
+
+    fn volume_footprint(&self, volume: &GameplayVolume) -> Vec<Vec2> {
+        convex_hull(
+            volume
+                .vertices
+                .iter()
+                .map(|v| self.world_to_pixel(*v))
+                .collect(),
+        )
+    }
+}
+
+/// Decodes the highest detail gbuffer geometry of a static mesh
+fn load_static_triangles(hash: TagHash) -> anyhow::Result<Vec<[Vec3; 3]>> {
+    let mesh: SStaticMesh = package_manager().read_tag_struct(hash)?;
+    let data = &mesh.opaque_meshes;
+
+    let mut buffers: FxHashMap<u8, (Vec<Vec3>, Vec<u32>)> = FxHashMap::default();
+    let mut triangles = vec![];
+    for group in data
+        .mesh_groups
+        .iter()
+        .filter(|g| g.render_stage == TfxRenderStage::GenerateGbuffer)
+    {
+        let Some(part) = data.parts.get(group.part_index as usize) else {
+            continue;
+        };
+        if !part.lod_category.is_highest_detail() {
+            continue;
+        }
+
+        if !buffers.contains_key(&part.buffer_index) {
+            let Some(&(index_buffer, vertex0_buffer, _, _)) =
+                data.buffers.get(part.buffer_index as usize)
+            else {
+                continue;
+            };
+
+            // Positions are snorm16, scaled and offset by the mesh
+            let (vertices, stride) = read_vertex_buffer(vertex0_buffer)?;
+            anyhow::ensure!(stride >= 6, "Unexpected static vertex stride {stride}");
+            let positions = vertices
+                .chunks_exact(stride)
+                .map(|v| {
+                    let c = |o: usize| i16::from_le_bytes([v[o], v[o + 1]]) as f32 / 32767.0;
+                    Vec3::new(c(0), c(2), c(4)) * data.mesh_scale + data.mesh_offset
+                })
+                .collect();
+
+            buffers.insert(
+                part.buffer_index,
+                (positions, read_index_buffer(index_buffer)?),
+            );
+        }
+
+        let (positions, indices) = &buffers[&part.buffer_index];
+        let start = part.index_start as usize;
+        let end = (start + part.index_count as usize).min(indices.len());
+        let indices = indices.get(start..end).unwrap_or(&[]);
+        let part_triangles = match part.primitive_type {
+            EPrimitiveType::Triangles => indices
+                .chunks_exact(3)
+                .map(|t| [t[0], t[1], t[2]])
+                .collect(),
+            EPrimitiveType::TriangleStrip => unroll_triangle_strip(indices),
+            _ => vec![],
+        };
+
+        triangles.extend(part_triangles.into_iter().filter_map(|t| {
+            Some([
+                *positions.get(t[0] as usize)?,
+                *positions.get(t[1] as usize)?,
+                *positions.get(t[2] as usize)?,
+            ])
+        }));
+    }
+
+    Ok(triangles)
+}
+
+fn load_terrain_triangles(hash: TagHash) -> anyhow::Result<Vec<[Vec3; 3]>> {
+    let terrain = TerrainData::load(hash)?;
+    let Some(&detail_level) = terrain.detail_levels().first() else {
+        return Ok(vec![]);
+    };
+
+    let mesh = terrain.mesh(detail_level);
+    Ok(mesh
+        .indices
+        .chunks_exact(3)
+        .map(|t| [0, 1, 2].map(|i| mesh.positions[t[i] as usize]))
+        .collect())
+}
+
+fn geometry_bounds(
+    instances: &[(Arc<TopdownMesh>, Mat4)],
+    range: Option<(f32, f32)>,
+) -> Option<Aabb> {
+    let (range_min, range_max) = range.unwrap_or((f32::NEG_INFINITY, f32::INFINITY));
+    overlay_bounds(
+        instances
+            .iter()
+            .flat_map(|(mesh, transform)| {
+                mesh.bounds.corners().map(|c| transform.transform_point3(c))
+            })
+            .filter(|p| p.z >= range_min && p.z <= range_max),
+    )
+}
+
+fn overlay_bounds(points: impl Iterator<Item = Vec3>) -> Option<Aabb> {
+    let (min, max) = points.fold((Vec3::INFINITY, Vec3::NEG_INFINITY), |(min, max), p| {
+        (min.min(p), max.max(p))
+    });
+
+    (min.x <= max.x).then_some(Aabb { min, max })
+}
+
+/// Andrew's monotone chain, counter-clockwise
+fn convex_hull(mut points: Vec<Vec2>) -> Vec<Vec2> {
+    points.retain(|p| p.is_finite());
+    points.sort_by(|a, b| a.x.total_cmp(&b.x).then(a.y.total_cmp(&b.y)));
+    points.dedup();
+    if points.len() < 3 {
+        return points;
+    }
+
+    let half_hull = |points: &mut dyn Iterator<Item = Vec2>| {
+        let mut hull: Vec<Vec2> = vec![];
+        for p in points {
+            while hull.len() >= 2
+                && (hull[hull.len() - 1] - hull[hull.len() - 2]).perp_dot(p - hull[hull.len() - 2])
+                    <= 0.0
+            {
+                hull.pop();
+            }
+            hull.push(p);
+        }
+        hull.pop();
+        hull
+    };
+
+    let mut hull = half_hull(&mut points.iter().copied());
+    hull.extend(half_hull(&mut points.iter().rev().copied()));
+    hull
+}
+
+fn node_filter_color(filter: NodeFilter) -> Vec4 {
+    Vec4::from(
+        ecolor::Color32::from(filter.color())
+            .to_array()
+            .map(|v| v as f32 / 255.0),
+    )
+}
+
+fn with_alpha(color: Vec4, alpha: f32) -> Vec4 {
+    color.truncate().extend(alpha)
+}
+
+fn svg_color(color: Vec4) -> String {
+    let [r, g, b, _] = color
+        .to_array()
+        .map(|v| (v.clamp(0.0, 1.0) * 255.0).round() as u8);
+    format!("rgb({r},{g},{b})")
+}
+
+fn svg_points(points: &[Vec2]) -> String {
+    points
+        .iter()
+        .map(|p| format!("{:.1},{:.1}", p.x, p.y))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn xml_escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Straight alpha sRGB canvas for the PNG layers
+struct Canvas {
+    width: usize,
+    height: usize,
+    pixels: Vec<Vec4>,
+}
+
+impl Canvas {
+    fn new(width: usize, height: usize) -> Self {
+        Self {
+            width,
+            height,
+            pixels: vec![Vec4::ZERO; width * height],
+        }
+    }
+
+    fn blend(&mut self, x: usize, y: usize, color: Vec4) {
+        let dst = &mut self.pixels[y * self.width + x];
+        let alpha = color.w + dst.w * (1.0 - color.w);
+        if alpha <= 0.0 {
+            return;
+        }
+
+        let rgb = (color.truncate() * color.w + dst.truncate() * dst.w * (1.0 - color.w)) / alpha;
+        *dst = rgb.extend(alpha);
+    }
+
+    /// Scanline fill, sampling pixel centers
+    fn fill_polygon(&mut self, points: &[Vec2], color: Vec4) {
+        if points.len() < 3 {
+            return;
+        }
+
+        let (min_y, max_y) = points
+            .iter()
+            .fold((f32::INFINITY, f32::NEG_INFINITY), |acc, p| {
+                (acc.0.min(p.y), acc.1.max(p.y))
+            });
+        let y_start = min_y.floor().max(0.0) as usize;
+        let y_end = (max_y.ceil().max(0.0) as usize).min(self.height);
+
+        let mut crossings = vec![];
+        for y in y_start..y_end {
+            let cy = y as f32 + 0.5;
+            crossings.clear();
+            for (i, a) in points.iter().enumerate() {
+                let b = points[(i + 1) % points.len()];
+                if (a.y <= cy) != (b.y <= cy) {
+                    crossings.push(a.x + (cy - a.y) / (b.y - a.y) * (b.x - a.x));
+                }
+            }
+            crossings.sort_by(f32::total_cmp);
+
+            for span in crossings.chunks_exact(2) {
+                let x_start = (span[0] - 0.5).ceil().max(0.0) as usize;
+                let x_end = ((span[1] - 0.5).floor() + 1.0).clamp(0.0, self.width as f32) as usize;
+                for x in x_start..x_end {
+                    self.blend(x, y, color);
+                }
+            }
+        }
+    }
+
+    fn stroke_polyline(&mut self, points: &[Vec2], closed: bool, width: f32, color: Vec4) {
+        let segment_count = if closed {
+            points.len()
+        } else {
+            points.len().saturating_sub(1)
+        };
+
+        let radius = width / 2.0;
+        for i in 0..segment_count {
+            let a = points[i];
+            let b = points[(i + 1) % points.len()];
+            let min = (a.min(b) - radius).floor().max(Vec2::ZERO);
+            let max = (a.max(b) + radius)
+                .ceil()
+                .min(Vec2::new(self.width as f32, self.height as f32));
+
+            for y in min.y as usize..max.y as usize {
+                for x in min.x as usize..max.x as usize {
+                    let p = Vec2::new(x as f32 + 0.5, y as f32 + 0.5);
+                    let ab = b - a;
+                    let t =
+                        ((p - a).dot(ab) / ab.length_squared().max(f32::EPSILON)).clamp(0.0, 1.0);
+                    if p.distance(a + ab * t) <= radius {
+                        self.pixels[y * self.width + x] = color;
+                    }
+                }
+            }
+        }
+    }
+
+    fn fill_circle(&mut self, center: Vec2, radius: f32, color: Vec4) {
+        let min = (center - radius).floor().max(Vec2::ZERO);
+        let max = (center + radius)
+            .ceil()
+            .min(Vec2::new(self.width as f32, self.height as f32));
+        for y in min.y as usize..max.y as usize {
+            for x in min.x as usize..max.x as usize {
+                if Vec2::new(x as f32 + 0.5, y as f32 + 0.5).distance(center) <= radius {
+                    self.pixels[y * self.width + x] = color;
+                }
+            }
+        }
+    }
+
+    fn to_png(&self) -> anyhow::Result<Vec<u8>> {
+        let data: Vec<u8> = self
+            .pixels
+            .iter()
+            .flat_map(|p| p.to_array())
+            .map(|v| (v.clamp(0.0, 1.0) * 255.0).round() as u8)
+            .collect();
+
+        encode_png(
+            &data,
+            self.width,
+            self.height,
+            png::ColorType::Rgba,
+            png::BitDepth::Eight,
+        )
+    }
+}
@@ -121,3 +121,19 @@ pub(crate) fn load_vertex_buffer(gctx: &GpuContext, hash: TagHash) -> anyhow::Re
     vb.buffer.set_debug_name(&format!("VertexBuffer: {hash}"));
     Ok(vb)
 }
+
+/// Reads the raw data of a vertex buffer on the CPU, along with its stride
+pub fn read_vertex_buffer(hash: TagHash) -> anyhow::Result<(Vec<u8>, usize)> {
+    let entry = package_manager()
+        .get_entry(hash)
+        .context("Entry not found")?;
+
+    let header: VertexBufferHeader = package_manager()
+        .read_tag_struct(hash)
+        .context("Failed to read header data")?;
+    let data = package_manager()
+        .read_tag(entry.reference)
+        .context("Failed to read buffer data")?;
+
+    Ok((data, header.stride as usize))
+}
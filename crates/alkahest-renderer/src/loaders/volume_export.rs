@@ -0,0 +1,407 @@
+//! Exports gameplay volumes (kill and turnback barriers, player containment volumes, named areas and
+//! slip surfaces) with their world space Havok shapes, as JSON and as glTF with a layer per volume type
+
+use std::{
+    io::Cursor,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use alkahest_data::{
+    map::{SSlipSurfaceVolume, SUnk80808604, SUnk80809178, SUnk8080917b, SUnk80809885},
+    occlusion::Aabb,
+    text::StringContainer,
+};
+use alkahest_pm::package_manager;
+use anyhow::Context;
+use destiny_havok::shape_collection::{self, Shape};
+use destiny_pkg::TagHash;
+use glam::{Mat4, Quat, Vec3};
+use rustc_hash::FxHashMap;
+use serde_json::{json, Value};
+use tiger_parse::TigerReadable;
+
+use crate::{
+    ecs::{tags::NodeFilter, transform::Transform},
+    loaders::{
+        gltf::{to_gltf_space, GltfBuilder, GLTF_FLOAT, GLTF_UNSIGNED_SHORT},
+        map_data::MapDataTables,
+    },
+};
+
+/// Volume types in the order they are written to the glTF layers
+pub const VOLUME_KINDS: [NodeFilter; 5] = [
+    NodeFilter::InstakillBarrier,
+    NodeFilter::TurnbackBarrier,
+    NodeFilter::PlayerContainmentVolume,
+    NodeFilter::NamedArea,
+    NodeFilter::SlipSurfaceVolume,
+];
+
+pub struct GameplayVolume {
+    pub kind: NodeFilter,
+    /// Area name, only set for named areas
+    pub name: Option<String>,
+    pub world_id: u64,
+    pub source_table: TagHash,
+    pub resource_offset: u64,
+    pub havok_file: TagHash,
+    pub shape_index: u32,
+    /// Transform that was applied to the Havok shape to bring it into world space
+    pub shape_to_world: Mat4,
+    /// World space vertices, in Destiny's Z-up coordinate space
+    pub vertices: Vec<Vec3>,
+    /// Triangle list indices
+    pub indices: Vec<u16>,
+}
+
+impl GameplayVolume {
+    pub fn bounds(&self) -> Aabb {
+        let (min, max) = self.vertices.iter().fold(
+            (Vec3::splat(f32::INFINITY), Vec3::splat(f32::NEG_INFINITY)),
+            |(min, max), v| (min.min(*v), max.max(*v)),
+        );
+
+        Aabb { min, max }
+    }
+
+    pub fn label(&self) -> String {
+        match &self.name {
+            Some(name) => format!("{} '{name}'", self.kind),
+            None => format!("{} {}", self.kind, self.world_id),
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct MapVolumes {
+    pub map_hash: TagHash,
+    pub map_name: String,
+    pub volumes: Vec<GameplayVolume>,
+}
+
+impl MapVolumes {
+    pub fn gather(map_hash: TagHash, stringmap: &StringContainer) -> anyhow::Result<Self> {
+        let tables = MapDataTables::load(map_hash)?;
+        let mut volumes = MapVolumes {
+            map_hash,
+            map_name: stringmap.get(tables.map_name),
+            ..Default::default()
+        };
+
+        let mut shape_cache = ShapeCache::default();
+        for entry in tables.entries() {
+            let (table_hash, entry, mut cur) = match entry {
+                Ok(entry) => entry,
+                Err(e) => {
+                    error!("{e:?}");
+                    continue;
+                }
+            };
+            match Self::read_volume(&entry, table_hash, &mut cur, stringmap, &mut shape_cache) {
+                Ok(Some(volume)) => volumes.volumes.push(volume),
+                Ok(None) => {}
+                Err(e) => error!(
+                    "Failed to read volume at offset 0x{:X} in table {table_hash}: {e:?}",
+                    entry.data_resource.offset
+                ),
+            }
+        }
+
+        Ok(volumes)
+    }
+
+    /// Mirrors the shape placement of the volume branches in `load_datatable_into_scene`
+    fn read_volume(
+        entry: &SUnk80809885,
+        table_hash: TagHash,
+        cur: &mut Cursor<Arc<[u8]>>,
+        stringmap: &StringContainer,
+        shape_cache: &mut ShapeCache,
+    ) -> anyhow::Result<Option<GameplayVolume>> {
+        let entry_transform = Transform {
+            translation: entry.translation.truncate(),
+            rotation: entry.rotation,
+            scale: Vec3::splat(entry.translation.w),
+            ..Default::default()
+        }
+        .local_to_world();
+
+        let resource_type = entry.data_resource.resource_type;
+        if !matches!(
+            resource_type,
+            0x80809178 | 0x8080917b | 0x80808604 | 0x80809121
+        ) {
+            return Ok(None);
+        }
+
+        let (kind, name, havok_file, shape_index, shape_to_world) = match resource_type {
+            0x80809178 => {
+                let d: SUnk80809178 = TigerReadable::read_ds(cur)?;
+                (
+                    NodeFilter::NamedArea,
+                    Some(stringmap.get(d.area_name)),
+                    d.unk0.havok_file,
+                    d.unk0.shape_index,
+                    entry_transform,
+                )
+            }
+            0x8080917b => {
+                let d: SUnk8080917b = TigerReadable::read_ds(cur)?;
+                let kind = match d.kind {
+                    0 => NodeFilter::InstakillBarrier,
+                    1 => NodeFilter::TurnbackBarrier,
+                    k => {
+                        warn!("Unknown kill barrier type {k}, exporting as an instakill barrier");
+                        NodeFilter::InstakillBarrier
+                    }
+                };
+
+                (
+                    kind,
+                    None,
+                    d.unk0.havok_file,
+                    d.unk0.shape_index,
+                    entry_transform,
+                )
+            }
+            0x80808604 => {
+                // Containment volume shapes carry their own world transform
+                let d: SUnk80808604 = TigerReadable::read_ds(cur)?;
+                let t = d.unk10.unk8.get(d.index as usize).with_context(|| {
+                    format!(
+                        "Containment volume index {} out of bounds ({} transforms)",
+                        d.index,
+                        d.unk10.unk8.len()
+                    )
+                })?;
+
+                (
+                    NodeFilter::PlayerContainmentVolume,
+                    None,
+                    d.unk10.havok_file,
+                    t.shape_index,
+                    Mat4::from_rotation_translation(t.rotation, t.translation.truncate()),
+                )
+            }
+            _ => {
+                let d: SSlipSurfaceVolume = TigerReadable::read_ds(cur)?;
+                (
+                    NodeFilter::SlipSurfaceVolume,
+                    None,
+                    d.havok_file,
+                    d.shape_index,
+                    entry_transform,
+                )
+            }
+        };
+
+        let Some(mut shape) = shape_cache.get(havok_file, shape_index)? else {
+            warn!(
+                "{kind} {} references missing shape {shape_index} in {havok_file}",
+                entry.world_id
+            );
+            return Ok(None);
+        };
+        shape.apply_transform(shape_to_world);
+
+        Ok(Some(GameplayVolume {
+            kind,
+            name,
+            world_id: entry.world_id,
+            source_table: table_hash,
+            resource_offset: entry.data_resource.offset,
+            havok_file,
+            shape_index,
+            shape_to_world,
+            vertices: shape.vertices,
+            indices: shape.indices,
+        }))
+    }
+
+    pub fn count(&self, kind: NodeFilter) -> usize {
+        self.volumes.iter().filter(|v| v.kind == kind).count()
+    }
+
+    /// Writes the glTF document to `path` with its buffer as `<name>.bin`, and the JSON volume list as
+    /// `<name>.volumes.json`. Returns the JSON path
+    pub fn write(&self, path: &Path) -> anyhow::Result<PathBuf> {
+        let bin_path = path.with_extension("bin");
+        let (gltf, buffer) = self.to_gltf(
+            &bin_path
+                .file_name()
+                .context("Invalid output path")?
+                .to_string_lossy(),
+        );
+
+        std::fs::write(path, serde_json::to_string_pretty(&gltf)?)
+            .with_context(|| format!("Failed to write {}", path.display()))?;
+        std::fs::write(&bin_path, buffer)
+            .with_context(|| format!("Failed to write {}", bin_path.display()))?;
+
+        let json_path = path.with_extension("volumes.json");
+        std::fs::write(&json_path, serde_json::to_string_pretty(&self.to_json())?)
+            .with_context(|| format!("Failed to write {}", json_path.display()))?;
+
+        Ok(json_path)
+    }
+
+    /// Volume list with world space shapes, in Destiny's Z-up coordinate space
+    pub fn to_json(&self) -> Value {
+        json!({
+            "map": self.map_hash.to_string(),
+            "map_name": self.map_name,
+            "volumes": self.volumes.iter().map(|v| {
+                let bounds = v.bounds();
+                let (scale, rotation, translation) = v.shape_to_world.to_scale_rotation_translation();
+                json!({
+                    "kind": v.kind.to_string(),
+                    "name": v.name,
+                    "world_id": format!("{:016X}", v.world_id),
+                    "source_table": v.source_table.to_string(),
+                    "resource_offset": v.resource_offset,
+                    "havok_file": v.havok_file.to_string(),
+                    "shape_index": v.shape_index,
+                    "shape_transform": {
+                        "translation": translation.to_array(),
+                        "rotation": rotation.to_array(),
+                        "scale": scale.to_array(),
+                    },
+                    "bounds": {
+                        "min": bounds.min.to_array(),
+                        "max": bounds.max.to_array(),
+                    },
+                    "vertices": v.vertices.iter().map(|p| p.to_array()).collect::<Vec<_>>(),
+                    "indices": v.indices,
+                })
+            }).collect::<Vec<_>>(),
+        })
+    }
+
+    /// One root node per volume type, with a child node per volume. Shapes are baked in world space
+    pub fn to_gltf(&self, buffer_uri: &str) -> (Value, Vec<u8>) {
+        let mut gltf = GltfBuilder::default();
+        let mut nodes = vec![];
+        let mut meshes = vec![];
+        let mut materials = vec![];
+        let mut layers = vec![];
+
+        for kind in VOLUME_KINDS {
+            let volumes: Vec<&GameplayVolume> =
+                self.volumes.iter().filter(|v| v.kind == kind).collect();
+            if volumes.is_empty() {
+                continue;
+            }
+
+            let [r, g, b, _] = kind.color().to_array();
+            let material = materials.len();
+            materials.push(json!({
+                "name": kind.to_string(),
+                "pbrMetallicRoughness": {
+                    "baseColorFactor": [r, g, b, 0.5],
+                    "metallicFactor": 0.0,
+                    "roughnessFactor": 1.0,
+                },
+                "alphaMode": "BLEND",
+                "doubleSided": true,
+            }));
+
+            let mut children = vec![];
+            for volume in volumes {
+                if volume.vertices.is_empty() || volume.indices.is_empty() {
+                    continue;
+                }
+
+                let positions: Vec<[f32; 3]> = volume
+                    .vertices
+                    .iter()
+                    .map(|v| to_gltf_space(*v, Quat::IDENTITY).0.to_array())
+                    .collect();
+                let (min, max) = positions.iter().fold(
+                    (Vec3::splat(f32::INFINITY), Vec3::splat(f32::NEG_INFINITY)),
+                    |(min, max), p| (min.min(Vec3::from(*p)), max.max(Vec3::from(*p))),
+                );
+
+                let position = gltf.accessor(
+                    bytemuck::cast_slice(&positions),
+                    positions.len(),
+                    GLTF_FLOAT,
+                    "VEC3",
+                    false,
+                    Some((min.to_array(), max.to_array())),
+                );
+                let indices = gltf.accessor(
+                    bytemuck::cast_slice(&volume.indices),
+                    volume.indices.len(),
+                    GLTF_UNSIGNED_SHORT,
+                    "SCALAR",
+                    false,
+                    None,
+                );
+
+                children.push(nodes.len());
+                nodes.push(json!({
+                    "name": volume.label(),
+                    "mesh": meshes.len(),
+                    "extras": {
+                        "kind": kind.to_string(),
+                        "name": volume.name,
+                        "world_id": format!("{:016X}", volume.world_id),
+                        "havok_file": volume.havok_file.to_string(),
+                        "shape_index": volume.shape_index,
+                    },
+                }));
+                meshes.push(json!({
+                    "name": volume.label(),
+                    "primitives": [{
+                        "attributes": { "POSITION": position },
+                        "indices": indices,
+                        "material": material,
+                    }],
+                }));
+            }
+
+            layers.push(nodes.len());
+            nodes.push(json!({
+                "name": kind.to_string(),
+                "children": children,
+            }));
+        }
+
+        let document = json!({
+            "asset": {
+                "version": "2.0",
+                "generator": concat!("alkahest ", env!("CARGO_PKG_VERSION")),
+            },
+            "scene": 0,
+            "scenes": [{
+                "name": self.map_name,
+                "nodes": layers,
+            }],
+            "nodes": nodes,
+            "meshes": meshes,
+            "materials": materials,
+        });
+
+        gltf.finish(document, buffer_uri)
+    }
+}
+
+/// Havok files are shared between many volumes, so each one is only parsed once
+#[derive(Default)]
+struct ShapeCache(FxHashMap<TagHash, Vec<Shape>>);
+
+impl ShapeCache {
+    fn get(&mut self, havok_file: TagHash, shape_index: u32) -> anyhow::Result<Option<Shape>> {
+        if !self.0.contains_key(&havok_file) {
+            let data = package_manager()
+                .read_tag(havok_file)
+                .with_context(|| format!("Failed to read havok file {havok_file}"))?;
+            let shapes = shape_collection::read_shape_collection(&mut Cursor::new(&data))
+                .with_context(|| format!("Failed to read shapes from {havok_file}"))?;
+            self.0.insert(havok_file, shapes);
+        }
+
+        Ok(self.0[&havok_file].get(shape_index as usize).cloned())
+    }
+}
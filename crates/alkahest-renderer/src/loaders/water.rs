@@ -0,0 +1,179 @@
+//! Water surface data gathered from the entity model of a water resource (`SUnk808068d4`)
+//!
+//! Apart from the entity model, nothing in the water resource is decoded. What is known about a
+//! surface comes from its model: the mesh bounds, the techniques it is drawn with, the cubemaps
+//! those techniques bind and the evaluated constants of the surface technique. The meaning of
+//! those constants is not known, so they are kept as raw constant buffer elements.
+
+use alkahest_data::{
+    entity::SDynamicModel,
+    occlusion::Aabb,
+    technique::{STechnique, STechniqueShader},
+    texture::STextureHeader,
+    tfx::TfxRenderStage,
+};
+use alkahest_pm::package_manager;
+use destiny_pkg::TagHash;
+use glam::{Mat4, Vec4, Vec4Swizzles};
+use serde::Serialize;
+use tiger_parse::PackageManagerExt;
+
+use super::technique::evaluate_cbuffer_cpu;
+use crate::tfx::externs::ExternStorage;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct WaterParameters {
+    #[serde(serialize_with = "alkahest_data::tag::serialize_taghash")]
+    pub model: TagHash,
+    /// World space bounds of the surface mesh
+    pub bounds_min: [f32; 3],
+    pub bounds_max: [f32; 3],
+
+    pub techniques: Vec<WaterTechnique>,
+    /// Cubemaps bound to the surface techniques
+    pub reflection_probes: Vec<WaterReflectionProbe>,
+
+    /// Vertex stage constant buffer of the surface technique after evaluating its bytecode
+    pub cbuffer_vertex: Vec<[f32; 4]>,
+    /// Pixel stage constant buffer of the surface technique after evaluating its bytecode
+    pub cbuffer_pixel: Vec<[f32; 4]>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct WaterTechnique {
+    pub stage: &'static str,
+    #[serde(serialize_with = "alkahest_data::tag::serialize_taghash")]
+    pub technique: TagHash,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct WaterReflectionProbe {
+    #[serde(serialize_with = "alkahest_data::tag::serialize_taghash")]
+    pub technique: TagHash,
+    pub slot: u32,
+    #[serde(serialize_with = "alkahest_data::tag::serialize_taghash")]
+    pub texture: TagHash,
+}
+
+impl WaterParameters {
+    pub fn from_model(
+        hash: TagHash,
+        model: &SDynamicModel,
+        local_to_world: Mat4,
+    ) -> anyhow::Result<Self> {
+        let local_bounds =
+            Aabb::from_center_extents(model.model_offset.xyz(), model.model_scale.xyz());
+        let bounds = Aabb::from_obbs([(local_to_world, local_bounds)]);
+
+        let mut techniques: Vec<WaterTechnique> = vec![];
+        for mesh in &model.meshes {
+            for stage in TfxRenderStage::VARIANTS {
+                for part in mesh
+                    .parts
+                    .get(mesh.get_range_for_stage(stage))
+                    .unwrap_or(&[])
+                {
+                    if part.technique.is_some()
+                        && !techniques
+                            .iter()
+                            .any(|t| t.technique == part.technique && t.stage == stage.as_str())
+                    {
+                        techniques.push(WaterTechnique {
+                            stage: stage.as_str(),
+                            technique: part.technique,
+                        });
+                    }
+                }
+            }
+        }
+
+        // Constants are read from the first technique drawn in the gbuffer or transparents stage
+        let surface_technique = [
+            TfxRenderStage::GenerateGbuffer,
+            TfxRenderStage::Transparents,
+        ]
+        .iter()
+        .find_map(|stage| techniques.iter().find(|t| t.stage == stage.as_str()))
+        .or(techniques.first())
+        .map(|t| t.technique);
+
+        let mut cbuffer_vertex = vec![];
+        let mut cbuffer_pixel = vec![];
+        if let Some(technique) = surface_technique {
+            let stech: STechnique = package_manager().read_tag_struct(technique)?;
+            cbuffer_vertex = evaluate_stage(&stech.shader_vertex);
+            cbuffer_pixel = evaluate_stage(&stech.shader_pixel);
+        }
+
+        let mut reflection_probes = vec![];
+        for technique in &techniques {
+            if reflection_probes
+                .iter()
+                .any(|p: &WaterReflectionProbe| p.technique == technique.technique)
+            {
+                continue;
+            }
+
+            match package_manager().read_tag_struct::<STechnique>(technique.technique) {
+                Ok(stech) => {
+                    reflection_probes.extend(find_cubemaps(&stech.shader_pixel).into_iter().map(
+                        |(slot, texture)| WaterReflectionProbe {
+                            technique: technique.technique,
+                            slot,
+                            texture,
+                        },
+                    ))
+                }
+                Err(e) => warn!(
+                    "Failed to read water technique {}: {e}",
+                    technique.technique
+                ),
+            }
+        }
+
+        Ok(Self {
+            model: hash,
+            bounds_min: bounds.min.to_array(),
+            bounds_max: bounds.max.to_array(),
+            techniques,
+            reflection_probes,
+            cbuffer_vertex: cbuffer_vertex.iter().map(|v| v.to_array()).collect(),
+            cbuffer_pixel: cbuffer_pixel.iter().map(|v| v.to_array()).collect(),
+        })
+    }
+
+    pub fn bounds(&self) -> Aabb {
+        Aabb {
+            min: self.bounds_min.into(),
+            max: self.bounds_max.into(),
+        }
+    }
+}
+
+fn evaluate_stage(shader: &STechniqueShader) -> Vec<Vec4> {
+    match evaluate_cbuffer_cpu(shader, &ExternStorage::default()) {
+        Ok((cbuffer, _)) => cbuffer,
+        Err(e) => {
+            warn!("Failed to evaluate water technique constants: {e:?}");
+            vec![]
+        }
+    }
+}
+
+fn find_cubemaps(shader: &STechniqueShader) -> Vec<(u32, TagHash)> {
+    shader
+        .textures
+        .iter()
+        .filter_map(|assignment| {
+            let texture = assignment.texture.hash32_checked()?;
+            match package_manager().read_tag_struct::<STextureHeader>(texture) {
+                Ok(header) if header.array_size == 6 => Some((assignment.slot, texture)),
+                Ok(_) => None,
+                Err(e) => {
+                    warn!("Failed to read water texture header {texture}: {e}");
+                    None
+                }
+            }
+        })
+        .collect()
+}
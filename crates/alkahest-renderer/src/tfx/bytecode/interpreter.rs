@@ -38,11 +38,49 @@ impl TfxBytecodeInterpreter {
         samplers: &[Option<ID3D11SamplerState>],
     ) -> anyhow::Result<()> {
         profiling::scope!("TfxBytecodeInterpreter::evaluate");
+        self.run(
+            Some(gctx),
+            externs,
+            buffer.map(|b| b.data_array()),
+            constants,
+            samplers,
+        )
+    }
+
+    /// Evaluates the bytecode without a GPU, writing the outputs to `output`.
+    /// Texture and sampler bindings are skipped
+    pub fn evaluate_cpu(
+        &self,
+        externs: &ExternStorage,
+        output: &mut [Vec4],
+        constants: &[Vec4],
+    ) -> anyhow::Result<()> {
+        self.run(None, externs, Some(output), constants, &[])
+    }
+
+    /// Output elements written by the bytecode, in order of evaluation. Matrices are written as 4 consecutive elements
+    pub fn output_elements(&self) -> Vec<(usize, bool)> {
+        self.opcodes
+            .iter()
+            .filter_map(|op| match op {
+                TfxBytecodeOp::PopOutput { element } => Some((*element as usize, false)),
+                TfxBytecodeOp::PopOutputMat4 { element } => Some((*element as usize, true)),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn run(
+        &self,
+        gctx: Option<&GpuContext>,
+        externs: &ExternStorage,
+        mut buffer_map: Option<&mut [Vec4]>,
+        constants: &[Vec4],
+        samplers: &[Option<ID3D11SamplerState>],
+    ) -> anyhow::Result<()> {
         let mut stack: SmallVec<[Vec4; 64]> = Default::default();
         let mut temp = [Vec4::ZERO; 16];
 
-        let mut buffer_map = buffer.map(|b| b.data_array());
-
         macro_rules! stack_pop {
             ($pops:literal) => {{
                 anyhow::ensure!(!stack.is_empty() && stack.len() >= $pops);
@@ -202,7 +240,9 @@ impl TfxBytecodeInterpreter {
                     stack_push!(v);
                 }
                 &TfxBytecodeOp::PushSampler { index, .. } => {
-                    if let Some(sampler) = samplers.get(index as usize) {
+                    if gctx.is_none() {
+                        stack_push!(Vec4::ZERO);
+                    } else if let Some(sampler) = samplers.get(index as usize) {
                         let handle: u64 = sampler
                             .as_ref()
                             .map(|sampler| unsafe { transmute_copy(sampler) })
@@ -224,17 +264,21 @@ impl TfxBytecodeInterpreter {
                 &TfxBytecodeOp::SetShaderSampler { stage, slot, .. } => {
                     let [v] = stack_pop!(1);
                     let [handle, _]: [u64; 2] = bytemuck::cast(v);
-                    self.set_shader_sampler(gctx, stage, slot as _, handle)
+                    if let Some(gctx) = gctx {
+                        self.set_shader_sampler(gctx, stage, slot as _, handle)
+                    }
                 }
                 &TfxBytecodeOp::SetShaderTexture { stage, slot, .. } => {
                     let [v] = stack_pop!(1);
                     let [handle, guard]: [u64; 2] = bytemuck::cast(v);
-                    if guard == HANDLE_SAFEGUARD {
-                        let resource: ID3D11ShaderResourceView = unsafe { transmute(handle) };
-
-                        self.set_shader_resource(gctx, stage, slot as _, Some(resource));
-                    } else {
-                        self.set_shader_resource(gctx, stage, slot as _, None);
+                    if let Some(gctx) = gctx {
+                        if guard == HANDLE_SAFEGUARD {
+                            let resource: ID3D11ShaderResourceView = unsafe { transmute(handle) };
+
+                            self.set_shader_resource(gctx, stage, slot as _, Some(resource));
+                        } else {
+                            self.set_shader_resource(gctx, stage, slot as _, None);
+                        }
                     }
                 }
                 TfxBytecodeOp::Triangle => {
@@ -8,6 +8,7 @@ use alkahest_pm::package_manager;
 use anyhow::{ensure, Context};
 use destiny_pkg::TagHash;
 use glam::Vec4;
+use render_states::PipelineStateDesc;
 use windows::Win32::Graphics::Direct3D11::{
     ID3D11ComputeShader, ID3D11DomainShader, ID3D11GeometryShader, ID3D11HullShader,
     ID3D11PixelShader, ID3D11SamplerState, ID3D11VertexShader,
@@ -50,6 +51,11 @@ impl Technique {
             (&self.tech.shader_compute, self.stage_compute.as_mut()),
         ]
     }
+
+    /// Fixed function states this technique selects, without inheriting anything from the pass
+    pub fn pipeline_state(&self) -> PipelineStateDesc {
+        PipelineStateDesc::from_selection(self.tech.states)
+    }
 }
 
 impl Technique {
@@ -0,0 +1,79 @@
+#[test]
+fn test_parse_lens_flares() {
+    use alkahest_data::{
+        lens_flare::LensFlareParameters,
+        map::{SLensFlare, SLensFlareElement},
+    };
+    use alkahest_pm::package_manager;
+    use tiger_parse::{PackageManagerExt, TigerReadable};
+
+    use crate::TestHarness;
+
+    let _harness = TestHarness::new();
+
+    let tags = package_manager().get_all_by_reference(SLensFlare::ID.unwrap());
+    assert!(!tags.is_empty(), "No lens flare tags found");
+
+    let mut errors = vec![];
+    for (tag, _) in &tags {
+        let data = match package_manager().read_tag(*tag) {
+            Ok(d) => d,
+            Err(e) => {
+                errors.push(format!("{tag}: {e}"));
+                continue;
+            }
+        };
+        let flare: SLensFlare = match package_manager().read_tag_struct(*tag) {
+            Ok(f) => f,
+            Err(e) => {
+                errors.push(format!("{tag}: {e}"));
+                continue;
+            }
+        };
+
+        // The element array is the last thing in the tag: its offset is relative to the offset
+        // field at 0x10 and it starts with a 16 byte header
+        let elements_offset = u64::from_le_bytes(data[0x10..0x18].try_into().unwrap()) as usize;
+        let parsed_size =
+            0x10 + elements_offset + 0x10 + flare.elements.len() * SLensFlareElement::SIZE;
+        assert_eq!(
+            flare.file_size as usize,
+            data.len(),
+            "Lens flare {tag} file size does not match the tag size"
+        );
+        assert_eq!(
+            parsed_size,
+            data.len(),
+            "Lens flare {tag} has 0x{:X} bytes but only 0x{parsed_size:X} were parsed",
+            data.len()
+        );
+
+        for (i, element) in flare.elements.iter().enumerate() {
+            assert!(
+                element.technique.is_none()
+                    || package_manager().get_entry(element.technique).is_some(),
+                "Lens flare {tag} element {i} references a missing technique {}",
+                element.technique
+            );
+            assert!(
+                element.color.is_finite() && element.scale.is_finite(),
+                "Lens flare {tag} element {i} has non-finite parameters"
+            );
+        }
+
+        let parameters = flare.parameters(*tag);
+        let json = serde_json::to_string(&parameters).unwrap();
+        let roundtrip: LensFlareParameters = serde_json::from_str(&json)
+            .unwrap_or_else(|e| panic!("Failed to read back exported lens flare {tag}: {e}"));
+        assert_eq!(parameters, roundtrip, "Lens flare {tag} did not round-trip");
+    }
+
+    assert!(
+        errors.is_empty(),
+        "Failed to parse {} of {} lens flares:\n{}",
+        errors.len(),
+        tags.len(),
+        errors.join("\n")
+    );
+    info!("Parsed {} lens flares", tags.len());
+}
@@ -1,3 +1,4 @@
+mod lens_flare;
 mod maps;
 
 #[allow(unused_imports)]
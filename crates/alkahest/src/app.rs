@@ -105,6 +105,8 @@ impl AlkahestApp {
         resources.insert(SelectionGizmoMode::default());
         resources.insert(HiddenWindows::default());
         resources.insert(ActionList::default());
+        #[cfg(feature = "audio_playback")]
+        resources.insert(crate::audio::AmbientAudioPlayer::default());
         let renderer = Renderer::create(
             gctx.clone(),
             (window.inner_size().width, window.inner_size().height),
@@ -382,6 +384,11 @@ impl AlkahestApp {
                                 .map(|m| &mut m.scene)
                                 .unwrap_or(scratch_map);
 
+                            #[cfg(feature = "audio_playback")]
+                            resources
+                                .get_mut::<crate::audio::AmbientAudioPlayer>()
+                                .update(&resources.get::<Camera>(), scene);
+
                             renderer.render_world(&*resources.get::<Camera>(), scene, resources);
                         }
 
@@ -0,0 +1,186 @@
+use std::{path::PathBuf, sync::Arc};
+
+use alkahest_data::wwise::vorbis::CodebookLibrary;
+use lazy_static::lazy_static;
+
+use crate::{config, util::RwLock};
+
+pub const CODEBOOKS_FILENAME: &str = "packed_codebooks_aoTuV_603.bin";
+
+lazy_static! {
+    static ref CODEBOOKS: RwLock<Option<Arc<CodebookLibrary>>> = RwLock::new(None);
+}
+
+/// The configured codebook library path, falling back to the directory alkahest is run from
+pub fn codebooks_path() -> PathBuf {
+    if let Some(path) = config::with(|c| c.audio.codebooks_path.clone()) {
+        return PathBuf::from(path);
+    }
+
+    std::env::current_exe()
+        .ok()
+        .and_then(|exe| exe.parent().map(|p| p.join(CODEBOOKS_FILENAME)))
+        .filter(|p| p.exists())
+        .unwrap_or_else(|| PathBuf::from(CODEBOOKS_FILENAME))
+}
+
+/// Loads the codebook library on first use
+pub fn codebooks() -> anyhow::Result<Arc<CodebookLibrary>> {
+    if let Some(codebooks) = CODEBOOKS.read().as_ref() {
+        return Ok(codebooks.clone());
+    }
+
+    let codebooks = Arc::new(CodebookLibrary::load(codebooks_path())?);
+    *CODEBOOKS.write() = Some(codebooks.clone());
+    Ok(codebooks)
+}
+
+#[cfg(feature = "audio_playback")]
+pub use playback::AmbientAudioPlayer;
+
+#[cfg(feature = "audio_playback")]
+mod playback {
+    use std::sync::Arc;
+
+    use alkahest_data::wwise::{self, DecodedAudio};
+    use alkahest_renderer::{
+        camera::Camera,
+        ecs::{audio::AmbientAudio, transform::Transform, Scene},
+    };
+    use bevy_ecs::{entity::Entity, world::WorldId};
+    use destiny_pkg::TagHash;
+    use rodio::{buffer::SamplesBuffer, OutputStream, OutputStreamHandle, Source, SpatialSink};
+    use rustc_hash::{FxHashMap, FxHashSet};
+
+    use crate::{config, util::RwLock};
+
+    /// Distance between the listener's ears, in meters
+    const EAR_DISTANCE: f32 = 0.2;
+
+    enum DecodeState {
+        Pending,
+        Ready(Arc<DecodedAudio>),
+        Failed,
+    }
+
+    /// Plays the ambient sound sources of the current map that are in range of the camera
+    #[derive(Default)]
+    pub struct AmbientAudioPlayer {
+        output: Option<(OutputStream, OutputStreamHandle)>,
+        output_failed: bool,
+        world: Option<WorldId>,
+        sinks: FxHashMap<Entity, SpatialSink>,
+        streams: Arc<RwLock<FxHashMap<TagHash, DecodeState>>>,
+    }
+
+    impl AmbientAudioPlayer {
+        pub fn update(&mut self, camera: &Camera, scene: &mut Scene) {
+            let (enabled, volume, radius) =
+                config::with(|c| (c.audio.playback, c.audio.volume, c.audio.radius));
+
+            if !enabled || self.world != Some(scene.id()) {
+                self.sinks.clear();
+                self.world = Some(scene.id());
+            }
+
+            if !enabled {
+                return;
+            }
+
+            if self.output.is_none() && !self.output_failed {
+                match OutputStream::try_default() {
+                    Ok(output) => self.output = Some(output),
+                    Err(e) => {
+                        error!("Failed to open audio output device: {e}");
+                        self.output_failed = true;
+                    }
+                }
+            }
+
+            let Some((_, handle)) = &self.output else {
+                return;
+            };
+
+            let listener = camera.position();
+            let left_ear = (listener - camera.right() * EAR_DISTANCE / 2.0).to_array();
+            let right_ear = (listener + camera.right() * EAR_DISTANCE / 2.0).to_array();
+
+            let mut in_range = FxHashSet::default();
+            for (entity, audio, transform) in scene
+                .query::<(Entity, &AmbientAudio, &Transform)>()
+                .iter(scene)
+            {
+                if transform.translation.distance(listener) > radius {
+                    continue;
+                }
+
+                if let Some(sink) = self.sinks.get(&entity) {
+                    sink.set_left_ear_position(left_ear);
+                    sink.set_right_ear_position(right_ear);
+                    sink.set_volume(volume);
+                    in_range.insert(entity);
+                    continue;
+                }
+
+                let Some(&stream) = audio.streams().first() else {
+                    continue;
+                };
+
+                let Some(decoded) = self.decoded(stream) else {
+                    continue;
+                };
+
+                match SpatialSink::try_new(
+                    handle,
+                    transform.translation.to_array(),
+                    left_ear,
+                    right_ear,
+                ) {
+                    Ok(sink) => {
+                        sink.set_volume(volume);
+                        sink.append(
+                            SamplesBuffer::new(
+                                decoded.channels,
+                                decoded.sample_rate,
+                                decoded.samples.clone(),
+                            )
+                            .repeat_infinite(),
+                        );
+                        self.sinks.insert(entity, sink);
+                        in_range.insert(entity);
+                    }
+                    Err(e) => error!("Failed to create audio sink for stream {stream}: {e}"),
+                }
+            }
+
+            // Dropping a sink stops playback
+            self.sinks.retain(|e, _| in_range.contains(e));
+        }
+
+        /// Returns the decoded stream, starting a background decode if it hasn't been requested yet
+        fn decoded(&self, stream: TagHash) -> Option<Arc<DecodedAudio>> {
+            match self.streams.read().get(&stream) {
+                Some(DecodeState::Ready(decoded)) => return Some(decoded.clone()),
+                Some(DecodeState::Pending | DecodeState::Failed) => return None,
+                None => {}
+            }
+
+            self.streams.write().insert(stream, DecodeState::Pending);
+            let streams = self.streams.clone();
+            std::thread::spawn(move || {
+                let result = super::codebooks()
+                    .and_then(|codebooks| wwise::decode_stream(stream, &codebooks));
+                let state = match result {
+                    Ok(decoded) => DecodeState::Ready(Arc::new(decoded)),
+                    Err(e) => {
+                        error!("Failed to decode audio stream {stream}: {e:?}");
+                        DecodeState::Failed
+                    }
+                };
+                streams.write().insert(stream, state);
+            });
+
+            None
+        }
+    }
+}
@@ -0,0 +1,971 @@
+//! Headless commands that run against the loaded packages and exit without opening a window
+
+use std::{collections::HashMap, fmt::Write, path::PathBuf, sync::Arc};
+
+use alkahest_data::{
+    activity::SActivity,
+    activity_graph::ActivityGraph,
+    dependencies::{tag_kind, DependencyNode},
+    entity::IdentifierMask,
+    lightprobe::VoxelIblEncoding,
+    map::{SBubbleParent, SLensFlare},
+    reverse_index::ReverseIndex,
+    statics::SStaticMesh,
+    technique::STechnique,
+    text::StringContainer,
+    wwise::{self, vorbis::CodebookLibrary, Wem},
+};
+use alkahest_pm::package_manager;
+use alkahest_renderer::loaders::{
+    atmosphere_export::{export_atmosphere, find_map_atmospheres},
+    cubemap_export::MapCubemaps,
+    decal_export::MapDecals,
+    decorator_export::MapDecorators,
+    light_export::MapLights,
+    light_probe::MapLightProbes,
+    map_stats::{MapStatSort, MapStats},
+    material_export::export_materials,
+    skinned_export::{export_skinned_models, find_map_skinned_entities, SkinnedModel},
+    terrain_export::{find_map_terrains, TerrainData},
+    topdown_map::{MapRoute, TopdownMap, TopdownOptions},
+    volume_export::{MapVolumes, VOLUME_KINDS},
+};
+use anyhow::Context;
+use clap::{Subcommand, ValueEnum};
+use destiny_pkg::TagHash;
+use glam::Mat4;
+use render_states::PipelineStateDesc;
+use tiger_parse::{PackageManagerExt, TigerReadable};
+
+use crate::{audio, parse_taghash, util::reverse_index::cache_path};
+
+#[derive(Subcommand, Debug, Clone)]
+pub enum CliCommand {
+    /// Export the graph of destinations, activities and the maps they load
+    ActivityGraph {
+        /// Output file
+        output: PathBuf,
+
+        #[arg(short, long, value_enum, default_value_t = GraphFormat::Json)]
+        format: GraphFormat,
+    },
+    /// Report entity counts, triangle budgets and texture memory for one or more maps
+    MapStats {
+        /// Map hashes to report on
+        #[arg(value_parser = parse_taghash)]
+        maps: Vec<TagHash>,
+
+        /// Also report on every map loaded by this activity
+        #[arg(short, long, value_parser = parse_taghash)]
+        activity: Option<TagHash>,
+
+        /// Sort rows by category, name or value
+        #[arg(short, long, default_value = "category", value_parser = |s: &str| s.parse::<MapStatSort>())]
+        sort: MapStatSort,
+
+        /// Write the report to a CSV file instead of printing it
+        #[arg(short, long)]
+        csv: Option<PathBuf>,
+    },
+    /// Export the tree of tags referenced by a tag
+    Dependencies {
+        #[arg(value_parser = parse_taghash)]
+        tag: TagHash,
+
+        /// Output file
+        output: PathBuf,
+
+        #[arg(short, long, value_enum, default_value_t = DependencyFormat::Json)]
+        format: DependencyFormat,
+
+        /// Maximum depth to walk references to
+        #[arg(short, long, default_value_t = 8)]
+        depth: usize,
+    },
+    /// List the tags that reference a tag, and the maps it ends up in
+    Uses {
+        #[arg(value_parser = parse_taghash)]
+        tag: TagHash,
+
+        /// Only list users of this class (eg. 80806D44 for statics), searching through indirect references
+        #[arg(short, long, value_parser = parse_class)]
+        class: Option<u32>,
+
+        /// Write the results to a CSV file instead of printing them
+        #[arg(short, long)]
+        csv: Option<PathBuf>,
+    },
+    /// Export a map's reflection probes as KTX2 or EXR cube faces, with their influence volumes in a cubemaps.json manifest
+    Cubemaps {
+        #[arg(value_parser = parse_taghash)]
+        map: TagHash,
+
+        /// Output directory
+        #[arg(short, long, default_value = "cubemaps")]
+        output: PathBuf,
+    },
+    /// Export the baked indirect lighting probes of a map as L1 spherical harmonics in a lightprobes.json grid (experimental)
+    LightProbes {
+        #[arg(value_parser = parse_taghash)]
+        map: TagHash,
+
+        /// Output directory
+        #[arg(short, long, default_value = "lightprobes")]
+        output: PathBuf,
+
+        /// How the voxel IBL textures are encoded. The encoding the game uses isn't known, `auto` guesses it
+        #[arg(short, long, value_enum, default_value_t = ProbeEncoding::Auto)]
+        encoding: ProbeEncoding,
+    },
+    /// Export map lights as glTF KHR_lights_punctual nodes, with a <output>.lights.json sidecar holding the raw light data
+    Lights {
+        #[arg(value_parser = parse_taghash)]
+        map: TagHash,
+
+        /// Output .gltf file
+        output: PathBuf,
+    },
+    /// Export the decoded world space transforms and colors of every decorator (foliage scatter) instance in a map
+    Decorators {
+        #[arg(value_parser = parse_taghash)]
+        map: TagHash,
+
+        /// Output file. glTF exports also write a .bin buffer next to it
+        output: PathBuf,
+
+        #[arg(short, long, value_enum, default_value_t = DecoratorFormat::Csv)]
+        format: DecoratorFormat,
+    },
+    /// Export a map's decals as axis aligned projector boxes with a glTF material per decal technique
+    Decals {
+        #[arg(value_parser = parse_taghash)]
+        map: TagHash,
+
+        /// Output .gltf file, with a node per decal. The decal list and technique textures are written next to it as .decals.json
+        output: PathBuf,
+    },
+    /// Export a map's kill barriers, turnback barriers, containment volumes, named areas and slip surfaces with their world space shapes
+    Volumes {
+        #[arg(value_parser = parse_taghash)]
+        map: TagHash,
+
+        /// Output .gltf file, with a glTF node per volume type. The volume list is written next to it as .volumes.json
+        output: PathBuf,
+    },
+    /// Render a top-down callout map without the GPU, with respawn points, named areas, barriers and routes as SVG and PNG layers
+    Topdown {
+        #[arg(value_parser = parse_taghash)]
+        map: TagHash,
+
+        /// Output directory
+        #[arg(short, long, default_value = ".")]
+        output: PathBuf,
+
+        /// Meters per pixel
+        #[arg(long, default_value_t = 0.5)]
+        resolution: f32,
+
+        /// Leave out geometry and markers below this height, for slicing multi-level interiors
+        #[arg(long, allow_hyphen_values = true)]
+        min_height: Option<f32>,
+
+        /// Leave out geometry and markers above this height
+        #[arg(long, allow_hyphen_values = true)]
+        max_height: Option<f32>,
+
+        /// JSON file with routes to draw, as a list of `{"name", "color": [r, g, b, a], "points": [[x, y, z], ...]}`
+        #[arg(long)]
+        routes: Option<PathBuf>,
+
+        #[arg(long)]
+        no_statics: bool,
+
+        #[arg(long)]
+        no_terrain: bool,
+    },
+    /// Export a map's terrain as glTF meshes per detail level, a heightmap and a splat texture stitched from its dyemaps
+    Terrain {
+        #[arg(value_parser = parse_taghash)]
+        map: TagHash,
+
+        /// Output directory
+        #[arg(short, long, default_value = ".")]
+        output: PathBuf,
+
+        /// Heightmap and splat texture resolution in meters per pixel
+        #[arg(long, default_value_t = 1.0)]
+        resolution: f32,
+    },
+    /// Export a map's decoded atmosphere and fog parameters, along with its lookup textures
+    Atmosphere {
+        #[arg(value_parser = parse_taghash)]
+        map: TagHash,
+
+        /// Output directory
+        #[arg(short, long, default_value = ".")]
+        output: PathBuf,
+    },
+    /// Export the parsed elements, techniques, textures and occlusion settings of lens flare tags as JSON
+    LensFlares {
+        #[arg(value_parser = parse_taghash, required = true)]
+        tags: Vec<TagHash>,
+
+        /// Output file
+        #[arg(short, long, default_value = "lens_flares.json")]
+        output: PathBuf,
+    },
+    /// Disassemble the shaders of techniques, annotated with their reflection data
+    Shaders {
+        /// Technique or shader tags
+        #[arg(value_parser = parse_taghash, required = true)]
+        tags: Vec<TagHash>,
+
+        /// Output directory
+        #[arg(short, long, default_value = ".")]
+        output: PathBuf,
+    },
+    /// Dump the blend, depth stencil, rasterizer and depth bias states selected by techniques as JSON
+    PipelineStates {
+        #[arg(value_parser = parse_taghash, required = true)]
+        tags: Vec<TagHash>,
+
+        /// Output directory, one file per technique
+        #[arg(short, long, default_value = ".")]
+        output: PathBuf,
+    },
+    /// Export techniques as glTF metallic-roughness materials, with the gstack unpacked into separate textures
+    Materials {
+        /// Technique tags, or static mesh tags to export every technique of
+        #[arg(value_parser = parse_taghash, required = true)]
+        tags: Vec<TagHash>,
+
+        /// Output glTF file, textures and the JSON sidecar are written next to it
+        #[arg(short, long, default_value = "materials.gltf")]
+        output: PathBuf,
+    },
+    /// Export entities as skinned glTF models, with their skeletons as glTF skins
+    Rigs {
+        /// Entity tags, exported at the origin
+        #[arg(value_parser = parse_taghash)]
+        tags: Vec<TagHash>,
+
+        /// Also export every skinned entity placed in this map, at its placement
+        #[arg(long, value_parser = parse_taghash)]
+        map: Option<TagHash>,
+
+        /// Output glTF file, the joint list is written next to it as .rigs.json
+        #[arg(short, long, default_value = "rigs.gltf")]
+        output: PathBuf,
+
+        /// Export part techniques as materials tinted by the entity dyes, writing their textures next
+        /// to the glTF file
+        #[arg(long)]
+        materials: bool,
+
+        /// Export the animation clips referenced by each entity as glTF animations
+        #[arg(long)]
+        animations: bool,
+
+        /// Material variant to resolve part techniques with
+        #[arg(long, default_value_t = 0)]
+        variant: usize,
+
+        /// Only export parts with these identifiers, as a comma separated list or 'all'
+        #[arg(long, default_value = "all")]
+        identifiers: IdentifierMask,
+    },
+    /// Decode Wwise audio streams to WAV or OGG
+    Audio {
+        /// Stream or sound collection tags
+        #[arg(value_parser = parse_taghash, required = true)]
+        tags: Vec<TagHash>,
+
+        /// Output directory
+        #[arg(short, long, default_value = ".")]
+        output: PathBuf,
+
+        #[arg(short, long, value_enum, default_value_t = AudioFormat::Wav)]
+        format: AudioFormat,
+
+        /// Path to ww2ogg's packed_codebooks_aoTuV_603.bin
+        #[arg(long)]
+        codebooks: Option<PathBuf>,
+
+        /// Write the checksum of every decoded stream to this CSV file
+        #[arg(long)]
+        checksums: Option<PathBuf>,
+    },
+}
+
+#[derive(ValueEnum, Debug, Clone, Copy)]
+pub enum GraphFormat {
+    Json,
+    /// Graphviz DOT
+    Dot,
+}
+
+#[derive(ValueEnum, Debug, Clone, Copy)]
+pub enum DependencyFormat {
+    Json,
+    /// One row per reference
+    Csv,
+    /// Plain list of every unique tag in the tree
+    List,
+}
+
+#[derive(ValueEnum, Debug, Clone, Copy)]
+pub enum DecoratorFormat {
+    /// One row per instance
+    Csv,
+    /// ASCII point cloud with instance colors
+    Ply,
+    /// Bounding box proxies instanced with EXT_mesh_gpu_instancing
+    Gltf,
+}
+
+#[derive(ValueEnum, Debug, Clone, Copy)]
+pub enum ProbeEncoding {
+    /// Guess from the depth of each texture (experimental)
+    Auto,
+    Irradiance,
+    ShL1,
+    AmbientCube,
+}
+
+impl ProbeEncoding {
+    fn encoding(self) -> Option<VoxelIblEncoding> {
+        match self {
+            Self::Auto => None,
+            Self::Irradiance => Some(VoxelIblEncoding::Irradiance),
+            Self::ShL1 => Some(VoxelIblEncoding::ShL1),
+            Self::AmbientCube => Some(VoxelIblEncoding::AmbientCube),
+        }
+    }
+}
+
+#[derive(ValueEnum, Debug, Clone, Copy)]
+pub enum AudioFormat {
+    /// 16-bit PCM
+    Wav,
+    /// Ogg Vorbis, remuxed without re-encoding
+    Ogg,
+}
+
+fn parse_class(s: &str) -> Result<u32, std::num::ParseIntError> {
+    u32::from_str_radix(s.trim_start_matches("0x"), 16)
+}
+
+pub fn run(command: &CliCommand) -> anyhow::Result<()> {
+    match command {
+        CliCommand::ActivityGraph { output, format } => {
+            let global_strings = StringContainer::load_all_global();
+            let graph = info_span!("Building activity graph")
+                .in_scope(|| ActivityGraph::build(&global_strings));
+
+            let data = match format {
+                GraphFormat::Json => graph.to_json()?,
+                GraphFormat::Dot => graph.to_dot(),
+            };
+
+            std::fs::write(output, data)
+                .with_context(|| format!("Failed to write {}", output.display()))?;
+            info!(
+                "Wrote activity graph with {} nodes and {} edges to {}",
+                graph.nodes.len(),
+                graph.edges.len(),
+                output.display()
+            );
+        }
+        CliCommand::MapStats {
+            maps,
+            activity,
+            sort,
+            csv,
+        } => {
+            let mut maps = maps.clone();
+            if let Some(activity_hash) = activity {
+                let activity: SActivity = package_manager()
+                    .read_tag_struct(*activity_hash)
+                    .context("Failed to read activity")?;
+                for bubble in &activity.unk50 {
+                    for map in &bubble.map_references {
+                        if !maps.contains(&map.hash32()) {
+                            maps.push(map.hash32());
+                        }
+                    }
+                }
+            }
+
+            if maps.is_empty() {
+                anyhow::bail!("No maps specified");
+            }
+
+            let global_strings = StringContainer::load_all_global();
+            let mut stats = vec![];
+            for map_hash in maps {
+                match MapStats::gather(map_hash, &global_strings) {
+                    Ok(s) => stats.push(s),
+                    Err(e) => error!("Failed to gather stats for map {map_hash}: {e}"),
+                }
+            }
+
+            if let Some(csv) = csv {
+                std::fs::write(csv, MapStats::to_csv(&stats, *sort))
+                    .with_context(|| format!("Failed to write {}", csv.display()))?;
+                info!("Wrote stats for {} maps to {}", stats.len(), csv.display());
+            } else {
+                for s in &stats {
+                    println!("{} ({})", s.map_name, s.map_hash);
+                    for row in s.rows(*sort) {
+                        println!("  {:<12} {:<24} {}", row.category, row.name, row.value);
+                    }
+                }
+            }
+        }
+        CliCommand::Dependencies {
+            tag,
+            output,
+            format,
+            depth,
+        } => {
+            let tree = DependencyNode::walk(*tag, *depth);
+            let data = match format {
+                DependencyFormat::Json => tree.to_json()?,
+                DependencyFormat::Csv => tree.to_csv(),
+                DependencyFormat::List => tree
+                    .unique_tags()
+                    .iter()
+                    .map(|t| format!("{t}\n"))
+                    .collect(),
+            };
+
+            std::fs::write(output, data)
+                .with_context(|| format!("Failed to write {}", output.display()))?;
+            info!(
+                "Wrote {} references ({} unique tags) of {tag} to {}",
+                tree.count() - 1,
+                tree.unique_tags().len(),
+                output.display()
+            );
+        }
+        CliCommand::Uses { tag, class, csv } => {
+            let index = ReverseIndex::load_or_build(&cache_path());
+            let users = match class {
+                Some(class) => index.users_of_class(*tag, *class),
+                None => index.users(*tag).to_vec(),
+            };
+            let maps = index.users_of_class(*tag, SBubbleParent::ID.unwrap());
+
+            let mut rows = vec![];
+            for user in &users {
+                rows.push(("user", tag_kind(*user), *user));
+            }
+            for map in &maps {
+                rows.push(("map", tag_kind(*map), *map));
+            }
+
+            if let Some(csv) = csv {
+                let mut data = String::from("relation,kind,hash\n");
+                for (relation, kind, hash) in &rows {
+                    data.push_str(&format!("{relation},{kind},{hash}\n"));
+                }
+                std::fs::write(csv, data)
+                    .with_context(|| format!("Failed to write {}", csv.display()))?;
+                info!("Wrote {} users of {tag} to {}", rows.len(), csv.display());
+            } else {
+                println!("{} tags reference {tag}:", users.len());
+                for user in &users {
+                    println!("  {} {user}", tag_kind(*user));
+                }
+                println!("Used in {} maps:", maps.len());
+                for map in &maps {
+                    println!("  {map}");
+                }
+            }
+        }
+        CliCommand::Cubemaps { map, output } => {
+            let global_strings = StringContainer::load_all_global();
+            let cubemaps = MapCubemaps::gather(*map, &global_strings)?;
+            let manifest = cubemaps.write(output)?;
+            info!(
+                "Wrote {} reflection probes of '{}' to {}",
+                cubemaps.probes.len(),
+                cubemaps.map_name,
+                manifest.display()
+            );
+        }
+        CliCommand::LightProbes {
+            map,
+            output,
+            encoding,
+        } => {
+            let global_strings = StringContainer::load_all_global();
+            let probes = MapLightProbes::gather(*map, &global_strings, encoding.encoding())?;
+            let path = probes.write(output)?;
+            info!(
+                "Wrote {} probe volumes of '{}' to {}",
+                probes.volumes.len(),
+                probes.map_name,
+                path.display()
+            );
+        }
+        CliCommand::Lights { map, output } => {
+            let global_strings = StringContainer::load_all_global();
+            let lights = MapLights::gather(*map, &global_strings)?;
+            let sidecar = lights.write(output)?;
+            info!(
+                "Wrote {} lights of '{}' to {} and {}",
+                lights.lights.len(),
+                lights.map_name,
+                output.display(),
+                sidecar.display()
+            );
+        }
+        CliCommand::Decorators {
+            map,
+            output,
+            format,
+        } => {
+            let global_strings = StringContainer::load_all_global();
+            let decorators = MapDecorators::gather(*map, &global_strings)?;
+            match format {
+                DecoratorFormat::Csv => std::fs::write(output, decorators.to_csv())
+                    .with_context(|| format!("Failed to write {}", output.display()))?,
+                DecoratorFormat::Ply => std::fs::write(output, decorators.to_ply())
+                    .with_context(|| format!("Failed to write {}", output.display()))?,
+                DecoratorFormat::Gltf => {
+                    decorators.write_gltf(output)?;
+                }
+            }
+
+            info!(
+                "Wrote {} instances of {} decorators in '{}' to {}",
+                decorators.instance_count(),
+                decorators.decorators.len(),
+                decorators.map_name,
+                output.display()
+            );
+        }
+        CliCommand::Decals { map, output } => {
+            let global_strings = StringContainer::load_all_global();
+            let decals = MapDecals::gather(*map, &global_strings)?;
+            let json_path = decals.write(output)?;
+            info!(
+                "Wrote {} decals of '{}' using {} techniques to {} and {}",
+                decals.decals.len(),
+                decals.map_name,
+                decals.materials.len(),
+                output.display(),
+                json_path.display()
+            );
+        }
+        CliCommand::Volumes { map, output } => {
+            let global_strings = StringContainer::load_all_global();
+            let volumes = MapVolumes::gather(*map, &global_strings)?;
+            let json_path = volumes.write(output)?;
+
+            let counts = VOLUME_KINDS
+                .iter()
+                .map(|kind| format!("{} {kind}", volumes.count(*kind)))
+                .collect::<Vec<_>>()
+                .join(", ");
+            info!(
+                "Wrote {} volumes of '{}' ({counts}) to {} and {}",
+                volumes.volumes.len(),
+                volumes.map_name,
+                output.display(),
+                json_path.display()
+            );
+        }
+        CliCommand::Topdown {
+            map,
+            output,
+            resolution,
+            min_height,
+            max_height,
+            routes,
+            no_statics,
+            no_terrain,
+        } => {
+            let routes: Vec<MapRoute> = match routes {
+                Some(path) => serde_json::from_slice(
+                    &std::fs::read(path)
+                        .with_context(|| format!("Failed to read {}", path.display()))?,
+                )
+                .context("Failed to parse routes")?,
+                None => vec![],
+            };
+
+            let options = TopdownOptions {
+                meters_per_pixel: *resolution,
+                height_range: (min_height.is_some() || max_height.is_some()).then(|| {
+                    (
+                        min_height.unwrap_or(f32::NEG_INFINITY),
+                        max_height.unwrap_or(f32::INFINITY),
+                    )
+                }),
+                include_statics: !no_statics,
+                include_terrain: !no_terrain,
+            };
+
+            let global_strings = StringContainer::load_all_global();
+            let topdown = TopdownMap::render(*map, &global_strings, options, routes)?;
+            topdown.export(output)?;
+            info!(
+                "Rendered '{}' at {}x{} ({} m/px) with {} respawn points and {} volumes to {}",
+                topdown.map_name,
+                topdown.width,
+                topdown.height,
+                topdown.meters_per_pixel,
+                topdown.respawn_points.len(),
+                topdown.volumes.len(),
+                output.display()
+            );
+        }
+        CliCommand::Terrain {
+            map,
+            output,
+            resolution,
+        } => {
+            let terrains = find_map_terrains(*map)?;
+            if terrains.is_empty() {
+                anyhow::bail!("Map {map} has no terrain");
+            }
+
+            for hash in &terrains {
+                let output = if terrains.len() > 1 {
+                    output.join(hash.to_string())
+                } else {
+                    output.clone()
+                };
+
+                let terrain = TerrainData::load(*hash)
+                    .with_context(|| format!("Failed to load terrain {hash}"))?;
+                terrain.export(&output, *resolution)?;
+                info!(
+                    "Wrote terrain {hash} ({} detail levels) to {}",
+                    terrain.detail_levels().len(),
+                    output.display()
+                );
+            }
+        }
+        CliCommand::Atmosphere { map, output } => {
+            let atmospheres = find_map_atmospheres(*map)?;
+            if atmospheres.is_empty() {
+                anyhow::bail!("Map {map} has no atmosphere definition");
+            }
+
+            for (i, atmosphere) in atmospheres.iter().enumerate() {
+                let output = if atmospheres.len() > 1 {
+                    output.join(i.to_string())
+                } else {
+                    output.clone()
+                };
+
+                let textures = export_atmosphere(atmosphere, &output)?;
+                info!(
+                    "Wrote atmosphere parameters and {textures} lookup textures to {}",
+                    output.display()
+                );
+            }
+        }
+        CliCommand::LensFlares { tags, output } => {
+            let mut flares = vec![];
+            for tag in tags {
+                match package_manager().read_tag_struct::<SLensFlare>(*tag) {
+                    Ok(flare) => flares.push(flare.parameters(*tag)),
+                    Err(e) => error!("Failed to read lens flare {tag}: {e}"),
+                }
+            }
+
+            std::fs::write(output, serde_json::to_string_pretty(&flares)?)
+                .with_context(|| format!("Failed to write {}", output.display()))?;
+            info!("Wrote {} lens flares to {}", flares.len(), output.display());
+        }
+        CliCommand::Shaders { tags, output } => {
+            std::fs::create_dir_all(output)
+                .with_context(|| format!("Failed to create {}", output.display()))?;
+
+            let mut written = 0;
+            for tag in tags {
+                let entry = package_manager()
+                    .get_entry(*tag)
+                    .with_context(|| format!("Entry not found for {tag}"))?;
+
+                let shaders = if entry.file_type == 33 {
+                    vec![(format!("{tag}"), *tag, None)]
+                } else {
+                    let technique = match package_manager().read_tag_struct::<STechnique>(*tag) {
+                        Ok(technique) => technique,
+                        Err(e) => {
+                            error!("Failed to read technique {tag}: {e}");
+                            continue;
+                        }
+                    };
+
+                    technique
+                        .all_valid_shaders()
+                        .into_iter()
+                        .map(|(stage, shader)| {
+                            (
+                                format!("{tag}_{}", stage.short_name().to_lowercase()),
+                                shader.shader,
+                                Some(shader.constants.constant_buffer_slot),
+                            )
+                        })
+                        .collect()
+                };
+
+                for (name, shader, cbuffer_slot) in shaders {
+                    match disassemble_shader(shader, cbuffer_slot) {
+                        Ok(text) => {
+                            let path = output.join(format!("{name}.asm"));
+                            std::fs::write(&path, text)
+                                .with_context(|| format!("Failed to write {}", path.display()))?;
+                            written += 1;
+                        }
+                        Err(e) => error!("Failed to disassemble shader {shader} ({name}): {e:?}"),
+                    }
+                }
+            }
+
+            info!(
+                "Wrote {written} shader disassemblies to {}",
+                output.display()
+            );
+        }
+        CliCommand::PipelineStates { tags, output } => {
+            std::fs::create_dir_all(output)
+                .with_context(|| format!("Failed to create {}", output.display()))?;
+
+            let mut written = 0;
+            for tag in tags {
+                let technique = match package_manager().read_tag_struct::<STechnique>(*tag) {
+                    Ok(technique) => technique,
+                    Err(e) => {
+                        error!("Failed to read technique {tag}: {e}");
+                        continue;
+                    }
+                };
+
+                let pipeline = PipelineStateDesc::from_selection(technique.states);
+                let json = serde_json::json!({
+                    "technique": tag.to_string(),
+                    "states": format!("0x{:08X}", technique.states.raw()),
+                    "alpha_blended": pipeline.is_alpha_blended(),
+                    "two_sided": pipeline.is_two_sided(),
+                    "writes_depth": pipeline.writes_depth(),
+                    "pipeline": pipeline,
+                });
+
+                let path = output.join(format!("{tag}.json"));
+                std::fs::write(&path, serde_json::to_string_pretty(&json)?)
+                    .with_context(|| format!("Failed to write {}", path.display()))?;
+                written += 1;
+            }
+
+            info!("Wrote {written} pipeline states to {}", output.display());
+        }
+        CliCommand::Materials { tags, output } => {
+            let mut techniques = vec![];
+            for tag in tags {
+                match package_manager().read_tag_struct::<SStaticMesh>(*tag) {
+                    Ok(mesh) => techniques.extend(mesh.techniques.iter().copied()),
+                    Err(_) => techniques.push(*tag),
+                }
+            }
+            let mut seen = std::collections::HashSet::new();
+            techniques.retain(|t| t.is_some() && seen.insert(*t));
+
+            if let Some(parent) = output.parent() {
+                std::fs::create_dir_all(parent)
+                    .with_context(|| format!("Failed to create {}", parent.display()))?;
+            }
+
+            let json_path = export_materials(&techniques, output)?;
+            info!(
+                "Wrote {} materials to {} and {}",
+                techniques.len(),
+                output.display(),
+                json_path.display()
+            );
+        }
+        CliCommand::Rigs {
+            tags,
+            map,
+            output,
+            materials,
+            animations,
+            variant,
+            identifiers,
+        } => {
+            let mut placements: Vec<(TagHash, Mat4)> =
+                tags.iter().map(|&t| (t, Mat4::IDENTITY)).collect();
+            if let Some(map) = map {
+                placements.extend(find_map_skinned_entities(*map)?);
+            }
+            if placements.is_empty() {
+                anyhow::bail!("No entities to export");
+            }
+
+            let mut models = vec![];
+            let mut model_indices: HashMap<TagHash, Option<usize>> = HashMap::new();
+            let mut placed = vec![];
+            for (entity, transform) in placements {
+                let index = *model_indices.entry(entity).or_insert_with(|| {
+                    let mut model =
+                        SkinnedModel::load_entity_variant(entity, *variant, *identifiers)
+                            .map_err(|e| error!("Failed to load entity {entity}: {e:?}"))
+                            .ok()?;
+                    if *animations {
+                        if let Err(e) = model.load_animations() {
+                            error!("Failed to load animations of entity {entity}: {e:?}");
+                        }
+                    }
+
+                    models.push(model);
+                    Some(models.len() - 1)
+                });
+
+                if let Some(index) = index {
+                    placed.push((index, transform));
+                }
+            }
+
+            if let Some(parent) = output.parent() {
+                std::fs::create_dir_all(parent)
+                    .with_context(|| format!("Failed to create {}", parent.display()))?;
+            }
+
+            let json_path = export_skinned_models(&models, &placed, output, *materials)?;
+            info!(
+                "Wrote {} placements of {} models ({} skinned, {} animation clips) to {} and {}",
+                placed.len(),
+                models.len(),
+                models.iter().filter(|m| m.skeleton.is_some()).count(),
+                models.iter().map(|m| m.animations.len()).sum::<usize>(),
+                output.display(),
+                json_path.display()
+            );
+        }
+        CliCommand::Audio {
+            tags,
+            output,
+            format,
+            codebooks,
+            checksums,
+        } => {
+            let codebooks = match codebooks {
+                Some(path) => Arc::new(CodebookLibrary::load(path)?),
+                None => audio::codebooks()?,
+            };
+
+            let mut streams = vec![];
+            for tag in tags {
+                for stream in wwise::resolve_streams(*tag)? {
+                    if !streams.contains(&stream) {
+                        streams.push(stream);
+                    }
+                }
+            }
+
+            std::fs::create_dir_all(output)
+                .with_context(|| format!("Failed to create {}", output.display()))?;
+
+            let mut checksum_csv = String::from("stream,checksum\n");
+            let mut exported = 0;
+            for stream in streams {
+                let result = (|| -> anyhow::Result<()> {
+                    let data = package_manager().read_tag(stream)?;
+                    let wem = Wem::parse(&data)?;
+                    let decoded = wem.decode(&codebooks)?;
+                    writeln!(checksum_csv, "{:08X},{:016X}", stream.0, decoded.checksum())?;
+
+                    let (extension, data) = match format {
+                        AudioFormat::Wav => ("wav", decoded.to_wav()),
+                        AudioFormat::Ogg => {
+                            ("ogg", wem.vorbis_packets(&codebooks)?.to_ogg(stream.0)?)
+                        }
+                    };
+
+                    let path = output.join(format!("{stream}.{extension}"));
+                    std::fs::write(&path, data)
+                        .with_context(|| format!("Failed to write {}", path.display()))?;
+                    info!(
+                        "Wrote {} ({} {:.1}s, {} channels, {} Hz)",
+                        path.display(),
+                        wem.codec_name(),
+                        decoded.duration_seconds(),
+                        decoded.channels,
+                        decoded.sample_rate
+                    );
+
+                    Ok(())
+                })();
+
+                match result {
+                    Ok(()) => exported += 1,
+                    Err(e) => error!("Failed to export stream {stream}: {e:?}"),
+                }
+            }
+
+            if let Some(checksums) = checksums {
+                std::fs::write(checksums, checksum_csv)
+                    .with_context(|| format!("Failed to write {}", checksums.display()))?;
+            }
+
+            info!("Exported {exported} streams to {}", output.display());
+        }
+    }
+
+    Ok(())
+}
+
+/// Disassembles a shader tag, prefixed with notes on the constant buffer fed by the technique's
+/// dynamic constants and the vertex inputs the shader expects
+fn disassemble_shader(shader: TagHash, cbuffer_slot: Option<i32>) -> anyhow::Result<String> {
+    let entry = package_manager()
+        .get_entry(shader)
+        .context("Entry not found")?;
+    anyhow::ensure!(entry.file_type == 33, "Not a shader tag");
+
+    let data = package_manager()
+        .read_tag(entry.reference)
+        .context("Failed to read shader data")?;
+    let dxbc = dxbc::Dxbc::parse(&data)?;
+
+    let mut out = format!(
+        "// Shader {shader} (bytecode {})\n",
+        TagHash(entry.reference)
+    );
+    if let Some(slot) = cbuffer_slot.filter(|s| *s >= 0) {
+        let binding = dxbc
+            .resource_definitions()?
+            .and_then(|rdef| {
+                rdef.constant_buffer_at(slot as u32)
+                    .map(|(binding, cbuffer)| {
+                        let size = cbuffer.map(|c| c.size).unwrap_or_default();
+                        format!("{} ({} bytes)", binding.name, size)
+                    })
+            })
+            .unwrap_or_else(|| "not referenced by the shader".to_string());
+        writeln!(out, "// Dynamic constants are bound to cb{slot}: {binding}")?;
+    }
+
+    if let (Some(program), Some(inputs)) = (dxbc.program()?, dxbc.input_signature()?) {
+        if program.version.program_type == dxbc::ProgramType::Vertex {
+            let inputs = inputs
+                .elements
+                .iter()
+                .filter(|e| e.system_value == 0)
+                .map(|e| format!("{}{}", e.semantic_name, e.semantic_index))
+                .collect::<Vec<_>>();
+            writeln!(out, "// Vertex inputs: {}", inputs.join(", "))?;
+        }
+    }
+
+    out.push_str("//\n");
+    out.push_str(&dxbc.disassemble()?);
+
+    Ok(out)
+}
@@ -74,6 +74,7 @@ pub struct Config {
     pub window: WindowConfig,
     pub renderer: RendererSettings,
     pub visual: VisualSettings,
+    pub audio: AudioSettings,
     pub update_channel: Option<UpdateChannel>,
     pub packages_directory: Option<String>,
 }
@@ -101,6 +102,7 @@ impl Default for VisualSettings {
                             | NodeFilter::SlipSurfaceVolume
                             | NodeFilter::InstakillBarrier
                             | NodeFilter::Cubemap
+                            | NodeFilter::Decal
                             | NodeFilter::NamedArea
                     ) {
                         Some(nf.to_string())
@@ -113,6 +115,30 @@ impl Default for VisualSettings {
     }
 }
 
+#[derive(Serialize, Deserialize)]
+#[serde(default)]
+pub struct AudioSettings {
+    /// Play ambient sound sources near the camera (requires the `audio_playback` feature)
+    pub playback: bool,
+    pub volume: f32,
+    /// Maximum distance from the camera at which sources are played
+    pub radius: f32,
+    /// Path to ww2ogg's `packed_codebooks_aoTuV_603.bin`, needed to decode Wwise vorbis streams.
+    /// Defaults to the directory alkahest is run from
+    pub codebooks_path: Option<String>,
+}
+
+impl Default for AudioSettings {
+    fn default() -> Self {
+        Self {
+            playback: false,
+            volume: 1.0,
+            radius: 40.0,
+            codebooks_path: None,
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize)]
 #[serde(default)]
 pub struct WindowConfig {
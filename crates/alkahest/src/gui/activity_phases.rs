@@ -0,0 +1,168 @@
+use alkahest_renderer::{
+    ecs::{
+        activity::{ActivityPhases, PhaseMember},
+        Scene,
+    },
+    icons::{ICON_ARROW_DOWN, ICON_ARROW_UP, ICON_EYE, ICON_EYE_OFF},
+};
+use egui::{Color32, Context, RichText};
+use winit::window::Window;
+
+use crate::{
+    gui::context::{GuiCtx, GuiView, HiddenWindows, ViewResult},
+    maplist::MapList,
+    resources::AppResources,
+};
+
+/// Lists the activity phases of the current map, allowing them to be toggled, soloed and diffed
+#[derive(Default)]
+pub struct ActivityPhasePanel;
+
+impl GuiView for ActivityPhasePanel {
+    fn draw(
+        &mut self,
+        ctx: &Context,
+        _window: &Window,
+        resources: &AppResources,
+        _gui: &GuiCtx<'_>,
+    ) -> Option<ViewResult> {
+        let mut windows = resources.get_mut::<HiddenWindows>();
+        let mut maps = resources.get_mut::<MapList>();
+
+        egui::Window::new("Activity Phases")
+            .open(&mut windows.activity_phases)
+            .show(ctx, |ui| {
+                let Some(map) = maps.current_map_mut() else {
+                    ui.label("No map loaded");
+                    return;
+                };
+
+                if map
+                    .scene
+                    .get_resource::<ActivityPhases>()
+                    .map_or(true, |p| p.is_empty())
+                {
+                    ui.label("The current map has no activity phases");
+                    return;
+                }
+
+                map.scene
+                    .resource_scope::<ActivityPhases, _>(|scene, mut phases| {
+                        ui.horizontal(|ui| {
+                            if ui.button("Show all").clicked() {
+                                phases.solo(scene, None);
+                            }
+                            ui.label(format!("{} phases", phases.len()));
+                        });
+                        ui.separator();
+
+                        let mut move_phase = None;
+                        egui::ScrollArea::vertical()
+                            .auto_shrink([false, true])
+                            .show(ui, |ui| {
+                                for i in 0..phases.len() {
+                                    phase_entry(ui, scene, &phases, i, &mut move_phase);
+                                }
+                            });
+
+                        if let Some((from, to)) = move_phase {
+                            phases.move_phase(from, to);
+                        }
+                    });
+            });
+
+        None
+    }
+}
+
+fn phase_entry(
+    ui: &mut egui::Ui,
+    scene: &mut Scene,
+    phases: &ActivityPhases,
+    i: usize,
+    move_phase: &mut Option<(usize, usize)>,
+) {
+    let visible = phases.is_visible(scene, i);
+    let phase = &phases.phases[i];
+    ui.horizontal(|ui| {
+        let icon = if visible { ICON_EYE } else { ICON_EYE_OFF };
+        if ui
+            .button(icon.to_string())
+            .on_hover_text("Toggle visibility")
+            .clicked()
+        {
+            phases.set_visible(scene, i, !visible);
+        }
+
+        if ui
+            .button("Solo")
+            .on_hover_text("Only show this phase")
+            .clicked()
+        {
+            phases.solo(scene, Some(i));
+        }
+
+        if ui
+            .add_enabled(i > 0, egui::Button::new(ICON_ARROW_UP.to_string()))
+            .clicked()
+        {
+            *move_phase = Some((i, i - 1));
+        }
+
+        if ui
+            .add_enabled(
+                i + 1 < phases.len(),
+                egui::Button::new(ICON_ARROW_DOWN.to_string()),
+            )
+            .clicked()
+        {
+            *move_phase = Some((i, i + 1));
+        }
+
+        let label = RichText::new(format!("{i}: {} ({})", phase.name, phase.origin));
+        ui.label(if visible {
+            label.color(Color32::WHITE)
+        } else {
+            label.color(Color32::GRAY)
+        });
+    });
+
+    let title = if i == 0 {
+        "Spawns".to_string()
+    } else {
+        format!("Diff against phase {}", i - 1)
+    };
+    ui.indent(("activity_phase_diff", i), |ui| {
+        ui.collapsing(title, |ui| {
+            let diff = phases.diff(scene, i);
+            member_list(ui, ("added", i), "Added", Color32::LIGHT_GREEN, &diff.added);
+            member_list(
+                ui,
+                ("removed", i),
+                "Removed",
+                Color32::LIGHT_RED,
+                &diff.removed,
+            );
+        });
+    });
+}
+
+fn member_list(
+    ui: &mut egui::Ui,
+    id: impl std::hash::Hash,
+    title: &str,
+    color: Color32,
+    members: &[PhaseMember],
+) {
+    egui::CollapsingHeader::new(RichText::new(format!("{title} ({})", members.len())).color(color))
+        .id_source(id)
+        .show(ui, |ui| {
+            for m in members {
+                if m.world_id == u64::MAX {
+                    ui.label(m.entity_tag.to_string());
+                } else {
+                    ui.label(format!("{} (world ID 0x{:016X})", m.entity_tag, m.world_id));
+                }
+            }
+        });
+}
@@ -200,6 +200,20 @@ impl GuiView for RenderSettingsPanel {
                     ui.checkbox(&mut c.renderer.stage_decals_additive, "Decals (additive)");
                 });
 
+                #[cfg(feature = "audio_playback")]
+                {
+                    ui.separator();
+                    ui.collapsing(RichText::new("Audio").heading(), |ui| {
+                        ui.checkbox(&mut c.audio.playback, "Play ambient sources");
+                        ui.add(egui::Slider::new(&mut c.audio.volume, 0.0..=2.0).text("Volume"));
+                        ui.add(
+                            egui::Slider::new(&mut c.audio.radius, 5.0..=200.0)
+                                .text("Radius")
+                                .suffix("m"),
+                        );
+                    });
+                }
+
                 resources
                     .get::<RendererShared>()
                     .set_render_settings(c.renderer.clone());
@@ -5,8 +5,11 @@ use std::{
 };
 
 use alkahest_data::{
+    dependencies::tag_kind,
     entity::{SDynamicModel, SEntity, Unk808072c5},
+    map::SBubbleParent,
     technique::STechnique,
+    text::StringContainerShared,
     tfx::{TfxFeatureRenderer, TfxRenderStage},
     WideHash,
 };
@@ -14,6 +17,7 @@ use alkahest_pm::package_manager;
 use alkahest_renderer::{
     camera::Camera,
     ecs::{
+        activity::ActivityPhases,
         common::{Icon, Label, Mutable, RenderCommonBundle},
         render::{dynamic_geometry::DynamicModelComponent, static_geometry::StaticModelSingle},
         tags::{EntityTag, Tags},
@@ -22,6 +26,13 @@ use alkahest_renderer::{
         visibility::Visibility,
     },
     icons::ICON_CUBE,
+    loaders::{
+        decorator_export::MapDecorators,
+        light_export::MapLights,
+        map_stats::{MapStatSort, MapStats},
+        topdown_map::{MapRoute, TopdownMap, TopdownOptions},
+        volume_export::MapVolumes,
+    },
     renderer::{Renderer, RendererShared},
     resources::AppResources,
     tfx::bytecode::{decompiler::TfxBytecodeDecompiler, opcodes::TfxBytecodeOp},
@@ -46,11 +57,15 @@ use winit::window::Window;
 
 use crate::{
     gui::{
+        activity_select::get_map_name,
         commands::load_pkg_entities,
         context::{GuiCtx, GuiView, ViewResult},
     },
     maplist::MapList,
-    util::action::{ActionList, ActivitySwapAction, SpawnRouteAction},
+    util::{
+        action::{ActionList, ActivitySwapAction, SpawnRouteAction},
+        reverse_index::reverse_index,
+    },
 };
 
 lazy_static! {
@@ -789,6 +804,267 @@ fn execute_command(command: &str, args: &[&str], resources: &AppResources) {
                 }
             }
         }
+        "phases" | "phase.list" => {
+            let maps = resources.get::<MapList>();
+            let Some(phases) = maps
+                .current_map()
+                .and_then(|m| m.scene.get_resource::<ActivityPhases>())
+            else {
+                error!("The current map has no activity phases");
+                return;
+            };
+
+            info!("Activity phases:");
+            for (i, phase) in phases.phases.iter().enumerate() {
+                info!("  {i}: {} ({})", phase.name, phase.origin);
+            }
+        }
+        "phase.solo" | "phase.show_all" => {
+            let solo = if command.eq_ignore_ascii_case("phase.solo") {
+                let Some(index) = args.first().and_then(|a| a.parse::<usize>().ok()) else {
+                    error!("Missing/invalid phase index argument");
+                    return;
+                };
+                Some(index)
+            } else {
+                None
+            };
+
+            let mut maps = resources.get_mut::<MapList>();
+            let Some(map) = maps.current_map_mut() else {
+                return;
+            };
+
+            if map.scene.get_resource::<ActivityPhases>().is_none() {
+                error!("The current map has no activity phases");
+                return;
+            }
+
+            map.scene
+                .resource_scope::<ActivityPhases, _>(|scene, phases| match solo {
+                    Some(index) if index >= phases.len() => {
+                        error!(
+                            "Phase index {index} is out of bounds ({} phases)",
+                            phases.len()
+                        );
+                    }
+                    _ => phases.solo(scene, solo),
+                });
+        }
+        "map.stats" => {
+            let sort = match args.first().map(|a| a.parse::<MapStatSort>()) {
+                Some(Ok(sort)) => sort,
+                Some(Err(_)) => {
+                    error!(
+                        "Invalid sort key '{}', expected category/name/value",
+                        args[0]
+                    );
+                    return;
+                }
+                None => MapStatSort::default(),
+            };
+
+            let Some(map_hash) = resources.get::<MapList>().current_map().map(|m| m.hash) else {
+                error!("No map loaded");
+                return;
+            };
+
+            let stringmap = resources.get::<StringContainerShared>().clone();
+            let stats = match MapStats::gather(map_hash, &stringmap) {
+                Ok(o) => o,
+                Err(e) => {
+                    error!("Failed to gather map stats: {e}");
+                    return;
+                }
+            };
+
+            info!("Map stats for '{}' ({map_hash}):", stats.map_name);
+            for row in stats.rows(sort) {
+                info!("  {:<12} {:<24} {}", row.category, row.name, row.value);
+            }
+
+            if let Some(path) = args.get(1) {
+                match std::fs::write(path, MapStats::to_csv(&[stats], sort)) {
+                    Ok(_) => info!("Wrote map stats to {path}"),
+                    Err(e) => error!("Failed to write map stats to {path}: {e}"),
+                }
+            }
+        }
+        "map.export_lights" => {
+            if args.len() != 1 {
+                error!("Missing output path, expected a .gltf file");
+                return;
+            }
+
+            let Some(map_hash) = resources.get::<MapList>().current_map().map(|m| m.hash) else {
+                error!("No map loaded");
+                return;
+            };
+
+            let stringmap = resources.get::<StringContainerShared>().clone();
+            let path = std::path::Path::new(args[0]);
+            match MapLights::gather(map_hash, &stringmap).and_then(|l| Ok((l.write(path)?, l))) {
+                Ok((sidecar, lights)) => info!(
+                    "Exported {} lights to {} and {}",
+                    lights.lights.len(),
+                    path.display(),
+                    sidecar.display()
+                ),
+                Err(e) => error!("Failed to export lights: {e:?}"),
+            }
+        }
+        "map.export_decorators" => {
+            if args.len() != 1 {
+                error!("Missing output path, expected a .csv, .ply or .gltf file");
+                return;
+            }
+
+            let Some(map_hash) = resources.get::<MapList>().current_map().map(|m| m.hash) else {
+                error!("No map loaded");
+                return;
+            };
+
+            let stringmap = resources.get::<StringContainerShared>().clone();
+            let path = std::path::Path::new(args[0]);
+            let result = MapDecorators::gather(map_hash, &stringmap).and_then(|d| {
+                match path.extension().and_then(|e| e.to_str()) {
+                    Some("gltf") => {
+                        d.write_gltf(path)?;
+                    }
+                    Some("ply") => std::fs::write(path, d.to_ply())?,
+                    _ => std::fs::write(path, d.to_csv())?,
+                }
+
+                Ok(d)
+            });
+
+            match result {
+                Ok(decorators) => info!(
+                    "Exported {} decorator instances to {}",
+                    decorators.instance_count(),
+                    path.display()
+                ),
+                Err(e) => error!("Failed to export decorators: {e:?}"),
+            }
+        }
+        "map.export_volumes" => {
+            if args.len() != 1 {
+                error!("Missing output path, expected a .gltf file");
+                return;
+            }
+
+            let Some(map_hash) = resources.get::<MapList>().current_map().map(|m| m.hash) else {
+                error!("No map loaded");
+                return;
+            };
+
+            let stringmap = resources.get::<StringContainerShared>().clone();
+            let path = std::path::Path::new(args[0]);
+            match MapVolumes::gather(map_hash, &stringmap).and_then(|v| Ok((v.write(path)?, v))) {
+                Ok((json_path, volumes)) => info!(
+                    "Exported {} gameplay volumes to {} and {}",
+                    volumes.volumes.len(),
+                    path.display(),
+                    json_path.display()
+                ),
+                Err(e) => error!("Failed to export volumes: {e:?}"),
+            }
+        }
+        "map.export_topdown" => {
+            if args.is_empty() {
+                error!("Missing output directory, expected: map.export_topdown <dir> [meters per pixel]");
+                return;
+            }
+
+            let mut options = TopdownOptions::default();
+            if let Some(resolution) = args.get(1) {
+                match resolution.parse() {
+                    Ok(r) => options.meters_per_pixel = r,
+                    Err(e) => {
+                        error!("Invalid resolution '{resolution}': {e}");
+                        return;
+                    }
+                }
+            }
+
+            let (map_hash, routes) = {
+                let maps = resources.get::<MapList>();
+                let Some(map) = maps.current_map() else {
+                    error!("No map loaded");
+                    return;
+                };
+
+                let routes: Vec<MapRoute> = map
+                    .scene
+                    .iter_entities()
+                    .filter_map(|e| e.get::<Route>())
+                    .map(|route| MapRoute {
+                        name: route
+                            .activity_hash
+                            .map(|h| format!("Route {h}"))
+                            .unwrap_or_else(|| "Route".to_string()),
+                        color: Color32::from(route.color).to_array(),
+                        points: route
+                            .path
+                            .iter()
+                            .filter(|n| n.map_hash.map_or(true, |h| h == map.hash))
+                            .map(|n| n.pos.to_array())
+                            .collect(),
+                    })
+                    .collect();
+
+                (map.hash, routes)
+            };
+
+            let stringmap = resources.get::<StringContainerShared>().clone();
+            let path = std::path::Path::new(args[0]);
+            match TopdownMap::render(map_hash, &stringmap, options, routes)
+                .and_then(|t| t.export(path).map(|_| t))
+            {
+                Ok(topdown) => info!(
+                    "Rendered {}x{} top-down map to {}",
+                    topdown.width,
+                    topdown.height,
+                    path.display()
+                ),
+                Err(e) => error!("Failed to render top-down map: {e:?}"),
+            }
+        }
+        "who_uses" | "refs.users" => {
+            if args.len() != 1 {
+                error!("Missing tag argument, expected 32-bit tag");
+                return;
+            }
+
+            let tag = match parse_extended_hash(args[0]) {
+                Ok(o) => o.hash32(),
+                Err(e) => {
+                    error!("Failed to parse tag: {e}");
+                    return;
+                }
+            };
+
+            let Some(index) = reverse_index() else {
+                warn!("The reverse reference index is still being built, try again later");
+                return;
+            };
+
+            let users = index.users(tag);
+            info!("{} tags reference {tag}:", users.len());
+            for user in users {
+                info!("  {} {user}", tag_kind(*user));
+            }
+
+            let maps = index.users_of_class(tag, SBubbleParent::ID.unwrap());
+            if !maps.is_empty() {
+                let stringmap = resources.get::<StringContainerShared>();
+                info!("Used in {} maps:", maps.len());
+                for map in maps {
+                    let name = get_map_name(map, &stringmap).unwrap_or_default();
+                    info!("  {map} {name}");
+                }
+            }
+        }
         "load_entities_pkg" => {
             // TODO(cohae): Make some abstraction for this
             if args.len() != 1 {
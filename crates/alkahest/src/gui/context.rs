@@ -15,10 +15,12 @@ use winit::{event::WindowEvent, window::Window};
 use crate::{
     config::APP_DIRS,
     gui::{
+        activity_phases::ActivityPhasePanel,
         bottom_bar::BottomBar,
         configuration::RenderSettingsPanel,
         console::ConsolePanel,
         crosshair::CrosshairOverlay,
+        dependencies::DependencyPanel,
         fps_display::FpsDisplayOverlay,
         gizmo::GizmoSelector,
         inspector::InspectorPanel,
@@ -213,6 +215,8 @@ impl GuiViewManager {
         views.insert(CrosshairOverlay);
         views.insert(ResourceLoadIndicatorOverlay);
         views.insert(GizmoSelector);
+        views.insert(ActivityPhasePanel);
+        views.insert(DependencyPanel::default());
 
         views.insert_overlay(FpsDisplayOverlay::default());
 
@@ -303,6 +307,8 @@ pub struct HiddenWindows {
     pub tfx_extern_editor: bool,
     pub tfx_extern_debugger: bool,
     pub cpu_profiler: bool,
+    pub activity_phases: bool,
+    pub tag_references: bool,
 }
 
 mod style {
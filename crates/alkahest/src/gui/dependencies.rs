@@ -0,0 +1,154 @@
+use std::sync::mpsc::{self, Receiver};
+
+use alkahest_data::dependencies::DependencyNode;
+use destiny_pkg::TagHash;
+use egui::{Color32, Context, RichText};
+use winit::window::Window;
+
+use crate::{
+    gui::context::{GuiCtx, GuiView, HiddenWindows, ViewResult},
+    parse_taghash,
+    resources::AppResources,
+};
+
+/// Shows the tree of tags referenced by a tag
+pub struct DependencyPanel {
+    input: String,
+    max_depth: usize,
+    tree: Option<DependencyNode>,
+    /// Walk running on a background thread, large tags can take a while
+    pending: Option<Receiver<DependencyNode>>,
+}
+
+impl Default for DependencyPanel {
+    fn default() -> Self {
+        Self {
+            input: String::new(),
+            max_depth: 4,
+            tree: None,
+            pending: None,
+        }
+    }
+}
+
+impl GuiView for DependencyPanel {
+    fn draw(
+        &mut self,
+        ctx: &Context,
+        _window: &Window,
+        resources: &AppResources,
+        _gui: &GuiCtx<'_>,
+    ) -> Option<ViewResult> {
+        if let Some(rx) = &self.pending {
+            match rx.try_recv() {
+                Ok(tree) => {
+                    self.tree = Some(tree);
+                    self.pending = None;
+                }
+                Err(mpsc::TryRecvError::Empty) => ctx.request_repaint(),
+                Err(mpsc::TryRecvError::Disconnected) => {
+                    error!("Tag reference walker thread exited without a result");
+                    self.pending = None;
+                }
+            }
+        }
+
+        let mut windows = resources.get_mut::<HiddenWindows>();
+
+        egui::Window::new("Tag References")
+            .open(&mut windows.tag_references)
+            .show(ctx, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label("Tag");
+                    let response = ui.text_edit_singleline(&mut self.input);
+                    ui.add(egui::Slider::new(&mut self.max_depth, 1..=16).text("Max depth"));
+
+                    let walking = self.pending.is_some();
+                    if ui.add_enabled(!walking, egui::Button::new("Walk")).clicked()
+                        || (!walking
+                            && response.lost_focus()
+                            && ui.input(|i| i.key_pressed(egui::Key::Enter)))
+                    {
+                        match parse_taghash(self.input.trim()) {
+                            Ok(tag) => self.start_walk(tag),
+                            Err(e) => error!("Failed to parse tag '{}': {e}", self.input),
+                        }
+                    }
+
+                    if walking {
+                        ui.spinner();
+                    }
+                });
+
+                let Some(tree) = &self.tree else {
+                    return;
+                };
+
+                ui.label(format!(
+                    "{} references, {} unique tags",
+                    tree.count() - 1,
+                    tree.unique_tags().len()
+                ));
+                ui.separator();
+
+                egui::ScrollArea::vertical()
+                    .auto_shrink([false, true])
+                    .show(ui, |ui| {
+                        dependency_tree(ui, tree);
+                    });
+            });
+
+        None
+    }
+}
+
+impl DependencyPanel {
+    fn start_walk(&mut self, tag: TagHash) {
+        let (tx, rx) = mpsc::channel();
+        let max_depth = self.max_depth;
+        std::thread::Builder::new()
+            .name("tag-references".to_string())
+            .spawn(move || {
+                tx.send(DependencyNode::walk(tag, max_depth)).ok();
+            })
+            .expect("Failed to spawn tag reference walker thread");
+
+        self.pending = Some(rx);
+    }
+}
+
+fn dependency_tree(ui: &mut egui::Ui, node: &DependencyNode) {
+    let mut text = format!("{}: {} {}", node.relation, node.kind, node.hash);
+    let mut color = Color32::WHITE;
+    if node.cycle {
+        text.push_str(" (cycle)");
+        color = Color32::LIGHT_RED;
+    } else if node.repeated {
+        text.push_str(" (repeated)");
+        color = Color32::GRAY;
+    } else if node.truncated {
+        text.push_str(" (depth limit)");
+        color = Color32::YELLOW;
+    }
+    let text = RichText::new(text).color(color);
+
+    let response = if node.children.is_empty() {
+        ui.label(text)
+    } else {
+        egui::CollapsingHeader::new(text)
+            .id_source((node.hash.0, &node.relation))
+            .show(ui, |ui| {
+                for (i, child) in node.children.iter().enumerate() {
+                    ui.push_id(i, |ui| dependency_tree(ui, child));
+                }
+            })
+            .header_response
+    };
+
+    response.context_menu(|ui| {
+        if ui.button("Copy hash").clicked() {
+            ui.output_mut(|o| o.copied_text = node.hash.to_string());
+            ui.close_menu();
+        }
+    });
+}
@@ -0,0 +1,82 @@
+use alkahest_renderer::{
+    ecs::{animation::EntityAnimation, Scene},
+    icons::{ICON_ANIMATION, ICON_PAUSE, ICON_PLAY},
+};
+use bevy_ecs::prelude::EntityRef;
+use egui::{Ui, Widget};
+
+use crate::{gui::inspector::ComponentPanel, resources::AppResources};
+
+impl ComponentPanel for EntityAnimation {
+    fn inspector_name() -> &'static str {
+        "Animation"
+    }
+
+    fn inspector_icon() -> char {
+        ICON_ANIMATION
+    }
+
+    fn show_inspector_ui<'s>(
+        &mut self,
+        _: &'s mut Scene,
+        _: EntityRef<'s>,
+        ui: &mut Ui,
+        _: &AppResources,
+    ) {
+        let mut selected = self.selected_clip;
+        egui::ComboBox::from_label("Clip").show_index(ui, &mut selected, self.clips.len(), |i| {
+            self.clips[i].to_string()
+        });
+        if selected != self.selected_clip {
+            self.select(selected);
+        }
+
+        let Some((duration, tracks)) = self.clip().map(|c| (c.duration, c.tracks.len())) else {
+            ui.colored_label(egui::Color32::RED, "Failed to load clip");
+            return;
+        };
+
+        ui.label(
+            egui::RichText::new("Only the root track (track 0) is applied to the entity transform")
+                .italics(),
+        );
+
+        ui.horizontal(|ui| {
+            ui.strong("Tracks:");
+            ui.label(format!("{tracks}"));
+            ui.strong("Duration:");
+            ui.label(format!("{duration:.2}s"));
+        });
+
+        ui.horizontal(|ui| {
+            let icon = if self.playing { ICON_PAUSE } else { ICON_PLAY };
+            if ui.button(icon.to_string()).clicked() {
+                if !self.playing && !self.looping && self.time >= duration {
+                    self.time = 0.0;
+                }
+                self.playing = !self.playing;
+            }
+
+            ui.checkbox(&mut self.looping, "Loop");
+            egui::DragValue::new(&mut self.speed)
+                .speed(0.05)
+                .range(-4f32..=4.0)
+                .suffix("x")
+                .ui(ui);
+        });
+
+        ui.style_mut().spacing.slider_width = 260.0;
+        if egui::Slider::new(&mut self.time, 0.0..=duration)
+            .suffix("s")
+            .ui(ui)
+            .dragged()
+        {
+            self.playing = false;
+        }
+
+        if ui.button("Reset to placement").clicked() {
+            self.playing = false;
+            self.time = 0.0;
+        }
+    }
+}
@@ -0,0 +1,72 @@
+use alkahest_renderer::{
+    ecs::{map::Decal, transform::Transform, Scene},
+    icons::ICON_FORMAT_PAINT,
+    renderer::RendererShared,
+};
+use bevy_ecs::prelude::EntityRef;
+use egui::{Color32, Ui};
+
+use crate::{gui::inspector::ComponentPanel, resources::AppResources};
+
+impl ComponentPanel for Decal {
+    fn inspector_name() -> &'static str {
+        "Decal"
+    }
+
+    fn inspector_icon() -> char {
+        ICON_FORMAT_PAINT
+    }
+
+    fn show_inspector_ui<'s>(
+        &mut self,
+        _: &'s mut Scene,
+        e: EntityRef<'s>,
+        ui: &mut Ui,
+        resources: &AppResources,
+    ) {
+        let renderer = resources.get::<RendererShared>();
+        let transform = e.get::<Transform>().expect("Decal missing Transform");
+        let color = Color32::from_rgb(255, 140, 60);
+        renderer.immediate.cube_outline(
+            Transform {
+                scale: self.projector.half_extents,
+                ..*transform
+            },
+            color,
+        );
+
+        let axis = self.projector.projection_axis();
+        let depth = axis.abs().dot(self.projector.half_extents);
+        renderer.immediate.line(
+            transform.translation - axis * depth,
+            transform.translation + axis * depth,
+            color,
+            2.0,
+        );
+
+        ui.horizontal(|ui| {
+            ui.strong("Collection:");
+            ui.label(format!("{} [{}]", self.collection, self.instance.index));
+        });
+        ui.horizontal(|ui| {
+            ui.strong("Technique:");
+            ui.label(self.instance.material.to_string());
+        });
+        ui.horizontal(|ui| {
+            ui.strong("Scale:");
+            ui.label(format!("{:.3}", self.instance.scale));
+        });
+        let e = self.projector.half_extents * 2.0;
+        ui.horizontal(|ui| {
+            ui.strong("Size:");
+            ui.label(format!("{:.2} x {:.2} x {:.2}", e.x, e.y, e.z));
+        });
+
+        if !self.textures.is_empty() {
+            ui.strong("Textures:");
+            for (slot, texture) in &self.textures {
+                ui.label(format!("{slot}: {texture}"));
+            }
+        }
+    }
+}
@@ -34,6 +34,11 @@ impl ComponentPanel for DecoratorRenderer {
             ui.label(format!("{}", self.models.len()));
         });
 
+        ui.horizontal(|ui| {
+            ui.strong("Instances:");
+            ui.label(format!("{}", self.data.unk48.instance_data.data.len()));
+        });
+
         let mesh_count = self.models[0].0.mesh_count();
         if mesh_count > 1 {
             egui::ComboBox::from_label("Mesh").show_index(
@@ -1,15 +1,20 @@
 use alkahest_data::map::{SLight, SLightCollection, SShadowingLight};
 use alkahest_renderer::{
     ecs::{
-        hierarchy::Children, map::CubemapVolume, render::light::LightRenderer,
-        transform::Transform, Scene,
+        hierarchy::Children,
+        map::CubemapVolume,
+        render::{lens_flare::LensFlare, light::LightRenderer},
+        transform::Transform,
+        Scene,
     },
-    icons::{ICON_LIGHTBULB_GROUP, ICON_LIGHTBULB_ON},
+    icons::{ICON_FLARE, ICON_LIGHTBULB_GROUP, ICON_LIGHTBULB_ON},
+    loaders::light_params::LightParameters,
     renderer::RendererShared,
     util::color::Color,
 };
 use bevy_ecs::prelude::EntityRef;
 use egui::{Color32, RichText, Ui};
+use itertools::Itertools;
 
 use crate::{gui::inspector::ComponentPanel, resources::AppResources};
 
@@ -107,6 +112,11 @@ impl ComponentPanel for LightRenderer {
             });
         }
 
+        if let Some(params) = &self.parameters {
+            ui.separator();
+            parameters_ui(ui, params);
+        }
+
         if let Some(transform) = e.get::<Transform>() {
             renderer.immediate.cube_outline(
                 transform.local_to_world() * self.projection_matrix,
@@ -128,6 +138,121 @@ impl ComponentPanel for LightRenderer {
     }
 }
 
+fn parameters_ui(ui: &mut Ui, params: &LightParameters) {
+    egui::Grid::new("light_parameters")
+        .num_columns(2)
+        .show(ui, |ui| {
+            ui.strong("Shape:");
+            ui.label(params.shape.name());
+            ui.end_row();
+
+            ui.strong("Color:");
+            ui.horizontal(|ui| {
+                let [r, g, b] = params.color;
+                let (rect, _) =
+                    ui.allocate_exact_size(egui::vec2(16.0, 16.0), egui::Sense::hover());
+                ui.painter()
+                    .rect_filled(rect, 2.0, egui::Rgba::from_rgb(r, g, b));
+                ui.label(format!("{r:.3} {g:.3} {b:.3}"));
+            });
+            ui.end_row();
+
+            ui.strong("Intensity:");
+            ui.label(format!("{:.3}", params.intensity))
+                .on_hover_text(format!("Source: {:?}", params.color_source));
+            ui.end_row();
+
+            ui.strong("Range:");
+            ui.label(format!("{:.2}", params.range));
+            ui.end_row();
+
+            if let Some(outer) = params.outer_cone_angle {
+                ui.strong("Cone angle:");
+                ui.label(format!("{:.1}°", (outer * 2.).to_degrees()));
+                ui.end_row();
+            }
+
+            for texture in &params.textures {
+                ui.strong(format!("{:?}:", texture.kind));
+                ui.label(format!("{} (slot {})", texture.texture, texture.slot));
+                ui.end_row();
+            }
+
+            if let Some(shadow) = &params.shadow {
+                let passes = [
+                    ("shading", shadow.shadowed_shading),
+                    ("volumetrics", shadow.shadowed_volumetrics),
+                    ("light probes", shadow.shadowed_lightprobe),
+                ]
+                .into_iter()
+                .filter_map(|(name, shadowed)| shadowed.then_some(name))
+                .join(", ");
+
+                ui.strong("Shadowed passes:");
+                ui.label(if passes.is_empty() {
+                    "None"
+                } else {
+                    passes.as_str()
+                });
+                ui.end_row();
+            }
+        });
+}
+
+impl ComponentPanel for LensFlare {
+    fn inspector_name() -> &'static str {
+        "Lens Flare"
+    }
+
+    fn inspector_icon() -> char {
+        ICON_FLARE
+    }
+
+    fn show_inspector_ui<'s>(
+        &mut self,
+        _: &'s mut Scene,
+        _: EntityRef<'s>,
+        ui: &mut Ui,
+        _: &AppResources,
+    ) {
+        let params = self.parameters();
+        ui.horizontal(|ui| {
+            ui.strong("Hash:");
+            ui.label(params.hash.to_string());
+        });
+
+        ui.collapsing("Occlusion", |ui| {
+            ui.label(format!("Technique: {}", params.occlusion.technique));
+            ui.label(format!(
+                "unk4/unk8/unkc: {:.3} {:.3} {:.3}",
+                params.occlusion.unk4, params.occlusion.unk8, params.occlusion.unkc
+            ));
+        });
+
+        for (i, element) in params.elements.iter().enumerate() {
+            egui::CollapsingHeader::new(format!("Element {i}"))
+                .id_source(("lens_flare_element", i))
+                .show(ui, |ui| {
+                    ui.label(format!("Technique: {}", element.technique));
+                    ui.label(format!("Flags: 0x{:X}", element.flags));
+                    ui.label(format!(
+                        "unk8/unkc: {:.3} {:.3}",
+                        element.unk8, element.unkc
+                    ));
+                    ui.label(format!(
+                        "Scale: {:.3} x {:.3}",
+                        element.scale[0], element.scale[1]
+                    ));
+                    let [r, g, b, a] = element.color;
+                    ui.label(format!("Color: {r:.3} {g:.3} {b:.3} {a:.3}"));
+                    for texture in &element.textures {
+                        ui.label(format!("Texture: {texture}"));
+                    }
+                });
+        }
+    }
+}
+
 impl ComponentPanel for CubemapVolume {
     fn inspector_name() -> &'static str {
         "Cubemap Volume"
@@ -1,17 +1,31 @@
+mod animation;
+mod decal;
 mod decorator;
 mod light;
+mod references;
 mod util;
 
-use alkahest_data::map::{SLightCollection, SRespawnPoint};
+use alkahest_data::{
+    atmosphere::AtmosphereParameters,
+    dye::{DyeChannel, GearDyeSlot},
+    entity::IdentifierMask,
+    map::{SLightCollection, SRespawnPoint},
+};
 use alkahest_renderer::{
     camera::Camera,
     ecs::{
+        animation::{EntityAnimation, EntityAnimationSource},
+        audio::AmbientAudio,
         common::{Global, Label, Mutable},
         hierarchy::{Children, Parent},
-        map::{CubemapVolume, NodeMetadata},
+        map::{CubemapVolume, Decal, NodeMetadata},
         render::{
-            decorators::DecoratorRenderer, dynamic_geometry::DynamicModelComponent,
+            decorators::DecoratorRenderer,
+            dynamic_geometry::DynamicModelComponent,
+            lens_flare::LensFlare,
             light::LightRenderer,
+            static_geometry::{StaticInstances, StaticModelSingle},
+            water::WaterSurface,
         },
         resources::SelectedEntity,
         tags::{insert_tag, remove_tag, EntityTag, Tags},
@@ -22,14 +36,14 @@ use alkahest_renderer::{
     },
     icons::{
         ICON_ACCOUNT_CONVERT, ICON_EYE_ARROW_RIGHT_OUTLINE, ICON_HUMAN_MALE,
-        ICON_HUMAN_MALE_FEMALE_CHILD, ICON_POKEBALL,
+        ICON_HUMAN_MALE_FEMALE_CHILD, ICON_POKEBALL, ICON_SPEAKER, ICON_WAVES, ICON_WEATHER_FOG,
     },
     renderer::RendererShared,
     shader::shader_ball::ShaderBallComponent,
     util::{black_magic::EntityRefDarkMagic, Hocus},
 };
 use bevy_ecs::{entity::Entity, prelude::EntityRef, system::Commands};
-use egui::{Align2, Color32, FontId, Key, RichText, Ui, Widget};
+use egui::{Align2, Color32, FontId, Key, Rgba, RichText, Ui, Widget};
 use glam::{Quat, Vec3};
 use winit::window::Window;
 
@@ -103,6 +117,29 @@ impl GuiView for InspectorPanel {
     }
 }
 
+/// Looks up the animation clips of a selected map entity. This is done once, the first time the
+/// entity is shown in the inspector
+fn resolve_entity_animation(scene: &mut Scene, ent: Entity) {
+    let Some(mut e) = scene.get_entity_mut(ent) else {
+        return;
+    };
+    let Some(source) = e.take::<EntityAnimationSource>() else {
+        return;
+    };
+
+    let transform = e.get::<Transform>().copied().unwrap_or_default();
+    match EntityAnimation::from_source(source, transform) {
+        Ok(Some(animation)) => {
+            e.insert(animation);
+        }
+        Ok(None) => {}
+        Err(err) => error!(
+            "Failed to look up animation clips for {}: {err:?}",
+            source.0
+        ),
+    }
+}
+
 pub fn show_inspector_panel(
     ui: &mut egui::Ui,
     scene: &mut Scene,
@@ -110,6 +147,8 @@ pub fn show_inspector_panel(
     ent: Entity,
     resources: &AppResources,
 ) {
+    resolve_entity_animation(scene, ent);
+
     let Some(e) = scene.get_entity(ent) else {
         return;
     };
@@ -278,12 +317,20 @@ fn show_inspector_components(
         Beacon,
         Route,
         DynamicModelComponent,
+        EntityAnimation,
+        StaticModelSingle,
+        StaticInstances,
         LightRenderer,
         SLightCollection,
+        LensFlare,
         CubemapVolume,
+        Decal,
         ShaderBallComponent,
         DecoratorRenderer,
         SRespawnPoint,
+        AmbientAudio,
+        AtmosphereParameters,
+        WaterSurface,
         NodeMetadata
     );
 }
@@ -481,14 +528,19 @@ impl ComponentPanel for DynamicModelComponent {
         _: &mut Scene,
         _: EntityRef<'_>,
         ui: &mut egui::Ui,
-        _: &AppResources,
+        resources: &AppResources,
     ) {
         ui.horizontal(|ui| {
             ui.strong("Hash:");
             ui.label(self.model.hash.to_string());
         });
+        references::used_by_ui(ui, self.model.hash, resources);
         ui.separator();
 
+        if let Err(e) = self.load_pending_dyes(&resources.get::<RendererShared>()) {
+            error!("Failed to set up dyes for {}: {e:?}", self.model.hash);
+        }
+
         let mesh_count = self.model.mesh_count();
         if mesh_count > 1 {
             egui::ComboBox::from_label("Mesh").show_index(
@@ -499,39 +551,65 @@ impl ComponentPanel for DynamicModelComponent {
             );
         }
 
-        let identifier_count = self.model.identifier_count();
-        if identifier_count > 1 {
+        let identifiers = self.model.identifiers();
+        if identifiers.len() > 1 {
             egui::ComboBox::from_label("Identifier")
-                .selected_text(if self.identifier == u16::MAX {
-                    "All".to_string()
-                } else {
-                    format!("ID {}", self.identifier)
-                })
+                .selected_text(self.identifiers.to_string())
                 .show_ui(ui, |ui| {
-                    ui.selectable_value(&mut self.identifier, u16::MAX, "All");
-                    for i in 0..identifier_count {
-                        ui.selectable_value(&mut self.identifier, i as u16, format!("ID {i}"));
+                    ui.selectable_value(&mut self.identifiers, IdentifierMask::All, "All");
+                    for &i in &identifiers {
+                        ui.selectable_value(
+                            &mut self.identifiers,
+                            IdentifierMask::Single(i),
+                            format!("ID {i} ({} parts)", self.model.identifier_part_count(i)),
+                        );
                     }
 
+                    // Cycles through the single identifiers, with 'All' in between the last and first
+                    let current = match self.identifiers {
+                        IdentifierMask::Single(i) => identifiers.iter().position(|&id| id == i),
+                        _ => None,
+                    };
                     if ui.input(|i| i.key_pressed(Key::ArrowUp)) {
-                        if self.identifier == u16::MAX {
-                            self.identifier = identifier_count as u16 - 1;
-                        } else {
-                            self.identifier = self.identifier.wrapping_sub(1);
-                        }
+                        self.identifiers = match current {
+                            None => IdentifierMask::Single(identifiers[identifiers.len() - 1]),
+                            Some(0) => IdentifierMask::All,
+                            Some(c) => IdentifierMask::Single(identifiers[c - 1]),
+                        };
                     }
 
                     if ui.input(|i| i.key_pressed(Key::ArrowDown)) {
-                        if self.identifier == u16::MAX {
-                            self.identifier = 0;
-                        } else {
-                            self.identifier = self.identifier.wrapping_add(1);
-                            if self.identifier >= identifier_count as u16 {
-                                self.identifier = u16::MAX;
-                            }
-                        }
+                        self.identifiers = match current {
+                            None => IdentifierMask::Single(identifiers[0]),
+                            Some(c) if c + 1 >= identifiers.len() => IdentifierMask::All,
+                            Some(c) => IdentifierMask::Single(identifiers[c + 1]),
+                        };
+                    }
+                });
+
+            ui.collapsing("Identifier mask", |ui| {
+                ui.horizontal(|ui| {
+                    if ui.button("All").clicked() {
+                        self.identifiers = IdentifierMask::All;
+                    }
+                    if ui.button("None").clicked() {
+                        self.identifiers = IdentifierMask::Bits(0);
                     }
                 });
+
+                for &i in &identifiers {
+                    let mut included = self.identifiers.contains(i);
+                    if ui
+                        .checkbox(
+                            &mut included,
+                            format!("ID {i} ({} parts)", self.model.identifier_part_count(i)),
+                        )
+                        .changed()
+                    {
+                        self.identifiers.set(i, included, &identifiers);
+                    }
+                }
+            });
         }
 
         let variant_count = self.model.variant_count();
@@ -540,10 +618,87 @@ impl ComponentPanel for DynamicModelComponent {
             egui::Slider::new(&mut self.model.selected_variant, 0..=(variant_count - 1))
                 .text("Material Variant")
                 .ui(ui);
+
+            ui.collapsing("Variant techniques", |ui| {
+                for (part, technique) in self.model.variant_techniques(self.model.selected_variant)
+                {
+                    ui.horizontal(|ui| {
+                        ui.strong(format!("Part {part}:"));
+                        ui.label(technique.to_string());
+                    });
+                }
+            });
+        }
+
+        let dye_slots = self.model.part_dye_slots();
+        if !dye_slots.is_empty() {
+            ui.separator();
+            ui.horizontal(|ui| {
+                ui.strong("Gear dyes");
+                ui.checkbox(&mut self.apply_dyes, "Apply");
+            });
+            if self.dyes.is_empty() {
+                ui.label("No dyes referenced by this entity");
+            } else {
+                ui.label(
+                    RichText::new(
+                        "Dyes are assigned to armor, cloth and suit in the order the entity \
+                         references them",
+                    )
+                    .italics(),
+                );
+            }
+
+            for slot in GearDyeSlot::ALL {
+                let parts = dye_slots.iter().filter(|(_, s)| *s == slot).count();
+                if parts == 0 {
+                    continue;
+                }
+
+                let Some(dye) = self.dyes.dye(slot) else {
+                    ui.label(format!("{}: {parts} parts, no dye", slot.name()));
+                    continue;
+                };
+
+                ui.collapsing(
+                    format!("{}: {parts} parts, dye {}", slot.name(), dye.hash),
+                    |ui| gear_dye_channel_ui(ui, slot, dye.parameters.channel(slot)),
+                );
+            }
         }
     }
 }
 
+fn gear_dye_channel_ui(ui: &mut egui::Ui, slot: GearDyeSlot, channel: &DyeChannel) {
+    let color = |c: [f32; 4]| Rgba::from_rgb(c[0], c[1], c[2]);
+    egui::Grid::new(("gear_dye_channel", slot)).show(ui, |ui| {
+        for (name, value) in [
+            ("Albedo tint", channel.albedo_tint),
+            ("Worn albedo tint", channel.worn_albedo_tint),
+            ("Emissive tint", channel.emissive_tint),
+        ] {
+            ui.strong(name);
+            egui::color_picker::show_color(ui, color(value), egui::vec2(40.0, 16.0));
+            ui.label(format!("{value:.3?}"));
+            ui.end_row();
+        }
+
+        for (name, value) in [
+            ("Material params", channel.material_params),
+            ("Advanced params", channel.material_advanced_params),
+            ("Roughness remap", channel.roughness_remap),
+            ("Wear remap", channel.wear_remap),
+            ("Worn roughness remap", channel.worn_roughness_remap),
+            ("Worn material params", channel.worn_material_params),
+        ] {
+            ui.strong(name);
+            ui.label("");
+            ui.label(format!("{value:.3?}"));
+            ui.end_row();
+        }
+    });
+}
+
 impl ComponentPanel for ShaderBallComponent {
     fn inspector_name() -> &'static str {
         "Shader Ball"
@@ -666,3 +821,131 @@ impl ComponentPanel for SRespawnPoint {
         });
     }
 }
+
+impl ComponentPanel for AmbientAudio {
+    fn inspector_name() -> &'static str {
+        "Ambient Audio"
+    }
+
+    fn inspector_icon() -> char {
+        ICON_SPEAKER
+    }
+
+    fn show_inspector_ui<'s>(
+        &mut self,
+        _: &'s mut Scene,
+        _: EntityRef<'s>,
+        ui: &mut Ui,
+        _: &AppResources,
+    ) {
+        ui.strong(format!("{} streams", self.streams().len()));
+        for stream in self.streams() {
+            ui.label(stream.to_string()).context_menu(|ui| {
+                if ui.button("Copy hash").clicked() {
+                    ui.output_mut(|o| o.copied_text = stream.to_string());
+                    ui.close_menu();
+                }
+            });
+        }
+    }
+}
+
+impl ComponentPanel for AtmosphereParameters {
+    fn inspector_name() -> &'static str {
+        "Atmosphere"
+    }
+
+    fn inspector_icon() -> char {
+        ICON_WEATHER_FOG
+    }
+
+    fn show_inspector_ui<'s>(
+        &mut self,
+        _: &'s mut Scene,
+        _: EntityRef<'s>,
+        ui: &mut Ui,
+        _: &AppResources,
+    ) {
+        egui::Grid::new("atmosphere_parameters")
+            .num_columns(2)
+            .striped(true)
+            .show(ui, |ui| {
+                for (name, value) in self.fields() {
+                    ui.strong(name);
+                    ui.label(value);
+                    ui.end_row();
+                }
+            });
+
+        ui.collapsing("Raw", |ui| {
+            for (i, word) in self.raw.iter().enumerate() {
+                ui.monospace(format!(
+                    "0x{:03X}: {word:08X} ({})",
+                    AtmosphereParameters::EXTERN_OFFSET + i * 4,
+                    f32::from_bits(*word)
+                ));
+            }
+        });
+    }
+}
+
+impl ComponentPanel for WaterSurface {
+    fn inspector_name() -> &'static str {
+        "Water Surface"
+    }
+
+    fn inspector_icon() -> char {
+        ICON_WAVES
+    }
+
+    fn show_inspector_ui<'s>(
+        &mut self,
+        _: &'s mut Scene,
+        _: EntityRef<'s>,
+        ui: &mut Ui,
+        _: &AppResources,
+    ) {
+        let p = &self.parameters;
+        egui::Grid::new("water_parameters")
+            .num_columns(2)
+            .striped(true)
+            .show(ui, |ui| {
+                ui.strong("Model");
+                ui.label(p.model.to_string());
+                ui.end_row();
+
+                ui.strong("Bounds");
+                ui.label(format!("{:.1?} - {:.1?}", p.bounds_min, p.bounds_max));
+                ui.end_row();
+            });
+
+        for (name, cbuffer) in [
+            ("Vertex constants", &p.cbuffer_vertex),
+            ("Pixel constants", &p.cbuffer_pixel),
+        ] {
+            ui.collapsing(format!("{name} ({})", cbuffer.len()), |ui| {
+                for (i, v) in cbuffer.iter().enumerate() {
+                    ui.monospace(format!("cb{i}: {v:.3?}"));
+                }
+            });
+        }
+
+        ui.collapsing(format!("Techniques ({})", p.techniques.len()), |ui| {
+            for t in &p.techniques {
+                ui.monospace(format!("{} {}", t.technique, t.stage));
+            }
+        });
+
+        ui.collapsing(
+            format!("Reflection probes ({})", p.reflection_probes.len()),
+            |ui| {
+                for probe in &p.reflection_probes {
+                    ui.monospace(format!(
+                        "{} (t{} of {})",
+                        probe.texture, probe.slot, probe.technique
+                    ));
+                }
+            },
+        );
+    }
+}
@@ -0,0 +1,102 @@
+use alkahest_data::{dependencies::tag_kind, map::SBubbleParent, text::StringContainerShared};
+use alkahest_renderer::ecs::{
+    render::static_geometry::{StaticInstances, StaticModelSingle},
+    Scene,
+};
+use bevy_ecs::prelude::EntityRef;
+use destiny_pkg::TagHash;
+use tiger_parse::TigerReadable;
+
+use crate::{
+    gui::{activity_select::get_map_name, inspector::ComponentPanel},
+    resources::AppResources,
+    util::reverse_index::reverse_index,
+};
+
+/// Lists the tags and maps that reference `hash`, using the reverse reference index
+pub fn used_by_ui(ui: &mut egui::Ui, hash: TagHash, resources: &AppResources) {
+    egui::CollapsingHeader::new("Used by")
+        .id_source(("used_by", hash.0))
+        .show(ui, |ui| {
+            let Some(index) = reverse_index() else {
+                ui.horizontal(|ui| {
+                    ui.spinner();
+                    ui.label("Building reverse reference index...");
+                });
+                return;
+            };
+
+            let users = index.users(hash);
+            if users.is_empty() {
+                ui.weak("No known users");
+            }
+
+            for user in users {
+                copyable_tag_label(ui, format!("{} {user}", tag_kind(*user)), *user);
+            }
+
+            let maps = index.users_of_class(hash, SBubbleParent::ID.unwrap());
+            if !maps.is_empty() {
+                ui.separator();
+                ui.strong(format!("Placed in {} maps", maps.len()));
+                let stringmap = resources.get::<StringContainerShared>();
+                for map in maps {
+                    let name = get_map_name(map, &stringmap).unwrap_or_default();
+                    copyable_tag_label(ui, format!("{name} ({map})"), map);
+                }
+            }
+        });
+}
+
+fn copyable_tag_label(ui: &mut egui::Ui, text: String, hash: TagHash) {
+    ui.label(text).context_menu(|ui| {
+        if ui.button("Copy hash").clicked() {
+            ui.output_mut(|o| o.copied_text = hash.to_string());
+            ui.close_menu();
+        }
+    });
+}
+
+impl ComponentPanel for StaticModelSingle {
+    fn inspector_name() -> &'static str {
+        "Static Model"
+    }
+
+    fn show_inspector_ui(
+        &mut self,
+        _: &mut Scene,
+        _: EntityRef<'_>,
+        ui: &mut egui::Ui,
+        resources: &AppResources,
+    ) {
+        ui.horizontal(|ui| {
+            ui.strong("Hash:");
+            ui.label(self.model.hash.to_string());
+        });
+        used_by_ui(ui, self.model.hash, resources);
+    }
+}
+
+impl ComponentPanel for StaticInstances {
+    fn inspector_name() -> &'static str {
+        "Static Instances"
+    }
+
+    fn show_inspector_ui(
+        &mut self,
+        _: &mut Scene,
+        _: EntityRef<'_>,
+        ui: &mut egui::Ui,
+        resources: &AppResources,
+    ) {
+        ui.horizontal(|ui| {
+            ui.strong("Hash:");
+            ui.label(self.model.hash.to_string());
+        });
+        ui.horizontal(|ui| {
+            ui.strong("Instances:");
+            ui.label(self.instance_count.to_string());
+        });
+        used_by_ui(ui, self.model.hash, resources);
+    }
+}
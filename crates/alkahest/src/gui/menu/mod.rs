@@ -69,6 +69,12 @@ impl GuiView for MenuBar {
                     windows.tfx_extern_editor ^= ui
                         .selectable_label(windows.tfx_extern_editor, "TFX Extern Editor")
                         .clicked();
+                    windows.activity_phases ^= ui
+                        .selectable_label(windows.activity_phases, "Activity Phases")
+                        .clicked();
+                    windows.tag_references ^= ui
+                        .selectable_label(windows.tag_references, "Tag References")
+                        .clicked();
 
                     if cfg!(feature = "profiler") {
                         windows.cpu_profiler ^= ui
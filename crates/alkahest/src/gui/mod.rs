@@ -1,8 +1,10 @@
 use egui::Response;
 
+mod activity_phases;
 pub mod activity_select;
 mod configuration;
 pub mod context;
+mod dependencies;
 mod fps_display;
 pub mod hotkeys;
 pub use alkahest_renderer::icons;
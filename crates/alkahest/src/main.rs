@@ -26,6 +26,8 @@ use winit::event_loop::EventLoop;
 use crate::gui::console::ConsoleLogLayer;
 
 mod app;
+mod audio;
+mod cli;
 mod config;
 mod game_selector;
 mod gui;
@@ -65,8 +67,10 @@ struct ApplicationArgs {
     low_res: bool,
 
     #[arg(long)]
-
     fullscreen: bool,
+
+    #[command(subcommand)]
+    command: Option<cli::CliCommand>,
 }
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
@@ -154,6 +158,10 @@ async fn main() -> anyhow::Result<()> {
     let mut event_loop = EventLoop::new()?;
     initialize_package_manager(&args, &mut event_loop, &icon)?;
 
+    if let Some(command) = &args.command {
+        return cli::run(command);
+    }
+
     // extract_tfx_externs()?;
 
     tokio::spawn(discord::discord_client_loop());
@@ -1,6 +1,7 @@
 use alkahest_data::text::StringContainerShared;
 use alkahest_renderer::{
     ecs::{
+        animation::update_entity_animation_system,
         common::Global,
         render::{
             dynamic_geometry::update_dynamic_model_system, light::update_shadowrenderer_system,
@@ -17,7 +18,7 @@ use alkahest_renderer::{
 use bevy_ecs::{
     entity::Entity,
     query::With,
-    schedule::{ExecutorKind, Schedule, ScheduleLabel},
+    schedule::{ExecutorKind, IntoSystemConfigs, Schedule, ScheduleLabel},
     system::Commands,
     world::CommandQueue,
 };
@@ -65,7 +66,11 @@ impl Systems {
         let mut schedule_pre = Schedule::new(PreUpdate);
 
         schedule_pre
-            .add_systems((update_static_instances_system, update_dynamic_model_system))
+            .add_systems((
+                update_entity_animation_system.before(update_dynamic_model_system),
+                update_static_instances_system,
+                update_dynamic_model_system,
+            ))
             .set_executor_kind(ExecutorKind::SingleThreaded)
             .initialize(world)
             .unwrap();
@@ -5,6 +5,7 @@ pub mod error;
 // pub mod export;
 pub mod action;
 pub mod image;
+pub mod reverse_index;
 pub mod text;
 
 pub use parking_lot::RwLock;
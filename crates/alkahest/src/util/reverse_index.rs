@@ -0,0 +1,47 @@
+use std::{
+    path::PathBuf,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
+
+use alkahest_data::reverse_index::ReverseIndex;
+use lazy_static::lazy_static;
+
+use crate::{config::APP_DIRS, util::RwLock};
+
+lazy_static! {
+    static ref REVERSE_INDEX: RwLock<Option<Arc<ReverseIndex>>> = RwLock::new(None);
+}
+static BUILD_STARTED: AtomicBool = AtomicBool::new(false);
+
+pub fn cache_path() -> PathBuf {
+    let dir = APP_DIRS.cache_dir();
+    std::fs::create_dir_all(dir).ok();
+    dir.join("reverse_index.bin")
+}
+
+/// Returns the reverse reference index, or `None` if it's still being built.
+/// The first call starts loading/building the index on a background thread
+pub fn reverse_index() -> Option<Arc<ReverseIndex>> {
+    if let Some(index) = REVERSE_INDEX.read().as_ref() {
+        return Some(index.clone());
+    }
+
+    if !BUILD_STARTED.swap(true, Ordering::Relaxed) {
+        std::thread::Builder::new()
+            .name("reverse-index".to_string())
+            .spawn(|| {
+                let index = ReverseIndex::load_or_build(&cache_path());
+                info!(
+                    "Reverse reference index ready ({} referenced tags)",
+                    index.len()
+                );
+                *REVERSE_INDEX.write() = Some(Arc::new(index));
+            })
+            .expect("Failed to spawn reverse index thread");
+    }
+
+    None
+}
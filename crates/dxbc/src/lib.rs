@@ -0,0 +1,187 @@
+//! Platform independent parser for DXBC shader containers, as produced by fxc
+//!
+//! Reads the resource definitions (`RDEF`), input/output signatures (`ISGN`/`OSGN` and their
+//! variants) and the shader program (`SHEX`/`SHDR`), and produces a textual disassembly resembling
+//! fxc's. This covers what `D3DReflect` and `D3DDisassemble` would otherwise be needed for.
+
+mod reader;
+
+pub mod rdef;
+pub mod shex;
+pub mod signature;
+
+use std::fmt::Write;
+
+use anyhow::ensure;
+pub use rdef::ResourceDefinitions;
+use reader::Reader;
+pub use shex::{Program, ProgramType, ShaderVersion};
+pub use signature::Signature;
+
+pub const DXBC_MAGIC: [u8; 4] = *b"DXBC";
+
+pub struct DxbcChunk<'a> {
+    pub fourcc: [u8; 4],
+    pub data: &'a [u8],
+}
+
+impl DxbcChunk<'_> {
+    pub fn name(&self) -> String {
+        String::from_utf8_lossy(&self.fourcc).into_owned()
+    }
+}
+
+pub struct Dxbc<'a> {
+    pub checksum: [u8; 16],
+    pub chunks: Vec<DxbcChunk<'a>>,
+}
+
+impl<'a> Dxbc<'a> {
+    pub fn parse(data: &'a [u8]) -> anyhow::Result<Self> {
+        let mut reader = Reader::new(data);
+        ensure!(reader.bytes(4)? == DXBC_MAGIC, "Not a DXBC container");
+
+        let checksum = reader.bytes(16)?.try_into().unwrap();
+        let _version = reader.u32()?;
+        let total_size = reader.u32()? as usize;
+        ensure!(
+            total_size <= data.len(),
+            "Container is truncated ({} bytes, header says {total_size})",
+            data.len()
+        );
+
+        let chunk_count = reader.u32()?;
+        let mut chunks = Vec::with_capacity(chunk_count as usize);
+        for _ in 0..chunk_count {
+            let offset = reader.u32()? as usize;
+            let mut chunk_reader = Reader::at(data, offset);
+            let fourcc = chunk_reader.bytes(4)?.try_into().unwrap();
+            let size = chunk_reader.u32()? as usize;
+            chunks.push(DxbcChunk {
+                fourcc,
+                data: chunk_reader.bytes(size)?,
+            });
+        }
+
+        Ok(Self { checksum, chunks })
+    }
+
+    pub fn chunk(&self, fourcc: &[u8; 4]) -> Option<&'a [u8]> {
+        self.chunks
+            .iter()
+            .find(|c| &c.fourcc == fourcc)
+            .map(|c| c.data)
+    }
+
+    fn first_chunk(&self, fourccs: &[&[u8; 4]]) -> Option<(&'a [u8], [u8; 4])> {
+        fourccs
+            .iter()
+            .find_map(|f| self.chunk(f).map(|data| (data, **f)))
+    }
+
+    pub fn resource_definitions(&self) -> anyhow::Result<Option<ResourceDefinitions>> {
+        self.chunk(b"RDEF")
+            .map(ResourceDefinitions::parse)
+            .transpose()
+    }
+
+    pub fn input_signature(&self) -> anyhow::Result<Option<Signature>> {
+        self.first_chunk(&[b"ISGN", b"ISG1"])
+            .map(|(data, fourcc)| Signature::parse(data, fourcc))
+            .transpose()
+    }
+
+    pub fn output_signature(&self) -> anyhow::Result<Option<Signature>> {
+        self.first_chunk(&[b"OSGN", b"OSG5", b"OSG1"])
+            .map(|(data, fourcc)| Signature::parse(data, fourcc))
+            .transpose()
+    }
+
+    /// Patch constant signature of hull and domain shaders
+    pub fn patch_constant_signature(&self) -> anyhow::Result<Option<Signature>> {
+        self.first_chunk(&[b"PCSG", b"PSG1"])
+            .map(|(data, fourcc)| Signature::parse(data, fourcc))
+            .transpose()
+    }
+
+    pub fn program(&self) -> anyhow::Result<Option<Program>> {
+        self.first_chunk(&[b"SHEX", b"SHDR"])
+            .map(|(data, _)| Program::parse(data))
+            .transpose()
+    }
+
+    /// Reflection as comments, followed by the program disassembly
+    pub fn disassemble(&self) -> anyhow::Result<String> {
+        let mut out = String::new();
+        if let Some(rdef) = self.resource_definitions()? {
+            write!(out, "{rdef}").unwrap();
+        }
+
+        for (name, signature) in [
+            ("Input signature", self.input_signature()?),
+            ("Output signature", self.output_signature()?),
+            ("Patch Constant signature", self.patch_constant_signature()?),
+        ] {
+            if let Some(signature) = signature {
+                writeln!(out, "//\n// {name}:\n//").unwrap();
+                write!(out, "{signature}").unwrap();
+            }
+        }
+
+        if let Some(program) = self.program()? {
+            writeln!(out, "//").unwrap();
+            out.push_str(&program.disassemble());
+        }
+
+        Ok(out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    pub(crate) fn push_u32(data: &mut Vec<u8>, values: &[u32]) {
+        for v in values {
+            data.extend_from_slice(&v.to_le_bytes());
+        }
+    }
+
+    pub(crate) fn build_container(chunks: &[(&[u8; 4], Vec<u8>)]) -> Vec<u8> {
+        let header_size = 32 + chunks.len() * 4;
+        let mut offsets = vec![];
+        let mut body = vec![];
+        for (fourcc, data) in chunks {
+            offsets.push((header_size + body.len()) as u32);
+            body.extend_from_slice(*fourcc);
+            push_u32(&mut body, &[data.len() as u32]);
+            body.extend_from_slice(data);
+        }
+
+        let mut out = DXBC_MAGIC.to_vec();
+        out.extend_from_slice(&[0; 16]);
+        push_u32(
+            &mut out,
+            &[1, (header_size + body.len()) as u32, chunks.len() as u32],
+        );
+        push_u32(&mut out, &offsets);
+        out.extend_from_slice(&body);
+        out
+    }
+
+    #[test]
+    fn parse_container() {
+        let data = build_container(&[(b"SFI0", vec![0; 8]), (b"STAT", vec![1, 2, 3, 4])]);
+        let dxbc = Dxbc::parse(&data).unwrap();
+
+        assert_eq!(dxbc.chunks.len(), 2);
+        assert_eq!(dxbc.chunks[0].name(), "SFI0");
+        assert_eq!(dxbc.chunk(b"STAT"), Some(&[1u8, 2, 3, 4][..]));
+        assert!(dxbc.program().unwrap().is_none());
+    }
+
+    #[test]
+    fn reject_invalid_magic() {
+        assert!(Dxbc::parse(b"DXBD").is_err());
+    }
+}
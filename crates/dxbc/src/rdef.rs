@@ -0,0 +1,459 @@
+use std::fmt::Display;
+
+use anyhow::ensure;
+
+use crate::{
+    reader::{read_cstring, Reader},
+    shex::ProgramType,
+};
+
+/// Variable is referenced by the program (`D3D_SVF_USED`)
+pub const VARIABLE_FLAG_USED: u32 = 0x2;
+
+#[derive(Debug, Clone)]
+pub struct ResourceDefinitions {
+    pub program_type: ProgramType,
+    pub major: u8,
+    pub minor: u8,
+    pub flags: u32,
+    pub creator: String,
+    pub constant_buffers: Vec<ConstantBuffer>,
+    pub bindings: Vec<ResourceBinding>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ConstantBuffer {
+    pub name: String,
+    /// `D3D_CBUFFER_TYPE`
+    pub kind: u32,
+    pub size: u32,
+    pub flags: u32,
+    pub variables: Vec<Variable>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Variable {
+    pub name: String,
+    pub offset: u32,
+    pub size: u32,
+    pub flags: u32,
+    pub ty: VariableType,
+}
+
+impl Variable {
+    pub fn is_used(&self) -> bool {
+        self.flags & VARIABLE_FLAG_USED != 0
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct VariableType {
+    /// `D3D_SHADER_VARIABLE_CLASS`
+    pub class: u16,
+    /// `D3D_SHADER_VARIABLE_TYPE`
+    pub kind: u16,
+    pub rows: u16,
+    pub columns: u16,
+    pub elements: u16,
+    pub members: Vec<(String, u32, VariableType)>,
+    /// Only present in shader model 5 and up
+    pub name: Option<String>,
+}
+
+impl VariableType {
+    /// HLSL style type name, such as `float4x4` or `uint2[3]`
+    pub fn type_name(&self) -> String {
+        let base = match self.kind {
+            0 => "void",
+            1 => "bool",
+            2 => "int",
+            3 => "float",
+            4 => "string",
+            5..=9 => "texture",
+            10 => "sampler",
+            19 => "uint",
+            20 => "uint8",
+            39 => "double",
+            57 => "min8float",
+            58 => "min10float",
+            59 => "min16float",
+            60 => "min12int",
+            61 => "min16int",
+            62 => "min16uint",
+            _ => "unknown",
+        };
+
+        let mut name = match self.class {
+            // Scalar
+            0 => base.to_string(),
+            // Vector
+            1 => format!("{base}{}", self.columns),
+            // Row and column major matrices
+            2 | 3 => format!("{base}{}x{}", self.rows, self.columns),
+            // Struct
+            5 => self.name.clone().unwrap_or_else(|| "struct".to_string()),
+            _ => self.name.clone().unwrap_or_else(|| base.to_string()),
+        };
+
+        if self.elements > 0 {
+            name.push_str(&format!("[{}]", self.elements));
+        }
+
+        name
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ResourceBinding {
+    pub name: String,
+    /// `D3D_SHADER_INPUT_TYPE`
+    pub input_type: u32,
+    /// `D3D_RESOURCE_RETURN_TYPE`
+    pub return_type: u32,
+    /// `D3D_SRV_DIMENSION`
+    pub dimension: u32,
+    pub sample_count: u32,
+    pub bind_point: u32,
+    pub bind_count: u32,
+    pub flags: u32,
+    /// Register space, only present in shader model 5.1
+    pub space: u32,
+}
+
+impl ResourceBinding {
+    pub fn input_type_name(&self) -> &'static str {
+        match self.input_type {
+            0 => "cbuffer",
+            1 => "tbuffer",
+            2 => "texture",
+            3 => "sampler",
+            4 => "UAV",
+            5 => "structured",
+            6 => "UAV structured",
+            7 => "byteaddress",
+            8 => "UAV byteaddress",
+            9 => "append",
+            10 => "consume",
+            11 => "UAV structured counter",
+            _ => "unknown",
+        }
+    }
+
+    pub fn dimension_name(&self) -> &'static str {
+        match self.dimension {
+            1 => "buf",
+            2 => "1d",
+            3 => "1darray",
+            4 => "2d",
+            5 => "2darray",
+            6 => "2dMS",
+            7 => "2dMSarray",
+            8 => "3d",
+            9 => "cube",
+            10 => "cubearray",
+            11 => "bufex",
+            _ => "NA",
+        }
+    }
+
+    /// Register name as used in the disassembly, such as `t3` or `cb12`
+    pub fn register(&self) -> String {
+        let prefix = match self.input_type {
+            0 => "cb",
+            3 => "s",
+            4 | 6 | 8 | 9 | 10 | 11 => "u",
+            _ => "t",
+        };
+
+        format!("{prefix}{}", self.bind_point)
+    }
+}
+
+impl ResourceDefinitions {
+    pub fn parse(data: &[u8]) -> anyhow::Result<Self> {
+        let mut reader = Reader::new(data);
+        let cbuffer_count = reader.u32()?;
+        let cbuffer_offset = reader.u32()?;
+        let binding_count = reader.u32()?;
+        let binding_offset = reader.u32()?;
+        let minor = reader.u8()?;
+        let major = reader.u8()?;
+        let program_type = ProgramType::from_rdef(reader.u16()?);
+        let flags = reader.u32()?;
+        let creator = read_cstring(data, reader.u32()?)?;
+
+        // Shader model 5 adds a header describing the size of every descriptor
+        let mut variable_size = 24;
+        let mut binding_size = 32;
+        let mut type_has_name = false;
+        if major >= 5 {
+            let _magic = reader.u32()?;
+            let _header_size = reader.u32()?;
+            let _cbuffer_size = reader.u32()?;
+            variable_size = reader.u32()? as usize;
+            let _type_size = reader.u32()?;
+            let _member_size = reader.u32()?;
+            binding_size = reader.u32()? as usize;
+            type_has_name = true;
+
+            ensure!(
+                variable_size >= 24 && binding_size >= 32,
+                "Unexpected descriptor sizes (variable {variable_size}, binding {binding_size})"
+            );
+        }
+
+        let mut bindings = Vec::with_capacity(binding_count as usize);
+        for i in 0..binding_count as usize {
+            let mut reader = Reader::at(data, binding_offset as usize + i * binding_size);
+            let name = read_cstring(data, reader.u32()?)?;
+            let input_type = reader.u32()?;
+            let return_type = reader.u32()?;
+            let dimension = reader.u32()?;
+            let sample_count = reader.u32()?;
+            let bind_point = reader.u32()?;
+            let bind_count = reader.u32()?;
+            let flags = reader.u32()?;
+            let space = if binding_size >= 40 { reader.u32()? } else { 0 };
+
+            bindings.push(ResourceBinding {
+                name,
+                input_type,
+                return_type,
+                dimension,
+                sample_count,
+                bind_point,
+                bind_count,
+                flags,
+                space,
+            });
+        }
+
+        let mut constant_buffers = Vec::with_capacity(cbuffer_count as usize);
+        for i in 0..cbuffer_count as usize {
+            let mut reader = Reader::at(data, cbuffer_offset as usize + i * 24);
+            let name = read_cstring(data, reader.u32()?)?;
+            let variable_count = reader.u32()?;
+            let variable_offset = reader.u32()?;
+            let size = reader.u32()?;
+            let flags = reader.u32()?;
+            let kind = reader.u32()?;
+
+            let mut variables = Vec::with_capacity(variable_count as usize);
+            for v in 0..variable_count as usize {
+                let mut reader = Reader::at(data, variable_offset as usize + v * variable_size);
+                let name = read_cstring(data, reader.u32()?)?;
+                let offset = reader.u32()?;
+                let size = reader.u32()?;
+                let flags = reader.u32()?;
+                let type_offset = reader.u32()?;
+
+                variables.push(Variable {
+                    name,
+                    offset,
+                    size,
+                    flags,
+                    ty: read_type(data, type_offset, type_has_name, 0)?,
+                });
+            }
+
+            constant_buffers.push(ConstantBuffer {
+                name,
+                kind,
+                size,
+                flags,
+                variables,
+            });
+        }
+
+        Ok(Self {
+            program_type,
+            major,
+            minor,
+            flags,
+            creator,
+            constant_buffers,
+            bindings,
+        })
+    }
+
+    /// Constant buffer bound to register `b{slot}`, along with its layout when there is one
+    pub fn constant_buffer_at(
+        &self,
+        slot: u32,
+    ) -> Option<(&ResourceBinding, Option<&ConstantBuffer>)> {
+        let binding = self.bindings.iter().find(|b| {
+            b.input_type == 0 && slot >= b.bind_point && slot < b.bind_point + b.bind_count.max(1)
+        })?;
+
+        Some((
+            binding,
+            self.constant_buffers
+                .iter()
+                .find(|c| c.name == binding.name),
+        ))
+    }
+}
+
+fn read_type(
+    data: &[u8],
+    offset: u32,
+    has_name: bool,
+    depth: usize,
+) -> anyhow::Result<VariableType> {
+    ensure!(depth < 32, "Type nesting is too deep");
+
+    let mut reader = Reader::at(data, offset as usize);
+    let class = reader.u16()?;
+    let kind = reader.u16()?;
+    let rows = reader.u16()?;
+    let columns = reader.u16()?;
+    let elements = reader.u16()?;
+    let member_count = reader.u16()?;
+    let member_offset = reader.u32()?;
+
+    let name = if has_name {
+        reader.skip(16)?;
+        match reader.u32()? {
+            0 => None,
+            name_offset => Some(read_cstring(data, name_offset)?),
+        }
+    } else {
+        None
+    };
+
+    let mut members = Vec::with_capacity(member_count as usize);
+    for i in 0..member_count as usize {
+        let mut reader = Reader::at(data, member_offset as usize + i * 12);
+        let name = read_cstring(data, reader.u32()?)?;
+        let type_offset = reader.u32()?;
+        let offset = reader.u32()?;
+        members.push((
+            name,
+            offset,
+            read_type(data, type_offset, has_name, depth + 1)?,
+        ));
+    }
+
+    Ok(VariableType {
+        class,
+        kind,
+        rows,
+        columns,
+        elements,
+        members,
+        name,
+    })
+}
+
+impl Display for ResourceDefinitions {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "// Generated by {}", self.creator)?;
+        if !self.constant_buffers.is_empty() {
+            writeln!(f, "//\n// Buffer Definitions:\n//")?;
+        }
+
+        for cbuffer in &self.constant_buffers {
+            let keyword = match cbuffer.kind {
+                1 => "tbuffer",
+                3 => "Resource bind info for",
+                _ => "cbuffer",
+            };
+            writeln!(f, "// {keyword} {}\n// {{\n//", cbuffer.name)?;
+            for v in &cbuffer.variables {
+                writeln!(
+                    f,
+                    "//   {:<36} // Offset: {:>5} Size: {:>5}{}",
+                    format!("{} {};", v.ty.type_name(), v.name),
+                    v.offset,
+                    v.size,
+                    if v.is_used() { "" } else { " [unused]" }
+                )?;
+            }
+            writeln!(f, "//\n// }}\n//")?;
+        }
+
+        if !self.bindings.is_empty() {
+            writeln!(f, "//\n// Resource Bindings:\n//")?;
+            writeln!(
+                f,
+                "// {:<30} {:<16} {:<6} {:<9} {:>6} {:>5}",
+                "Name", "Type", "Dim", "Slot", "Space", "Count"
+            )?;
+            writeln!(
+                f,
+                "// {:-<30} {:-<16} {:-<6} {:-<9} {:->6} {:->5}",
+                "", "", "", "", "", ""
+            )?;
+            for b in &self.bindings {
+                writeln!(
+                    f,
+                    "// {:<30} {:<16} {:<6} {:<9} {:>6} {:>5}",
+                    b.name,
+                    b.input_type_name(),
+                    b.dimension_name(),
+                    b.register(),
+                    b.space,
+                    b.bind_count
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        tests::{build_container, push_u32},
+        Dxbc,
+    };
+
+    #[test]
+    fn parse_resource_definitions() {
+        let mut rdef = vec![];
+        // Header, vs_5_0
+        push_u32(&mut rdef, &[1, 60, 1, 84, 0x500 | (0xFFFE << 16), 0, 214]);
+        // RD11
+        push_u32(&mut rdef, &[0x31314452, 60, 24, 40, 36, 12, 32, 0]);
+        // cbuffer cb_test: 1 variable at 116, 64 bytes
+        push_u32(&mut rdef, &[192, 1, 116, 64, 0, 0]);
+        // Binding cb_test at b2
+        push_u32(&mut rdef, &[192, 0, 0, 0, 0, 2, 1, 1]);
+        // float4 values[4], used
+        push_u32(
+            &mut rdef,
+            &[200, 0, 64, 2, 156, 0, u32::MAX, 0, u32::MAX, 0],
+        );
+        // Type: vector float, 1x4, 4 elements
+        rdef.extend_from_slice(&1u16.to_le_bytes());
+        rdef.extend_from_slice(&3u16.to_le_bytes());
+        rdef.extend_from_slice(&1u16.to_le_bytes());
+        rdef.extend_from_slice(&4u16.to_le_bytes());
+        rdef.extend_from_slice(&4u16.to_le_bytes());
+        rdef.extend_from_slice(&0u16.to_le_bytes());
+        push_u32(&mut rdef, &[0, 0, 0, 0, 0, 207]);
+        assert_eq!(rdef.len(), 192);
+        rdef.extend_from_slice(b"cb_test\0values\0float4\0creator\0");
+
+        let data = build_container(&[(b"RDEF", rdef)]);
+        let rdef = Dxbc::parse(&data)
+            .unwrap()
+            .resource_definitions()
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(rdef.program_type, ProgramType::Vertex);
+        assert_eq!(rdef.creator, "creator");
+
+        let (binding, cbuffer) = rdef.constant_buffer_at(2).unwrap();
+        assert_eq!(binding.register(), "cb2");
+        let cbuffer = cbuffer.unwrap();
+        assert_eq!(cbuffer.size, 64);
+        assert_eq!(cbuffer.variables[0].name, "values");
+        assert_eq!(cbuffer.variables[0].ty.type_name(), "float4[4]");
+        assert!(cbuffer.variables[0].is_used());
+        assert!(rdef.constant_buffer_at(0).is_none());
+    }
+}
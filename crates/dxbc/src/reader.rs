@@ -0,0 +1,55 @@
+use anyhow::Context;
+
+/// Little endian cursor over a chunk
+pub(crate) struct Reader<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    pub fn new(data: &'a [u8]) -> Self {
+        Self { data, pos: 0 }
+    }
+
+    pub fn at(data: &'a [u8], pos: usize) -> Self {
+        Self { data, pos }
+    }
+
+    pub fn bytes(&mut self, count: usize) -> anyhow::Result<&'a [u8]> {
+        let bytes = self
+            .data
+            .get(self.pos..self.pos + count)
+            .with_context(|| format!("Unexpected end of data at offset 0x{:X}", self.pos))?;
+        self.pos += count;
+        Ok(bytes)
+    }
+
+    pub fn u8(&mut self) -> anyhow::Result<u8> {
+        Ok(self.bytes(1)?[0])
+    }
+
+    pub fn u16(&mut self) -> anyhow::Result<u16> {
+        Ok(u16::from_le_bytes(self.bytes(2)?.try_into().unwrap()))
+    }
+
+    pub fn u32(&mut self) -> anyhow::Result<u32> {
+        Ok(u32::from_le_bytes(self.bytes(4)?.try_into().unwrap()))
+    }
+
+    pub fn skip(&mut self, count: usize) -> anyhow::Result<()> {
+        self.bytes(count).map(|_| ())
+    }
+}
+
+/// Reads a null-terminated string at `offset`
+pub(crate) fn read_cstring(data: &[u8], offset: u32) -> anyhow::Result<String> {
+    let bytes = data
+        .get(offset as usize..)
+        .with_context(|| format!("String offset 0x{offset:X} is out of bounds"))?;
+    let end = bytes
+        .iter()
+        .position(|&b| b == 0)
+        .context("Unterminated string")?;
+
+    Ok(String::from_utf8_lossy(&bytes[..end]).into_owned())
+}
@@ -0,0 +1,1049 @@
+use std::fmt::{Display, Write};
+
+use anyhow::ensure;
+
+use crate::{reader::Reader, signature::mask_string};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProgramType {
+    Pixel,
+    Vertex,
+    Geometry,
+    Hull,
+    Domain,
+    Compute,
+    Unknown(u16),
+}
+
+impl ProgramType {
+    pub fn from_shex(value: u16) -> Self {
+        match value {
+            0 => Self::Pixel,
+            1 => Self::Vertex,
+            2 => Self::Geometry,
+            3 => Self::Hull,
+            4 => Self::Domain,
+            5 => Self::Compute,
+            u => Self::Unknown(u),
+        }
+    }
+
+    /// The `RDEF` header uses the shader model 3 style version tags
+    pub fn from_rdef(value: u16) -> Self {
+        match value {
+            0xFFFF => Self::Pixel,
+            0xFFFE => Self::Vertex,
+            0x4753 => Self::Geometry,
+            0x4853 => Self::Hull,
+            0x4453 => Self::Domain,
+            0x4353 => Self::Compute,
+            u => Self::Unknown(u),
+        }
+    }
+
+    pub fn prefix(&self) -> &'static str {
+        match self {
+            Self::Pixel => "ps",
+            Self::Vertex => "vs",
+            Self::Geometry => "gs",
+            Self::Hull => "hs",
+            Self::Domain => "ds",
+            Self::Compute => "cs",
+            Self::Unknown(_) => "xs",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ShaderVersion {
+    pub program_type: ProgramType,
+    pub major: u8,
+    pub minor: u8,
+}
+
+impl Display for ShaderVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}_{}_{}",
+            self.program_type.prefix(),
+            self.major,
+            self.minor
+        )
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Instruction {
+    /// Offset in dwords from the start of the program
+    pub offset: usize,
+    pub tokens: Vec<u32>,
+}
+
+impl Instruction {
+    pub fn opcode(&self) -> u32 {
+        self.tokens[0] & 0x7FF
+    }
+
+    pub fn name(&self) -> &'static str {
+        OPCODE_NAMES
+            .get(self.opcode() as usize)
+            .copied()
+            .unwrap_or("unknown")
+    }
+
+    pub fn is_declaration(&self) -> bool {
+        matches!(self.opcode(), 88..=106 | 143..=162 | 206)
+            || (self.opcode() == OPCODE_CUSTOMDATA && self.tokens[0] >> 11 == 3)
+    }
+
+    /// Opcode specific control bits
+    fn controls(&self) -> u32 {
+        (self.tokens[0] >> 11) & 0x1FFF
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Program {
+    pub version: ShaderVersion,
+    pub instructions: Vec<Instruction>,
+}
+
+const OPCODE_CUSTOMDATA: u32 = 53;
+
+impl Program {
+    /// Parses the token stream of a `SHEX` or `SHDR` chunk
+    pub fn parse(data: &[u8]) -> anyhow::Result<Self> {
+        let mut reader = Reader::new(data);
+        let version = reader.u32()?;
+        let length = reader.u32()? as usize;
+        ensure!(
+            length * 4 <= data.len(),
+            "Program is truncated ({} bytes, header says {} dwords)",
+            data.len(),
+            length
+        );
+
+        let version = ShaderVersion {
+            program_type: ProgramType::from_shex((version >> 16) as u16),
+            major: ((version >> 4) & 0xF) as u8,
+            minor: (version & 0xF) as u8,
+        };
+
+        let mut offset = 2;
+        let mut instructions = vec![];
+        while offset < length {
+            let token = reader.u32()?;
+            let instruction_length = if token & 0x7FF == OPCODE_CUSTOMDATA {
+                reader.u32()? as usize
+            } else {
+                ((token >> 24) & 0x7F) as usize
+            };
+            ensure!(
+                instruction_length > 0 && offset + instruction_length <= length,
+                "Invalid instruction length {instruction_length} at dword {offset}"
+            );
+
+            let mut tokens = vec![token];
+            let already_read = if token & 0x7FF == OPCODE_CUSTOMDATA {
+                tokens.push(instruction_length as u32);
+                2
+            } else {
+                1
+            };
+            for _ in already_read..instruction_length {
+                tokens.push(reader.u32()?);
+            }
+
+            instructions.push(Instruction { offset, tokens });
+            offset += instruction_length;
+        }
+
+        Ok(Self {
+            version,
+            instructions,
+        })
+    }
+
+    pub fn disassemble(&self) -> String {
+        let mut out = format!("{}\n", self.version);
+        let mut indent = 0usize;
+        for instruction in &self.instructions {
+            if matches!(instruction.opcode(), 18 | 21 | 22 | 23) {
+                indent = indent.saturating_sub(1);
+            }
+
+            let text = format_instruction(instruction).unwrap_or_else(|| {
+                format!(
+                    "// {} {}",
+                    instruction.name(),
+                    instruction
+                        .tokens
+                        .iter()
+                        .map(|t| format!("{t:08X}"))
+                        .collect::<Vec<_>>()
+                        .join(" ")
+                )
+            });
+            writeln!(out, "{}{text}", "  ".repeat(indent)).unwrap();
+
+            // if, else, loop, switch
+            if matches!(instruction.opcode(), 31 | 18 | 48 | 76) {
+                indent += 1;
+            }
+        }
+
+        writeln!(
+            out,
+            "// Approximately {} instruction slots used",
+            self.instructions
+                .iter()
+                .filter(|i| !i.is_declaration() && i.opcode() != OPCODE_CUSTOMDATA)
+                .count()
+        )
+        .unwrap();
+
+        out
+    }
+}
+
+struct Tokens<'a> {
+    tokens: &'a [u32],
+    pos: usize,
+}
+
+impl Tokens<'_> {
+    fn next(&mut self) -> Option<u32> {
+        let token = self.tokens.get(self.pos).copied();
+        self.pos += 1;
+        token
+    }
+
+    fn is_empty(&self) -> bool {
+        self.pos >= self.tokens.len()
+    }
+
+    fn operand(&mut self) -> Option<String> {
+        self.operand_inner(true)
+    }
+
+    /// Operand without its component selection, as printed in some declarations
+    fn operand_register(&mut self) -> Option<String> {
+        self.operand_inner(false)
+    }
+
+    fn operand_inner(&mut self, with_components: bool) -> Option<String> {
+        let token = self.next()?;
+        let component_count = token & 0x3;
+        let selection_mode = (token >> 2) & 0x3;
+        let operand_type = (token >> 12) & 0xFF;
+        let index_dimension = (token >> 20) & 0x3;
+
+        let mut modifier = 0;
+        if token & 0x8000_0000 != 0 {
+            let extended = self.next()?;
+            if extended & 0x3F == 1 {
+                modifier = (extended >> 6) & 0xFF;
+            }
+        }
+
+        let mut text = match operand_type {
+            // Immediates are printed as literals, without any components
+            4 | 5 => {
+                let count = if component_count == 2 { 4 } else { 1 };
+                let values = (0..count)
+                    .map(|_| {
+                        if operand_type == 4 {
+                            self.next().map(format_immediate)
+                        } else {
+                            let lo = self.next()? as u64;
+                            let hi = self.next()? as u64;
+                            Some(format!("{:.6}", f64::from_bits(lo | (hi << 32))))
+                        }
+                    })
+                    .collect::<Option<Vec<_>>>()?;
+
+                return Some(format!(
+                    "{}({})",
+                    if operand_type == 4 { "l" } else { "d" },
+                    values.join(", ")
+                ));
+            }
+            _ => operand_prefix(operand_type).to_string(),
+        };
+
+        for i in 0..index_dimension {
+            let representation = (token >> (22 + i * 3)) & 0x7;
+            let index = self.operand_index(representation)?;
+            if i == 0 && representation < 2 {
+                text.push_str(&index);
+            } else {
+                write!(text, "[{index}]").unwrap();
+            }
+        }
+
+        if with_components && component_count == 2 {
+            match selection_mode {
+                0 => {
+                    let mask = ((token >> 4) & 0xF) as u8;
+                    if mask != 0 {
+                        write!(text, ".{}", mask_string(mask).replace(' ', "")).unwrap();
+                    }
+                }
+                1 => {
+                    text.push('.');
+                    for c in 0..4 {
+                        text.push(component_name((token >> (4 + c * 2)) & 0x3));
+                    }
+                }
+                _ => {
+                    text.push('.');
+                    text.push(component_name((token >> 4) & 0x3));
+                }
+            }
+        }
+
+        Some(match modifier {
+            1 => format!("-{text}"),
+            2 => format!("|{text}|"),
+            3 => format!("-|{text}|"),
+            _ => text,
+        })
+    }
+
+    fn operand_index(&mut self, representation: u32) -> Option<String> {
+        Some(match representation {
+            0 => self.next()?.to_string(),
+            1 => {
+                let hi = self.next()? as u64;
+                let lo = self.next()? as u64;
+                ((hi << 32) | lo).to_string()
+            }
+            2 => self.operand()?,
+            3 => {
+                let immediate = self.next()?;
+                format!("{} + {immediate}", self.operand()?)
+            }
+            4 => {
+                let hi = self.next()? as u64;
+                let lo = self.next()? as u64;
+                format!("{} + {}", self.operand()?, (hi << 32) | lo)
+            }
+            _ => return None,
+        })
+    }
+}
+
+fn component_name(index: u32) -> char {
+    ['x', 'y', 'z', 'w'][index as usize & 3]
+}
+
+fn operand_prefix(operand_type: u32) -> &'static str {
+    match operand_type {
+        0 => "r",
+        1 => "v",
+        2 => "o",
+        3 => "x",
+        6 => "s",
+        7 => "t",
+        8 => "cb",
+        9 => "icb",
+        10 => "label",
+        11 => "vPrim",
+        12 => "oDepth",
+        13 => "null",
+        14 => "rasterizer",
+        15 => "oMask",
+        16 => "m",
+        17 => "fb",
+        18 => "ft",
+        19 => "fp",
+        20 => "fi",
+        21 => "fo",
+        22 => "vOutputControlPointID",
+        23 => "vForkInstanceID",
+        24 => "vJoinInstanceID",
+        25 => "vicp",
+        26 => "vocp",
+        27 => "vpc",
+        28 => "vDomain",
+        29 => "this",
+        30 => "u",
+        31 => "g",
+        32 => "vThreadID",
+        33 => "vThreadGroupID",
+        34 => "vThreadIDInGroup",
+        35 => "vCoverage",
+        36 => "vThreadIDInGroupFlattened",
+        37 => "vGSInstanceID",
+        38 => "oDepthGE",
+        39 => "oDepthLE",
+        40 => "vCycleCounter",
+        41 => "oStencilRef",
+        42 => "vInnerCoverage",
+        _ => "unknown",
+    }
+}
+
+/// Immediates carry no type information, so small values are assumed to be integers like fxc does
+fn format_immediate(value: u32) -> String {
+    let signed = value as i32;
+    if (-0x10000..=0x10000).contains(&signed) {
+        return signed.to_string();
+    }
+
+    let float = f32::from_bits(value);
+    if float.is_finite() {
+        format!("{float:.6}")
+    } else {
+        format!("0x{value:08x}")
+    }
+}
+
+fn resource_dimension_name(dimension: u32) -> &'static str {
+    match dimension {
+        1 => "buffer",
+        2 => "texture1d",
+        3 => "texture2d",
+        4 => "texture2dms",
+        5 => "texture3d",
+        6 => "texturecube",
+        7 => "texture1darray",
+        8 => "texture2darray",
+        9 => "texture2dmsarray",
+        10 => "texturecubearray",
+        11 => "raw_buffer",
+        12 => "structured_buffer",
+        _ => "unknown",
+    }
+}
+
+fn return_types(token: u32) -> String {
+    let names = (0..4)
+        .map(|c| match (token >> (c * 4)) & 0xF {
+            1 => "unorm",
+            2 => "snorm",
+            3 => "sint",
+            4 => "uint",
+            5 => "float",
+            6 => "mixed",
+            7 => "double",
+            8 => "continued",
+            9 => "unused",
+            _ => "unknown",
+        })
+        .collect::<Vec<_>>();
+
+    format!("({})", names.join(","))
+}
+
+fn system_value_name(name: u32) -> String {
+    match name {
+        0 => "undefined",
+        1 => "position",
+        2 => "clip_distance",
+        3 => "cull_distance",
+        4 => "rendertarget_array_index",
+        5 => "viewport_array_index",
+        6 => "vertex_id",
+        7 => "primitive_id",
+        8 => "instance_id",
+        9 => "is_front_face",
+        10 => "sampleIndex",
+        11 => "finalQuadUeq0EdgeTessFactor",
+        12 => "finalQuadVeq0EdgeTessFactor",
+        13 => "finalQuadUeq1EdgeTessFactor",
+        14 => "finalQuadVeq1EdgeTessFactor",
+        15 => "finalQuadUInsideTessFactor",
+        16 => "finalQuadVInsideTessFactor",
+        17 => "finalTriUeq0EdgeTessFactor",
+        18 => "finalTriVeq0EdgeTessFactor",
+        19 => "finalTriWeq0EdgeTessFactor",
+        20 => "finalTriInsideTessFactor",
+        21 => "finalLineDetailTessFactor",
+        22 => "finalLineDensityTessFactor",
+        n => return format!("unknown_{n}"),
+    }
+    .to_string()
+}
+
+fn interpolation_name(mode: u32) -> &'static str {
+    match mode {
+        1 => "constant",
+        2 => "linear",
+        3 => "linear centroid",
+        4 => "linear noperspective",
+        5 => "linear noperspective centroid",
+        6 => "linear sample",
+        7 => "linear noperspective sample",
+        _ => "undefined",
+    }
+}
+
+fn global_flag_names(flags: u32) -> String {
+    const NAMES: [&str; 8] = [
+        "refactoringAllowed",
+        "enableDoublePrecisionFloatOps",
+        "forceEarlyDepthStencil",
+        "enableRawAndStructuredBuffers",
+        "skipOptimization",
+        "enableMinimumPrecision",
+        "enable11_1DoubleExtensions",
+        "enable11_1ShaderExtensions",
+    ];
+
+    NAMES
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| flags & (1 << i) != 0)
+        .map(|(_, n)| *n)
+        .collect::<Vec<_>>()
+        .join(" | ")
+}
+
+fn format_instruction(instruction: &Instruction) -> Option<String> {
+    let opcode = instruction.opcode();
+    let controls = instruction.controls();
+    let mut tokens = Tokens {
+        tokens: &instruction.tokens,
+        pos: 1,
+    };
+
+    let text = match opcode {
+        OPCODE_CUSTOMDATA => {
+            // Only immediate constant buffers are meaningful, other classes are comments and debug info
+            if instruction.tokens[0] >> 11 != 3 {
+                return Some(format!(
+                    "// customdata class {} ({} dwords)",
+                    instruction.tokens[0] >> 11,
+                    instruction.tokens.len()
+                ));
+            }
+
+            let rows = instruction.tokens[2..]
+                .chunks(4)
+                .map(|row| {
+                    let values = row.iter().map(|v| format_immediate(*v)).collect::<Vec<_>>();
+                    format!("{{ {} }}", values.join(", "))
+                })
+                .collect::<Vec<_>>();
+            format!("dcl_immediateConstantBuffer {{ {} }}", rows.join(",\n  "))
+        }
+        88 => {
+            let dimension = controls & 0x1F;
+            let operand = tokens.operand()?;
+            let ret = return_types(tokens.next()?);
+            let samples = if matches!(dimension, 4 | 9) {
+                format!("({})", (controls >> 5) & 0x7F)
+            } else {
+                String::new()
+            };
+            format!(
+                "dcl_resource_{}{samples} {ret} {operand}",
+                resource_dimension_name(dimension)
+            )
+        }
+        89 => format!(
+            "dcl_constantbuffer {}, {}",
+            tokens.operand_register()?,
+            if controls & 1 != 0 {
+                "dynamicIndexed"
+            } else {
+                "immediateIndexed"
+            }
+        ),
+        90 => format!(
+            "dcl_sampler {}, {}",
+            tokens.operand_register()?,
+            match controls & 0xF {
+                1 => "mode_comparison",
+                2 => "mode_mono",
+                _ => "mode_default",
+            }
+        ),
+        91 => format!("dcl_indexrange {} {}", tokens.operand()?, tokens.next()?),
+        92 => format!(
+            "dcl_outputtopology {}",
+            match controls & 0x7F {
+                1 => "pointlist",
+                2 => "linelist",
+                3 => "linestrip",
+                4 => "trianglelist",
+                5 => "trianglestrip",
+                _ => "undefined",
+            }
+        ),
+        93 => {
+            let primitive = controls & 0x3F;
+            format!(
+                "dcl_inputprimitive {}",
+                match primitive {
+                    1 => "point".to_string(),
+                    2 => "line".to_string(),
+                    3 => "triangle".to_string(),
+                    6 => "lineadj".to_string(),
+                    7 => "triangleadj".to_string(),
+                    8..=39 => format!("{}_control_point_patch", primitive - 7),
+                    _ => "undefined".to_string(),
+                }
+            )
+        }
+        94 => format!("dcl_maxout {}", tokens.next()?),
+        95 | 101 | 143 => format!("{} {}", instruction.name(), tokens.operand()?),
+        96 | 97 | 102 | 103 => {
+            let operand = tokens.operand()?;
+            format!(
+                "{} {operand}, {}",
+                instruction.name(),
+                system_value_name(tokens.next()?)
+            )
+        }
+        98 => format!(
+            "dcl_input_ps {} {}",
+            interpolation_name(controls & 0xF),
+            tokens.operand()?
+        ),
+        99 | 100 => {
+            let operand = tokens.operand()?;
+            format!(
+                "{} {} {operand}, {}",
+                instruction.name(),
+                interpolation_name(controls & 0xF),
+                system_value_name(tokens.next()?)
+            )
+        }
+        104 => format!("dcl_temps {}", tokens.next()?),
+        105 => {
+            let register = tokens.next()?;
+            let count = tokens.next()?;
+            let components = tokens.next()?;
+            format!("dcl_indexableTemp x{register}[{count}], {components}")
+        }
+        106 => format!("dcl_globalFlags {}", global_flag_names(controls)),
+        147 | 148 => format!("{} {}", instruction.name(), controls & 0x3F),
+        149 => format!(
+            "dcl_tessellator_domain {}",
+            match controls & 0x3 {
+                1 => "domain_isoline",
+                2 => "domain_tri",
+                3 => "domain_quad",
+                _ => "domain_undefined",
+            }
+        ),
+        150 => format!(
+            "dcl_tessellator_partitioning {}",
+            match controls & 0x7 {
+                1 => "partitioning_integer",
+                2 => "partitioning_pow2",
+                3 => "partitioning_fractional_odd",
+                4 => "partitioning_fractional_even",
+                _ => "partitioning_undefined",
+            }
+        ),
+        151 => format!(
+            "dcl_tessellator_output_primitive {}",
+            match controls & 0x7 {
+                1 => "output_point",
+                2 => "output_line",
+                3 => "output_triangle_cw",
+                4 => "output_triangle_ccw",
+                _ => "output_undefined",
+            }
+        ),
+        152 => format!(
+            "dcl_hs_max_tessfactor l({})",
+            format_immediate(tokens.next()?)
+        ),
+        153 | 154 | 206 => format!("{} {}", instruction.name(), tokens.next()?),
+        155 => format!(
+            "dcl_thread_group {}, {}, {}",
+            tokens.next()?,
+            tokens.next()?,
+            tokens.next()?
+        ),
+        156 => {
+            let dimension = controls & 0x1F;
+            let coherent = if controls & 0x20 != 0 { "_glc" } else { "" };
+            let operand = tokens.operand()?;
+            let ret = return_types(tokens.next()?);
+            format!(
+                "dcl_uav_typed_{}{coherent} {ret} {operand}",
+                resource_dimension_name(dimension)
+            )
+        }
+        157 | 161 => format!("{} {}", instruction.name(), tokens.operand()?),
+        158 => {
+            let counter = if controls & 0x1000 != 0 { "_opc" } else { "" };
+            let operand = tokens.operand()?;
+            format!("dcl_uav_structured{counter} {operand}, {}", tokens.next()?)
+        }
+        159 | 162 => {
+            let operand = tokens.operand()?;
+            format!("{} {operand}, {}", instruction.name(), tokens.next()?)
+        }
+        160 => {
+            let operand = tokens.operand()?;
+            let stride = tokens.next()?;
+            format!(
+                "dcl_tgsm_structured {operand}, {stride}, {}",
+                tokens.next()?
+            )
+        }
+        190 => {
+            let mut name = "sync".to_string();
+            for (bit, flag) in ["_g", "_t", "_uglobal", "_ugroup"].iter().enumerate() {
+                if controls & (1 << bit) != 0 {
+                    name.push_str(flag);
+                }
+            }
+            name
+        }
+        // fcall takes the interface call site index before its operand
+        120 => {
+            let index = tokens.next()?;
+            format!("fcall {} [{index}]", tokens.operand()?)
+        }
+        // Remaining declarations are printed as raw tokens
+        _ if instruction.is_declaration() => return None,
+        _ => {
+            let mut name = instruction.name().to_string();
+            let mut extended = instruction.tokens[0] & 0x8000_0000 != 0;
+            while extended {
+                let token = tokens.next()?;
+                extended = token & 0x8000_0000 != 0;
+                match token & 0x3F {
+                    1 => {
+                        let offset = |shift: u32| ((token << (28 - shift)) as i32) >> 28;
+                        write!(
+                            name,
+                            "_aoffimmi({},{},{})",
+                            offset(9),
+                            offset(13),
+                            offset(17)
+                        )
+                        .unwrap();
+                    }
+                    2 => write!(
+                        name,
+                        "_indexable({})",
+                        resource_dimension_name((token >> 6) & 0x1F)
+                    )
+                    .unwrap(),
+                    3 => name.push_str(&return_types(token >> 6)),
+                    _ => {}
+                }
+            }
+
+            match opcode {
+                // breakc, callc, continuec, discard, if, retc
+                3 | 5 | 8 | 13 | 31 | 63 => {
+                    name.push_str(if controls & 0x80 != 0 { "_nz" } else { "_z" })
+                }
+                // resinfo, sampleinfo
+                61 | 111 => name.push_str(match controls & 0x3 {
+                    1 => "_rcpFloat",
+                    2 => "_uint",
+                    _ => "",
+                }),
+                _ => {
+                    if controls & 0x4 != 0 {
+                        name.push_str("_sat");
+                    }
+                }
+            }
+
+            let mut operands = vec![];
+            while !tokens.is_empty() {
+                operands.push(tokens.operand()?);
+            }
+
+            if operands.is_empty() {
+                name
+            } else {
+                format!("{name} {}", operands.join(", "))
+            }
+        }
+    };
+
+    Some(text)
+}
+
+/// Opcode names, indexed by `D3D10_SB_OPCODE_TYPE`
+pub const OPCODE_NAMES: [&str; 218] = [
+    "add",
+    "and",
+    "break",
+    "breakc",
+    "call",
+    "callc",
+    "case",
+    "continue",
+    "continuec",
+    "cut",
+    "default",
+    "deriv_rtx",
+    "deriv_rty",
+    "discard",
+    "div",
+    "dp2",
+    "dp3",
+    "dp4",
+    "else",
+    "emit",
+    "emitThenCut",
+    "endif",
+    "endloop",
+    "endswitch",
+    "eq",
+    "exp",
+    "frc",
+    "ftoi",
+    "ftou",
+    "ge",
+    "iadd",
+    "if",
+    "ieq",
+    "ige",
+    "ilt",
+    "imad",
+    "imax",
+    "imin",
+    "imul",
+    "ine",
+    "ineg",
+    "ishl",
+    "ishr",
+    "itof",
+    "label",
+    "ld",
+    "ld_ms",
+    "log",
+    "loop",
+    "lt",
+    "mad",
+    "min",
+    "max",
+    "customdata",
+    "mov",
+    "movc",
+    "mul",
+    "ne",
+    "nop",
+    "not",
+    "or",
+    "resinfo",
+    "ret",
+    "retc",
+    "round_ne",
+    "round_ni",
+    "round_pi",
+    "round_z",
+    "rsq",
+    "sample",
+    "sample_c",
+    "sample_c_lz",
+    "sample_l",
+    "sample_d",
+    "sample_b",
+    "sqrt",
+    "switch",
+    "sincos",
+    "udiv",
+    "ult",
+    "uge",
+    "umul",
+    "umad",
+    "umax",
+    "umin",
+    "ushr",
+    "utof",
+    "xor",
+    "dcl_resource",
+    "dcl_constantbuffer",
+    "dcl_sampler",
+    "dcl_indexrange",
+    "dcl_outputtopology",
+    "dcl_inputprimitive",
+    "dcl_maxout",
+    "dcl_input",
+    "dcl_input_sgv",
+    "dcl_input_siv",
+    "dcl_input_ps",
+    "dcl_input_ps_sgv",
+    "dcl_input_ps_siv",
+    "dcl_output",
+    "dcl_output_sgv",
+    "dcl_output_siv",
+    "dcl_temps",
+    "dcl_indexableTemp",
+    "dcl_globalFlags",
+    "reserved0",
+    "lod",
+    "gather4",
+    "samplepos",
+    "sampleinfo",
+    "reserved1",
+    "hs_decls",
+    "hs_control_point_phase",
+    "hs_fork_phase",
+    "hs_join_phase",
+    "emit_stream",
+    "cut_stream",
+    "emitThenCut_stream",
+    "fcall",
+    "bufinfo",
+    "deriv_rtx_coarse",
+    "deriv_rtx_fine",
+    "deriv_rty_coarse",
+    "deriv_rty_fine",
+    "gather4_c",
+    "gather4_po",
+    "gather4_po_c",
+    "rcp",
+    "f32tof16",
+    "f16tof32",
+    "uaddc",
+    "usubb",
+    "countbits",
+    "firstbit_hi",
+    "firstbit_lo",
+    "firstbit_shi",
+    "ubfe",
+    "ibfe",
+    "bfi",
+    "bfrev",
+    "swapc",
+    "dcl_stream",
+    "dcl_function_body",
+    "dcl_function_table",
+    "dcl_interface",
+    "dcl_input_control_point_count",
+    "dcl_output_control_point_count",
+    "dcl_tessellator_domain",
+    "dcl_tessellator_partitioning",
+    "dcl_tessellator_output_primitive",
+    "dcl_hs_max_tessfactor",
+    "dcl_hs_fork_phase_instance_count",
+    "dcl_hs_join_phase_instance_count",
+    "dcl_thread_group",
+    "dcl_uav_typed",
+    "dcl_uav_raw",
+    "dcl_uav_structured",
+    "dcl_tgsm_raw",
+    "dcl_tgsm_structured",
+    "dcl_resource_raw",
+    "dcl_resource_structured",
+    "ld_uav_typed",
+    "store_uav_typed",
+    "ld_raw",
+    "store_raw",
+    "ld_structured",
+    "store_structured",
+    "atomic_and",
+    "atomic_or",
+    "atomic_xor",
+    "atomic_cmp_store",
+    "atomic_iadd",
+    "atomic_imax",
+    "atomic_imin",
+    "atomic_umax",
+    "atomic_umin",
+    "imm_atomic_alloc",
+    "imm_atomic_consume",
+    "imm_atomic_iadd",
+    "imm_atomic_and",
+    "imm_atomic_or",
+    "imm_atomic_xor",
+    "imm_atomic_exch",
+    "imm_atomic_cmp_exch",
+    "imm_atomic_imax",
+    "imm_atomic_imin",
+    "imm_atomic_umax",
+    "imm_atomic_umin",
+    "sync",
+    "dadd",
+    "dmax",
+    "dmin",
+    "dmul",
+    "deq",
+    "dge",
+    "dlt",
+    "dne",
+    "dmov",
+    "dmovc",
+    "dtof",
+    "ftod",
+    "eval_snapped",
+    "eval_sample_index",
+    "eval_centroid",
+    "dcl_gsinstances",
+    "abort",
+    "debug_break",
+    "reserved2",
+    "ddiv",
+    "dfma",
+    "drcp",
+    "msad",
+    "dtoi",
+    "dtou",
+    "itod",
+    "utod",
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        tests::{build_container, push_u32},
+        Dxbc,
+    };
+
+    #[test]
+    fn disassemble_vertex_program() {
+        let mut shex = vec![];
+        push_u32(
+            &mut shex,
+            &[
+                0x00010050, 23,         //
+                0x0100086A, //
+                0x04000059, 0x00208E46, 0, 4, //
+                0x0300005F, 0x001010F2, 0, //
+                0x04000067, 0x001020F2, 0, 1, //
+                0x08000011, 0x00102012, 0, 0x00101E46, 0, 0x00208E46, 0, 0, //
+                0x0100003E,
+            ],
+        );
+
+        let data = build_container(&[(b"SHEX", shex)]);
+        let program = Dxbc::parse(&data).unwrap().program().unwrap().unwrap();
+
+        assert_eq!(program.version.to_string(), "vs_5_0");
+        assert_eq!(program.instructions.len(), 6);
+        assert!(program.instructions[1].is_declaration());
+        assert!(!program.instructions[4].is_declaration());
+
+        let lines = program.disassemble();
+        let lines = lines.lines().collect::<Vec<_>>();
+        assert_eq!(
+            lines,
+            [
+                "vs_5_0",
+                "dcl_globalFlags refactoringAllowed",
+                "dcl_constantbuffer cb0[4], immediateIndexed",
+                "dcl_input v0.xyzw",
+                "dcl_output_siv o0.xyzw, position",
+                "dp4 o0.x, v0.xyzw, cb0[0].xyzw",
+                "ret",
+                "// Approximately 2 instruction slots used",
+            ]
+        );
+    }
+
+    #[test]
+    fn format_immediates() {
+        assert_eq!(format_immediate(3), "3");
+        assert_eq!(format_immediate(-1i32 as u32), "-1");
+        assert_eq!(format_immediate(1.0f32.to_bits()), "1.000000");
+        assert_eq!(format_immediate(f32::NAN.to_bits()), "0x7fc00000");
+    }
+}
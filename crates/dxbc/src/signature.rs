@@ -0,0 +1,205 @@
+use std::fmt::Display;
+
+use anyhow::bail;
+
+use crate::reader::{read_cstring, Reader};
+
+#[derive(Debug, Clone)]
+pub struct SignatureElement {
+    pub semantic_name: String,
+    pub semantic_index: u32,
+    /// `D3D_NAME`
+    pub system_value: u32,
+    /// `D3D_REGISTER_COMPONENT_TYPE`
+    pub component_type: u32,
+    pub register: u32,
+    pub mask: u8,
+    /// Components read by the shader for inputs, components never written for outputs
+    pub rw_mask: u8,
+    /// Geometry shader output stream, only present in `OSG5`/`OSG1`
+    pub stream: u32,
+    /// `D3D_MIN_PRECISION`, only present in `ISG1`/`OSG1`
+    pub min_precision: u32,
+}
+
+impl SignatureElement {
+    pub fn system_value_name(&self) -> &'static str {
+        system_value_name(self.system_value)
+    }
+
+    pub fn component_type_name(&self) -> &'static str {
+        match self.component_type {
+            1 => "uint",
+            2 => "int",
+            3 => "float",
+            _ => "unknown",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Signature {
+    pub elements: Vec<SignatureElement>,
+}
+
+impl Signature {
+    /// Parses an `ISGN`, `OSGN`, `PCSG`, `OSG5` or `ISG1`/`OSG1`/`PSG1` chunk
+    pub fn parse(data: &[u8], fourcc: [u8; 4]) -> anyhow::Result<Self> {
+        let (has_stream, has_min_precision) = match &fourcc {
+            b"ISGN" | b"OSGN" | b"PCSG" => (false, false),
+            b"OSG5" => (true, false),
+            b"ISG1" | b"OSG1" | b"PSG1" => (true, true),
+            _ => bail!(
+                "Unsupported signature chunk {}",
+                String::from_utf8_lossy(&fourcc)
+            ),
+        };
+
+        let mut reader = Reader::new(data);
+        let count = reader.u32()?;
+        let offset = reader.u32()?;
+        let mut reader = Reader::at(data, offset as usize);
+
+        let mut elements = Vec::with_capacity(count as usize);
+        for _ in 0..count {
+            let stream = if has_stream { reader.u32()? } else { 0 };
+            let name_offset = reader.u32()?;
+            let semantic_index = reader.u32()?;
+            let system_value = reader.u32()?;
+            let component_type = reader.u32()?;
+            let register = reader.u32()?;
+            let mask = reader.u8()?;
+            let rw_mask = reader.u8()?;
+            reader.skip(2)?;
+            let min_precision = if has_min_precision { reader.u32()? } else { 0 };
+
+            elements.push(SignatureElement {
+                semantic_name: read_cstring(data, name_offset)?,
+                semantic_index,
+                system_value,
+                component_type,
+                register,
+                mask,
+                rw_mask,
+                stream,
+                min_precision,
+            });
+        }
+
+        Ok(Self { elements })
+    }
+
+    pub fn find(&self, semantic_name: &str, semantic_index: u32) -> Option<&SignatureElement> {
+        self.elements.iter().find(|e| {
+            e.semantic_name.eq_ignore_ascii_case(semantic_name)
+                && e.semantic_index == semantic_index
+        })
+    }
+}
+
+impl Display for Signature {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "// Name                 Index   Mask Register SysValue  Format   Used"
+        )?;
+        writeln!(
+            f,
+            "// -------------------- ----- ------ -------- -------- ------- ------"
+        )?;
+        for e in &self.elements {
+            writeln!(
+                f,
+                "// {:<20} {:>5} {:>6} {:>8} {:>8} {:>7} {:>6}",
+                e.semantic_name,
+                e.semantic_index,
+                mask_string(e.mask),
+                e.register,
+                e.system_value_name(),
+                e.component_type_name(),
+                mask_string(e.rw_mask),
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Component mask as `xyzw` letters, with gaps kept as spaces like fxc does
+pub fn mask_string(mask: u8) -> String {
+    "xyzw"
+        .chars()
+        .enumerate()
+        .map(|(i, c)| if mask & (1 << i) != 0 { c } else { ' ' })
+        .collect::<String>()
+        .trim_end()
+        .to_string()
+}
+
+/// `D3D_NAME` values as printed in signatures
+pub fn system_value_name(value: u32) -> &'static str {
+    match value {
+        0 => "NONE",
+        1 => "POS",
+        2 => "CLIPDST",
+        3 => "CULLDST",
+        4 => "RTINDEX",
+        5 => "VPINDEX",
+        6 => "VERTID",
+        7 => "PRIMID",
+        8 => "INSTID",
+        9 => "FFACE",
+        10 => "SAMPLE",
+        11 => "QUADEDGE",
+        12 => "QUADINT",
+        13 => "TRIEDGE",
+        14 => "TRIINT",
+        15 => "LINEDET",
+        16 => "LINEDEN",
+        64 => "TARGET",
+        65 => "DEPTH",
+        66 => "COVERAGE",
+        67 => "DEPTHGE",
+        68 => "DEPTHLE",
+        69 => "STENCILREF",
+        70 => "INNERCOV",
+        _ => "UNKNOWN",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        tests::{build_container, push_u32},
+        Dxbc,
+    };
+
+    #[test]
+    fn parse_input_signature() {
+        let mut isgn = vec![];
+        push_u32(&mut isgn, &[2, 8]);
+        // POSITION0, float, v0.xyz, reads xyz
+        push_u32(&mut isgn, &[56, 0, 0, 3, 0]);
+        isgn.extend_from_slice(&[0b0111, 0b0111, 0, 0]);
+        // TEXCOORD3, float, v1.xy, reads x
+        push_u32(&mut isgn, &[65, 3, 0, 3, 1]);
+        isgn.extend_from_slice(&[0b0011, 0b0001, 0, 0]);
+        isgn.extend_from_slice(b"POSITION\0TEXCOORD\0");
+
+        let data = build_container(&[(b"ISGN", isgn)]);
+        let signature = Dxbc::parse(&data)
+            .unwrap()
+            .input_signature()
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(signature.elements.len(), 2);
+        let texcoord = signature.find("texcoord", 3).unwrap();
+        assert_eq!(texcoord.register, 1);
+        assert_eq!(mask_string(texcoord.mask), "xy");
+        assert_eq!(texcoord.component_type_name(), "float");
+        assert_eq!(signature.elements[0].semantic_name, "POSITION");
+        assert_eq!(mask_string(0b0101), "x z");
+    }
+}
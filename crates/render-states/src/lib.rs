@@ -0,0 +1,365 @@
+//! Platform independent description of the fixed function states selected by techniques
+//!
+//! Holds the game's blend, depth/stencil, rasterizer, depth bias and input layout tables. The
+//! enum values match their D3D11 counterparts, so the renderer can build its D3D11 state objects
+//! from the same tables the exporters describe.
+
+mod tables;
+
+use std::ffi::CStr;
+
+use alkahest_data::{dxgi::DxgiFormat, technique::StateSelection};
+use serde::Serialize;
+pub use tables::*;
+
+/// Fixed function state selected by a [`StateSelection`]. States that are not set by the selection
+/// are inherited from whatever the render pass has bound, and are left as `None`
+#[derive(Debug, Clone, Serialize)]
+pub struct PipelineStateDesc {
+    pub blend_index: Option<usize>,
+    pub blend: Option<BlendStateDesc>,
+    pub depth_stencil_index: Option<usize>,
+    pub depth_stencil: Option<DepthStencilStateDesc>,
+    pub rasterizer_index: Option<usize>,
+    pub rasterizer: Option<RasterizerStateDesc>,
+    pub depth_bias_index: Option<usize>,
+    pub depth_bias: Option<DepthBiasDesc>,
+}
+
+impl PipelineStateDesc {
+    pub fn from_selection(states: StateSelection) -> Self {
+        let blend_index = states.blend_state();
+        let depth_stencil_index = states.depth_stencil_state();
+        let rasterizer_index = states.rasterizer_state();
+        let depth_bias_index = states.depth_bias_state();
+
+        Self {
+            blend_index,
+            blend: blend_index.and_then(|i| BLEND_STATES.get(i).cloned()),
+            depth_stencil_index,
+            depth_stencil: depth_stencil_index.and_then(DepthStencilStateDesc::get),
+            rasterizer_index,
+            rasterizer: rasterizer_index.and_then(|i| RASTERIZER_STATES.get(i).cloned()),
+            depth_bias_index,
+            depth_bias: depth_bias_index.and_then(|i| DEPTH_BIASES.get(i).cloned()),
+        }
+    }
+
+    /// Whether the first render target blends with what is already in it
+    pub fn is_alpha_blended(&self) -> bool {
+        self.blend
+            .as_ref()
+            .is_some_and(|b| b.render_targets[0].enabled)
+    }
+
+    pub fn is_two_sided(&self) -> bool {
+        self.rasterizer
+            .as_ref()
+            .is_some_and(|r| r.cull_mode == CullMode::None)
+    }
+
+    pub fn writes_depth(&self) -> bool {
+        self.depth_stencil
+            .as_ref()
+            .is_some_and(|d| d.depth_enable && d.depth_write)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+#[repr(i32)]
+pub enum BlendFactor {
+    Zero = 1,
+    One = 2,
+    SrcColor = 3,
+    InvSrcColor = 4,
+    SrcAlpha = 5,
+    InvSrcAlpha = 6,
+    DestAlpha = 7,
+    InvDestAlpha = 8,
+    DestColor = 9,
+    InvDestColor = 10,
+    SrcAlphaSat = 11,
+    BlendFactor = 14,
+    InvBlendFactor = 15,
+    Src1Color = 16,
+    InvSrc1Color = 17,
+    Src1Alpha = 18,
+    InvSrc1Alpha = 19,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+#[repr(i32)]
+pub enum BlendOp {
+    Add = 1,
+    Subtract = 2,
+    RevSubtract = 3,
+    Min = 4,
+    Max = 5,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+#[repr(i32)]
+pub enum CompareFunc {
+    Never = 1,
+    Less = 2,
+    Equal = 3,
+    LessEqual = 4,
+    Greater = 5,
+    NotEqual = 6,
+    GreaterEqual = 7,
+    Always = 8,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+#[repr(i32)]
+pub enum StencilOp {
+    Keep = 1,
+    Zero = 2,
+    Replace = 3,
+    IncrSat = 4,
+    DecrSat = 5,
+    Invert = 6,
+    Incr = 7,
+    Decr = 8,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+#[repr(i32)]
+pub enum CullMode {
+    None = 1,
+    Front = 2,
+    Back = 3,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+#[repr(i32)]
+pub enum FillMode {
+    Wireframe = 2,
+    Solid = 3,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RenderTargetBlendDesc {
+    pub enabled: bool,
+    pub src_color: BlendFactor,
+    pub dst_color: BlendFactor,
+    pub color_op: BlendOp,
+    pub src_alpha: BlendFactor,
+    pub dst_alpha: BlendFactor,
+    pub alpha_op: BlendOp,
+    /// RGBA write mask, bit 0 is red
+    pub write_mask: u8,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BlendStateDesc {
+    /// The game only defines 4 render targets, the remaining ones repeat the last
+    pub render_targets: [RenderTargetBlendDesc; 4],
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct StencilFaceDesc {
+    pub func: CompareFunc,
+    pub pass_op: StencilOp,
+    pub fail_op: StencilOp,
+    pub depth_fail_op: StencilOp,
+}
+
+pub struct StencilDesc {
+    pub stencil_enable: bool,
+    pub stencil_read_mask: u8,
+    pub stencil_write_mask: u8,
+    pub front_face: StencilFaceDesc,
+    pub back_face: StencilFaceDesc,
+}
+
+pub struct DepthDesc {
+    pub enable: bool,
+    pub write_mask: u32,
+    pub func: CompareFunc,
+    pub enable_alt: bool,
+    pub write_mask_alt: u32,
+    /// Comparison used when the depth range is flipped
+    pub func_alt: CompareFunc,
+}
+
+/// Combination of a depth and a stencil state, as selected by a depth stencil state index
+#[derive(Debug, Clone, Serialize)]
+pub struct DepthStencilStateDesc {
+    pub depth_enable: bool,
+    pub depth_write: bool,
+    pub depth_func: CompareFunc,
+    /// Comparison used when the depth range is flipped (see `use_flipped_depth_comparison`)
+    pub depth_func_flipped: CompareFunc,
+    pub stencil_enable: bool,
+    pub stencil_read_mask: u8,
+    pub stencil_write_mask: u8,
+    pub stencil_front: StencilFaceDesc,
+    pub stencil_back: StencilFaceDesc,
+}
+
+impl DepthStencilStateDesc {
+    pub fn get(index: usize) -> Option<Self> {
+        let (depth_index, stencil_index) = DEPTH_STENCIL_COMBOS.get(index)?;
+        let depth = DEPTH_STATES.get(*depth_index)?;
+        let stencil = STENCIL_STATES.get(*stencil_index)?;
+
+        Some(Self {
+            depth_enable: depth.enable,
+            depth_write: depth.write_mask != 0,
+            depth_func: depth.func,
+            depth_func_flipped: depth.func_alt,
+            stencil_enable: stencil.stencil_enable,
+            stencil_read_mask: stencil.stencil_read_mask,
+            stencil_write_mask: stencil.stencil_write_mask,
+            stencil_front: stencil.front_face.clone(),
+            stencil_back: stencil.back_face.clone(),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RasterizerStateDesc {
+    pub fill_mode: FillMode,
+    pub cull_mode: CullMode,
+    pub front_counter_clockwise: bool,
+    pub depth_clip_enable: bool,
+    pub scissor_enable: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DepthBiasDesc {
+    pub depth_bias: i32,
+    pub slope_scale: f32,
+    pub clamp: f32,
+}
+
+pub struct InputLayout {
+    pub elements: &'static [InputLayoutElement],
+}
+
+pub struct InputLayoutElement {
+    pub hlsl_type: &'static str,
+    pub format: DxgiFormat,
+    pub stride: u32,
+    pub semantic_name: &'static CStr,
+    pub semantic_index: u32,
+    pub buffer_index: u32,
+    pub is_instance_data: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct InputElementDesc {
+    pub semantic_name: String,
+    pub semantic_index: u32,
+    /// DXGI format name, such as `R16G16B16A16_SNORM`
+    pub format: String,
+    pub hlsl_type: &'static str,
+    pub buffer_index: u32,
+    pub per_instance: bool,
+}
+
+/// Elements of one of the game's fixed input layouts, as selected by a vertex buffer layout index
+pub fn input_layout_desc(index: usize) -> Option<Vec<InputElementDesc>> {
+    let layout = INPUT_LAYOUTS.get(index)?;
+    Some(
+        layout
+            .elements
+            .iter()
+            .map(|e| InputElementDesc {
+                semantic_name: e.semantic_name.to_string_lossy().into_owned(),
+                semantic_index: e.semantic_index,
+                format: format!("{:?}", e.format),
+                hlsl_type: e.hlsl_type,
+                buffer_index: e.buffer_index,
+                per_instance: e.is_instance_data,
+            })
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unset_states_are_inherited() {
+        let desc = PipelineStateDesc::from_selection(StateSelection::from_raw(0));
+        assert!(desc.blend.is_none());
+        assert!(desc.depth_stencil.is_none());
+        assert!(desc.rasterizer.is_none());
+        assert!(desc.depth_bias.is_none());
+        assert!(!desc.is_alpha_blended());
+    }
+
+    #[test]
+    fn known_state_indices() {
+        let desc = PipelineStateDesc::from_selection(StateSelection::new(
+            Some(2),
+            Some(2),
+            Some(0),
+            Some(2),
+        ));
+
+        // Blend 2 is additive
+        let rt = &desc.blend.as_ref().unwrap().render_targets[0];
+        assert!(desc.is_alpha_blended());
+        assert_eq!(
+            (rt.src_color, rt.dst_color, rt.color_op),
+            (BlendFactor::One, BlendFactor::One, BlendOp::Add)
+        );
+
+        // Depth stencil 2 combines depth state 2 and stencil state 1
+        let depth_stencil = desc.depth_stencil.as_ref().unwrap();
+        assert!(desc.writes_depth());
+        assert_eq!(depth_stencil.depth_func, CompareFunc::GreaterEqual);
+        assert_eq!(depth_stencil.depth_func_flipped, CompareFunc::LessEqual);
+        assert!(!depth_stencil.stencil_enable);
+
+        // Rasterizer 0 does not cull
+        assert!(desc.is_two_sided());
+
+        let depth_bias = desc.depth_bias.as_ref().unwrap();
+        assert_eq!(depth_bias.depth_bias, 5);
+        assert_eq!(depth_bias.slope_scale, 2.0);
+    }
+
+    #[test]
+    fn opaque_blend_and_culling() {
+        let desc =
+            PipelineStateDesc::from_selection(StateSelection::new(Some(0), None, Some(2), None));
+        assert!(!desc.is_alpha_blended());
+        assert_eq!(
+            desc.blend.as_ref().unwrap().render_targets[0].write_mask,
+            0xf
+        );
+
+        let rasterizer = desc.rasterizer.as_ref().unwrap();
+        assert!(!desc.is_two_sided());
+        assert_eq!(rasterizer.cull_mode, CullMode::Back);
+        assert_eq!(rasterizer.fill_mode, FillMode::Solid);
+        assert_eq!(RASTERIZER_STATES[4].fill_mode, FillMode::Wireframe);
+    }
+
+    #[test]
+    fn out_of_range_indices() {
+        assert!(DepthStencilStateDesc::get(DEPTH_STENCIL_COMBOS.len()).is_none());
+        assert!(input_layout_desc(INPUT_LAYOUTS.len()).is_none());
+    }
+
+    #[test]
+    fn input_layout_elements() {
+        let layout = input_layout_desc(0).unwrap();
+        assert_eq!(layout.len(), 1);
+        assert_eq!(layout[0].semantic_name, "POSITION");
+        assert_eq!(layout[0].format, "R32G32B32_FLOAT");
+        assert_eq!(layout[0].hlsl_type, "float3");
+        assert!(!layout[0].per_instance);
+    }
+}
@@ -0,0 +1,11017 @@
+//! The game's fixed function state tables, indexed by the values in a [`StateSelection`](alkahest_data::technique::StateSelection)
+
+use alkahest_data::dxgi::DxgiFormat;
+
+use crate::{
+    BlendFactor, BlendOp, BlendStateDesc, CompareFunc, CullMode, DepthBiasDesc, DepthDesc,
+    FillMode, InputLayout, InputLayoutElement, RasterizerStateDesc, RenderTargetBlendDesc,
+    StencilDesc, StencilFaceDesc, StencilOp,
+};
+
+//region Blend states
+pub const BLEND_STATES: [BlendStateDesc; 0x5a] = [
+    BlendStateDesc {
+        render_targets: [
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+        ],
+    },
+    BlendStateDesc {
+        render_targets: [
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+        ],
+    },
+    BlendStateDesc {
+        render_targets: [
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::One,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::One,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::One,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::One,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::One,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::One,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::One,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::One,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+        ],
+    },
+    BlendStateDesc {
+        render_targets: [
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::DestColor,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::DestAlpha,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::DestColor,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::DestAlpha,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::DestColor,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::DestAlpha,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::DestColor,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::DestAlpha,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+        ],
+    },
+    BlendStateDesc {
+        render_targets: [
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::DestColor,
+                dst_color: BlendFactor::SrcColor,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::DestAlpha,
+                dst_alpha: BlendFactor::SrcAlpha,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::DestColor,
+                dst_color: BlendFactor::SrcColor,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::DestAlpha,
+                dst_alpha: BlendFactor::SrcAlpha,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::DestColor,
+                dst_color: BlendFactor::SrcColor,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::DestAlpha,
+                dst_alpha: BlendFactor::SrcAlpha,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::DestColor,
+                dst_color: BlendFactor::SrcColor,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::DestAlpha,
+                dst_alpha: BlendFactor::SrcAlpha,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+        ],
+    },
+    BlendStateDesc {
+        render_targets: [
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::SrcAlpha,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::SrcAlpha,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::SrcAlpha,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::SrcAlpha,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::SrcAlpha,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::SrcAlpha,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::SrcAlpha,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::SrcAlpha,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+        ],
+    },
+    BlendStateDesc {
+        render_targets: [
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::SrcAlpha,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::DestAlpha,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::SrcAlpha,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::DestAlpha,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::SrcAlpha,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::DestAlpha,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::SrcAlpha,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::DestAlpha,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+        ],
+    },
+    BlendStateDesc {
+        render_targets: [
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::DestAlpha,
+                dst_color: BlendFactor::One,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::DestAlpha,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::DestAlpha,
+                dst_color: BlendFactor::One,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::DestAlpha,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::DestAlpha,
+                dst_color: BlendFactor::One,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::DestAlpha,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::DestAlpha,
+                dst_color: BlendFactor::One,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::DestAlpha,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+        ],
+    },
+    BlendStateDesc {
+        render_targets: [
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::InvSrcAlpha,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::InvSrcAlpha,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::InvSrcAlpha,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::InvSrcAlpha,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::InvSrcAlpha,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::InvSrcAlpha,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::InvSrcAlpha,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::InvSrcAlpha,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+        ],
+    },
+    BlendStateDesc {
+        render_targets: [
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::One,
+                color_op: BlendOp::Min,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::One,
+                alpha_op: BlendOp::Min,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::One,
+                color_op: BlendOp::Min,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::One,
+                alpha_op: BlendOp::Min,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::One,
+                color_op: BlendOp::Min,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::One,
+                alpha_op: BlendOp::Min,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::One,
+                color_op: BlendOp::Min,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::One,
+                alpha_op: BlendOp::Min,
+                write_mask: 0xf,
+            },
+        ],
+    },
+    BlendStateDesc {
+        render_targets: [
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::One,
+                color_op: BlendOp::Max,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::One,
+                alpha_op: BlendOp::Max,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::One,
+                color_op: BlendOp::Max,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::One,
+                alpha_op: BlendOp::Max,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::One,
+                color_op: BlendOp::Max,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::One,
+                alpha_op: BlendOp::Max,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::One,
+                color_op: BlendOp::Max,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::One,
+                alpha_op: BlendOp::Max,
+                write_mask: 0xf,
+            },
+        ],
+    },
+    BlendStateDesc {
+        render_targets: [
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::DestColor,
+                dst_color: BlendFactor::One,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::DestAlpha,
+                dst_alpha: BlendFactor::One,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::DestColor,
+                dst_color: BlendFactor::One,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::DestAlpha,
+                dst_alpha: BlendFactor::One,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::DestColor,
+                dst_color: BlendFactor::One,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::DestAlpha,
+                dst_alpha: BlendFactor::One,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::DestColor,
+                dst_color: BlendFactor::One,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::DestAlpha,
+                dst_alpha: BlendFactor::One,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+        ],
+    },
+    BlendStateDesc {
+        render_targets: [
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::SrcAlpha,
+                dst_color: BlendFactor::InvSrcAlpha,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::SrcAlpha,
+                dst_alpha: BlendFactor::InvSrcAlpha,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::SrcAlpha,
+                dst_color: BlendFactor::InvSrcAlpha,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::SrcAlpha,
+                dst_alpha: BlendFactor::InvSrcAlpha,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::SrcAlpha,
+                dst_color: BlendFactor::InvSrcAlpha,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::SrcAlpha,
+                dst_alpha: BlendFactor::InvSrcAlpha,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::SrcAlpha,
+                dst_color: BlendFactor::InvSrcAlpha,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::SrcAlpha,
+                dst_alpha: BlendFactor::InvSrcAlpha,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+        ],
+    },
+    BlendStateDesc {
+        render_targets: [
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::BlendFactor,
+                dst_color: BlendFactor::InvBlendFactor,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::BlendFactor,
+                dst_alpha: BlendFactor::InvBlendFactor,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::BlendFactor,
+                dst_color: BlendFactor::InvBlendFactor,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::BlendFactor,
+                dst_alpha: BlendFactor::InvBlendFactor,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::BlendFactor,
+                dst_color: BlendFactor::InvBlendFactor,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::BlendFactor,
+                dst_alpha: BlendFactor::InvBlendFactor,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::BlendFactor,
+                dst_color: BlendFactor::InvBlendFactor,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::BlendFactor,
+                dst_alpha: BlendFactor::InvBlendFactor,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+        ],
+    },
+    BlendStateDesc {
+        render_targets: [
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::InvSrcAlpha,
+                dst_color: BlendFactor::SrcAlpha,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::InvSrcAlpha,
+                dst_alpha: BlendFactor::SrcAlpha,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::InvSrcAlpha,
+                dst_color: BlendFactor::SrcAlpha,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::InvSrcAlpha,
+                dst_alpha: BlendFactor::SrcAlpha,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::InvSrcAlpha,
+                dst_color: BlendFactor::SrcAlpha,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::InvSrcAlpha,
+                dst_alpha: BlendFactor::SrcAlpha,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::InvSrcAlpha,
+                dst_color: BlendFactor::SrcAlpha,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::InvSrcAlpha,
+                dst_alpha: BlendFactor::SrcAlpha,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+        ],
+    },
+    BlendStateDesc {
+        render_targets: [
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::DestAlpha,
+                dst_color: BlendFactor::InvDestAlpha,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::DestAlpha,
+                dst_color: BlendFactor::InvDestAlpha,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::DestAlpha,
+                dst_color: BlendFactor::InvDestAlpha,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::DestAlpha,
+                dst_color: BlendFactor::InvDestAlpha,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+        ],
+    },
+    BlendStateDesc {
+        render_targets: [
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::InvSrcAlpha,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::Zero,
+                dst_alpha: BlendFactor::InvSrcAlpha,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::InvSrcAlpha,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::Zero,
+                dst_alpha: BlendFactor::InvSrcAlpha,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::InvSrcAlpha,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::Zero,
+                dst_alpha: BlendFactor::InvSrcAlpha,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::InvSrcAlpha,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::Zero,
+                dst_alpha: BlendFactor::InvSrcAlpha,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+        ],
+    },
+    BlendStateDesc {
+        render_targets: [
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+        ],
+    },
+    BlendStateDesc {
+        render_targets: [
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+        ],
+    },
+    BlendStateDesc {
+        render_targets: [
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+        ],
+    },
+    BlendStateDesc {
+        render_targets: [
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+        ],
+    },
+    BlendStateDesc {
+        render_targets: [
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+        ],
+    },
+    BlendStateDesc {
+        render_targets: [
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::SrcAlpha,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::Zero,
+                dst_alpha: BlendFactor::One,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::SrcAlpha,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::Zero,
+                dst_alpha: BlendFactor::One,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::SrcAlpha,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::Zero,
+                dst_alpha: BlendFactor::One,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::SrcAlpha,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::Zero,
+                dst_alpha: BlendFactor::One,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+        ],
+    },
+    BlendStateDesc {
+        render_targets: [
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::SrcAlpha,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::Zero,
+                dst_alpha: BlendFactor::One,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::SrcAlpha,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::Zero,
+                dst_alpha: BlendFactor::One,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::SrcAlpha,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::Zero,
+                dst_alpha: BlendFactor::One,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::SrcAlpha,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::Zero,
+                dst_alpha: BlendFactor::One,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+        ],
+    },
+    BlendStateDesc {
+        render_targets: [
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::SrcAlpha,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::Zero,
+                dst_alpha: BlendFactor::One,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::SrcAlpha,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::Zero,
+                dst_alpha: BlendFactor::One,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::SrcAlpha,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::Zero,
+                dst_alpha: BlendFactor::One,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::SrcAlpha,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::Zero,
+                dst_alpha: BlendFactor::One,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+        ],
+    },
+    BlendStateDesc {
+        render_targets: [
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::SrcAlpha,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::Zero,
+                dst_alpha: BlendFactor::One,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::SrcAlpha,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::Zero,
+                dst_alpha: BlendFactor::One,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::SrcAlpha,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::Zero,
+                dst_alpha: BlendFactor::One,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::SrcAlpha,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::Zero,
+                dst_alpha: BlendFactor::One,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+        ],
+    },
+    BlendStateDesc {
+        render_targets: [
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::SrcAlpha,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::Zero,
+                dst_alpha: BlendFactor::One,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::SrcAlpha,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::Zero,
+                dst_alpha: BlendFactor::One,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::SrcAlpha,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::Zero,
+                dst_alpha: BlendFactor::One,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::SrcAlpha,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::Zero,
+                dst_alpha: BlendFactor::One,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+        ],
+    },
+    BlendStateDesc {
+        render_targets: [
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::SrcAlpha,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::Zero,
+                dst_alpha: BlendFactor::One,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::SrcAlpha,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::Zero,
+                dst_alpha: BlendFactor::One,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::SrcAlpha,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::Zero,
+                dst_alpha: BlendFactor::One,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::SrcAlpha,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::Zero,
+                dst_alpha: BlendFactor::One,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+        ],
+    },
+    BlendStateDesc {
+        render_targets: [
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::SrcAlpha,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::Zero,
+                dst_alpha: BlendFactor::One,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::SrcAlpha,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::Zero,
+                dst_alpha: BlendFactor::One,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::SrcAlpha,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::Zero,
+                dst_alpha: BlendFactor::One,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::SrcAlpha,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::Zero,
+                dst_alpha: BlendFactor::One,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+        ],
+    },
+    BlendStateDesc {
+        render_targets: [
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::SrcAlpha,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::Zero,
+                dst_alpha: BlendFactor::One,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::SrcAlpha,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::Zero,
+                dst_alpha: BlendFactor::One,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::SrcAlpha,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::Zero,
+                dst_alpha: BlendFactor::One,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::SrcAlpha,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::Zero,
+                dst_alpha: BlendFactor::One,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+        ],
+    },
+    BlendStateDesc {
+        render_targets: [
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::SrcAlpha,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::Zero,
+                dst_alpha: BlendFactor::One,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::SrcAlpha,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::Zero,
+                dst_alpha: BlendFactor::One,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::SrcAlpha,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::Zero,
+                dst_alpha: BlendFactor::One,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::SrcAlpha,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::Zero,
+                dst_alpha: BlendFactor::One,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+        ],
+    },
+    BlendStateDesc {
+        render_targets: [
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::SrcAlpha,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::Zero,
+                dst_alpha: BlendFactor::One,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::SrcAlpha,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::Zero,
+                dst_alpha: BlendFactor::One,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::SrcAlpha,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::Zero,
+                dst_alpha: BlendFactor::One,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::SrcAlpha,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::Zero,
+                dst_alpha: BlendFactor::One,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+        ],
+    },
+    BlendStateDesc {
+        render_targets: [
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::SrcAlpha,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::Zero,
+                dst_alpha: BlendFactor::One,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::SrcAlpha,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::Zero,
+                dst_alpha: BlendFactor::One,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::SrcAlpha,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::Zero,
+                dst_alpha: BlendFactor::One,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::SrcAlpha,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::Zero,
+                dst_alpha: BlendFactor::One,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+        ],
+    },
+    BlendStateDesc {
+        render_targets: [
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::SrcAlpha,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::Zero,
+                dst_alpha: BlendFactor::One,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::SrcAlpha,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::Zero,
+                dst_alpha: BlendFactor::One,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::SrcAlpha,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::Zero,
+                dst_alpha: BlendFactor::One,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::SrcAlpha,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::Zero,
+                dst_alpha: BlendFactor::One,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+        ],
+    },
+    BlendStateDesc {
+        render_targets: [
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::SrcAlpha,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::Zero,
+                dst_alpha: BlendFactor::One,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::SrcAlpha,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::Zero,
+                dst_alpha: BlendFactor::One,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::SrcAlpha,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::Zero,
+                dst_alpha: BlendFactor::One,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::SrcAlpha,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::Zero,
+                dst_alpha: BlendFactor::One,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+        ],
+    },
+    BlendStateDesc {
+        render_targets: [
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::SrcAlpha,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::Zero,
+                dst_alpha: BlendFactor::One,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::SrcAlpha,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::Zero,
+                dst_alpha: BlendFactor::One,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::SrcAlpha,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::Zero,
+                dst_alpha: BlendFactor::One,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::SrcAlpha,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::Zero,
+                dst_alpha: BlendFactor::One,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+        ],
+    },
+    BlendStateDesc {
+        render_targets: [
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+        ],
+    },
+    BlendStateDesc {
+        render_targets: [
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+        ],
+    },
+    BlendStateDesc {
+        render_targets: [
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+        ],
+    },
+    BlendStateDesc {
+        render_targets: [
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+        ],
+    },
+    BlendStateDesc {
+        render_targets: [
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+        ],
+    },
+    BlendStateDesc {
+        render_targets: [
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+        ],
+    },
+    BlendStateDesc {
+        render_targets: [
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+        ],
+    },
+    BlendStateDesc {
+        render_targets: [
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+        ],
+    },
+    BlendStateDesc {
+        render_targets: [
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+        ],
+    },
+    BlendStateDesc {
+        render_targets: [
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+        ],
+    },
+    BlendStateDesc {
+        render_targets: [
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+        ],
+    },
+    BlendStateDesc {
+        render_targets: [
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+        ],
+    },
+    BlendStateDesc {
+        render_targets: [
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+        ],
+    },
+    BlendStateDesc {
+        render_targets: [
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+        ],
+    },
+    BlendStateDesc {
+        render_targets: [
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+        ],
+    },
+    BlendStateDesc {
+        render_targets: [
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+        ],
+    },
+    BlendStateDesc {
+        render_targets: [
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+        ],
+    },
+    BlendStateDesc {
+        render_targets: [
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+        ],
+    },
+    BlendStateDesc {
+        render_targets: [
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+        ],
+    },
+    BlendStateDesc {
+        render_targets: [
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+        ],
+    },
+    BlendStateDesc {
+        render_targets: [
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+        ],
+    },
+    BlendStateDesc {
+        render_targets: [
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+        ],
+    },
+    BlendStateDesc {
+        render_targets: [
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+        ],
+    },
+    BlendStateDesc {
+        render_targets: [
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+        ],
+    },
+    BlendStateDesc {
+        render_targets: [
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+        ],
+    },
+    BlendStateDesc {
+        render_targets: [
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+        ],
+    },
+    BlendStateDesc {
+        render_targets: [
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+        ],
+    },
+    BlendStateDesc {
+        render_targets: [
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+        ],
+    },
+    BlendStateDesc {
+        render_targets: [
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+        ],
+    },
+    BlendStateDesc {
+        render_targets: [
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+        ],
+    },
+    BlendStateDesc {
+        render_targets: [
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+        ],
+    },
+    BlendStateDesc {
+        render_targets: [
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+        ],
+    },
+    BlendStateDesc {
+        render_targets: [
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+        ],
+    },
+    BlendStateDesc {
+        render_targets: [
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+        ],
+    },
+    BlendStateDesc {
+        render_targets: [
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+        ],
+    },
+    BlendStateDesc {
+        render_targets: [
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+        ],
+    },
+    BlendStateDesc {
+        render_targets: [
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+        ],
+    },
+    BlendStateDesc {
+        render_targets: [
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+        ],
+    },
+    BlendStateDesc {
+        render_targets: [
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+        ],
+    },
+    BlendStateDesc {
+        render_targets: [
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+        ],
+    },
+    BlendStateDesc {
+        render_targets: [
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::DestColor,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::Zero,
+                dst_alpha: BlendFactor::One,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::DestColor,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::Zero,
+                dst_alpha: BlendFactor::One,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::DestColor,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::Zero,
+                dst_alpha: BlendFactor::One,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::DestColor,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::Zero,
+                dst_alpha: BlendFactor::One,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+        ],
+    },
+    BlendStateDesc {
+        render_targets: [
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::DestColor,
+                dst_color: BlendFactor::SrcColor,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::Zero,
+                dst_alpha: BlendFactor::One,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::DestColor,
+                dst_color: BlendFactor::SrcColor,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::Zero,
+                dst_alpha: BlendFactor::One,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::DestColor,
+                dst_color: BlendFactor::SrcColor,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::Zero,
+                dst_alpha: BlendFactor::One,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::DestColor,
+                dst_color: BlendFactor::SrcColor,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::Zero,
+                dst_alpha: BlendFactor::One,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+        ],
+    },
+    BlendStateDesc {
+        render_targets: [
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+        ],
+    },
+    BlendStateDesc {
+        render_targets: [
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::DestAlpha,
+                dst_color: BlendFactor::One,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::Zero,
+                dst_alpha: BlendFactor::One,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::DestAlpha,
+                dst_color: BlendFactor::One,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::Zero,
+                dst_alpha: BlendFactor::One,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::DestAlpha,
+                dst_color: BlendFactor::One,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::Zero,
+                dst_alpha: BlendFactor::One,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::DestAlpha,
+                dst_color: BlendFactor::One,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::Zero,
+                dst_alpha: BlendFactor::One,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+        ],
+    },
+    BlendStateDesc {
+        render_targets: [
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::InvDestAlpha,
+                dst_color: BlendFactor::One,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::Zero,
+                dst_alpha: BlendFactor::One,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::InvDestAlpha,
+                dst_color: BlendFactor::One,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::Zero,
+                dst_alpha: BlendFactor::One,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::InvDestAlpha,
+                dst_color: BlendFactor::One,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::Zero,
+                dst_alpha: BlendFactor::One,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::InvDestAlpha,
+                dst_color: BlendFactor::One,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::Zero,
+                dst_alpha: BlendFactor::One,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+        ],
+    },
+    BlendStateDesc {
+        render_targets: [
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::Zero,
+                dst_color: BlendFactor::DestAlpha,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::Zero,
+                dst_alpha: BlendFactor::One,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::Zero,
+                dst_color: BlendFactor::DestAlpha,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::Zero,
+                dst_alpha: BlendFactor::One,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::Zero,
+                dst_color: BlendFactor::DestAlpha,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::Zero,
+                dst_alpha: BlendFactor::One,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::Zero,
+                dst_color: BlendFactor::DestAlpha,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::Zero,
+                dst_alpha: BlendFactor::One,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+        ],
+    },
+    BlendStateDesc {
+        render_targets: [
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::Zero,
+                dst_color: BlendFactor::InvDestAlpha,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::Zero,
+                dst_alpha: BlendFactor::One,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::Zero,
+                dst_color: BlendFactor::InvDestAlpha,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::Zero,
+                dst_alpha: BlendFactor::One,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::Zero,
+                dst_color: BlendFactor::InvDestAlpha,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::Zero,
+                dst_alpha: BlendFactor::One,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::Zero,
+                dst_color: BlendFactor::InvDestAlpha,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::Zero,
+                dst_alpha: BlendFactor::One,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+        ],
+    },
+    BlendStateDesc {
+        render_targets: [
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::Zero,
+                dst_color: BlendFactor::InvSrcAlpha,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::InvSrcAlpha,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::Zero,
+                dst_color: BlendFactor::InvSrcAlpha,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::InvSrcAlpha,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::Zero,
+                dst_color: BlendFactor::InvSrcAlpha,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::InvSrcAlpha,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::Zero,
+                dst_color: BlendFactor::InvSrcAlpha,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::InvSrcAlpha,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+        ],
+    },
+    BlendStateDesc {
+        render_targets: [
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::InvSrcAlpha,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::InvSrcAlpha,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::InvSrcAlpha,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::InvSrcAlpha,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::InvSrcAlpha,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::InvSrcAlpha,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::InvSrcAlpha,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::InvSrcAlpha,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+        ],
+    },
+    BlendStateDesc {
+        render_targets: [
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::InvSrcAlpha,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::InvSrcAlpha,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::InvSrcAlpha,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::InvSrcAlpha,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::InvSrcAlpha,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::InvSrcAlpha,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::InvSrcAlpha,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::InvSrcAlpha,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+        ],
+    },
+    BlendStateDesc {
+        render_targets: [
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::Src1Color,
+                dst_color: BlendFactor::InvSrc1Color,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::Src1Alpha,
+                dst_alpha: BlendFactor::InvSrc1Alpha,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+            RenderTargetBlendDesc {
+                enabled: false,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::Zero,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::Zero,
+                alpha_op: BlendOp::Add,
+                write_mask: 0,
+            },
+        ],
+    },
+    BlendStateDesc {
+        render_targets: [
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::InvSrcAlpha,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::Zero,
+                dst_alpha: BlendFactor::InvSrcAlpha,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::InvSrcAlpha,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::Zero,
+                dst_alpha: BlendFactor::InvSrcAlpha,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::One,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::One,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::One,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::One,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+        ],
+    },
+    BlendStateDesc {
+        render_targets: [
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::InvSrcAlpha,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::Zero,
+                dst_alpha: BlendFactor::InvSrcAlpha,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::InvSrcAlpha,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::Zero,
+                dst_alpha: BlendFactor::InvSrcAlpha,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::Zero,
+                dst_color: BlendFactor::InvSrcAlpha,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::Zero,
+                dst_alpha: BlendFactor::InvSrcAlpha,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::Zero,
+                dst_color: BlendFactor::InvSrcAlpha,
+                color_op: BlendOp::Add,
+                src_alpha: BlendFactor::Zero,
+                dst_alpha: BlendFactor::InvSrcAlpha,
+                alpha_op: BlendOp::Add,
+                write_mask: 0xf,
+            },
+        ],
+    },
+    BlendStateDesc {
+        render_targets: [
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::One,
+                color_op: BlendOp::RevSubtract,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::One,
+                alpha_op: BlendOp::RevSubtract,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::One,
+                color_op: BlendOp::RevSubtract,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::One,
+                alpha_op: BlendOp::RevSubtract,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::One,
+                color_op: BlendOp::RevSubtract,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::One,
+                alpha_op: BlendOp::RevSubtract,
+                write_mask: 0xf,
+            },
+            RenderTargetBlendDesc {
+                enabled: true,
+                src_color: BlendFactor::One,
+                dst_color: BlendFactor::One,
+                color_op: BlendOp::RevSubtract,
+                src_alpha: BlendFactor::One,
+                dst_alpha: BlendFactor::One,
+                alpha_op: BlendOp::RevSubtract,
+                write_mask: 0xf,
+            },
+        ],
+    },
+];
+//endregion
+
+//region Input layouts
+pub const INPUT_LAYOUTS: [InputLayout; 77] = [
+    // Layout 0
+    InputLayout {
+        elements: &[InputLayoutElement {
+            hlsl_type: "float3",
+            format: DxgiFormat::R32G32B32_FLOAT,
+            stride: 12,
+            semantic_name: c"POSITION",
+            semantic_index: 0,
+            buffer_index: 0,
+            is_instance_data: false,
+        }],
+    },
+    // Layout 1
+    InputLayout {
+        elements: &[InputLayoutElement {
+            hlsl_type: "float3",
+            format: DxgiFormat::R32G32B32_FLOAT,
+            stride: 12,
+            semantic_name: c"POSITION",
+            semantic_index: 0,
+            buffer_index: 0,
+            is_instance_data: false,
+        }],
+    },
+    // Layout 2
+    InputLayout {
+        elements: &[
+            InputLayoutElement {
+                hlsl_type: "float2",
+                format: DxgiFormat::R32G32_FLOAT,
+                stride: 8,
+                semantic_name: c"POSITION",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float2",
+                format: DxgiFormat::R32G32_FLOAT,
+                stride: 8,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R8G8B8A8_UNORM,
+                stride: 4,
+                semantic_name: c"COLOR",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+        ],
+    },
+    // Layout 3
+    InputLayout {
+        elements: &[
+            InputLayoutElement {
+                hlsl_type: "float3",
+                format: DxgiFormat::R32G32B32_FLOAT,
+                stride: 12,
+                semantic_name: c"POSITION",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float2",
+                format: DxgiFormat::R32G32_FLOAT,
+                stride: 8,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R8G8B8A8_UNORM,
+                stride: 4,
+                semantic_name: c"COLOR",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+        ],
+    },
+    // Layout 4
+    InputLayout {
+        elements: &[
+            InputLayoutElement {
+                hlsl_type: "float3",
+                format: DxgiFormat::R32G32B32_FLOAT,
+                stride: 12,
+                semantic_name: c"POSITION",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R8G8B8A8_UNORM,
+                stride: 4,
+                semantic_name: c"COLOR",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+        ],
+    },
+    // Layout 5
+    InputLayout {
+        elements: &[
+            InputLayoutElement {
+                hlsl_type: "float2",
+                format: DxgiFormat::R32G32_FLOAT,
+                stride: 8,
+                semantic_name: c"POSITION",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float2",
+                format: DxgiFormat::R32G32_FLOAT,
+                stride: 8,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+        ],
+    },
+    // Layout 6
+    InputLayout {
+        elements: &[
+            InputLayoutElement {
+                hlsl_type: "float3",
+                format: DxgiFormat::R32G32B32_FLOAT,
+                stride: 12,
+                semantic_name: c"POSITION",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float3",
+                format: DxgiFormat::R32G32B32_FLOAT,
+                stride: 12,
+                semantic_name: c"NORMAL",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TANGENT",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float2",
+                format: DxgiFormat::R32G32_FLOAT,
+                stride: 8,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+        ],
+    },
+    // Layout 7
+    InputLayout {
+        elements: &[
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R16G16B16A16_SNORM,
+                stride: 8,
+                semantic_name: c"POSITION",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R16G16B16A16_SNORM,
+                stride: 8,
+                semantic_name: c"NORMAL",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R16G16B16A16_SNORM,
+                stride: 8,
+                semantic_name: c"TANGENT",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float2",
+                format: DxgiFormat::R16G16_SNORM,
+                stride: 4,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 0,
+                buffer_index: 1,
+                is_instance_data: false,
+            },
+        ],
+    },
+    // Layout 8
+    InputLayout {
+        elements: &[
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R16G16B16A16_SNORM,
+                stride: 8,
+                semantic_name: c"POSITION",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R16G16B16A16_SNORM,
+                stride: 8,
+                semantic_name: c"TANGENT",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float2",
+                format: DxgiFormat::R16G16_SNORM,
+                stride: 4,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 0,
+                buffer_index: 1,
+                is_instance_data: false,
+            },
+        ],
+    },
+    // Layout 9
+    InputLayout {
+        elements: &[
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R16G16B16A16_SNORM,
+                stride: 8,
+                semantic_name: c"POSITION",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float2",
+                format: DxgiFormat::R16G16_SNORM,
+                stride: 4,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R16G16B16A16_SNORM,
+                stride: 8,
+                semantic_name: c"NORMAL",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R16G16B16A16_SNORM,
+                stride: 8,
+                semantic_name: c"TANGENT",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R8G8B8A8_UNORM,
+                stride: 4,
+                semantic_name: c"COLOR",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+        ],
+    },
+    // Layout 10
+    InputLayout {
+        elements: &[
+            InputLayoutElement {
+                hlsl_type: "float3",
+                format: DxgiFormat::R32G32B32_FLOAT,
+                stride: 12,
+                semantic_name: c"POSITION",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R16G16B16A16_SNORM,
+                stride: 8,
+                semantic_name: c"NORMAL",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float2",
+                format: DxgiFormat::R32G32_FLOAT,
+                stride: 8,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 1,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+        ],
+    },
+    // Layout 11
+    InputLayout {
+        elements: &[
+            InputLayoutElement {
+                hlsl_type: "float2",
+                format: DxgiFormat::R32G32_FLOAT,
+                stride: 8,
+                semantic_name: c"POSITION",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float3",
+                format: DxgiFormat::R32G32B32_FLOAT,
+                stride: 12,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+        ],
+    },
+    // Layout 12
+    InputLayout {
+        elements: &[
+            InputLayoutElement {
+                hlsl_type: "float3",
+                format: DxgiFormat::R32G32B32_FLOAT,
+                stride: 12,
+                semantic_name: c"POSITION",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float2",
+                format: DxgiFormat::R32G32_FLOAT,
+                stride: 8,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float3",
+                format: DxgiFormat::R32G32B32_FLOAT,
+                stride: 12,
+                semantic_name: c"NORMAL",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+        ],
+    },
+    // Layout 13
+    InputLayout {
+        elements: &[
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"POSITION",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"NORMAL",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TANGENT",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float2",
+                format: DxgiFormat::R16G16_SNORM,
+                stride: 4,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 0,
+                buffer_index: 1,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R8G8B8A8_UNORM,
+                stride: 4,
+                semantic_name: c"BLENDWEIGHT",
+                semantic_index: 0,
+                buffer_index: 2,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "uint4",
+                format: DxgiFormat::R8G8B8A8_UINT,
+                stride: 4,
+                semantic_name: c"BLENDINDICES",
+                semantic_index: 0,
+                buffer_index: 2,
+                is_instance_data: false,
+            },
+        ],
+    },
+    // Layout 14
+    InputLayout {
+        elements: &[
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"POSITION",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float3",
+                format: DxgiFormat::R32G32B32_FLOAT,
+                stride: 12,
+                semantic_name: c"NORMAL",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float3",
+                format: DxgiFormat::R32G32B32_FLOAT,
+                stride: 12,
+                semantic_name: c"TANGENT",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 1,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 2,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float3",
+                format: DxgiFormat::R32G32B32_FLOAT,
+                stride: 12,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 3,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float3",
+                format: DxgiFormat::R32G32B32_FLOAT,
+                stride: 12,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 4,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R16G16B16A16_SNORM,
+                stride: 8,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 5,
+                buffer_index: 1,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R8G8B8A8_UNORM,
+                stride: 4,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 6,
+                buffer_index: 1,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R8G8B8A8_UNORM,
+                stride: 4,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 7,
+                buffer_index: 1,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"BLENDINDICES",
+                semantic_index: 0,
+                buffer_index: 2,
+                is_instance_data: false,
+            },
+        ],
+    },
+    // Layout 15
+    InputLayout {
+        elements: &[
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"POSITION",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float2",
+                format: DxgiFormat::R32G32_FLOAT,
+                stride: 8,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R16G16B16A16_SNORM,
+                stride: 8,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 5,
+                buffer_index: 1,
+                is_instance_data: true,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R8G8B8A8_UNORM,
+                stride: 4,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 6,
+                buffer_index: 1,
+                is_instance_data: true,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R8G8B8A8_UNORM,
+                stride: 4,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 7,
+                buffer_index: 1,
+                is_instance_data: true,
+            },
+            InputLayoutElement {
+                hlsl_type: "float2",
+                format: DxgiFormat::R32G32_FLOAT,
+                stride: 8,
+                semantic_name: c"BINORMAL",
+                semantic_index: 0,
+                buffer_index: 2,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"BLENDINDICES",
+                semantic_index: 0,
+                buffer_index: 3,
+                is_instance_data: false,
+            },
+        ],
+    },
+    // Layout 16
+    InputLayout {
+        elements: &[InputLayoutElement {
+            hlsl_type: "float3",
+            format: DxgiFormat::R32G32B32_FLOAT,
+            stride: 12,
+            semantic_name: c"POSITION",
+            semantic_index: 0,
+            buffer_index: 0,
+            is_instance_data: false,
+        }],
+    },
+    // Layout 17
+    InputLayout {
+        elements: &[
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R8G8B8A8_UNORM,
+                stride: 4,
+                semantic_name: c"POSITION",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float3",
+                format: DxgiFormat::R32G32B32_FLOAT,
+                stride: 12,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 0,
+                buffer_index: 1,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R16G16B16A16_SNORM,
+                stride: 8,
+                semantic_name: c"NORMAL",
+                semantic_index: 0,
+                buffer_index: 1,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R16G16B16A16_FLOAT,
+                stride: 8,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 1,
+                buffer_index: 1,
+                is_instance_data: false,
+            },
+        ],
+    },
+    // Layout 18
+    InputLayout {
+        elements: &[
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R16G16B16A16_SNORM,
+                stride: 8,
+                semantic_name: c"POSITION",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float2",
+                format: DxgiFormat::R16G16_SNORM,
+                stride: 4,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R16G16B16A16_SNORM,
+                stride: 8,
+                semantic_name: c"NORMAL",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R16G16B16A16_SNORM,
+                stride: 8,
+                semantic_name: c"TANGENT",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R16G16B16A16_SNORM,
+                stride: 8,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 5,
+                buffer_index: 1,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R8G8B8A8_UNORM,
+                stride: 4,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 6,
+                buffer_index: 1,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R8G8B8A8_UNORM,
+                stride: 4,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 7,
+                buffer_index: 1,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R8G8B8A8_UNORM,
+                stride: 4,
+                semantic_name: c"BLENDINDICES",
+                semantic_index: 0,
+                buffer_index: 3,
+                is_instance_data: false,
+            },
+        ],
+    },
+    // Layout 19
+    InputLayout {
+        elements: &[
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R16G16B16A16_SNORM,
+                stride: 8,
+                semantic_name: c"POSITION",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R8G8B8A8_UNORM,
+                stride: 4,
+                semantic_name: c"NORMAL",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R8G8B8A8_UNORM,
+                stride: 4,
+                semantic_name: c"TANGENT",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float2",
+                format: DxgiFormat::R16G16_SNORM,
+                stride: 4,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R16G16B16A16_FLOAT,
+                stride: 8,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 1,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R16G16B16A16_SNORM,
+                stride: 8,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 5,
+                buffer_index: 1,
+                is_instance_data: true,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R8G8B8A8_UNORM,
+                stride: 4,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 6,
+                buffer_index: 1,
+                is_instance_data: true,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R8G8B8A8_UNORM,
+                stride: 4,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 7,
+                buffer_index: 1,
+                is_instance_data: true,
+            },
+            InputLayoutElement {
+                hlsl_type: "float2",
+                format: DxgiFormat::R32G32_FLOAT,
+                stride: 8,
+                semantic_name: c"BINORMAL",
+                semantic_index: 0,
+                buffer_index: 2,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"BLENDINDICES",
+                semantic_index: 0,
+                buffer_index: 3,
+                is_instance_data: false,
+            },
+        ],
+    },
+    // Layout 20
+    InputLayout {
+        elements: &[
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R16G16B16A16_SNORM,
+                stride: 8,
+                semantic_name: c"POSITION",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R8G8B8A8_UNORM,
+                stride: 4,
+                semantic_name: c"NORMAL",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R8G8B8A8_UNORM,
+                stride: 4,
+                semantic_name: c"TANGENT",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float2",
+                format: DxgiFormat::R16G16_SNORM,
+                stride: 4,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R16G16B16A16_FLOAT,
+                stride: 8,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 1,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R16G16B16A16_FLOAT,
+                stride: 8,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 2,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R8G8B8A8_UNORM,
+                stride: 4,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 3,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R16G16B16A16_SNORM,
+                stride: 8,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 5,
+                buffer_index: 1,
+                is_instance_data: true,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R8G8B8A8_UNORM,
+                stride: 4,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 6,
+                buffer_index: 1,
+                is_instance_data: true,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R8G8B8A8_UNORM,
+                stride: 4,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 7,
+                buffer_index: 1,
+                is_instance_data: true,
+            },
+            InputLayoutElement {
+                hlsl_type: "float2",
+                format: DxgiFormat::R32G32_FLOAT,
+                stride: 8,
+                semantic_name: c"BINORMAL",
+                semantic_index: 0,
+                buffer_index: 2,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"BLENDINDICES",
+                semantic_index: 0,
+                buffer_index: 3,
+                is_instance_data: false,
+            },
+        ],
+    },
+    // Layout 21
+    InputLayout {
+        elements: &[
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R8G8B8A8_UNORM,
+                stride: 4,
+                semantic_name: c"POSITION",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float3",
+                format: DxgiFormat::R32G32B32_FLOAT,
+                stride: 12,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 0,
+                buffer_index: 1,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"NORMAL",
+                semantic_index: 0,
+                buffer_index: 1,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 1,
+                buffer_index: 1,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float3",
+                format: DxgiFormat::R32G32B32_FLOAT,
+                stride: 12,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 2,
+                buffer_index: 1,
+                is_instance_data: false,
+            },
+        ],
+    },
+    // Layout 22
+    InputLayout {
+        elements: &[
+            InputLayoutElement {
+                hlsl_type: "int4",
+                format: DxgiFormat::R16G16B16A16_SINT,
+                stride: 8,
+                semantic_name: c"POSITION",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R16G16B16A16_SNORM,
+                stride: 8,
+                semantic_name: c"NORMAL",
+                semantic_index: 0,
+                buffer_index: 1,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float2",
+                format: DxgiFormat::R16G16_FLOAT,
+                stride: 4,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 1,
+                buffer_index: 1,
+                is_instance_data: false,
+            },
+        ],
+    },
+    // Layout 23
+    InputLayout {
+        elements: &[InputLayoutElement {
+            hlsl_type: "int4",
+            format: DxgiFormat::R16G16B16A16_SINT,
+            stride: 8,
+            semantic_name: c"POSITION",
+            semantic_index: 0,
+            buffer_index: 0,
+            is_instance_data: false,
+        }],
+    },
+    // Layout 24
+    InputLayout {
+        elements: &[
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R16G16B16A16_SNORM,
+                stride: 8,
+                semantic_name: c"POSITION",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R8G8B8A8_UNORM,
+                stride: 4,
+                semantic_name: c"NORMAL",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float2",
+                format: DxgiFormat::R16G16_SNORM,
+                stride: 4,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R16G16B16A16_SNORM,
+                stride: 8,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 5,
+                buffer_index: 1,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R8G8B8A8_UNORM,
+                stride: 4,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 6,
+                buffer_index: 1,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R8G8B8A8_UNORM,
+                stride: 4,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 7,
+                buffer_index: 1,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float2",
+                format: DxgiFormat::R32G32_FLOAT,
+                stride: 8,
+                semantic_name: c"BINORMAL",
+                semantic_index: 0,
+                buffer_index: 2,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"BLENDINDICES",
+                semantic_index: 0,
+                buffer_index: 3,
+                is_instance_data: false,
+            },
+        ],
+    },
+    // Layout 25
+    InputLayout {
+        elements: &[
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R16G16B16A16_SNORM,
+                stride: 8,
+                semantic_name: c"POSITION",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R8G8B8A8_UNORM,
+                stride: 4,
+                semantic_name: c"NORMAL",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float2",
+                format: DxgiFormat::R16G16_SNORM,
+                stride: 4,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R16G16B16A16_SNORM,
+                stride: 8,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 5,
+                buffer_index: 1,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R8G8B8A8_UNORM,
+                stride: 4,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 6,
+                buffer_index: 1,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R8G8B8A8_UNORM,
+                stride: 4,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 7,
+                buffer_index: 1,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float2",
+                format: DxgiFormat::R32G32_FLOAT,
+                stride: 8,
+                semantic_name: c"BINORMAL",
+                semantic_index: 0,
+                buffer_index: 2,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"BLENDINDICES",
+                semantic_index: 0,
+                buffer_index: 3,
+                is_instance_data: false,
+            },
+        ],
+    },
+    // Layout 26
+    InputLayout {
+        elements: &[
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R16G16B16A16_SNORM,
+                stride: 8,
+                semantic_name: c"POSITION",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float2",
+                format: DxgiFormat::R16G16_SNORM,
+                stride: 4,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R16G16B16A16_SNORM,
+                stride: 8,
+                semantic_name: c"NORMAL",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R16G16B16A16_SNORM,
+                stride: 8,
+                semantic_name: c"TANGENT",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R8G8B8A8_UNORM,
+                stride: 4,
+                semantic_name: c"COLOR",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R16G16B16A16_SNORM,
+                stride: 8,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 5,
+                buffer_index: 1,
+                is_instance_data: true,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R8G8B8A8_UNORM,
+                stride: 4,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 6,
+                buffer_index: 1,
+                is_instance_data: true,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R8G8B8A8_UNORM,
+                stride: 4,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 7,
+                buffer_index: 1,
+                is_instance_data: true,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R8G8B8A8_UNORM,
+                stride: 4,
+                semantic_name: c"BLENDINDICES",
+                semantic_index: 0,
+                buffer_index: 3,
+                is_instance_data: false,
+            },
+        ],
+    },
+    // Layout 27
+    InputLayout {
+        elements: &[InputLayoutElement {
+            hlsl_type: "float4",
+            format: DxgiFormat::R32G32B32A32_FLOAT,
+            stride: 16,
+            semantic_name: c"TEXCOORD",
+            semantic_index: 0,
+            buffer_index: 0,
+            is_instance_data: false,
+        }],
+    },
+    // Layout 28
+    InputLayout {
+        elements: &[
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 1,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+        ],
+    },
+    // Layout 29
+    InputLayout {
+        elements: &[
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 1,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 2,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+        ],
+    },
+    // Layout 30
+    InputLayout {
+        elements: &[
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 1,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 2,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 3,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+        ],
+    },
+    // Layout 31
+    InputLayout {
+        elements: &[
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 1,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 2,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 3,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 4,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+        ],
+    },
+    // Layout 32
+    InputLayout {
+        elements: &[
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 1,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 2,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 3,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 4,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 5,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+        ],
+    },
+    // Layout 33
+    InputLayout {
+        elements: &[
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 1,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 2,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 3,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 4,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 5,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 6,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+        ],
+    },
+    // Layout 34
+    InputLayout {
+        elements: &[
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 1,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 2,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 3,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 4,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 5,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 6,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 7,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+        ],
+    },
+    // Layout 35
+    InputLayout {
+        elements: &[
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 1,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 2,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 3,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 4,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 5,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 6,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 7,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 8,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+        ],
+    },
+    // Layout 36
+    InputLayout {
+        elements: &[
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 1,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 2,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 3,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 4,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 5,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 6,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 7,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 8,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 9,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+        ],
+    },
+    // Layout 37
+    InputLayout {
+        elements: &[
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 1,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 2,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 3,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 4,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 5,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 6,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 7,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 8,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 9,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 10,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+        ],
+    },
+    // Layout 38
+    InputLayout {
+        elements: &[
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 1,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 2,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 3,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 4,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 5,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 6,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 7,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 8,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 9,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 10,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 11,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+        ],
+    },
+    // Layout 39
+    InputLayout {
+        elements: &[
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 1,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 2,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 3,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 4,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 5,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 6,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 7,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 8,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 9,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 10,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 11,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 12,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+        ],
+    },
+    // Layout 40
+    InputLayout {
+        elements: &[
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 1,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 2,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 3,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 4,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 5,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 6,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 7,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 8,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 9,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 10,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 11,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 12,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 13,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+        ],
+    },
+    // Layout 41
+    InputLayout {
+        elements: &[
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 1,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 2,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 3,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 4,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 5,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 6,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 7,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 8,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 9,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 10,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 11,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 12,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 13,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 14,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+        ],
+    },
+    // Layout 42
+    InputLayout {
+        elements: &[
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 1,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 2,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 3,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 4,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 5,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 6,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 7,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 8,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 9,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 10,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 11,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 12,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 13,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 14,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 15,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+        ],
+    },
+    // Layout 43
+    InputLayout {
+        elements: &[InputLayoutElement {
+            hlsl_type: "float4",
+            format: DxgiFormat::R32G32B32A32_FLOAT,
+            stride: 16,
+            semantic_name: c"TEXCOORD",
+            semantic_index: 0,
+            buffer_index: 0,
+            is_instance_data: false,
+        }],
+    },
+    // Layout 44
+    InputLayout {
+        elements: &[
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 1,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+        ],
+    },
+    // Layout 45
+    InputLayout {
+        elements: &[
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 1,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 2,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+        ],
+    },
+    // Layout 46
+    InputLayout {
+        elements: &[
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 1,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 2,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 3,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+        ],
+    },
+    // Layout 47
+    InputLayout {
+        elements: &[
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 1,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 2,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 3,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 4,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+        ],
+    },
+    // Layout 48
+    InputLayout {
+        elements: &[
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 1,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 2,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 3,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 4,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 5,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+        ],
+    },
+    // Layout 49
+    InputLayout {
+        elements: &[
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 1,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 2,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 3,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 4,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 5,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 6,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+        ],
+    },
+    // Layout 50
+    InputLayout {
+        elements: &[
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 1,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 2,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 3,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 4,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 5,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 6,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 7,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+        ],
+    },
+    // Layout 51
+    InputLayout {
+        elements: &[
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 1,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 2,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 3,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 4,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 5,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 6,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 7,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 8,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+        ],
+    },
+    // Layout 52
+    InputLayout {
+        elements: &[
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 1,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 2,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 3,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 4,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 5,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 6,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 7,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 8,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 9,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+        ],
+    },
+    // Layout 53
+    InputLayout {
+        elements: &[
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 1,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 2,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 3,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 4,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 5,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 6,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 7,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 8,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 9,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 10,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+        ],
+    },
+    // Layout 54
+    InputLayout {
+        elements: &[
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 1,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 2,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 3,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 4,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 5,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 6,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 7,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 8,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 9,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 10,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 11,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+        ],
+    },
+    // Layout 55
+    InputLayout {
+        elements: &[
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 1,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 2,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 3,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 4,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 5,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 6,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 7,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 8,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 9,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 10,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 11,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 12,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+        ],
+    },
+    // Layout 56
+    InputLayout {
+        elements: &[
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 1,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 2,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 3,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 4,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 5,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 6,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 7,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 8,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 9,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 10,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 11,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 12,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 13,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+        ],
+    },
+    // Layout 57
+    InputLayout {
+        elements: &[
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 1,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 2,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 3,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 4,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 5,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 6,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 7,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 8,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 9,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 10,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 11,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 12,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 13,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 14,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+        ],
+    },
+    // Layout 58
+    InputLayout {
+        elements: &[
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 1,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 2,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 3,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 4,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 5,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 6,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 7,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 8,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 9,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 10,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 11,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 12,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 13,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 14,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 15,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+        ],
+    },
+    // Layout 59
+    InputLayout {
+        elements: &[
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float3",
+                format: DxgiFormat::R32G32B32_FLOAT,
+                stride: 12,
+                semantic_name: c"POSITION",
+                semantic_index: 0,
+                buffer_index: 1,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float2",
+                format: DxgiFormat::R32G32_FLOAT,
+                stride: 8,
+                semantic_name: c"POSITION",
+                semantic_index: 1,
+                buffer_index: 1,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float3",
+                format: DxgiFormat::R32G32B32_FLOAT,
+                stride: 12,
+                semantic_name: c"POSITION",
+                semantic_index: 2,
+                buffer_index: 1,
+                is_instance_data: false,
+            },
+        ],
+    },
+    // Layout 60
+    InputLayout {
+        elements: &[
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 1,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float3",
+                format: DxgiFormat::R32G32B32_FLOAT,
+                stride: 12,
+                semantic_name: c"POSITION",
+                semantic_index: 0,
+                buffer_index: 1,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float2",
+                format: DxgiFormat::R32G32_FLOAT,
+                stride: 8,
+                semantic_name: c"POSITION",
+                semantic_index: 1,
+                buffer_index: 1,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float3",
+                format: DxgiFormat::R32G32B32_FLOAT,
+                stride: 12,
+                semantic_name: c"POSITION",
+                semantic_index: 2,
+                buffer_index: 1,
+                is_instance_data: false,
+            },
+        ],
+    },
+    // Layout 61
+    InputLayout {
+        elements: &[
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 1,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 2,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float3",
+                format: DxgiFormat::R32G32B32_FLOAT,
+                stride: 12,
+                semantic_name: c"POSITION",
+                semantic_index: 0,
+                buffer_index: 1,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float2",
+                format: DxgiFormat::R32G32_FLOAT,
+                stride: 8,
+                semantic_name: c"POSITION",
+                semantic_index: 1,
+                buffer_index: 1,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float3",
+                format: DxgiFormat::R32G32B32_FLOAT,
+                stride: 12,
+                semantic_name: c"POSITION",
+                semantic_index: 2,
+                buffer_index: 1,
+                is_instance_data: false,
+            },
+        ],
+    },
+    // Layout 62
+    InputLayout {
+        elements: &[
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 1,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 2,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 3,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float3",
+                format: DxgiFormat::R32G32B32_FLOAT,
+                stride: 12,
+                semantic_name: c"POSITION",
+                semantic_index: 0,
+                buffer_index: 1,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float2",
+                format: DxgiFormat::R32G32_FLOAT,
+                stride: 8,
+                semantic_name: c"POSITION",
+                semantic_index: 1,
+                buffer_index: 1,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float3",
+                format: DxgiFormat::R32G32B32_FLOAT,
+                stride: 12,
+                semantic_name: c"POSITION",
+                semantic_index: 2,
+                buffer_index: 1,
+                is_instance_data: false,
+            },
+        ],
+    },
+    // Layout 63
+    InputLayout {
+        elements: &[
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 1,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 2,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 3,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 4,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float3",
+                format: DxgiFormat::R32G32B32_FLOAT,
+                stride: 12,
+                semantic_name: c"POSITION",
+                semantic_index: 0,
+                buffer_index: 1,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float2",
+                format: DxgiFormat::R32G32_FLOAT,
+                stride: 8,
+                semantic_name: c"POSITION",
+                semantic_index: 1,
+                buffer_index: 1,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float3",
+                format: DxgiFormat::R32G32B32_FLOAT,
+                stride: 12,
+                semantic_name: c"POSITION",
+                semantic_index: 2,
+                buffer_index: 1,
+                is_instance_data: false,
+            },
+        ],
+    },
+    // Layout 64
+    InputLayout {
+        elements: &[
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 1,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 2,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 3,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 4,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 5,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float3",
+                format: DxgiFormat::R32G32B32_FLOAT,
+                stride: 12,
+                semantic_name: c"POSITION",
+                semantic_index: 0,
+                buffer_index: 1,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float2",
+                format: DxgiFormat::R32G32_FLOAT,
+                stride: 8,
+                semantic_name: c"POSITION",
+                semantic_index: 1,
+                buffer_index: 1,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float3",
+                format: DxgiFormat::R32G32B32_FLOAT,
+                stride: 12,
+                semantic_name: c"POSITION",
+                semantic_index: 2,
+                buffer_index: 1,
+                is_instance_data: false,
+            },
+        ],
+    },
+    // Layout 65
+    InputLayout {
+        elements: &[
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 1,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 2,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 3,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 4,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 5,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 6,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float3",
+                format: DxgiFormat::R32G32B32_FLOAT,
+                stride: 12,
+                semantic_name: c"POSITION",
+                semantic_index: 0,
+                buffer_index: 1,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float2",
+                format: DxgiFormat::R32G32_FLOAT,
+                stride: 8,
+                semantic_name: c"POSITION",
+                semantic_index: 1,
+                buffer_index: 1,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float3",
+                format: DxgiFormat::R32G32B32_FLOAT,
+                stride: 12,
+                semantic_name: c"POSITION",
+                semantic_index: 2,
+                buffer_index: 1,
+                is_instance_data: false,
+            },
+        ],
+    },
+    // Layout 66
+    InputLayout {
+        elements: &[
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 1,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 2,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 3,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 4,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 5,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 6,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 7,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float3",
+                format: DxgiFormat::R32G32B32_FLOAT,
+                stride: 12,
+                semantic_name: c"POSITION",
+                semantic_index: 0,
+                buffer_index: 1,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float2",
+                format: DxgiFormat::R32G32_FLOAT,
+                stride: 8,
+                semantic_name: c"POSITION",
+                semantic_index: 1,
+                buffer_index: 1,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float3",
+                format: DxgiFormat::R32G32B32_FLOAT,
+                stride: 12,
+                semantic_name: c"POSITION",
+                semantic_index: 2,
+                buffer_index: 1,
+                is_instance_data: false,
+            },
+        ],
+    },
+    // Layout 67
+    InputLayout {
+        elements: &[
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 1,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 2,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 3,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 4,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 5,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 6,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 7,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 8,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float3",
+                format: DxgiFormat::R32G32B32_FLOAT,
+                stride: 12,
+                semantic_name: c"POSITION",
+                semantic_index: 0,
+                buffer_index: 1,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float2",
+                format: DxgiFormat::R32G32_FLOAT,
+                stride: 8,
+                semantic_name: c"POSITION",
+                semantic_index: 1,
+                buffer_index: 1,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float3",
+                format: DxgiFormat::R32G32B32_FLOAT,
+                stride: 12,
+                semantic_name: c"POSITION",
+                semantic_index: 2,
+                buffer_index: 1,
+                is_instance_data: false,
+            },
+        ],
+    },
+    // Layout 68
+    InputLayout {
+        elements: &[
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 1,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 2,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 3,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 4,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 5,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 6,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 7,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 8,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 9,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float3",
+                format: DxgiFormat::R32G32B32_FLOAT,
+                stride: 12,
+                semantic_name: c"POSITION",
+                semantic_index: 0,
+                buffer_index: 1,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float2",
+                format: DxgiFormat::R32G32_FLOAT,
+                stride: 8,
+                semantic_name: c"POSITION",
+                semantic_index: 1,
+                buffer_index: 1,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float3",
+                format: DxgiFormat::R32G32B32_FLOAT,
+                stride: 12,
+                semantic_name: c"POSITION",
+                semantic_index: 2,
+                buffer_index: 1,
+                is_instance_data: false,
+            },
+        ],
+    },
+    // Layout 69
+    InputLayout {
+        elements: &[
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 1,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 2,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 3,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 4,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 5,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 6,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 7,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 8,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 9,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 10,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float3",
+                format: DxgiFormat::R32G32B32_FLOAT,
+                stride: 12,
+                semantic_name: c"POSITION",
+                semantic_index: 0,
+                buffer_index: 1,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float2",
+                format: DxgiFormat::R32G32_FLOAT,
+                stride: 8,
+                semantic_name: c"POSITION",
+                semantic_index: 1,
+                buffer_index: 1,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float3",
+                format: DxgiFormat::R32G32B32_FLOAT,
+                stride: 12,
+                semantic_name: c"POSITION",
+                semantic_index: 2,
+                buffer_index: 1,
+                is_instance_data: false,
+            },
+        ],
+    },
+    // Layout 70
+    InputLayout {
+        elements: &[
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 1,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 2,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 3,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 4,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 5,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 6,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 7,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 8,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 9,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 10,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 11,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float3",
+                format: DxgiFormat::R32G32B32_FLOAT,
+                stride: 12,
+                semantic_name: c"POSITION",
+                semantic_index: 0,
+                buffer_index: 1,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float2",
+                format: DxgiFormat::R32G32_FLOAT,
+                stride: 8,
+                semantic_name: c"POSITION",
+                semantic_index: 1,
+                buffer_index: 1,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float3",
+                format: DxgiFormat::R32G32B32_FLOAT,
+                stride: 12,
+                semantic_name: c"POSITION",
+                semantic_index: 2,
+                buffer_index: 1,
+                is_instance_data: false,
+            },
+        ],
+    },
+    // Layout 71
+    InputLayout {
+        elements: &[
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 1,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 2,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 3,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 4,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 5,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 6,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 7,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 8,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 9,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 10,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 11,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 12,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float3",
+                format: DxgiFormat::R32G32B32_FLOAT,
+                stride: 12,
+                semantic_name: c"POSITION",
+                semantic_index: 0,
+                buffer_index: 1,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float2",
+                format: DxgiFormat::R32G32_FLOAT,
+                stride: 8,
+                semantic_name: c"POSITION",
+                semantic_index: 1,
+                buffer_index: 1,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float3",
+                format: DxgiFormat::R32G32B32_FLOAT,
+                stride: 12,
+                semantic_name: c"POSITION",
+                semantic_index: 2,
+                buffer_index: 1,
+                is_instance_data: false,
+            },
+        ],
+    },
+    // Layout 72
+    InputLayout {
+        elements: &[
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 1,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 2,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 3,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 4,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 5,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 6,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 7,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 8,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 9,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 10,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 11,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 12,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 13,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float3",
+                format: DxgiFormat::R32G32B32_FLOAT,
+                stride: 12,
+                semantic_name: c"POSITION",
+                semantic_index: 0,
+                buffer_index: 1,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float2",
+                format: DxgiFormat::R32G32_FLOAT,
+                stride: 8,
+                semantic_name: c"POSITION",
+                semantic_index: 1,
+                buffer_index: 1,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float3",
+                format: DxgiFormat::R32G32B32_FLOAT,
+                stride: 12,
+                semantic_name: c"POSITION",
+                semantic_index: 2,
+                buffer_index: 1,
+                is_instance_data: false,
+            },
+        ],
+    },
+    // Layout 73
+    InputLayout {
+        elements: &[
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 1,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 2,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 3,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 4,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 5,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 6,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 7,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 8,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 9,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 10,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 11,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 12,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 13,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 14,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float3",
+                format: DxgiFormat::R32G32B32_FLOAT,
+                stride: 12,
+                semantic_name: c"POSITION",
+                semantic_index: 0,
+                buffer_index: 1,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float2",
+                format: DxgiFormat::R32G32_FLOAT,
+                stride: 8,
+                semantic_name: c"POSITION",
+                semantic_index: 1,
+                buffer_index: 1,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float3",
+                format: DxgiFormat::R32G32B32_FLOAT,
+                stride: 12,
+                semantic_name: c"POSITION",
+                semantic_index: 2,
+                buffer_index: 1,
+                is_instance_data: false,
+            },
+        ],
+    },
+    // Layout 74
+    InputLayout {
+        elements: &[
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 1,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 2,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 3,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 4,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 5,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 6,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 7,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 8,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 9,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 10,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 11,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 12,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 13,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 14,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 15,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float3",
+                format: DxgiFormat::R32G32B32_FLOAT,
+                stride: 12,
+                semantic_name: c"POSITION",
+                semantic_index: 0,
+                buffer_index: 1,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float2",
+                format: DxgiFormat::R32G32_FLOAT,
+                stride: 8,
+                semantic_name: c"POSITION",
+                semantic_index: 1,
+                buffer_index: 1,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float3",
+                format: DxgiFormat::R32G32B32_FLOAT,
+                stride: 12,
+                semantic_name: c"POSITION",
+                semantic_index: 2,
+                buffer_index: 1,
+                is_instance_data: false,
+            },
+        ],
+    },
+    // Layout 75
+    InputLayout {
+        elements: &[
+            InputLayoutElement {
+                hlsl_type: "float3",
+                format: DxgiFormat::R32G32B32_FLOAT,
+                stride: 12,
+                semantic_name: c"POSITION",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float3",
+                format: DxgiFormat::R32G32B32_FLOAT,
+                stride: 12,
+                semantic_name: c"NORMAL",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TANGENT",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float2",
+                format: DxgiFormat::R32G32_FLOAT,
+                stride: 8,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R8G8B8A8_UNORM,
+                stride: 4,
+                semantic_name: c"COLOR",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+        ],
+    },
+    // Layout 76
+    InputLayout {
+        elements: &[
+            InputLayoutElement {
+                hlsl_type: "float3",
+                format: DxgiFormat::R32G32B32_FLOAT,
+                stride: 12,
+                semantic_name: c"POSITION",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float3",
+                format: DxgiFormat::R32G32B32_FLOAT,
+                stride: 12,
+                semantic_name: c"NORMAL",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TANGENT",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float2",
+                format: DxgiFormat::R32G32_FLOAT,
+                stride: 8,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R8G8B8A8_UNORM,
+                stride: 4,
+                semantic_name: c"COLOR",
+                semantic_index: 0,
+                buffer_index: 0,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 4,
+                buffer_index: 1,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 5,
+                buffer_index: 1,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 6,
+                buffer_index: 1,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 7,
+                buffer_index: 1,
+                is_instance_data: false,
+            },
+            InputLayoutElement {
+                hlsl_type: "float4",
+                format: DxgiFormat::R32G32B32A32_FLOAT,
+                stride: 16,
+                semantic_name: c"TEXCOORD",
+                semantic_index: 8,
+                buffer_index: 1,
+                is_instance_data: false,
+            },
+        ],
+    },
+];
+//endregion
+
+//region Depth Biases
+pub const DEPTH_BIASES: [DepthBiasDesc; 9] = [
+    // DepthBias 0
+    DepthBiasDesc {
+        depth_bias: 0,
+        slope_scale: 0.0,
+        clamp: 0.0,
+    },
+    // DepthBias 1
+    DepthBiasDesc {
+        depth_bias: 0,
+        slope_scale: 0.0,
+        clamp: 0.0,
+    },
+    // DepthBias 2
+    DepthBiasDesc {
+        depth_bias: 5,
+        slope_scale: 2.0,
+        clamp: 10000000000.0,
+    },
+    // DepthBias 3
+    DepthBiasDesc {
+        depth_bias: 10,
+        slope_scale: 4.0,
+        clamp: 10000000000.0,
+    },
+    // DepthBias 4
+    DepthBiasDesc {
+        depth_bias: 15,
+        slope_scale: 6.0,
+        clamp: 10000000000.0,
+    },
+    // DepthBias 5
+    DepthBiasDesc {
+        depth_bias: 20,
+        slope_scale: 8.0,
+        clamp: 10000000000.0,
+    },
+    // DepthBias 6
+    DepthBiasDesc {
+        depth_bias: 2,
+        slope_scale: 2.0,
+        clamp: 10000000000.0,
+    },
+    // DepthBias 7
+    DepthBiasDesc {
+        depth_bias: -1,
+        slope_scale: -2.0,
+        clamp: 10000000000.0,
+    },
+    // DepthBias 8
+    DepthBiasDesc {
+        depth_bias: 51,
+        slope_scale: 2.0,
+        clamp: 10000000000.0,
+    },
+];
+//endregion
+
+//region Rasterizer States
+pub const RASTERIZER_STATES: [RasterizerStateDesc; 9] = [
+    // Rasterizer 0
+    RasterizerStateDesc {
+        fill_mode: FillMode::Solid,
+        cull_mode: CullMode::None,
+        front_counter_clockwise: true,
+        depth_clip_enable: true,
+        scissor_enable: false,
+    },
+    // Rasterizer 1
+    RasterizerStateDesc {
+        fill_mode: FillMode::Solid,
+        cull_mode: CullMode::None,
+        front_counter_clockwise: true,
+        depth_clip_enable: true,
+        scissor_enable: false,
+    },
+    // Rasterizer 2
+    RasterizerStateDesc {
+        fill_mode: FillMode::Solid,
+        cull_mode: CullMode::Back,
+        front_counter_clockwise: true,
+        depth_clip_enable: true,
+        scissor_enable: false,
+    },
+    // Rasterizer 3
+    RasterizerStateDesc {
+        fill_mode: FillMode::Solid,
+        cull_mode: CullMode::Front,
+        front_counter_clockwise: true,
+        depth_clip_enable: true,
+        scissor_enable: false,
+    },
+    // Rasterizer 4
+    RasterizerStateDesc {
+        fill_mode: FillMode::Wireframe,
+        cull_mode: CullMode::Back,
+        front_counter_clockwise: true,
+        depth_clip_enable: true,
+        scissor_enable: false,
+    },
+    // Rasterizer 5
+    RasterizerStateDesc {
+        fill_mode: FillMode::Wireframe,
+        cull_mode: CullMode::None,
+        front_counter_clockwise: true,
+        depth_clip_enable: true,
+        scissor_enable: false,
+    },
+    // Rasterizer 6
+    RasterizerStateDesc {
+        fill_mode: FillMode::Solid,
+        cull_mode: CullMode::Back,
+        front_counter_clockwise: true,
+        depth_clip_enable: false,
+        scissor_enable: false,
+    },
+    // Rasterizer 7
+    RasterizerStateDesc {
+        fill_mode: FillMode::Solid,
+        cull_mode: CullMode::None,
+        front_counter_clockwise: true,
+        depth_clip_enable: false,
+        scissor_enable: false,
+    },
+    // Rasterizer 8
+    RasterizerStateDesc {
+        fill_mode: FillMode::Solid,
+        cull_mode: CullMode::Front,
+        front_counter_clockwise: true,
+        depth_clip_enable: false,
+        scissor_enable: false,
+    },
+];
+
+//endregion
+
+//region Depth/Stencil States
+
+pub const DEPTH_STENCIL_COMBOS: [(usize, usize); 89] = [
+    (0, 0), // 0
+    (1, 1),
+    (2, 1), // 2
+    (8, 1),
+    (2, 2), // 4
+    (1, 3),
+    (1, 4), // 6
+    (2, 5),
+    (2, 6), // 8
+    (2, 9),
+    (2, 10), // 10
+    (2, 0xb),
+    (2, 0xc), // 12
+    (4, 1),
+    (6, 1), // 14
+    (3, 1),
+    (7, 1), // 16
+    (3, 0x10),
+    (9, 0x10),
+    (3, 0x11),
+    (3, 0x12),
+    (7, 0x13),
+    (7, 0x1b),
+    (3, 0x13),
+    (3, 0x19),
+    (3, 0x1b),
+    (6, 0x14),
+    (2, 0x15),
+    (3, 0x15),
+    (3, 0x18),
+    (3, 0x1a),
+    (1, 0x1d),
+    (1, 0x12),
+    (1, 0x13),
+    (10, 1),
+    (0xb, 1),
+    (3, 0x1e),
+    (0xc, 0x1f),
+    (1, 0x1f),
+    (1, 0x20),
+    (1, 0x21),
+    (3, 0x21),
+    (2, 0x21),
+    (6, 0x20),
+    (3, 0x20),
+    (3, 6),
+    (3, 10),
+    (3, 0xb),
+    (3, 0xc),
+    (3, 9),
+    (0xd, 0x22),
+    (1, 0x23),
+    (3, 0x1c),
+    (7, 0x1c),
+    (0xd, 0x10),
+    (0xd, 0x25),
+    (9, 0x24),
+    (3, 0x26),
+    (1, 0x26),
+    (3, 0x27),
+    (1, 0x27),
+    (3, 0x14),
+    (1, 0x14),
+    (3, 0x28),
+    (3, 8),
+    (2, 8),
+    (1, 2),
+    (1, 8),
+    (3, 7),
+    (3, 0x17),
+    (3, 0xd),
+    (3, 0xe),
+    (3, 0xf),
+    (1, 0x29),
+    (1, 0x2a),
+    (1, 0x2b),
+    (1, 0x2c),
+    (1, 0x2d),
+    (1, 0x2e),
+    (1, 0x2f),
+    (1, 0x30),
+    (1, 0x1a),
+    (2, 0x16),
+    (5, 1),
+    (5, 0x29),
+    (5, 0x2a),
+    (10, 0x16),
+    (1, 0x16),
+    (14, 1), // CUSTOM ALKAHEST DEPTH STATE
+];
+
+pub const DEPTH_STATES: [DepthDesc; 15] = [
+    // Depth 0
+    DepthDesc {
+        enable: false,
+        write_mask: 0,
+        func: CompareFunc::Always,
+        enable_alt: false,
+        write_mask_alt: 0,
+        func_alt: CompareFunc::Always,
+    },
+    // Depth 1
+    DepthDesc {
+        enable: false,
+        write_mask: 0,
+        func: CompareFunc::Always,
+        enable_alt: false,
+        write_mask_alt: 0,
+        func_alt: CompareFunc::Always,
+    },
+    // Depth 2
+    DepthDesc {
+        enable: true,
+        write_mask: 1,
+        func: CompareFunc::GreaterEqual,
+        enable_alt: true,
+        write_mask_alt: 1,
+        func_alt: CompareFunc::LessEqual,
+    },
+    // Depth 3
+    DepthDesc {
+        enable: true,
+        write_mask: 0,
+        func: CompareFunc::GreaterEqual,
+        enable_alt: true,
+        write_mask_alt: 0,
+        func_alt: CompareFunc::LessEqual,
+    },
+    // Depth 4
+    DepthDesc {
+        enable: true,
+        write_mask: 1,
+        func: CompareFunc::LessEqual,
+        enable_alt: true,
+        write_mask_alt: 1,
+        func_alt: CompareFunc::GreaterEqual,
+    },
+    // Depth 5
+    DepthDesc {
+        enable: true,
+        write_mask: 1,
+        func: CompareFunc::Less,
+        enable_alt: true,
+        write_mask_alt: 1,
+        func_alt: CompareFunc::Greater,
+    },
+    // Depth 6
+    DepthDesc {
+        enable: true,
+        write_mask: 0,
+        func: CompareFunc::LessEqual,
+        enable_alt: true,
+        write_mask_alt: 0,
+        func_alt: CompareFunc::GreaterEqual,
+    },
+    // Depth 7
+    DepthDesc {
+        enable: true,
+        write_mask: 0,
+        func: CompareFunc::Less,
+        enable_alt: true,
+        write_mask_alt: 0,
+        func_alt: CompareFunc::Greater,
+    },
+    // Depth 8
+    DepthDesc {
+        enable: true,
+        write_mask: 1,
+        func: CompareFunc::GreaterEqual,
+        enable_alt: true,
+        write_mask_alt: 1,
+        func_alt: CompareFunc::LessEqual,
+    },
+    // Depth 9
+    DepthDesc {
+        enable: true,
+        write_mask: 0,
+        func: CompareFunc::GreaterEqual,
+        enable_alt: true,
+        write_mask_alt: 0,
+        func_alt: CompareFunc::LessEqual,
+    },
+    // Depth 10
+    DepthDesc {
+        enable: true,
+        write_mask: 1,
+        func: CompareFunc::Always,
+        enable_alt: true,
+        write_mask_alt: 1,
+        func_alt: CompareFunc::Always,
+    },
+    // Depth 11
+    DepthDesc {
+        enable: true,
+        write_mask: 0,
+        func: CompareFunc::Never,
+        enable_alt: true,
+        write_mask_alt: 0,
+        func_alt: CompareFunc::Never,
+    },
+    // Depth 12
+    DepthDesc {
+        enable: true,
+        write_mask: 0,
+        func: CompareFunc::Always,
+        enable_alt: true,
+        write_mask_alt: 0,
+        func_alt: CompareFunc::Always,
+    },
+    // Depth 13
+    DepthDesc {
+        enable: true,
+        write_mask: 0,
+        func: CompareFunc::GreaterEqual,
+        enable_alt: true,
+        write_mask_alt: 0,
+        func_alt: CompareFunc::LessEqual,
+    },
+    // Depth 14 (CUSTOM FOR ALKAHEST)
+    DepthDesc {
+        enable: true,
+        write_mask: 0,
+        func: CompareFunc::Equal,
+        enable_alt: true,
+        write_mask_alt: 0,
+        func_alt: CompareFunc::Equal,
+    },
+];
+
+pub const STENCIL_STATES: [StencilDesc; 49] = [
+    // Stencil 0
+    StencilDesc {
+        stencil_enable: false,
+        stencil_read_mask: 0,
+        stencil_write_mask: 0,
+        front_face: StencilFaceDesc {
+            func: CompareFunc::Always,
+            pass_op: StencilOp::Keep,
+            fail_op: StencilOp::Keep,
+            depth_fail_op: StencilOp::Keep,
+        },
+        back_face: StencilFaceDesc {
+            func: CompareFunc::Always,
+            pass_op: StencilOp::Keep,
+            fail_op: StencilOp::Keep,
+            depth_fail_op: StencilOp::Keep,
+        },
+    },
+    // Stencil 1
+    StencilDesc {
+        stencil_enable: false,
+        stencil_read_mask: 0,
+        stencil_write_mask: 0,
+        front_face: StencilFaceDesc {
+            func: CompareFunc::Always,
+            pass_op: StencilOp::Keep,
+            fail_op: StencilOp::Keep,
+            depth_fail_op: StencilOp::Keep,
+        },
+        back_face: StencilFaceDesc {
+            func: CompareFunc::Always,
+            pass_op: StencilOp::Keep,
+            fail_op: StencilOp::Keep,
+            depth_fail_op: StencilOp::Keep,
+        },
+    },
+    // Stencil 2
+    StencilDesc {
+        stencil_enable: true,
+        stencil_read_mask: 0,
+        stencil_write_mask: 175,
+        front_face: StencilFaceDesc {
+            func: CompareFunc::Always,
+            pass_op: StencilOp::Replace,
+            fail_op: StencilOp::Keep,
+            depth_fail_op: StencilOp::Keep,
+        },
+        back_face: StencilFaceDesc {
+            func: CompareFunc::Always,
+            pass_op: StencilOp::Replace,
+            fail_op: StencilOp::Keep,
+            depth_fail_op: StencilOp::Keep,
+        },
+    },
+    // Stencil 3
+    StencilDesc {
+        stencil_enable: true,
+        stencil_read_mask: 0,
+        stencil_write_mask: 2,
+        front_face: StencilFaceDesc {
+            func: CompareFunc::Always,
+            pass_op: StencilOp::Replace,
+            fail_op: StencilOp::Keep,
+            depth_fail_op: StencilOp::Keep,
+        },
+        back_face: StencilFaceDesc {
+            func: CompareFunc::Always,
+            pass_op: StencilOp::Replace,
+            fail_op: StencilOp::Keep,
+            depth_fail_op: StencilOp::Keep,
+        },
+    },
+    // Stencil 4
+    StencilDesc {
+        stencil_enable: true,
+        stencil_read_mask: 0,
+        stencil_write_mask: 1,
+        front_face: StencilFaceDesc {
+            func: CompareFunc::Always,
+            pass_op: StencilOp::Replace,
+            fail_op: StencilOp::Keep,
+            depth_fail_op: StencilOp::Keep,
+        },
+        back_face: StencilFaceDesc {
+            func: CompareFunc::Always,
+            pass_op: StencilOp::Replace,
+            fail_op: StencilOp::Keep,
+            depth_fail_op: StencilOp::Keep,
+        },
+    },
+    // Stencil 5
+    StencilDesc {
+        stencil_enable: true,
+        stencil_read_mask: 0,
+        stencil_write_mask: 16,
+        front_face: StencilFaceDesc {
+            func: CompareFunc::Always,
+            pass_op: StencilOp::Replace,
+            fail_op: StencilOp::Keep,
+            depth_fail_op: StencilOp::Keep,
+        },
+        back_face: StencilFaceDesc {
+            func: CompareFunc::Always,
+            pass_op: StencilOp::Replace,
+            fail_op: StencilOp::Keep,
+            depth_fail_op: StencilOp::Keep,
+        },
+    },
+    // Stencil 6
+    StencilDesc {
+        stencil_enable: true,
+        stencil_read_mask: 4,
+        stencil_write_mask: 184,
+        front_face: StencilFaceDesc {
+            func: CompareFunc::Equal,
+            pass_op: StencilOp::Replace,
+            fail_op: StencilOp::Keep,
+            depth_fail_op: StencilOp::Keep,
+        },
+        back_face: StencilFaceDesc {
+            func: CompareFunc::Equal,
+            pass_op: StencilOp::Replace,
+            fail_op: StencilOp::Keep,
+            depth_fail_op: StencilOp::Keep,
+        },
+    },
+    // Stencil 7
+    StencilDesc {
+        stencil_enable: true,
+        stencil_read_mask: 20,
+        stencil_write_mask: 184,
+        front_face: StencilFaceDesc {
+            func: CompareFunc::Equal,
+            pass_op: StencilOp::Zero,
+            fail_op: StencilOp::Keep,
+            depth_fail_op: StencilOp::Keep,
+        },
+        back_face: StencilFaceDesc {
+            func: CompareFunc::Equal,
+            pass_op: StencilOp::Zero,
+            fail_op: StencilOp::Keep,
+            depth_fail_op: StencilOp::Keep,
+        },
+    },
+    // Stencil 8
+    StencilDesc {
+        stencil_enable: true,
+        stencil_read_mask: 4,
+        stencil_write_mask: 0,
+        front_face: StencilFaceDesc {
+            func: CompareFunc::Equal,
+            pass_op: StencilOp::Replace,
+            fail_op: StencilOp::Keep,
+            depth_fail_op: StencilOp::Keep,
+        },
+        back_face: StencilFaceDesc {
+            func: CompareFunc::Equal,
+            pass_op: StencilOp::Replace,
+            fail_op: StencilOp::Keep,
+            depth_fail_op: StencilOp::Keep,
+        },
+    },
+    // Stencil 9
+    StencilDesc {
+        stencil_enable: true,
+        stencil_read_mask: 16,
+        stencil_write_mask: 16,
+        front_face: StencilFaceDesc {
+            func: CompareFunc::NotEqual,
+            pass_op: StencilOp::Zero,
+            fail_op: StencilOp::Keep,
+            depth_fail_op: StencilOp::Keep,
+        },
+        back_face: StencilFaceDesc {
+            func: CompareFunc::NotEqual,
+            pass_op: StencilOp::Zero,
+            fail_op: StencilOp::Keep,
+            depth_fail_op: StencilOp::Keep,
+        },
+    },
+    // Stencil 10
+    StencilDesc {
+        stencil_enable: true,
+        stencil_read_mask: 6,
+        stencil_write_mask: 184,
+        front_face: StencilFaceDesc {
+            func: CompareFunc::Equal,
+            pass_op: StencilOp::Replace,
+            fail_op: StencilOp::Keep,
+            depth_fail_op: StencilOp::Keep,
+        },
+        back_face: StencilFaceDesc {
+            func: CompareFunc::Equal,
+            pass_op: StencilOp::Replace,
+            fail_op: StencilOp::Keep,
+            depth_fail_op: StencilOp::Keep,
+        },
+    },
+    // Stencil 11
+    StencilDesc {
+        stencil_enable: true,
+        stencil_read_mask: 7,
+        stencil_write_mask: 184,
+        front_face: StencilFaceDesc {
+            func: CompareFunc::LessEqual,
+            pass_op: StencilOp::Replace,
+            fail_op: StencilOp::Keep,
+            depth_fail_op: StencilOp::Keep,
+        },
+        back_face: StencilFaceDesc {
+            func: CompareFunc::LessEqual,
+            pass_op: StencilOp::Replace,
+            fail_op: StencilOp::Keep,
+            depth_fail_op: StencilOp::Keep,
+        },
+    },
+    // Stencil 12
+    StencilDesc {
+        stencil_enable: true,
+        stencil_read_mask: 3,
+        stencil_write_mask: 184,
+        front_face: StencilFaceDesc {
+            func: CompareFunc::Greater,
+            pass_op: StencilOp::Replace,
+            fail_op: StencilOp::Keep,
+            depth_fail_op: StencilOp::Keep,
+        },
+        back_face: StencilFaceDesc {
+            func: CompareFunc::Greater,
+            pass_op: StencilOp::Replace,
+            fail_op: StencilOp::Keep,
+            depth_fail_op: StencilOp::Keep,
+        },
+    },
+    // Stencil 13
+    StencilDesc {
+        stencil_enable: true,
+        stencil_read_mask: 22,
+        stencil_write_mask: 184,
+        front_face: StencilFaceDesc {
+            func: CompareFunc::Equal,
+            pass_op: StencilOp::Zero,
+            fail_op: StencilOp::Keep,
+            depth_fail_op: StencilOp::Keep,
+        },
+        back_face: StencilFaceDesc {
+            func: CompareFunc::Equal,
+            pass_op: StencilOp::Zero,
+            fail_op: StencilOp::Keep,
+            depth_fail_op: StencilOp::Keep,
+        },
+    },
+    // Stencil 14
+    StencilDesc {
+        stencil_enable: true,
+        stencil_read_mask: 23,
+        stencil_write_mask: 184,
+        front_face: StencilFaceDesc {
+            func: CompareFunc::LessEqual,
+            pass_op: StencilOp::Zero,
+            fail_op: StencilOp::Keep,
+            depth_fail_op: StencilOp::Keep,
+        },
+        back_face: StencilFaceDesc {
+            func: CompareFunc::LessEqual,
+            pass_op: StencilOp::Zero,
+            fail_op: StencilOp::Keep,
+            depth_fail_op: StencilOp::Keep,
+        },
+    },
+    // Stencil 15
+    StencilDesc {
+        stencil_enable: true,
+        stencil_read_mask: 19,
+        stencil_write_mask: 184,
+        front_face: StencilFaceDesc {
+            func: CompareFunc::Greater,
+            pass_op: StencilOp::Zero,
+            fail_op: StencilOp::Keep,
+            depth_fail_op: StencilOp::Keep,
+        },
+        back_face: StencilFaceDesc {
+            func: CompareFunc::Greater,
+            pass_op: StencilOp::Zero,
+            fail_op: StencilOp::Keep,
+            depth_fail_op: StencilOp::Keep,
+        },
+    },
+    // Stencil 16
+    StencilDesc {
+        stencil_enable: true,
+        stencil_read_mask: 0,
+        stencil_write_mask: 16,
+        front_face: StencilFaceDesc {
+            func: CompareFunc::Always,
+            pass_op: StencilOp::Zero,
+            fail_op: StencilOp::Keep,
+            depth_fail_op: StencilOp::Keep,
+        },
+        back_face: StencilFaceDesc {
+            func: CompareFunc::Always,
+            pass_op: StencilOp::Zero,
+            fail_op: StencilOp::Keep,
+            depth_fail_op: StencilOp::Keep,
+        },
+    },
+    // Stencil 17
+    StencilDesc {
+        stencil_enable: true,
+        stencil_read_mask: 16,
+        stencil_write_mask: 16,
+        front_face: StencilFaceDesc {
+            func: CompareFunc::Equal,
+            pass_op: StencilOp::Keep,
+            fail_op: StencilOp::Keep,
+            depth_fail_op: StencilOp::Zero,
+        },
+        back_face: StencilFaceDesc {
+            func: CompareFunc::Equal,
+            pass_op: StencilOp::Keep,
+            fail_op: StencilOp::Keep,
+            depth_fail_op: StencilOp::Zero,
+        },
+    },
+    // Stencil 18
+    StencilDesc {
+        stencil_enable: true,
+        stencil_read_mask: 16,
+        stencil_write_mask: 16,
+        front_face: StencilFaceDesc {
+            func: CompareFunc::Equal,
+            pass_op: StencilOp::Keep,
+            fail_op: StencilOp::Keep,
+            depth_fail_op: StencilOp::Keep,
+        },
+        back_face: StencilFaceDesc {
+            func: CompareFunc::Equal,
+            pass_op: StencilOp::Keep,
+            fail_op: StencilOp::Keep,
+            depth_fail_op: StencilOp::Keep,
+        },
+    },
+    // Stencil 19
+    StencilDesc {
+        stencil_enable: true,
+        stencil_read_mask: 16,
+        stencil_write_mask: 0,
+        front_face: StencilFaceDesc {
+            func: CompareFunc::Equal,
+            pass_op: StencilOp::Keep,
+            fail_op: StencilOp::Keep,
+            depth_fail_op: StencilOp::Keep,
+        },
+        back_face: StencilFaceDesc {
+            func: CompareFunc::Equal,
+            pass_op: StencilOp::Keep,
+            fail_op: StencilOp::Keep,
+            depth_fail_op: StencilOp::Keep,
+        },
+    },
+    // Stencil 20
+    StencilDesc {
+        stencil_enable: true,
+        stencil_read_mask: 32,
+        stencil_write_mask: 0,
+        front_face: StencilFaceDesc {
+            func: CompareFunc::NotEqual,
+            pass_op: StencilOp::Keep,
+            fail_op: StencilOp::Keep,
+            depth_fail_op: StencilOp::Keep,
+        },
+        back_face: StencilFaceDesc {
+            func: CompareFunc::NotEqual,
+            pass_op: StencilOp::Keep,
+            fail_op: StencilOp::Keep,
+            depth_fail_op: StencilOp::Keep,
+        },
+    },
+    // Stencil 21
+    StencilDesc {
+        stencil_enable: true,
+        stencil_read_mask: 0,
+        stencil_write_mask: 184,
+        front_face: StencilFaceDesc {
+            func: CompareFunc::Always,
+            pass_op: StencilOp::Replace,
+            fail_op: StencilOp::Keep,
+            depth_fail_op: StencilOp::Keep,
+        },
+        back_face: StencilFaceDesc {
+            func: CompareFunc::Always,
+            pass_op: StencilOp::Replace,
+            fail_op: StencilOp::Keep,
+            depth_fail_op: StencilOp::Keep,
+        },
+    },
+    // Stencil 22
+    StencilDesc {
+        stencil_enable: true,
+        stencil_read_mask: 0,
+        stencil_write_mask: 255,
+        front_face: StencilFaceDesc {
+            func: CompareFunc::Always,
+            pass_op: StencilOp::Replace,
+            fail_op: StencilOp::Keep,
+            depth_fail_op: StencilOp::Keep,
+        },
+        back_face: StencilFaceDesc {
+            func: CompareFunc::Always,
+            pass_op: StencilOp::Replace,
+            fail_op: StencilOp::Keep,
+            depth_fail_op: StencilOp::Keep,
+        },
+    },
+    // Stencil 23
+    StencilDesc {
+        stencil_enable: true,
+        stencil_read_mask: 0,
+        stencil_write_mask: 184,
+        front_face: StencilFaceDesc {
+            func: CompareFunc::Always,
+            pass_op: StencilOp::Zero,
+            fail_op: StencilOp::Keep,
+            depth_fail_op: StencilOp::Keep,
+        },
+        back_face: StencilFaceDesc {
+            func: CompareFunc::Always,
+            pass_op: StencilOp::Zero,
+            fail_op: StencilOp::Keep,
+            depth_fail_op: StencilOp::Keep,
+        },
+    },
+    // Stencil 24
+    StencilDesc {
+        stencil_enable: true,
+        stencil_read_mask: 0,
+        stencil_write_mask: 16,
+        front_face: StencilFaceDesc {
+            func: CompareFunc::Always,
+            pass_op: StencilOp::Invert,
+            fail_op: StencilOp::Keep,
+            depth_fail_op: StencilOp::Keep,
+        },
+        back_face: StencilFaceDesc {
+            func: CompareFunc::Always,
+            pass_op: StencilOp::Invert,
+            fail_op: StencilOp::Keep,
+            depth_fail_op: StencilOp::Keep,
+        },
+    },
+    // Stencil 25
+    StencilDesc {
+        stencil_enable: true,
+        stencil_read_mask: 0,
+        stencil_write_mask: 16,
+        front_face: StencilFaceDesc {
+            func: CompareFunc::Always,
+            pass_op: StencilOp::Keep,
+            fail_op: StencilOp::Keep,
+            depth_fail_op: StencilOp::Invert,
+        },
+        back_face: StencilFaceDesc {
+            func: CompareFunc::Always,
+            pass_op: StencilOp::Keep,
+            fail_op: StencilOp::Keep,
+            depth_fail_op: StencilOp::Invert,
+        },
+    },
+    // Stencil 26
+    StencilDesc {
+        stencil_enable: true,
+        stencil_read_mask: 16,
+        stencil_write_mask: 16,
+        front_face: StencilFaceDesc {
+            func: CompareFunc::NotEqual,
+            pass_op: StencilOp::Zero,
+            fail_op: StencilOp::Keep,
+            depth_fail_op: StencilOp::Keep,
+        },
+        back_face: StencilFaceDesc {
+            func: CompareFunc::NotEqual,
+            pass_op: StencilOp::Zero,
+            fail_op: StencilOp::Keep,
+            depth_fail_op: StencilOp::Keep,
+        },
+    },
+    // Stencil 27
+    StencilDesc {
+        stencil_enable: true,
+        stencil_read_mask: 16,
+        stencil_write_mask: 16,
+        front_face: StencilFaceDesc {
+            func: CompareFunc::NotEqual,
+            pass_op: StencilOp::Keep,
+            fail_op: StencilOp::Keep,
+            depth_fail_op: StencilOp::Keep,
+        },
+        back_face: StencilFaceDesc {
+            func: CompareFunc::NotEqual,
+            pass_op: StencilOp::Zero,
+            fail_op: StencilOp::Keep,
+            depth_fail_op: StencilOp::Keep,
+        },
+    },
+    // Stencil 28
+    StencilDesc {
+        stencil_enable: true,
+        stencil_read_mask: 16,
+        stencil_write_mask: 0,
+        front_face: StencilFaceDesc {
+            func: CompareFunc::NotEqual,
+            pass_op: StencilOp::Keep,
+            fail_op: StencilOp::Keep,
+            depth_fail_op: StencilOp::Keep,
+        },
+        back_face: StencilFaceDesc {
+            func: CompareFunc::NotEqual,
+            pass_op: StencilOp::Keep,
+            fail_op: StencilOp::Keep,
+            depth_fail_op: StencilOp::Keep,
+        },
+    },
+    // Stencil 29
+    StencilDesc {
+        stencil_enable: true,
+        stencil_read_mask: 16,
+        stencil_write_mask: 0,
+        front_face: StencilFaceDesc {
+            func: CompareFunc::NotEqual,
+            pass_op: StencilOp::Keep,
+            fail_op: StencilOp::Keep,
+            depth_fail_op: StencilOp::Keep,
+        },
+        back_face: StencilFaceDesc {
+            func: CompareFunc::NotEqual,
+            pass_op: StencilOp::Keep,
+            fail_op: StencilOp::Keep,
+            depth_fail_op: StencilOp::Keep,
+        },
+    },
+    // Stencil 30
+    StencilDesc {
+        stencil_enable: true,
+        stencil_read_mask: 0,
+        stencil_write_mask: 16,
+        front_face: StencilFaceDesc {
+            func: CompareFunc::Always,
+            pass_op: StencilOp::Replace,
+            fail_op: StencilOp::Keep,
+            depth_fail_op: StencilOp::Keep,
+        },
+        back_face: StencilFaceDesc {
+            func: CompareFunc::Always,
+            pass_op: StencilOp::Replace,
+            fail_op: StencilOp::Keep,
+            depth_fail_op: StencilOp::Keep,
+        },
+    },
+    // Stencil 31
+    StencilDesc {
+        stencil_enable: true,
+        stencil_read_mask: 255,
+        stencil_write_mask: 255,
+        front_face: StencilFaceDesc {
+            func: CompareFunc::Always,
+            pass_op: StencilOp::Replace,
+            fail_op: StencilOp::Keep,
+            depth_fail_op: StencilOp::Keep,
+        },
+        back_face: StencilFaceDesc {
+            func: CompareFunc::Always,
+            pass_op: StencilOp::Replace,
+            fail_op: StencilOp::Keep,
+            depth_fail_op: StencilOp::Keep,
+        },
+    },
+    // Stencil 32
+    StencilDesc {
+        stencil_enable: true,
+        stencil_read_mask: 255,
+        stencil_write_mask: 255,
+        front_face: StencilFaceDesc {
+            func: CompareFunc::NotEqual,
+            pass_op: StencilOp::Zero,
+            fail_op: StencilOp::Keep,
+            depth_fail_op: StencilOp::Keep,
+        },
+        back_face: StencilFaceDesc {
+            func: CompareFunc::NotEqual,
+            pass_op: StencilOp::Zero,
+            fail_op: StencilOp::Keep,
+            depth_fail_op: StencilOp::Keep,
+        },
+    },
+    // Stencil 33
+    StencilDesc {
+        stencil_enable: true,
+        stencil_read_mask: 255,
+        stencil_write_mask: 0,
+        front_face: StencilFaceDesc {
+            func: CompareFunc::NotEqual,
+            pass_op: StencilOp::Keep,
+            fail_op: StencilOp::Keep,
+            depth_fail_op: StencilOp::Keep,
+        },
+        back_face: StencilFaceDesc {
+            func: CompareFunc::NotEqual,
+            pass_op: StencilOp::Keep,
+            fail_op: StencilOp::Keep,
+            depth_fail_op: StencilOp::Keep,
+        },
+    },
+    // Stencil 34
+    StencilDesc {
+        stencil_enable: true,
+        stencil_read_mask: 16,
+        stencil_write_mask: 16,
+        front_face: StencilFaceDesc {
+            func: CompareFunc::Always,
+            pass_op: StencilOp::Zero,
+            fail_op: StencilOp::Zero,
+            depth_fail_op: StencilOp::Keep,
+        },
+        back_face: StencilFaceDesc {
+            func: CompareFunc::Always,
+            pass_op: StencilOp::Zero,
+            fail_op: StencilOp::Zero,
+            depth_fail_op: StencilOp::Keep,
+        },
+    },
+    // Stencil 35
+    StencilDesc {
+        stencil_enable: true,
+        stencil_read_mask: 16,
+        stencil_write_mask: 0,
+        front_face: StencilFaceDesc {
+            func: CompareFunc::NotEqual,
+            pass_op: StencilOp::Keep,
+            fail_op: StencilOp::Keep,
+            depth_fail_op: StencilOp::Keep,
+        },
+        back_face: StencilFaceDesc {
+            func: CompareFunc::NotEqual,
+            pass_op: StencilOp::Keep,
+            fail_op: StencilOp::Keep,
+            depth_fail_op: StencilOp::Keep,
+        },
+    },
+    // Stencil 36
+    StencilDesc {
+        stencil_enable: true,
+        stencil_read_mask: 0,
+        stencil_write_mask: 16,
+        front_face: StencilFaceDesc {
+            func: CompareFunc::Always,
+            pass_op: StencilOp::Zero,
+            fail_op: StencilOp::Keep,
+            depth_fail_op: StencilOp::Replace,
+        },
+        back_face: StencilFaceDesc {
+            func: CompareFunc::Always,
+            pass_op: StencilOp::Zero,
+            fail_op: StencilOp::Keep,
+            depth_fail_op: StencilOp::Replace,
+        },
+    },
+    // Stencil 37
+    StencilDesc {
+        stencil_enable: true,
+        stencil_read_mask: 16,
+        stencil_write_mask: 16,
+        front_face: StencilFaceDesc {
+            func: CompareFunc::Equal,
+            pass_op: StencilOp::Zero,
+            fail_op: StencilOp::Keep,
+            depth_fail_op: StencilOp::Keep,
+        },
+        back_face: StencilFaceDesc {
+            func: CompareFunc::Equal,
+            pass_op: StencilOp::Zero,
+            fail_op: StencilOp::Keep,
+            depth_fail_op: StencilOp::Keep,
+        },
+    },
+    // Stencil 38
+    StencilDesc {
+        stencil_enable: true,
+        stencil_read_mask: 16,
+        stencil_write_mask: 16,
+        front_face: StencilFaceDesc {
+            func: CompareFunc::Equal,
+            pass_op: StencilOp::Keep,
+            fail_op: StencilOp::Keep,
+            depth_fail_op: StencilOp::Keep,
+        },
+        back_face: StencilFaceDesc {
+            func: CompareFunc::Equal,
+            pass_op: StencilOp::Keep,
+            fail_op: StencilOp::Keep,
+            depth_fail_op: StencilOp::Keep,
+        },
+    },
+    // Stencil 39
+    StencilDesc {
+        stencil_enable: true,
+        stencil_read_mask: 16,
+        stencil_write_mask: 16,
+        front_face: StencilFaceDesc {
+            func: CompareFunc::Equal,
+            pass_op: StencilOp::Zero,
+            fail_op: StencilOp::Keep,
+            depth_fail_op: StencilOp::Keep,
+        },
+        back_face: StencilFaceDesc {
+            func: CompareFunc::Equal,
+            pass_op: StencilOp::Zero,
+            fail_op: StencilOp::Keep,
+            depth_fail_op: StencilOp::Keep,
+        },
+    },
+    // Stencil 40
+    StencilDesc {
+        stencil_enable: true,
+        stencil_read_mask: 0,
+        stencil_write_mask: 64,
+        front_face: StencilFaceDesc {
+            func: CompareFunc::Always,
+            pass_op: StencilOp::Replace,
+            fail_op: StencilOp::Keep,
+            depth_fail_op: StencilOp::Keep,
+        },
+        back_face: StencilFaceDesc {
+            func: CompareFunc::Always,
+            pass_op: StencilOp::Replace,
+            fail_op: StencilOp::Keep,
+            depth_fail_op: StencilOp::Keep,
+        },
+    },
+    // Stencil 41
+    StencilDesc {
+        stencil_enable: true,
+        stencil_read_mask: 0,
+        stencil_write_mask: 1,
+        front_face: StencilFaceDesc {
+            func: CompareFunc::Always,
+            pass_op: StencilOp::Replace,
+            fail_op: StencilOp::Keep,
+            depth_fail_op: StencilOp::Keep,
+        },
+        back_face: StencilFaceDesc {
+            func: CompareFunc::Always,
+            pass_op: StencilOp::Replace,
+            fail_op: StencilOp::Keep,
+            depth_fail_op: StencilOp::Keep,
+        },
+    },
+    // Stencil 42
+    StencilDesc {
+        stencil_enable: true,
+        stencil_read_mask: 0,
+        stencil_write_mask: 2,
+        front_face: StencilFaceDesc {
+            func: CompareFunc::Always,
+            pass_op: StencilOp::Replace,
+            fail_op: StencilOp::Keep,
+            depth_fail_op: StencilOp::Keep,
+        },
+        back_face: StencilFaceDesc {
+            func: CompareFunc::Always,
+            pass_op: StencilOp::Replace,
+            fail_op: StencilOp::Keep,
+            depth_fail_op: StencilOp::Keep,
+        },
+    },
+    // Stencil 43
+    StencilDesc {
+        stencil_enable: true,
+        stencil_read_mask: 0,
+        stencil_write_mask: 4,
+        front_face: StencilFaceDesc {
+            func: CompareFunc::Always,
+            pass_op: StencilOp::Replace,
+            fail_op: StencilOp::Keep,
+            depth_fail_op: StencilOp::Keep,
+        },
+        back_face: StencilFaceDesc {
+            func: CompareFunc::Always,
+            pass_op: StencilOp::Replace,
+            fail_op: StencilOp::Keep,
+            depth_fail_op: StencilOp::Keep,
+        },
+    },
+    // Stencil 44
+    StencilDesc {
+        stencil_enable: true,
+        stencil_read_mask: 0,
+        stencil_write_mask: 8,
+        front_face: StencilFaceDesc {
+            func: CompareFunc::Always,
+            pass_op: StencilOp::Replace,
+            fail_op: StencilOp::Keep,
+            depth_fail_op: StencilOp::Keep,
+        },
+        back_face: StencilFaceDesc {
+            func: CompareFunc::Always,
+            pass_op: StencilOp::Replace,
+            fail_op: StencilOp::Keep,
+            depth_fail_op: StencilOp::Keep,
+        },
+    },
+    // Stencil 45
+    StencilDesc {
+        stencil_enable: true,
+        stencil_read_mask: 0,
+        stencil_write_mask: 16,
+        front_face: StencilFaceDesc {
+            func: CompareFunc::Always,
+            pass_op: StencilOp::Replace,
+            fail_op: StencilOp::Keep,
+            depth_fail_op: StencilOp::Keep,
+        },
+        back_face: StencilFaceDesc {
+            func: CompareFunc::Always,
+            pass_op: StencilOp::Replace,
+            fail_op: StencilOp::Keep,
+            depth_fail_op: StencilOp::Keep,
+        },
+    },
+    // Stencil 46
+    StencilDesc {
+        stencil_enable: true,
+        stencil_read_mask: 0,
+        stencil_write_mask: 32,
+        front_face: StencilFaceDesc {
+            func: CompareFunc::Always,
+            pass_op: StencilOp::Replace,
+            fail_op: StencilOp::Keep,
+            depth_fail_op: StencilOp::Keep,
+        },
+        back_face: StencilFaceDesc {
+            func: CompareFunc::Always,
+            pass_op: StencilOp::Replace,
+            fail_op: StencilOp::Keep,
+            depth_fail_op: StencilOp::Keep,
+        },
+    },
+    // Stencil 47
+    StencilDesc {
+        stencil_enable: true,
+        stencil_read_mask: 0,
+        stencil_write_mask: 64,
+        front_face: StencilFaceDesc {
+            func: CompareFunc::Always,
+            pass_op: StencilOp::Replace,
+            fail_op: StencilOp::Keep,
+            depth_fail_op: StencilOp::Keep,
+        },
+        back_face: StencilFaceDesc {
+            func: CompareFunc::Always,
+            pass_op: StencilOp::Replace,
+            fail_op: StencilOp::Keep,
+            depth_fail_op: StencilOp::Keep,
+        },
+    },
+    // Stencil 48
+    StencilDesc {
+        stencil_enable: true,
+        stencil_read_mask: 0,
+        stencil_write_mask: 128,
+        front_face: StencilFaceDesc {
+            func: CompareFunc::Always,
+            pass_op: StencilOp::Replace,
+            fail_op: StencilOp::Keep,
+            depth_fail_op: StencilOp::Keep,
+        },
+        back_face: StencilFaceDesc {
+            func: CompareFunc::Always,
+            pass_op: StencilOp::Replace,
+            fail_op: StencilOp::Keep,
+            depth_fail_op: StencilOp::Keep,
+        },
+    },
+];
+
+//endregion